@@ -1,12 +1,50 @@
 pub use sea_orm_migration::prelude::*;
 
 mod m20220101_000001_create_table;
+mod m20220101_000002_create_virtual_accounts_table;
+mod m20220101_000003_create_invoices_table;
+mod m20220101_000004_create_subscriptions_table;
+mod m20220101_000005_create_scheduled_transfers_table;
+mod m20220101_000006_create_account_number_sequence;
+mod m20220101_000007_create_beneficiaries_table;
+mod m20220101_000008_create_payout_batches_table;
+mod m20220101_000009_add_fee_columns;
+mod m20220101_000010_add_kyc_tier_to_users;
+mod m20220101_000011_create_kyc_documents_table;
+mod m20220101_000012_create_risk_decisions_table;
+mod m20220101_000013_create_screening_matches_table;
+mod m20220101_000014_create_account_status_changes_table;
+mod m20220101_000015_create_virtual_account_payments_table;
+mod m20220101_000016_add_invoice_payment_status;
+mod m20220101_000017_create_fee_entries_table;
+mod m20220101_000018_add_billing_anchor_to_subscriptions;
+mod m20220101_000019_add_anchor_to_scheduled_transfers;
 
 pub struct Migrator;
 
 #[async_trait::async_trait]
 impl MigratorTrait for Migrator {
     fn migrations() -> Vec<Box<dyn MigrationTrait>> {
-        vec![Box::new(m20220101_000001_create_table::Migration)]
+        vec![
+            Box::new(m20220101_000001_create_table::Migration),
+            Box::new(m20220101_000002_create_virtual_accounts_table::Migration),
+            Box::new(m20220101_000003_create_invoices_table::Migration),
+            Box::new(m20220101_000004_create_subscriptions_table::Migration),
+            Box::new(m20220101_000005_create_scheduled_transfers_table::Migration),
+            Box::new(m20220101_000006_create_account_number_sequence::Migration),
+            Box::new(m20220101_000007_create_beneficiaries_table::Migration),
+            Box::new(m20220101_000008_create_payout_batches_table::Migration),
+            Box::new(m20220101_000009_add_fee_columns::Migration),
+            Box::new(m20220101_000010_add_kyc_tier_to_users::Migration),
+            Box::new(m20220101_000011_create_kyc_documents_table::Migration),
+            Box::new(m20220101_000012_create_risk_decisions_table::Migration),
+            Box::new(m20220101_000013_create_screening_matches_table::Migration),
+            Box::new(m20220101_000014_create_account_status_changes_table::Migration),
+            Box::new(m20220101_000015_create_virtual_account_payments_table::Migration),
+            Box::new(m20220101_000016_add_invoice_payment_status::Migration),
+            Box::new(m20220101_000017_create_fee_entries_table::Migration),
+            Box::new(m20220101_000018_add_billing_anchor_to_subscriptions::Migration),
+            Box::new(m20220101_000019_add_anchor_to_scheduled_transfers::Migration),
+        ]
     }
 }
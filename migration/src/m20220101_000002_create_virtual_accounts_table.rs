@@ -0,0 +1,93 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Create Virtual Accounts Table
+        let virtual_accounts_table = Table::create()
+            .table(VirtualAccounts::Table)
+            .if_not_exists()
+            .col(
+                ColumnDef::new(VirtualAccounts::VaId)
+                    .integer()
+                    .not_null()
+                    .auto_increment()
+                    .primary_key(),
+            )
+            .col(ColumnDef::new(VirtualAccounts::UserId).integer().not_null())
+            .col(
+                ColumnDef::new(VirtualAccounts::BankCode)
+                    .string_len(50)
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(VirtualAccounts::VaNumber)
+                    .string_len(50)
+                    .unique_key()
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(VirtualAccounts::CreatedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .col(
+                ColumnDef::new(VirtualAccounts::UpdatedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .name("fk-virtual_accounts-user_id")
+                    .from(VirtualAccounts::Table, VirtualAccounts::UserId)
+                    .to(Users::Table, Users::UserId)
+                    .on_update(ForeignKeyAction::Cascade)
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .to_owned();
+        manager.create_table(virtual_accounts_table).await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx-virtual_accounts-user_id-bank_code")
+                    .table(VirtualAccounts::Table)
+                    .col(VirtualAccounts::UserId)
+                    .col(VirtualAccounts::BankCode)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(VirtualAccounts::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum Users {
+    Table,
+    UserId,
+}
+
+#[derive(Iden)]
+enum VirtualAccounts {
+    Table,
+    VaId,
+    UserId,
+    BankCode,
+    VaNumber,
+    CreatedAt,
+    UpdatedAt,
+}
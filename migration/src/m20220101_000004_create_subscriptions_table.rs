@@ -0,0 +1,251 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Create Subscription Plans Table
+        let subscription_plans_table = Table::create()
+            .table(SubscriptionPlans::Table)
+            .if_not_exists()
+            .col(
+                ColumnDef::new(SubscriptionPlans::PlanId)
+                    .integer()
+                    .not_null()
+                    .auto_increment()
+                    .primary_key(),
+            )
+            .col(ColumnDef::new(SubscriptionPlans::MerchantId).integer().not_null())
+            .col(ColumnDef::new(SubscriptionPlans::Name).string_len(100).not_null())
+            .col(ColumnDef::new(SubscriptionPlans::Amount).integer().not_null())
+            .col(
+                ColumnDef::new(SubscriptionPlans::BillingInterval)
+                    .string_len(20)
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(SubscriptionPlans::IntervalCount)
+                    .integer()
+                    .not_null()
+                    .default(1),
+            )
+            .col(
+                ColumnDef::new(SubscriptionPlans::IsActive)
+                    .boolean()
+                    .not_null()
+                    .default(true),
+            )
+            .col(
+                ColumnDef::new(SubscriptionPlans::CreatedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .col(
+                ColumnDef::new(SubscriptionPlans::UpdatedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .name("fk-subscription_plans-merchant_id")
+                    .from(SubscriptionPlans::Table, SubscriptionPlans::MerchantId)
+                    .to(Users::Table, Users::UserId)
+                    .on_update(ForeignKeyAction::Cascade)
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .to_owned();
+        manager.create_table(subscription_plans_table).await?;
+
+        // Create Subscriptions Table
+        let subscriptions_table = Table::create()
+            .table(Subscriptions::Table)
+            .if_not_exists()
+            .col(
+                ColumnDef::new(Subscriptions::SubscriptionId)
+                    .integer()
+                    .not_null()
+                    .auto_increment()
+                    .primary_key(),
+            )
+            .col(ColumnDef::new(Subscriptions::PlanId).integer().not_null())
+            .col(ColumnDef::new(Subscriptions::SubscriberId).integer().not_null())
+            .col(
+                ColumnDef::new(Subscriptions::Status)
+                    .string_len(20)
+                    .not_null()
+                    .default("active"),
+            )
+            .col(ColumnDef::new(Subscriptions::NextChargeAt).timestamp().not_null())
+            .col(
+                ColumnDef::new(Subscriptions::RetryCount)
+                    .integer()
+                    .not_null()
+                    .default(0),
+            )
+            .col(ColumnDef::new(Subscriptions::MandateAcceptedAt).timestamp().not_null())
+            .col(ColumnDef::new(Subscriptions::CancelledAt).timestamp())
+            .col(
+                ColumnDef::new(Subscriptions::CreatedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .col(
+                ColumnDef::new(Subscriptions::UpdatedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .name("fk-subscriptions-plan_id")
+                    .from(Subscriptions::Table, Subscriptions::PlanId)
+                    .to(SubscriptionPlans::Table, SubscriptionPlans::PlanId)
+                    .on_update(ForeignKeyAction::Cascade)
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .name("fk-subscriptions-subscriber_id")
+                    .from(Subscriptions::Table, Subscriptions::SubscriberId)
+                    .to(Users::Table, Users::UserId)
+                    .on_update(ForeignKeyAction::Cascade)
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .to_owned();
+        manager.create_table(subscriptions_table).await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx-subscriptions-status-next_charge_at")
+                    .table(Subscriptions::Table)
+                    .col(Subscriptions::Status)
+                    .col(Subscriptions::NextChargeAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        // Create Subscription Charges Table
+        let subscription_charges_table = Table::create()
+            .table(SubscriptionCharges::Table)
+            .if_not_exists()
+            .col(
+                ColumnDef::new(SubscriptionCharges::ChargeId)
+                    .integer()
+                    .not_null()
+                    .auto_increment()
+                    .primary_key(),
+            )
+            .col(
+                ColumnDef::new(SubscriptionCharges::SubscriptionId)
+                    .integer()
+                    .not_null(),
+            )
+            .col(ColumnDef::new(SubscriptionCharges::TransferId).integer())
+            .col(ColumnDef::new(SubscriptionCharges::Amount).integer().not_null())
+            .col(ColumnDef::new(SubscriptionCharges::Status).string_len(20).not_null())
+            .col(ColumnDef::new(SubscriptionCharges::Attempt).integer().not_null())
+            .col(ColumnDef::new(SubscriptionCharges::FailureReason).text())
+            .col(
+                ColumnDef::new(SubscriptionCharges::CreatedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .col(
+                ColumnDef::new(SubscriptionCharges::UpdatedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .name("fk-subscription_charges-subscription_id")
+                    .from(SubscriptionCharges::Table, SubscriptionCharges::SubscriptionId)
+                    .to(Subscriptions::Table, Subscriptions::SubscriptionId)
+                    .on_update(ForeignKeyAction::Cascade)
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .name("fk-subscription_charges-transfer_id")
+                    .from(SubscriptionCharges::Table, SubscriptionCharges::TransferId)
+                    .to(Transfers::Table, Transfers::TransferId)
+                    .on_update(ForeignKeyAction::Cascade)
+                    .on_delete(ForeignKeyAction::SetNull),
+            )
+            .to_owned();
+        manager.create_table(subscription_charges_table).await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SubscriptionCharges::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(Subscriptions::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(SubscriptionPlans::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum Users {
+    Table,
+    UserId,
+}
+
+#[derive(Iden)]
+enum Transfers {
+    Table,
+    TransferId,
+}
+
+#[derive(Iden)]
+enum SubscriptionPlans {
+    Table,
+    PlanId,
+    MerchantId,
+    Name,
+    Amount,
+    BillingInterval,
+    IntervalCount,
+    IsActive,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(Iden)]
+enum Subscriptions {
+    Table,
+    SubscriptionId,
+    PlanId,
+    SubscriberId,
+    Status,
+    NextChargeAt,
+    RetryCount,
+    MandateAcceptedAt,
+    CancelledAt,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(Iden)]
+enum SubscriptionCharges {
+    Table,
+    ChargeId,
+    SubscriptionId,
+    TransferId,
+    Amount,
+    Status,
+    Attempt,
+    FailureReason,
+    CreatedAt,
+    UpdatedAt,
+}
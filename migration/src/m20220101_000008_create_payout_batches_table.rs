@@ -0,0 +1,216 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Create Payout Batches Table
+        let payout_batches_table = Table::create()
+            .table(PayoutBatches::Table)
+            .if_not_exists()
+            .col(
+                ColumnDef::new(PayoutBatches::BatchId)
+                    .integer()
+                    .not_null()
+                    .auto_increment()
+                    .primary_key(),
+            )
+            .col(ColumnDef::new(PayoutBatches::UserId).integer().not_null())
+            .col(
+                ColumnDef::new(PayoutBatches::Status)
+                    .string_len(20)
+                    .not_null()
+                    .default("pending"),
+            )
+            .col(ColumnDef::new(PayoutBatches::ItemCount).integer().not_null())
+            .col(ColumnDef::new(PayoutBatches::TotalAmount).integer().not_null())
+            .col(ColumnDef::new(PayoutBatches::ReservedAmount).integer().not_null())
+            .col(ColumnDef::new(PayoutBatches::CompletedAt).timestamp())
+            .col(
+                ColumnDef::new(PayoutBatches::CreatedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .col(
+                ColumnDef::new(PayoutBatches::UpdatedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .name("fk-payout_batches-user_id")
+                    .from(PayoutBatches::Table, PayoutBatches::UserId)
+                    .to(Users::Table, Users::UserId)
+                    .on_update(ForeignKeyAction::Cascade)
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .to_owned();
+        manager.create_table(payout_batches_table).await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx-payout_batches-status")
+                    .table(PayoutBatches::Table)
+                    .col(PayoutBatches::Status)
+                    .to_owned(),
+            )
+            .await?;
+
+        // Create Payout Batch Items Table
+        let payout_batch_items_table = Table::create()
+            .table(PayoutBatchItems::Table)
+            .if_not_exists()
+            .col(
+                ColumnDef::new(PayoutBatchItems::ItemId)
+                    .integer()
+                    .not_null()
+                    .auto_increment()
+                    .primary_key(),
+            )
+            .col(ColumnDef::new(PayoutBatchItems::BatchId).integer().not_null())
+            .col(ColumnDef::new(PayoutBatchItems::LineNumber).integer().not_null())
+            .col(ColumnDef::new(PayoutBatchItems::Recipient).string_len(255))
+            .col(ColumnDef::new(PayoutBatchItems::RecipientUserId).integer())
+            .col(ColumnDef::new(PayoutBatchItems::BankCode).string_len(20))
+            .col(ColumnDef::new(PayoutBatchItems::AccountNumber).string_len(20))
+            .col(ColumnDef::new(PayoutBatchItems::AccountName).string_len(255))
+            .col(ColumnDef::new(PayoutBatchItems::Amount).integer().not_null())
+            .col(ColumnDef::new(PayoutBatchItems::Reference).string_len(255))
+            .col(
+                ColumnDef::new(PayoutBatchItems::Status)
+                    .string_len(20)
+                    .not_null()
+                    .default("pending"),
+            )
+            .col(ColumnDef::new(PayoutBatchItems::FailureReason).text())
+            .col(ColumnDef::new(PayoutBatchItems::TransferId).integer())
+            .col(ColumnDef::new(PayoutBatchItems::WithdrawId).integer())
+            .col(ColumnDef::new(PayoutBatchItems::ProcessedAt).timestamp())
+            .col(
+                ColumnDef::new(PayoutBatchItems::CreatedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .col(
+                ColumnDef::new(PayoutBatchItems::UpdatedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .name("fk-payout_batch_items-batch_id")
+                    .from(PayoutBatchItems::Table, PayoutBatchItems::BatchId)
+                    .to(PayoutBatches::Table, PayoutBatches::BatchId)
+                    .on_update(ForeignKeyAction::Cascade)
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .name("fk-payout_batch_items-recipient_user_id")
+                    .from(PayoutBatchItems::Table, PayoutBatchItems::RecipientUserId)
+                    .to(Users::Table, Users::UserId)
+                    .on_update(ForeignKeyAction::Cascade)
+                    .on_delete(ForeignKeyAction::SetNull),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .name("fk-payout_batch_items-transfer_id")
+                    .from(PayoutBatchItems::Table, PayoutBatchItems::TransferId)
+                    .to(Transfers::Table, Transfers::TransferId)
+                    .on_update(ForeignKeyAction::Cascade)
+                    .on_delete(ForeignKeyAction::SetNull),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .name("fk-payout_batch_items-withdraw_id")
+                    .from(PayoutBatchItems::Table, PayoutBatchItems::WithdrawId)
+                    .to(Withdraws::Table, Withdraws::WithdrawId)
+                    .on_update(ForeignKeyAction::Cascade)
+                    .on_delete(ForeignKeyAction::SetNull),
+            )
+            .to_owned();
+        manager.create_table(payout_batch_items_table).await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx-payout_batch_items-batch_id")
+                    .table(PayoutBatchItems::Table)
+                    .col(PayoutBatchItems::BatchId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(PayoutBatchItems::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(PayoutBatches::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum Users {
+    Table,
+    UserId,
+}
+
+#[derive(Iden)]
+enum Transfers {
+    Table,
+    TransferId,
+}
+
+#[derive(Iden)]
+enum Withdraws {
+    Table,
+    WithdrawId,
+}
+
+#[derive(Iden)]
+enum PayoutBatches {
+    Table,
+    BatchId,
+    UserId,
+    Status,
+    ItemCount,
+    TotalAmount,
+    ReservedAmount,
+    CompletedAt,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(Iden)]
+enum PayoutBatchItems {
+    Table,
+    ItemId,
+    BatchId,
+    LineNumber,
+    Recipient,
+    RecipientUserId,
+    BankCode,
+    AccountNumber,
+    AccountName,
+    Amount,
+    Reference,
+    Status,
+    FailureReason,
+    TransferId,
+    WithdrawId,
+    ProcessedAt,
+    CreatedAt,
+    UpdatedAt,
+}
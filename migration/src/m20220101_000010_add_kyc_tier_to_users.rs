@@ -0,0 +1,101 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Users::Table)
+                    .add_column(
+                        ColumnDef::new(Users::KycTier)
+                            .string_len(20)
+                            .not_null()
+                            .default("unverified"),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Rolling limit checks sum recent history per user
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx-transfers-transfer_from-transfer_time")
+                    .table(Transfers::Table)
+                    .col(Transfers::TransferFrom)
+                    .col(Transfers::TransferTime)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx-withdraws-user_id-withdraw_time")
+                    .table(Withdraws::Table)
+                    .col(Withdraws::UserId)
+                    .col(Withdraws::WithdrawTime)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx-withdraws-user_id-withdraw_time")
+                    .table(Withdraws::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx-transfers-transfer_from-transfer_time")
+                    .table(Transfers::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Users::Table)
+                    .drop_column(Users::KycTier)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum Users {
+    Table,
+    KycTier,
+}
+
+#[derive(Iden)]
+enum Transfers {
+    Table,
+    TransferFrom,
+    TransferTime,
+}
+
+#[derive(Iden)]
+enum Withdraws {
+    Table,
+    UserId,
+    WithdrawTime,
+}
@@ -0,0 +1,173 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Create KYC Documents Table
+        let kyc_documents_table = Table::create()
+            .table(KycDocuments::Table)
+            .if_not_exists()
+            .col(
+                ColumnDef::new(KycDocuments::DocumentId)
+                    .integer()
+                    .not_null()
+                    .auto_increment()
+                    .primary_key(),
+            )
+            .col(ColumnDef::new(KycDocuments::UserId).integer().not_null())
+            .col(ColumnDef::new(KycDocuments::DocumentType).string_len(30).not_null())
+            .col(ColumnDef::new(KycDocuments::RequestedTier).string_len(20).not_null())
+            .col(ColumnDef::new(KycDocuments::FileName).string_len(255).not_null())
+            .col(ColumnDef::new(KycDocuments::ContentType).string_len(100).not_null())
+            .col(ColumnDef::new(KycDocuments::FileSize).integer().not_null())
+            .col(ColumnDef::new(KycDocuments::StorageKey).string_len(255).not_null())
+            .col(
+                ColumnDef::new(KycDocuments::Status)
+                    .string_len(20)
+                    .not_null()
+                    .default("pending"),
+            )
+            .col(ColumnDef::new(KycDocuments::RejectionReason).text())
+            .col(ColumnDef::new(KycDocuments::ReviewedBy).integer())
+            .col(ColumnDef::new(KycDocuments::ReviewedAt).timestamp())
+            .col(
+                ColumnDef::new(KycDocuments::CreatedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .col(
+                ColumnDef::new(KycDocuments::UpdatedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .name("fk-kyc_documents-user_id")
+                    .from(KycDocuments::Table, KycDocuments::UserId)
+                    .to(Users::Table, Users::UserId)
+                    .on_update(ForeignKeyAction::Cascade)
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .to_owned();
+        manager.create_table(kyc_documents_table).await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx-kyc_documents-status")
+                    .table(KycDocuments::Table)
+                    .col(KycDocuments::Status)
+                    .to_owned(),
+            )
+            .await?;
+
+        // Create KYC Reviews Table, the audit history of every review decision
+        let kyc_reviews_table = Table::create()
+            .table(KycReviews::Table)
+            .if_not_exists()
+            .col(
+                ColumnDef::new(KycReviews::ReviewId)
+                    .integer()
+                    .not_null()
+                    .auto_increment()
+                    .primary_key(),
+            )
+            .col(ColumnDef::new(KycReviews::DocumentId).integer().not_null())
+            .col(ColumnDef::new(KycReviews::UserId).integer().not_null())
+            .col(ColumnDef::new(KycReviews::ReviewerId).integer().not_null())
+            .col(ColumnDef::new(KycReviews::Decision).string_len(20).not_null())
+            .col(ColumnDef::new(KycReviews::Reason).text())
+            .col(ColumnDef::new(KycReviews::PreviousTier).string_len(20).not_null())
+            .col(ColumnDef::new(KycReviews::NewTier).string_len(20).not_null())
+            .col(
+                ColumnDef::new(KycReviews::CreatedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .name("fk-kyc_reviews-document_id")
+                    .from(KycReviews::Table, KycReviews::DocumentId)
+                    .to(KycDocuments::Table, KycDocuments::DocumentId)
+                    .on_update(ForeignKeyAction::Cascade)
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .name("fk-kyc_reviews-user_id")
+                    .from(KycReviews::Table, KycReviews::UserId)
+                    .to(Users::Table, Users::UserId)
+                    .on_update(ForeignKeyAction::Cascade)
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .to_owned();
+        manager.create_table(kyc_reviews_table).await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx-kyc_reviews-user_id")
+                    .table(KycReviews::Table)
+                    .col(KycReviews::UserId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(KycReviews::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(KycDocuments::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum Users {
+    Table,
+    UserId,
+}
+
+#[derive(Iden)]
+enum KycDocuments {
+    Table,
+    DocumentId,
+    UserId,
+    DocumentType,
+    RequestedTier,
+    FileName,
+    ContentType,
+    FileSize,
+    StorageKey,
+    Status,
+    RejectionReason,
+    ReviewedBy,
+    ReviewedAt,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(Iden)]
+enum KycReviews {
+    Table,
+    ReviewId,
+    DocumentId,
+    UserId,
+    ReviewerId,
+    Decision,
+    Reason,
+    PreviousTier,
+    NewTier,
+    CreatedAt,
+}
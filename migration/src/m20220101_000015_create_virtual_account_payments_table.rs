@@ -0,0 +1,98 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Create Virtual Account Payments Table, one row per bank payment that was credited
+        let virtual_account_payments_table = Table::create()
+            .table(VirtualAccountPayments::Table)
+            .if_not_exists()
+            .col(
+                ColumnDef::new(VirtualAccountPayments::PaymentId)
+                    .integer()
+                    .not_null()
+                    .auto_increment()
+                    .primary_key(),
+            )
+            .col(
+                ColumnDef::new(VirtualAccountPayments::BankCode)
+                    .string_len(50)
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(VirtualAccountPayments::PaymentReference)
+                    .string_len(255)
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(VirtualAccountPayments::TopupId)
+                    .integer()
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(VirtualAccountPayments::CreatedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .name("fk-virtual_account_payments-topup_id")
+                    .from(
+                        VirtualAccountPayments::Table,
+                        VirtualAccountPayments::TopupId,
+                    )
+                    .to(Topups::Table, Topups::TopupId)
+                    .on_update(ForeignKeyAction::Cascade)
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .to_owned();
+        manager.create_table(virtual_account_payments_table).await?;
+
+        // Banks retry notifications, and a reference is only unique within the bank that issued it
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx-virtual_account_payments-bank_code-payment_reference")
+                    .table(VirtualAccountPayments::Table)
+                    .col(VirtualAccountPayments::BankCode)
+                    .col(VirtualAccountPayments::PaymentReference)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(VirtualAccountPayments::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum Topups {
+    Table,
+    TopupId,
+}
+
+#[derive(Iden)]
+enum VirtualAccountPayments {
+    Table,
+    PaymentId,
+    BankCode,
+    PaymentReference,
+    TopupId,
+    CreatedAt,
+}
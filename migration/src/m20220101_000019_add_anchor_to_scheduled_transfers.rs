@@ -0,0 +1,72 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Recurring runs are the anchor plus whole intervals, so month-end clamping never
+        // carries over from one run to the next
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(ScheduledTransfers::Table)
+                    .add_column(
+                        ColumnDef::new(ScheduledTransfers::AnchorAt)
+                            .timestamp()
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Existing schedules are anchored on their upcoming run
+        manager
+            .exec_stmt(
+                Query::update()
+                    .table(ScheduledTransfers::Table)
+                    .value(
+                        ScheduledTransfers::AnchorAt,
+                        Expr::col(ScheduledTransfers::NextRunAt),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(ScheduledTransfers::Table)
+                    .modify_column(
+                        ColumnDef::new(ScheduledTransfers::AnchorAt)
+                            .timestamp()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(ScheduledTransfers::Table)
+                    .drop_column(ScheduledTransfers::AnchorAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum ScheduledTransfers {
+    Table,
+    NextRunAt,
+    AnchorAt,
+}
@@ -0,0 +1,14 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+
+use crate::domain::response::{account::AccountLookupResponse, ApiResponse, ErrorResponse};
+
+
+pub type DynAccountService = Arc<dyn AccountServiceTrait + Send + Sync>;
+
+
+
+#[async_trait]
+pub trait AccountServiceTrait {
+    async fn lookup_account(&self, noc_transfer: &str) -> Result<ApiResponse<AccountLookupResponse>, ErrorResponse>;
+}
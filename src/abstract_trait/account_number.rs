@@ -0,0 +1,23 @@
+use std::sync::Arc;
+use sea_orm::DbErr;
+use async_trait::async_trait;
+
+use crate::{domain::{request::user::CreateUserRequest, response::ErrorResponse}, entities::users};
+
+
+pub type DynAccountNumberRepository = Arc<dyn AccountNumberRepositoryTrait + Send + Sync>;
+pub type DynAccountNumberAllocator = Arc<dyn AccountNumberAllocatorTrait + Send + Sync>;
+
+
+
+#[async_trait]
+pub trait AccountNumberRepositoryTrait {
+    async fn next_sequence(&self) -> Result<i64, DbErr>;
+    async fn exists(&self, noc_transfer: &str) -> Result<bool, DbErr>;
+}
+
+#[async_trait]
+pub trait AccountNumberAllocatorTrait {
+    async fn allocate(&self) -> Result<String, ErrorResponse>;
+    async fn create_user(&self, input: &CreateUserRequest) -> Result<users::Model, ErrorResponse>;
+}
@@ -0,0 +1,24 @@
+use std::sync::Arc;
+use sea_orm::DbErr;
+use async_trait::async_trait;
+
+use crate::{domain::{request::account_status::{CreateAccountStatusChangeRequest, UpdateAccountStatusRequest}, response::{account_status::AccountStatusChangeResponse, user::UserResponse, ApiResponse, ErrorResponse}}, entities::{account_status_changes, users}};
+
+
+pub type DynAccountStatusRepository = Arc<dyn AccountStatusRepositoryTrait + Send + Sync>;
+pub type DynAccountStatusService = Arc<dyn AccountStatusServiceTrait + Send + Sync>;
+
+
+
+#[async_trait]
+pub trait AccountStatusRepositoryTrait {
+    async fn find_by_user(&self, user_id: i32) -> Result<Vec<account_status_changes::Model>, DbErr>;
+    // Returns None when the account was no longer in the previous status
+    async fn change(&self, input: &CreateAccountStatusChangeRequest) -> Result<Option<users::Model>, DbErr>;
+}
+
+#[async_trait]
+pub trait AccountStatusServiceTrait {
+    async fn update_status(&self, input: &UpdateAccountStatusRequest) -> Result<ApiResponse<UserResponse>, ErrorResponse>;
+    async fn get_status_history(&self, user_id: i32) -> Result<ApiResponse<Vec<AccountStatusChangeResponse>>, ErrorResponse>;
+}
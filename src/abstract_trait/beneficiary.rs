@@ -0,0 +1,26 @@
+use std::sync::Arc;
+use sea_orm::DbErr;
+use async_trait::async_trait;
+
+use crate::{domain::{request::beneficiary::CreateBeneficiaryRequest, response::{beneficiary::BeneficiaryResponse, ApiResponse, ErrorResponse}}, entities::beneficiaries};
+
+
+pub type DynBeneficiaryRepository = Arc<dyn BeneficiaryRepositoryTrait + Send + Sync>;
+pub type DynBeneficiaryService = Arc<dyn BeneficiaryServiceTrait + Send + Sync>;
+
+
+
+#[async_trait]
+pub trait BeneficiaryRepositoryTrait {
+    async fn find_by_id(&self, id: i32) -> Result<Option<beneficiaries::Model>, DbErr>;
+    async fn find_by_user(&self, user_id: i32) -> Result<Vec<beneficiaries::Model>, DbErr>;
+    async fn create(&self, input: &CreateBeneficiaryRequest) -> Result<beneficiaries::Model, DbErr>;
+    async fn delete(&self, id: i32) -> Result<(), DbErr>;
+}
+
+#[async_trait]
+pub trait BeneficiaryServiceTrait {
+    async fn get_beneficiary_users(&self, user_id: i32) -> Result<ApiResponse<Vec<BeneficiaryResponse>>, ErrorResponse>;
+    async fn create_beneficiary(&self, input: &CreateBeneficiaryRequest) -> Result<ApiResponse<BeneficiaryResponse>, ErrorResponse>;
+    async fn delete_beneficiary(&self, id: i32, user_id: i32) -> Result<ApiResponse<()>, ErrorResponse>;
+}
@@ -0,0 +1,14 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+
+
+pub type DynDocumentStore = Arc<dyn DocumentStoreTrait + Send + Sync>;
+
+
+
+#[async_trait]
+pub trait DocumentStoreTrait {
+    async fn put(&self, key: &str, content: &[u8]) -> Result<(), String>;
+    async fn get(&self, key: &str) -> Result<Vec<u8>, String>;
+    async fn delete(&self, key: &str) -> Result<(), String>;
+}
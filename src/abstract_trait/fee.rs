@@ -0,0 +1,32 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use sea_orm::DbErr;
+
+use crate::{entities::fee_entries, domain::{request::fee::FeePreviewRequest, response::{fee::FeePreviewResponse, ApiResponse, ErrorResponse}}, utils::fee::{FeeOperation, FeeQuote}};
+
+
+pub type DynFeeRepository = Arc<dyn FeeRepositoryTrait + Send + Sync>;
+pub type DynFeeService = Arc<dyn FeeServiceTrait + Send + Sync>;
+
+
+
+#[async_trait]
+pub trait FeeRepositoryTrait {
+    async fn find_by_status(&self, status: &str) -> Result<Vec<fee_entries::Model>, DbErr>;
+    // Records the entry and credits the revenue account in one transaction
+    async fn book(&self, revenue_user_id: i32, amount: i32, fee_rule: Option<String>) -> Result<fee_entries::Model, DbErr>;
+    async fn queue(&self, amount: i32, fee_rule: Option<String>) -> Result<fee_entries::Model, DbErr>;
+    // Ok(false) when the entry was already booked by another run
+    async fn book_pending(&self, fee_entry_id: i32, revenue_user_id: i32) -> Result<bool, DbErr>;
+}
+
+#[async_trait]
+pub trait FeeServiceTrait {
+    fn quote(&self, operation: FeeOperation, channel: Option<&str>, amount: i32) -> FeeQuote;
+    async fn collect(&self, fee: &FeeQuote) -> Result<(), ErrorResponse>;
+    async fn refund(&self, fee: &FeeQuote) -> Result<(), ErrorResponse>;
+    // Books the difference when an amount change re-quotes a fee that was already collected
+    async fn adjust(&self, previous: &FeeQuote, updated: &FeeQuote) -> Result<(), ErrorResponse>;
+    async fn retry_pending_fees(&self) -> Result<u64, ErrorResponse>;
+    async fn preview_fee(&self, input: &FeePreviewRequest) -> Result<ApiResponse<FeePreviewResponse>, ErrorResponse>;
+}
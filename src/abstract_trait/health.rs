@@ -0,0 +1,18 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+
+use crate::domain::response::{health::HealthDetailsResponse, ApiResponse, ErrorResponse, MessageResponse};
+
+
+pub type DynHealthService = Arc<dyn HealthServiceTrait + Send + Sync>;
+
+
+
+#[async_trait]
+pub trait HealthServiceTrait {
+    // Makes readiness fail from now on, so traffic drains before the server stops
+    fn begin_shutdown(&self);
+    // Fails while shutting down, when the database is unreachable or migrations are pending
+    async fn check_ready(&self) -> Result<MessageResponse, ErrorResponse>;
+    async fn get_details(&self) -> Result<ApiResponse<HealthDetailsResponse>, ErrorResponse>;
+}
@@ -0,0 +1,42 @@
+use std::sync::Arc;
+use chrono::NaiveDateTime;
+use sea_orm::DbErr;
+use async_trait::async_trait;
+
+use crate::{domain::{request::invoice::{CreateInvoicePaymentRequest, CreateInvoiceRequest, PayInvoiceRequest}, response::{invoice::{InvoicePaymentResponse, InvoiceResponse}, ApiResponse, ErrorResponse}}, entities::{invoice_items, invoice_payments, invoices}};
+
+
+pub type DynInvoiceRepository = Arc<dyn InvoiceRepositoryTrait + Send + Sync>;
+pub type DynInvoiceService = Arc<dyn InvoiceServiceTrait + Send + Sync>;
+
+
+
+#[async_trait]
+pub trait InvoiceRepositoryTrait {
+    async fn find_by_id(&self, id: i32) -> Result<Option<invoices::Model>, DbErr>;
+    async fn find_by_token(&self, token: &str) -> Result<Option<invoices::Model>, DbErr>;
+    async fn find_by_merchant(&self, merchant_id: i32) -> Result<Vec<invoices::Model>, DbErr>;
+    async fn find_items(&self, invoice_id: i32) -> Result<Vec<invoice_items::Model>, DbErr>;
+    async fn find_payments(&self, invoice_id: i32) -> Result<Vec<invoice_payments::Model>, DbErr>;
+    // Payments with a transfer attached, e.g. pending ones whose transfer is held for review
+    async fn find_payments_by_status(&self, status: &str) -> Result<Vec<invoice_payments::Model>, DbErr>;
+    async fn create(&self, input: &CreateInvoiceRequest, token: &str) -> Result<invoices::Model, DbErr>;
+    // Counts a pending payment towards the invoice; None when another payment got there first
+    async fn reserve_payment(&self, input: &CreateInvoicePaymentRequest) -> Result<Option<invoice_payments::Model>, DbErr>;
+    async fn complete_payment(&self, payment_id: i32, transfer_id: i32, status: &str) -> Result<invoice_payments::Model, DbErr>;
+    // Takes a payment whose transfer did not go through back off the invoice
+    async fn release_payment(&self, payment_id: i32) -> Result<(), DbErr>;
+    async fn expire_overdue(&self, now: NaiveDateTime) -> Result<u64, DbErr>;
+}
+
+#[async_trait]
+pub trait InvoiceServiceTrait {
+    async fn get_invoice(&self, id: i32) -> Result<ApiResponse<InvoiceResponse>, ErrorResponse>;
+    async fn get_invoice_users(&self, merchant_id: i32) -> Result<ApiResponse<Vec<InvoiceResponse>>, ErrorResponse>;
+    async fn get_invoice_payments(&self, id: i32) -> Result<ApiResponse<Vec<InvoicePaymentResponse>>, ErrorResponse>;
+    async fn get_payment_link(&self, token: &str) -> Result<ApiResponse<InvoiceResponse>, ErrorResponse>;
+    async fn create_invoice(&self, input: &CreateInvoiceRequest) -> Result<ApiResponse<InvoiceResponse>, ErrorResponse>;
+    async fn pay_invoice(&self, input: &PayInvoiceRequest) -> Result<ApiResponse<InvoicePaymentResponse>, ErrorResponse>;
+    async fn expire_invoices(&self) -> Result<u64, ErrorResponse>;
+    async fn settle_held_payments(&self) -> Result<u64, ErrorResponse>;
+}
\ No newline at end of file
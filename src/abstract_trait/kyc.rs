@@ -0,0 +1,32 @@
+use std::sync::Arc;
+use sea_orm::DbErr;
+use async_trait::async_trait;
+
+use crate::{domain::{request::kyc::{CreateKycDocumentRequest, CreateKycReviewRequest, ReviewKycDocumentRequest}, response::{kyc::{KycDocumentFile, KycDocumentResponse, KycReviewResponse}, ApiResponse, ErrorResponse}}, entities::{kyc_documents, kyc_reviews}};
+
+
+pub type DynKycRepository = Arc<dyn KycRepositoryTrait + Send + Sync>;
+pub type DynKycService = Arc<dyn KycServiceTrait + Send + Sync>;
+
+
+
+#[async_trait]
+pub trait KycRepositoryTrait {
+    async fn find_by_id(&self, id: i32) -> Result<Option<kyc_documents::Model>, DbErr>;
+    async fn find_by_user(&self, user_id: i32) -> Result<Vec<kyc_documents::Model>, DbErr>;
+    async fn find_by_status(&self, status: &str) -> Result<Vec<kyc_documents::Model>, DbErr>;
+    async fn find_reviews_by_user(&self, user_id: i32) -> Result<Vec<kyc_reviews::Model>, DbErr>;
+    async fn create(&self, input: &CreateKycDocumentRequest, storage_key: &str) -> Result<kyc_documents::Model, DbErr>;
+    // Returns None when the document was no longer pending
+    async fn review(&self, input: &CreateKycReviewRequest) -> Result<Option<kyc_documents::Model>, DbErr>;
+}
+
+#[async_trait]
+pub trait KycServiceTrait {
+    async fn submit_document(&self, input: &CreateKycDocumentRequest) -> Result<ApiResponse<KycDocumentResponse>, ErrorResponse>;
+    async fn get_document_users(&self, user_id: i32) -> Result<ApiResponse<Vec<KycDocumentResponse>>, ErrorResponse>;
+    async fn get_pending_documents(&self) -> Result<ApiResponse<Vec<KycDocumentResponse>>, ErrorResponse>;
+    async fn get_document_file(&self, id: i32) -> Result<KycDocumentFile, ErrorResponse>;
+    async fn review_document(&self, input: &ReviewKycDocumentRequest) -> Result<ApiResponse<KycDocumentResponse>, ErrorResponse>;
+    async fn get_review_users(&self, user_id: i32) -> Result<ApiResponse<Vec<KycReviewResponse>>, ErrorResponse>;
+}
@@ -0,0 +1,19 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+
+use crate::{domain::response::{limit::UserLimitResponse, ApiResponse, ErrorResponse}, utils::kyc::LimitOperation};
+
+
+pub type DynLimitService = Arc<dyn LimitServiceTrait + Send + Sync>;
+
+
+
+#[async_trait]
+pub trait LimitServiceTrait {
+    // Both checks also refuse accounts whose status does not allow money to leave or arrive
+    async fn check_outgoing(&self, user_id: i32, operation: LimitOperation, amounts: &[i32]) -> Result<(), ErrorResponse>;
+    // Checks an existing debit growing from previous to updated, a decrease always passes
+    async fn check_outgoing_change(&self, user_id: i32, operation: LimitOperation, previous: i32, updated: i32) -> Result<(), ErrorResponse>;
+    async fn check_incoming(&self, user_id: i32, amount: i32) -> Result<(), ErrorResponse>;
+    async fn get_limits(&self, user_id: i32) -> Result<ApiResponse<UserLimitResponse>, ErrorResponse>;
+}
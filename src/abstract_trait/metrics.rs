@@ -0,0 +1,16 @@
+use std::{sync::Arc, time::Duration};
+
+use crate::utils::metrics::{TransactionKind, TransactionOutcome};
+
+
+pub type DynMetrics = Arc<dyn MetricsTrait + Send + Sync>;
+
+
+
+pub trait MetricsTrait {
+    fn observe_request(&self, method: &str, route: &str, status: u16, duration: Duration);
+    fn record_transaction(&self, kind: TransactionKind, outcome: TransactionOutcome);
+    fn record_volume(&self, kind: TransactionKind, channel: &str, amount: i32);
+    fn record_rollback(&self, kind: TransactionKind);
+    fn render(&self) -> Result<String, String>;
+}
@@ -0,0 +1,12 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+
+
+pub type DynNotificationService = Arc<dyn NotificationServiceTrait + Send + Sync>;
+
+
+
+#[async_trait]
+pub trait NotificationServiceTrait {
+    async fn notify(&self, user_id: i32, subject: &str, message: &str);
+}
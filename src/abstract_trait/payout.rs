@@ -0,0 +1,17 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+
+use crate::domain::{request::payout::PayoutRequest, response::payout::PayoutStatus};
+
+
+pub type DynPayoutProvider = Arc<dyn PayoutProviderTrait + Send + Sync>;
+
+
+
+#[async_trait]
+pub trait PayoutProviderTrait {
+    // Returns the provider reference of an accepted payout or the rejection reason. Submitting the
+    // same withdraw_id again returns the original reference, so an unrecorded submission can be retried
+    async fn submit(&self, input: &PayoutRequest) -> Result<String, String>;
+    async fn status(&self, reference: &str) -> Result<PayoutStatus, String>;
+}
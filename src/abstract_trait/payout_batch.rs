@@ -0,0 +1,34 @@
+use std::sync::Arc;
+use sea_orm::DbErr;
+use async_trait::async_trait;
+
+use crate::{domain::{request::payout_batch::{CreatePayoutBatchItemRequest, CreatePayoutBatchRequest, UpdatePayoutBatchItemRequest}, response::{payout_batch::PayoutBatchResponse, ApiResponse, ErrorResponse}}, entities::{payout_batch_items, payout_batches, withdraws}};
+
+
+pub type DynPayoutBatchRepository = Arc<dyn PayoutBatchRepositoryTrait + Send + Sync>;
+pub type DynPayoutBatchService = Arc<dyn PayoutBatchServiceTrait + Send + Sync>;
+
+
+
+#[async_trait]
+pub trait PayoutBatchRepositoryTrait {
+    async fn find_by_id(&self, id: i32) -> Result<Option<payout_batches::Model>, DbErr>;
+    async fn find_by_user(&self, user_id: i32) -> Result<Vec<payout_batches::Model>, DbErr>;
+    async fn find_by_status(&self, status: &str) -> Result<Vec<payout_batches::Model>, DbErr>;
+    async fn find_items(&self, batch_id: i32) -> Result<Vec<payout_batch_items::Model>, DbErr>;
+    async fn find_items_by_status(&self, batch_id: i32, status: &str) -> Result<Vec<payout_batch_items::Model>, DbErr>;
+    async fn find_withdraws(&self, batch_id: i32) -> Result<Vec<withdraws::Model>, DbErr>;
+    async fn create(&self, user_id: i32, total_amount: i32, reserved_amount: i32, items: &[CreatePayoutBatchItemRequest]) -> Result<payout_batches::Model, DbErr>;
+    async fn update_status(&self, batch_id: i32, from_status: &str, status: &str) -> Result<bool, DbErr>;
+    async fn update_item(&self, input: &UpdatePayoutBatchItemRequest) -> Result<payout_batch_items::Model, DbErr>;
+    // Moves a pending item to processing and returns its reserved amount to the batch owner in one transaction
+    async fn claim_item(&self, item_id: i32, user_id: i32) -> Result<Option<payout_batch_items::Model>, DbErr>;
+}
+
+#[async_trait]
+pub trait PayoutBatchServiceTrait {
+    async fn get_payout_batch(&self, id: i32, user_id: i32) -> Result<ApiResponse<PayoutBatchResponse>, ErrorResponse>;
+    async fn get_payout_batch_users(&self, user_id: i32) -> Result<ApiResponse<Vec<PayoutBatchResponse>>, ErrorResponse>;
+    async fn create_payout_batch(&self, input: &CreatePayoutBatchRequest) -> Result<ApiResponse<PayoutBatchResponse>, ErrorResponse>;
+    async fn run_pending_batches(&self) -> Result<u64, ErrorResponse>;
+}
@@ -0,0 +1,14 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+
+use crate::domain::{request::qris::{CreateQrisRequest, ParseQrisRequest, RenderQrisRequest}, response::{qris::{QrisImage, QrisResponse}, ApiResponse, ErrorResponse}};
+
+
+pub type DynQrisService = Arc<dyn QrisServiceTrait + Send + Sync>;
+
+#[async_trait]
+pub trait QrisServiceTrait {
+    async fn generate_qris(&self, input: &CreateQrisRequest) -> Result<ApiResponse<QrisResponse>, ErrorResponse>;
+    async fn parse_qris(&self, input: &ParseQrisRequest) -> Result<ApiResponse<QrisResponse>, ErrorResponse>;
+    async fn render_qris(&self, input: &RenderQrisRequest) -> Result<QrisImage, ErrorResponse>;
+}
\ No newline at end of file
@@ -0,0 +1,15 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+
+use crate::utils::rate_limit::{RateLimitDecision, RateLimitGroup, RateLimitIdentities};
+
+
+pub type DynRateLimitService = Arc<dyn RateLimitServiceTrait + Send + Sync>;
+
+
+
+#[async_trait]
+pub trait RateLimitServiceTrait {
+    fn is_limited(&self, group: RateLimitGroup) -> bool;
+    async fn check(&self, group: RateLimitGroup, identities: &RateLimitIdentities) -> Option<RateLimitDecision>;
+}
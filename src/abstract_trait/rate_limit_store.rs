@@ -0,0 +1,15 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+
+use crate::utils::rate_limit::{RateLimitDecision, RateLimitRule};
+
+
+pub type DynRateLimitStore = Arc<dyn RateLimitStoreTrait + Send + Sync>;
+
+
+
+// Token buckets keyed by group and identity; a shared backend lets several instances enforce one limit
+#[async_trait]
+pub trait RateLimitStoreTrait {
+    async fn take(&self, key: &str, rule: &RateLimitRule) -> Result<RateLimitDecision, String>;
+}
@@ -0,0 +1,34 @@
+use std::sync::Arc;
+use chrono::NaiveDateTime;
+use sea_orm::DbErr;
+use async_trait::async_trait;
+
+use crate::{domain::{request::risk::{CreateRiskDecisionRequest, ResolveRiskReviewRequest, RiskAssessmentRequest}, response::{risk::{RiskAssessment, RiskDecisionResponse}, ApiResponse, ErrorResponse}}, entities::risk_decisions, utils::risk::RiskOperation};
+
+
+pub type DynRiskRepository = Arc<dyn RiskRepositoryTrait + Send + Sync>;
+pub type DynRiskEngine = Arc<dyn RiskEngineTrait + Send + Sync>;
+
+
+
+#[async_trait]
+pub trait RiskRepositoryTrait {
+    async fn find_by_user(&self, user_id: i32) -> Result<Vec<risk_decisions::Model>, DbErr>;
+    async fn find_by_review_status(&self, status: &str) -> Result<Vec<risk_decisions::Model>, DbErr>;
+    async fn find_by_reference(&self, operation: RiskOperation, reference_id: i32) -> Result<Option<risk_decisions::Model>, DbErr>;
+    // Counts operations that went through, i.e. decisions linked to a created transaction
+    async fn count_since(&self, user_id: i32, operation: RiskOperation, since: NaiveDateTime) -> Result<u64, DbErr>;
+    async fn create(&self, input: &CreateRiskDecisionRequest) -> Result<risk_decisions::Model, DbErr>;
+    async fn set_reference(&self, decision_id: i32, reference_id: i32) -> Result<(), DbErr>;
+    async fn resolve(&self, decision_id: i32, input: &ResolveRiskReviewRequest) -> Result<bool, DbErr>;
+}
+
+#[async_trait]
+pub trait RiskEngineTrait {
+    // Blocked operations come back as an error; allowed and held ones as an assessment
+    async fn assess(&self, input: &RiskAssessmentRequest) -> Result<RiskAssessment, ErrorResponse>;
+    async fn attach(&self, decision_id: i32, reference_id: i32);
+    async fn resolve(&self, operation: RiskOperation, input: &ResolveRiskReviewRequest) -> Result<(), ErrorResponse>;
+    async fn get_pending_reviews(&self) -> Result<ApiResponse<Vec<RiskDecisionResponse>>, ErrorResponse>;
+    async fn get_decision_users(&self, user_id: i32) -> Result<ApiResponse<Vec<RiskDecisionResponse>>, ErrorResponse>;
+}
@@ -4,10 +4,14 @@ use std::sync::Arc;
 
 use crate::{
     domain::{
-        request::saldo::{
-            CreateSaldoRequest, UpdateSaldoBalance, UpdateSaldoRequest, UpdateSaldoWithdraw,
+        request::{
+            pagination::ListRequest,
+            saldo::{CreateSaldoRequest, UpdateSaldoBalance, UpdateSaldoRequest},
+        },
+        response::{
+            pagination::Pagination, saldo::SaldoResponse, ApiResponse, ApiResponsePagination,
+            ErrorResponse,
         },
-        response::{saldo::SaldoResponse, ApiResponse, ErrorResponse},
     },
     entities::saldo,
 };
@@ -17,7 +21,10 @@ pub type DynSaldoService = Arc<dyn SaldoServiceTrait + Send + Sync>;
 
 #[async_trait]
 pub trait SaldoRepositoryTrait {
-    async fn find_all(&self) -> Result<Vec<saldo::Model>, DbErr>;
+    async fn find_paginated(
+        &self,
+        input: &ListRequest,
+    ) -> Result<(Vec<saldo::Model>, Pagination), DbErr>;
     async fn find_by_id(&self, id: i32) -> Result<Option<saldo::Model>, DbErr>;
 
     async fn find_by_users_id(&self, id: i32) -> Result<Vec<Option<saldo::Model>>, DbErr>;
@@ -26,16 +33,19 @@ pub trait SaldoRepositoryTrait {
     async fn create(&self, input: &CreateSaldoRequest) -> Result<saldo::Model, DbErr>;
     async fn update(&self, input: &UpdateSaldoRequest) -> Result<saldo::Model, DbErr>;
     async fn update_balance(&self, input: &UpdateSaldoBalance) -> Result<saldo::Model, DbErr>;
-    async fn update_saldo_withdraw(
-        &self,
-        input: &UpdateSaldoWithdraw,
-    ) -> Result<saldo::Model, DbErr>;
+    // Ok(false) when the saldo is missing or would go below the amount
+    async fn debit(&self, user_id: i32, amount: i32) -> Result<bool, DbErr>;
+    // Ok(false) when the user has no saldo
+    async fn credit(&self, user_id: i32, amount: i32) -> Result<bool, DbErr>;
     async fn delete(&self, id: i32) -> Result<(), DbErr>;
 }
 
 #[async_trait]
 pub trait SaldoServiceTrait {
-    async fn get_saldos(&self) -> Result<ApiResponse<Vec<SaldoResponse>>, ErrorResponse>;
+    async fn get_saldos(
+        &self,
+        input: &ListRequest,
+    ) -> Result<ApiResponsePagination<Vec<SaldoResponse>>, ErrorResponse>;
     async fn get_saldo(&self, id: i32)
         -> Result<ApiResponse<Option<SaldoResponse>>, ErrorResponse>;
     async fn get_saldo_users(
@@ -0,0 +1,42 @@
+use std::sync::Arc;
+use chrono::NaiveDateTime;
+use sea_orm::DbErr;
+use async_trait::async_trait;
+
+use crate::{domain::{request::scheduled_transfer::{CreateScheduledTransferExecutionRequest, CreateScheduledTransferRequest, UpdateScheduledTransferRequest, UpdateScheduledTransferRunRequest}, response::{scheduled_transfer::{ScheduledTransferExecutionResponse, ScheduledTransferResponse}, ApiResponse, ErrorResponse}}, entities::{scheduled_transfer_executions, scheduled_transfers}};
+
+
+pub type DynScheduledTransferRepository = Arc<dyn ScheduledTransferRepositoryTrait + Send + Sync>;
+pub type DynScheduledTransferService = Arc<dyn ScheduledTransferServiceTrait + Send + Sync>;
+
+
+
+#[async_trait]
+pub trait ScheduledTransferRepositoryTrait {
+    async fn find_by_id(&self, id: i32) -> Result<Option<scheduled_transfers::Model>, DbErr>;
+    async fn find_by_user(&self, user_id: i32) -> Result<Vec<scheduled_transfers::Model>, DbErr>;
+    async fn find_due(&self, now: NaiveDateTime) -> Result<Vec<scheduled_transfers::Model>, DbErr>;
+    async fn find_executions(&self, id: i32) -> Result<Vec<scheduled_transfer_executions::Model>, DbErr>;
+    async fn create(&self, input: &CreateScheduledTransferRequest) -> Result<scheduled_transfers::Model, DbErr>;
+    async fn update(&self, input: &UpdateScheduledTransferRequest) -> Result<scheduled_transfers::Model, DbErr>;
+    // Ok(false) when the schedule is no longer active or its due occurrence has already moved
+    async fn claim_run(&self, input: &UpdateScheduledTransferRunRequest, due_at: NaiveDateTime) -> Result<bool, DbErr>;
+    // Ok(false) when the one-off run is no longer processing, e.g. it was cancelled
+    async fn finish_run(&self, id: i32, status: &str) -> Result<bool, DbErr>;
+    async fn cancel(&self, id: i32) -> Result<scheduled_transfers::Model, DbErr>;
+    async fn record_execution(&self, input: &CreateScheduledTransferExecutionRequest) -> Result<scheduled_transfer_executions::Model, DbErr>;
+    async fn find_executions_by_status(&self, status: &str) -> Result<Vec<scheduled_transfer_executions::Model>, DbErr>;
+    // Ok(false) when the execution is no longer pending
+    async fn settle_execution(&self, execution_id: i32, status: &str, failure_reason: Option<String>) -> Result<bool, DbErr>;
+}
+
+#[async_trait]
+pub trait ScheduledTransferServiceTrait {
+    async fn get_scheduled_transfer(&self, id: i32) -> Result<ApiResponse<ScheduledTransferResponse>, ErrorResponse>;
+    async fn get_scheduled_transfer_users(&self, user_id: i32) -> Result<ApiResponse<Vec<ScheduledTransferResponse>>, ErrorResponse>;
+    async fn get_scheduled_transfer_executions(&self, id: i32) -> Result<ApiResponse<Vec<ScheduledTransferExecutionResponse>>, ErrorResponse>;
+    async fn create_scheduled_transfer(&self, input: &CreateScheduledTransferRequest) -> Result<ApiResponse<ScheduledTransferResponse>, ErrorResponse>;
+    async fn update_scheduled_transfer(&self, input: &UpdateScheduledTransferRequest) -> Result<ApiResponse<ScheduledTransferResponse>, ErrorResponse>;
+    async fn delete_scheduled_transfer(&self, id: i32, user_id: i32) -> Result<ApiResponse<()>, ErrorResponse>;
+    async fn run_due_transfers(&self) -> Result<u64, ErrorResponse>;
+}
@@ -0,0 +1,36 @@
+use std::sync::Arc;
+use sea_orm::DbErr;
+use async_trait::async_trait;
+
+use crate::{domain::{request::screening::{CreateScreeningMatchRequest, ReviewScreeningMatchRequest}, response::{screening::{ScreeningMatchResponse, WatchlistStatusResponse}, ApiResponse, ErrorResponse}}, entities::{screening_matches, users}};
+
+
+pub type DynScreeningRepository = Arc<dyn ScreeningRepositoryTrait + Send + Sync>;
+pub type DynScreeningService = Arc<dyn ScreeningServiceTrait + Send + Sync>;
+
+
+
+#[async_trait]
+pub trait ScreeningRepositoryTrait {
+    async fn find_by_id(&self, id: i32) -> Result<Option<screening_matches::Model>, DbErr>;
+    async fn find_by_user(&self, user_id: i32) -> Result<Vec<screening_matches::Model>, DbErr>;
+    async fn find_by_status(&self, status: &str) -> Result<Vec<screening_matches::Model>, DbErr>;
+    async fn find_by_user_entry(&self, user_id: i32, entry_reference: &str) -> Result<Option<screening_matches::Model>, DbErr>;
+    // Matches that are still pending or were confirmed
+    async fn count_open(&self, user_id: i32) -> Result<u64, DbErr>;
+    async fn create(&self, input: &CreateScreeningMatchRequest) -> Result<screening_matches::Model, DbErr>;
+    async fn review(&self, input: &ReviewScreeningMatchRequest) -> Result<Option<screening_matches::Model>, DbErr>;
+}
+
+#[async_trait]
+pub trait ScreeningServiceTrait {
+    // Records any new watchlist matches for the user and returns how many are still open
+    async fn screen_user(&self, user: &users::Model, context: &str) -> Result<u64, ErrorResponse>;
+    // Refuses a recipient that has an open watchlist match
+    async fn check_recipient(&self, user_id: i32) -> Result<(), ErrorResponse>;
+    async fn get_pending_matches(&self) -> Result<ApiResponse<Vec<ScreeningMatchResponse>>, ErrorResponse>;
+    async fn get_match_users(&self, user_id: i32) -> Result<ApiResponse<Vec<ScreeningMatchResponse>>, ErrorResponse>;
+    async fn review_match(&self, input: &ReviewScreeningMatchRequest) -> Result<ApiResponse<ScreeningMatchResponse>, ErrorResponse>;
+    async fn get_watchlist(&self) -> Result<ApiResponse<WatchlistStatusResponse>, ErrorResponse>;
+    async fn reload_watchlist(&self) -> Result<ApiResponse<WatchlistStatusResponse>, ErrorResponse>;
+}
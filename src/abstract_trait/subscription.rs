@@ -0,0 +1,48 @@
+use std::sync::Arc;
+use chrono::NaiveDateTime;
+use sea_orm::DbErr;
+use async_trait::async_trait;
+
+use crate::{domain::{request::subscription::{CreateSubscriptionChargeRequest, CreateSubscriptionPlanRequest, CreateSubscriptionRequest, UpdateSubscriptionScheduleRequest}, response::{subscription::{SubscriptionChargeResponse, SubscriptionPlanResponse, SubscriptionResponse}, ApiResponse, ErrorResponse}}, entities::{subscription_charges, subscription_plans, subscriptions}};
+
+
+pub type DynSubscriptionRepository = Arc<dyn SubscriptionRepositoryTrait + Send + Sync>;
+pub type DynSubscriptionService = Arc<dyn SubscriptionServiceTrait + Send + Sync>;
+
+
+
+#[async_trait]
+pub trait SubscriptionRepositoryTrait {
+    async fn find_plan_by_id(&self, id: i32) -> Result<Option<subscription_plans::Model>, DbErr>;
+    async fn find_plans_by_merchant(&self, merchant_id: i32) -> Result<Vec<subscription_plans::Model>, DbErr>;
+    async fn create_plan(&self, input: &CreateSubscriptionPlanRequest) -> Result<subscription_plans::Model, DbErr>;
+    async fn find_by_id(&self, id: i32) -> Result<Option<subscriptions::Model>, DbErr>;
+    async fn find_by_subscriber(&self, subscriber_id: i32) -> Result<Vec<subscriptions::Model>, DbErr>;
+    async fn find_due(&self, now: NaiveDateTime) -> Result<Vec<subscriptions::Model>, DbErr>;
+    async fn create(&self, input: &CreateSubscriptionRequest, next_charge_at: NaiveDateTime) -> Result<subscriptions::Model, DbErr>;
+    // Ok(false) when the subscription changed since it was loaded
+    async fn claim_charge(&self, subscription: &subscriptions::Model) -> Result<bool, DbErr>;
+    // Ok(None) when the subscription is no longer in `current_status`
+    async fn update_schedule(&self, input: &UpdateSubscriptionScheduleRequest, current_status: &str) -> Result<Option<subscriptions::Model>, DbErr>;
+    async fn update_status(&self, id: i32, status: &str) -> Result<subscriptions::Model, DbErr>;
+    async fn find_charges(&self, subscription_id: i32) -> Result<Vec<subscription_charges::Model>, DbErr>;
+    async fn record_charge(&self, input: &CreateSubscriptionChargeRequest) -> Result<subscription_charges::Model, DbErr>;
+    async fn find_charges_by_status(&self, status: &str) -> Result<Vec<subscription_charges::Model>, DbErr>;
+    // Ok(false) when the charge is no longer pending
+    async fn settle_charge(&self, charge_id: i32, status: &str, failure_reason: Option<String>) -> Result<bool, DbErr>;
+}
+
+#[async_trait]
+pub trait SubscriptionServiceTrait {
+    async fn get_plan(&self, id: i32) -> Result<ApiResponse<SubscriptionPlanResponse>, ErrorResponse>;
+    async fn get_plan_users(&self, merchant_id: i32) -> Result<ApiResponse<Vec<SubscriptionPlanResponse>>, ErrorResponse>;
+    async fn create_plan(&self, input: &CreateSubscriptionPlanRequest) -> Result<ApiResponse<SubscriptionPlanResponse>, ErrorResponse>;
+    async fn get_subscription(&self, id: i32) -> Result<ApiResponse<SubscriptionResponse>, ErrorResponse>;
+    async fn get_subscription_users(&self, subscriber_id: i32) -> Result<ApiResponse<Vec<SubscriptionResponse>>, ErrorResponse>;
+    async fn get_subscription_charges(&self, id: i32) -> Result<ApiResponse<Vec<SubscriptionChargeResponse>>, ErrorResponse>;
+    async fn create_subscription(&self, input: &CreateSubscriptionRequest) -> Result<ApiResponse<SubscriptionResponse>, ErrorResponse>;
+    async fn pause_subscription(&self, id: i32, user_id: i32) -> Result<ApiResponse<SubscriptionResponse>, ErrorResponse>;
+    async fn resume_subscription(&self, id: i32, user_id: i32) -> Result<ApiResponse<SubscriptionResponse>, ErrorResponse>;
+    async fn cancel_subscription(&self, id: i32, user_id: i32) -> Result<ApiResponse<SubscriptionResponse>, ErrorResponse>;
+    async fn run_due_charges(&self) -> Result<u64, ErrorResponse>;
+}
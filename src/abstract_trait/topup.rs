@@ -4,10 +4,20 @@ use std::sync::Arc;
 
 use crate::{
     domain::{
-        request::topup::{CreateTopupRequest, UpdateTopupAmount, UpdateTopupRequest},
-        response::{topup::TopupResponse, ApiResponse, ErrorResponse},
+        request::{
+            pagination::ListRequest,
+            risk::ResolveRiskReviewRequest,
+            topup::{
+                BankPaymentReference, CreateTopupRequest, UpdateTopupAmount, UpdateTopupRequest,
+            },
+        },
+        response::{
+            pagination::Pagination, topup::TopupResponse, ApiResponse, ApiResponsePagination,
+            ErrorResponse,
+        },
     },
     entities::topups,
+    utils::fee::FeeQuote,
 };
 
 pub type DynTopupRepository = Arc<dyn TopupRepositoryTrait + Send + Sync>;
@@ -15,7 +25,10 @@ pub type DynTopupService = Arc<dyn TopupServiceTrait + Send + Sync>;
 
 #[async_trait]
 pub trait TopupRepositoryTrait {
-    async fn find_all(&self) -> Result<Vec<topups::Model>, DbErr>;
+    async fn find_paginated(
+        &self,
+        input: &ListRequest,
+    ) -> Result<(Vec<topups::Model>, Pagination), DbErr>;
 
     async fn find_by_id(&self, id: i32) -> Result<Option<topups::Model>, DbErr>;
 
@@ -23,18 +36,44 @@ pub trait TopupRepositoryTrait {
 
     async fn find_by_user(&self, id: i32) -> Result<Option<topups::Model>, DbErr>;
 
-    async fn create(&self, input: &CreateTopupRequest) -> Result<topups::Model, DbErr>;
+    async fn find_by_bank_payment(
+        &self,
+        payment: &BankPaymentReference,
+    ) -> Result<Option<topups::Model>, DbErr>;
+
+    async fn create(
+        &self,
+        input: &CreateTopupRequest,
+        fee: &FeeQuote,
+        status: &str,
+    ) -> Result<topups::Model, DbErr>;
+
+    // Records the topup, claims the bank reference and credits `credit` to the saldo in one
+    // transaction; None when the reference was already claimed
+    async fn create_for_bank_payment(
+        &self,
+        input: &CreateTopupRequest,
+        fee: &FeeQuote,
+        status: &str,
+        payment: &BankPaymentReference,
+        credit: Option<i32>,
+    ) -> Result<Option<topups::Model>, DbErr>;
 
     async fn update(&self, input: &UpdateTopupRequest) -> Result<topups::Model, DbErr>;
 
     async fn update_amount(&self, input: &UpdateTopupAmount) -> Result<topups::Model, DbErr>;
 
+    async fn update_status(&self, id: i32, from_status: &str, status: &str) -> Result<bool, DbErr>;
+
     async fn delete(&self, id: i32) -> Result<(), DbErr>;
 }
 
 #[async_trait]
 pub trait TopupServiceTrait {
-    async fn get_topups(&self) -> Result<ApiResponse<Vec<TopupResponse>>, ErrorResponse>;
+    async fn get_topups(
+        &self,
+        input: &ListRequest,
+    ) -> Result<ApiResponsePagination<Vec<TopupResponse>>, ErrorResponse>;
     async fn get_topup(&self, id: i32)
         -> Result<ApiResponse<Option<TopupResponse>>, ErrorResponse>;
     async fn get_topup_users(
@@ -49,6 +88,15 @@ pub trait TopupServiceTrait {
         &self,
         input: &CreateTopupRequest,
     ) -> Result<ApiResponse<TopupResponse>, ErrorResponse>;
+    async fn create_bank_topup(
+        &self,
+        input: &CreateTopupRequest,
+        payment: &BankPaymentReference,
+    ) -> Result<ApiResponse<TopupResponse>, ErrorResponse>;
+    async fn review_topup(
+        &self,
+        input: &ResolveRiskReviewRequest,
+    ) -> Result<ApiResponse<TopupResponse>, ErrorResponse>;
     async fn update_topup(
         &self,
         input: &UpdateTopupRequest,
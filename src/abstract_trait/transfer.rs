@@ -1,8 +1,9 @@
 use std::sync::Arc;
+use chrono::NaiveDateTime;
 use sea_orm::DbErr;
 use async_trait::async_trait;
 
-use crate::{domain::{request::transfer::{CreateTransferRequest, UpdateTransferAmountRequest, UpdateTransferRequest}, response::{transfer::TransferResponse, ApiResponse, ErrorResponse}}, entities::transfers};
+use crate::{domain::{request::{pagination::ListRequest, risk::ResolveRiskReviewRequest, transfer::{CreateTransferRequest, UpdateTransferAmountRequest, UpdateTransferRequest}}, response::{pagination::Pagination, transfer::TransferResponse, ApiResponse, ApiResponsePagination, ErrorResponse}}, entities::transfers, utils::fee::FeeQuote};
 
 
 pub type DynTransferRepository = Arc<dyn TransferRepositoryTrait + Send + Sync>;
@@ -12,23 +13,27 @@ pub type DynTransferService = Arc<dyn TransferServiceTrait + Send + Sync>;
 
 #[async_trait]
 pub trait TransferRepositoryTrait {
-    async fn find_all(&self) -> Result<Vec<transfers::Model>, DbErr>;
+    async fn find_paginated(&self, input: &ListRequest) -> Result<(Vec<transfers::Model>, Pagination), DbErr>;
     async fn find_by_id(&self, id: i32) -> Result<Option<transfers::Model>, DbErr>;
     async fn find_by_users(&self, id: i32) -> Result<Option<Vec<transfers::Model>>, DbErr> ;
     async fn find_by_user(&self, id: i32) ->  Result<Option<transfers::Model>, DbErr>;
-    async fn create(&self, input: &CreateTransferRequest) -> Result<transfers::Model, DbErr>;
-    async fn update(&self, input: &UpdateTransferRequest) -> Result<transfers::Model, DbErr>;
+    async fn create(&self, input: &CreateTransferRequest, fee: &FeeQuote, status: &str) -> Result<transfers::Model, DbErr>;
+    async fn update(&self, input: &UpdateTransferRequest, fee: &FeeQuote) -> Result<transfers::Model, DbErr>;
     async fn update_amount(&self, input: &UpdateTransferAmountRequest) -> Result<transfers::Model, DbErr>;
+    async fn sum_sent_since(&self, user_id: i32, since: NaiveDateTime) -> Result<i64, DbErr>;
+    async fn count_between(&self, transfer_from: i32, transfer_to: i32, since: Option<NaiveDateTime>) -> Result<u64, DbErr>;
+    async fn update_status(&self, id: i32, from_status: &str, status: &str) -> Result<bool, DbErr>;
     async fn delete(&self, id: i32) -> Result<(), DbErr>;
 }
 
 #[async_trait]
 pub trait TransferServiceTrait {
-    async fn get_transfers(&self) -> Result<ApiResponse<Vec<TransferResponse>>, ErrorResponse>;
+    async fn get_transfers(&self, input: &ListRequest) -> Result<ApiResponsePagination<Vec<TransferResponse>>, ErrorResponse>;
     async fn get_transfer(&self, id: i32) -> Result<ApiResponse<Option<TransferResponse>>, ErrorResponse>;
     async fn get_transfer_users(&self, id: i32) -> Result<ApiResponse<Option<Vec<TransferResponse>>>, ErrorResponse>;
     async fn get_transfer_user(&self, id: i32) -> Result<ApiResponse<Option<TransferResponse>>, ErrorResponse> ;
     async fn create_transfer(&self, input: &CreateTransferRequest) -> Result<ApiResponse<TransferResponse>, ErrorResponse>;
     async fn update_transfer(&self, input: &UpdateTransferRequest) -> Result<ApiResponse<TransferResponse>, ErrorResponse> ;
+    async fn review_transfer(&self, input: &ResolveRiskReviewRequest) -> Result<ApiResponse<TransferResponse>, ErrorResponse>;
     async fn delete_transfer(&self, id: i32) -> Result<ApiResponse<()>, ErrorResponse>;
 }
\ No newline at end of file
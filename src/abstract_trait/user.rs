@@ -3,7 +3,7 @@ use std::sync::Arc;
 use async_trait::async_trait;
 use sea_orm::DbErr;
 
-use crate::{domain::{request::{auth::RegisterRequest, user::{CreateUserRequest, UpdateUserRequest}}, response::{user::UserResponse, ApiResponse, ErrorResponse}}, entities::users};
+use crate::{domain::{request::{auth::RegisterRequest, pagination::ListRequest, user::{CreateUserRequest, UpdateUserRequest}}, response::{pagination::Pagination, user::UserResponse, ApiResponse, ApiResponsePagination, ErrorResponse}}, entities::users};
 
 
 pub type DynUserRepository = Arc<dyn UserRepositoryTrait + Send + Sync>;
@@ -11,7 +11,7 @@ pub type DynUserService = Arc<dyn UserServiceTrait + Send + Sync>;
 
 #[async_trait]
 pub trait UserRepositoryTrait {
-    async fn find_all(&self) -> Result<Vec<users::Model>, DbErr>;
+    async fn find_paginated(&self, input: &ListRequest) -> Result<(Vec<users::Model>, Pagination), DbErr>;
     async fn find_by_email_exists(&self, email: &str) -> Result<bool, DbErr>;
     async fn create_user(
         &self,
@@ -19,17 +19,17 @@ pub trait UserRepositoryTrait {
     ) -> Result<users::Model, DbErr>;
     async fn find_by_email(&self, email: &str) -> Result<Option<users::Model>, DbErr>;
     async fn find_by_id(&self, id: i32) -> Result<Option<users::Model>, DbErr>;
+    async fn find_by_noc_transfer(&self, noc_transfer: &str) -> Result<Option<users::Model>, DbErr>;
     async fn update_user(
         &self,
         input: &UpdateUserRequest
     ) -> Result<users::Model, DbErr>;
-    async fn delete_user(&self, id: i32) -> Result<(), DbErr>;
 }
 
 
 #[async_trait]
 pub trait UserServiceTrait{
-    async fn get_users(&self) -> Result<ApiResponse<Vec<UserResponse>>, ErrorResponse>;
+    async fn get_users(&self, input: &ListRequest) -> Result<ApiResponsePagination<Vec<UserResponse>>, ErrorResponse>;
     async fn find_by_id(&self, id: i32) -> Result<ApiResponse<Option<UserResponse>>, ErrorResponse>;
     async fn create_user(
         &self,
@@ -39,5 +39,4 @@ pub trait UserServiceTrait{
         &self,
         input: &UpdateUserRequest
     ) -> Result<Option<ApiResponse<UserResponse>>, ErrorResponse>;
-    async fn delete_user(&self, id: i32) -> Result<ApiResponse<()>, ErrorResponse>;
 }
\ No newline at end of file
@@ -0,0 +1,28 @@
+use std::sync::Arc;
+use sea_orm::DbErr;
+use async_trait::async_trait;
+
+use crate::{domain::{request::virtual_account::{CreateVirtualAccountRequest, VirtualAccountNotificationRequest}, response::{topup::TopupResponse, virtual_account::VirtualAccountResponse, ApiResponse, ErrorResponse}}, entities::virtual_accounts};
+
+
+pub type DynVirtualAccountRepository = Arc<dyn VirtualAccountRepositoryTrait + Send + Sync>;
+pub type DynVirtualAccountService = Arc<dyn VirtualAccountServiceTrait + Send + Sync>;
+
+
+
+#[async_trait]
+pub trait VirtualAccountRepositoryTrait {
+    async fn find_by_user(&self, user_id: i32) -> Result<Vec<virtual_accounts::Model>, DbErr>;
+    async fn find_by_va_number(&self, va_number: &str) -> Result<Option<virtual_accounts::Model>, DbErr>;
+    async fn create(&self, input: &CreateVirtualAccountRequest) -> Result<virtual_accounts::Model, DbErr>;
+}
+
+#[async_trait]
+pub trait VirtualAccountServiceTrait {
+    async fn get_virtual_accounts(&self, user_id: i32) -> Result<ApiResponse<Vec<VirtualAccountResponse>>, ErrorResponse>;
+    // Issues a virtual account for every configured bank the user does not have one with yet
+    async fn issue_virtual_accounts(&self, user_id: i32) -> Result<ApiResponse<Vec<VirtualAccountResponse>>, ErrorResponse>;
+    // Checks the bank's signature over the raw body before anything in it is trusted
+    fn verify_notification(&self, bank_code: &str, signature: &str, body: &[u8]) -> Result<(), ErrorResponse>;
+    async fn handle_payment_notification(&self, bank_code: &str, input: &VirtualAccountNotificationRequest) -> Result<ApiResponse<TopupResponse>, ErrorResponse>;
+}
\ No newline at end of file
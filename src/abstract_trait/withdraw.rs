@@ -1,8 +1,9 @@
 use std::sync::Arc;
+use chrono::NaiveDateTime;
 use sea_orm::DbErr;
 use async_trait::async_trait;
 
-use crate::{domain::{request::withdraw::{CreateWithdrawRequest, UpdateWithdrawRequest}, response::{withdraw::WithdrawResponse, ApiResponse, ErrorResponse}}, entities::withdraws};
+use crate::{domain::{request::{pagination::ListRequest, risk::ResolveRiskReviewRequest, withdraw::{CreateWithdrawRequest, UpdateWithdrawPayoutRequest, UpdateWithdrawRequest}}, response::{pagination::Pagination, withdraw::WithdrawResponse, ApiResponse, ApiResponsePagination, ErrorResponse}}, entities::withdraws, utils::fee::FeeQuote};
 
 
 pub type DynWithdrawRepository = Arc<dyn WithdrawRepositoryTrait + Send + Sync>;
@@ -12,22 +13,28 @@ pub type DynWithdrawService = Arc<dyn WithdrawServiceTrait + Send + Sync>;
 
 #[async_trait]
 pub trait WithdrawRepositoryTrait {
-    async fn find_all(&self) -> Result<Vec<withdraws::Model>, DbErr>;
+    async fn find_paginated(&self, input: &ListRequest) -> Result<(Vec<withdraws::Model>, Pagination), DbErr>;
     async fn find_by_id(&self, id: i32) -> Result<Option<withdraws::Model>, DbErr>;
     async fn find_by_users(&self, id: i32) -> Result<Option<Vec<withdraws::Model>>, DbErr> ;
     async fn find_by_user(&self, id: i32) ->  Result<Option<withdraws::Model>, DbErr>;
-    async fn create(&self, input: &CreateWithdrawRequest) -> Result<withdraws::Model, DbErr>;
-    async fn update(&self, input: &UpdateWithdrawRequest) -> Result<withdraws::Model, DbErr>;
+    async fn create(&self, input: &CreateWithdrawRequest, fee: &FeeQuote, status: &str) -> Result<withdraws::Model, DbErr>;
+    // Only a pending withdraw of the same user is updated, otherwise None
+    async fn update(&self, input: &UpdateWithdrawRequest, fee: &FeeQuote) -> Result<Option<withdraws::Model>, DbErr>;
+    async fn find_by_status(&self, status: &str) -> Result<Vec<withdraws::Model>, DbErr>;
+    async fn update_payout(&self, input: &UpdateWithdrawPayoutRequest) -> Result<bool, DbErr>;
+    async fn sum_since(&self, user_id: i32, since: NaiveDateTime) -> Result<i64, DbErr>;
     async fn delete(&self, id: i32) -> Result<(), DbErr>;
 }
 
 #[async_trait]
 pub trait WithdrawServiceTrait {
-    async fn get_withdraws(&self) -> Result<ApiResponse<Vec<WithdrawResponse>>, ErrorResponse>;
+    async fn get_withdraws(&self, input: &ListRequest) -> Result<ApiResponsePagination<Vec<WithdrawResponse>>, ErrorResponse>;
     async fn get_withdraw(&self, id: i32) -> Result<ApiResponse<Option<WithdrawResponse>>, ErrorResponse>;
     async fn get_withdraw_users(&self, id: i32) -> Result<ApiResponse<Option<Vec<WithdrawResponse>>>, ErrorResponse>;
     async fn get_withdraw_user(&self, id: i32) -> Result<ApiResponse<Option<WithdrawResponse>>, ErrorResponse> ;
     async fn create_withdraw(&self, input: &CreateWithdrawRequest) -> Result<ApiResponse<WithdrawResponse>, ErrorResponse>;
+    async fn review_withdraw(&self, input: &ResolveRiskReviewRequest) -> Result<ApiResponse<WithdrawResponse>, ErrorResponse>;
     async fn update_withdraw(&self, input: &UpdateWithdrawRequest) -> Result<ApiResponse<Option<WithdrawResponse>>, ErrorResponse> ;
     async fn delete_withdraw(&self, id: i32) -> Result<ApiResponse<()>, ErrorResponse>;
+    async fn process_payouts(&self) -> Result<u64, ErrorResponse>;
 }
\ No newline at end of file
@@ -1,9 +1,45 @@
+use chrono::{DateTime, Utc};
+
+use crate::utils::{
+    account_number::{derive_key, AccountNumberFormat, AccountNumberScheme},
+    api_version::DeprecationPolicy,
+    fee::FeeSchedule,
+    log_tracing::{LogFormat, OtlpConfig},
+    rate_limit::RateLimitRules,
+    risk::RiskRules,
+    screening::Watchlist,
+};
+
 #[derive(Debug, Clone)]
 pub struct Config {
     pub database_url: String,
     pub jwt_secret: String,
     pub run_migrations: bool,
     pub port: u16,
+    pub va_bank_prefixes: Vec<(String, String)>,
+    pub va_notification_secrets: Vec<(String, String)>,
+    pub qris_merchant_city: String,
+    pub qris_merchant_category_code: String,
+    pub scheduler_interval_secs: u64,
+    pub dunning_retry_hours: Vec<i64>,
+    pub account_number_format: AccountNumberFormat,
+    pub account_number_scheme: AccountNumberScheme,
+    pub account_number_key: u64,
+    pub account_number_max_attempts: u32,
+    pub fee_schedule: FeeSchedule,
+    pub platform_revenue_user_id: Option<i32>,
+    pub kyc_storage_dir: String,
+    pub operator_user_ids: Vec<i32>,
+    pub risk_rules: RiskRules,
+    pub sanctions_list_file: Option<String>,
+    pub sanctions_watchlist: Watchlist,
+    pub sanctions_match_threshold: f64,
+    pub api_v1_deprecation: DeprecationPolicy,
+    pub rate_limit_rules: RateLimitRules,
+    pub rate_limit_trust_proxy: bool,
+    pub log_format: LogFormat,
+    pub otlp: Option<OtlpConfig>,
+    pub shutdown_delay_secs: u64,
 }
 
 impl Config {
@@ -15,6 +51,191 @@ impl Config {
             std::env::var("RUN_MIGRATIONS").expect("RUN_MIGRATIONS must be set");
         let port_str = std::env::var("PORT").expect("PORT must be set");
 
+        // Format: "bca:39358,bni:8808,bri:26215,mandiri:89022"
+        let va_bank_prefixes_str = std::env::var("VA_BANK_PREFIXES")
+            .unwrap_or_else(|_| "bca:39358,bni:8808,bri:26215,mandiri:89022".to_string());
+
+        // Shared secrets banks sign their payment notifications with, e.g. "bca:s3cret,bni:t0ken";
+        // notifications from a bank without one are always rejected
+        let va_notification_secrets_str =
+            std::env::var("VA_NOTIFICATION_SECRETS").unwrap_or_default();
+
+        let qris_merchant_city =
+            std::env::var("QRIS_MERCHANT_CITY").unwrap_or_else(|_| "JAKARTA".to_string());
+        let qris_merchant_category_code =
+            std::env::var("QRIS_MERCHANT_CATEGORY_CODE").unwrap_or_else(|_| "5499".to_string());
+
+        let scheduler_interval_secs = std::env::var("SCHEDULER_INTERVAL_SECS")
+            .unwrap_or_else(|_| "60".to_string())
+            .parse()
+            .expect("Invalid value for SCHEDULER_INTERVAL_SECS");
+
+        // Delay before each retry of a failed subscription charge, e.g. "24,72,168"
+        let dunning_retry_hours = std::env::var("DUNNING_RETRY_HOURS")
+            .unwrap_or_else(|_| "24,72,168".to_string())
+            .split(',')
+            .filter(|entry| !entry.trim().is_empty())
+            .map(|entry| {
+                entry
+                    .trim()
+                    .parse()
+                    .expect("Invalid value in DUNNING_RETRY_HOURS")
+            })
+            .collect();
+
+        let account_number_prefix =
+            std::env::var("ACCOUNT_NUMBER_PREFIX").unwrap_or_else(|_| "4".to_string());
+        let account_number_length = std::env::var("ACCOUNT_NUMBER_LENGTH")
+            .unwrap_or_else(|_| "16".to_string())
+            .parse()
+            .expect("Invalid value for ACCOUNT_NUMBER_LENGTH");
+        let account_number_format =
+            AccountNumberFormat::new(&account_number_prefix, account_number_length)
+                .unwrap_or_else(|e| panic!("Invalid account number format: {}", e));
+
+        let account_number_scheme: AccountNumberScheme = std::env::var("ACCOUNT_NUMBER_SCHEME")
+            .unwrap_or_else(|_| "random".to_string())
+            .parse()
+            .unwrap_or_else(|e: String| panic!("{}", e));
+
+        // The encrypted-sequence scheme needs its own secret; reusing JWT_SECRET would tie
+        // every issued account number to the token signing key
+        let account_number_key = match std::env::var("ACCOUNT_NUMBER_KEY") {
+            Ok(secret) if !secret.trim().is_empty() => derive_key(&secret),
+            _ if account_number_scheme == AccountNumberScheme::EncryptedSequence => {
+                panic!("ACCOUNT_NUMBER_KEY must be set when ACCOUNT_NUMBER_SCHEME is encrypted")
+            }
+            _ => 0,
+        };
+
+        let account_number_max_attempts = std::env::var("ACCOUNT_NUMBER_MAX_ATTEMPTS")
+            .unwrap_or_else(|_| "5".to_string())
+            .parse()
+            .expect("Invalid value for ACCOUNT_NUMBER_MAX_ATTEMPTS");
+
+        // No fees are charged unless a JSON rules file is configured
+        let fee_schedule = match std::env::var("FEE_RULES_FILE") {
+            Ok(path) => {
+                let json = std::fs::read_to_string(&path)
+                    .unwrap_or_else(|e| panic!("Failed to read FEE_RULES_FILE {}: {}", path, e));
+                FeeSchedule::from_json(&json).unwrap_or_else(|e| panic!("{}", e))
+            }
+            Err(_) => FeeSchedule::default(),
+        };
+
+        let platform_revenue_user_id = std::env::var("PLATFORM_REVENUE_USER_ID")
+            .ok()
+            .map(|id| id.parse().expect("Invalid value for PLATFORM_REVENUE_USER_ID"));
+
+        if !fee_schedule.is_empty() && platform_revenue_user_id.is_none() {
+            panic!("PLATFORM_REVENUE_USER_ID must be set when FEE_RULES_FILE is configured");
+        }
+
+        let kyc_storage_dir =
+            std::env::var("KYC_STORAGE_DIR").unwrap_or_else(|_| "storage/kyc".to_string());
+
+        // Users allowed to work the KYC and risk review queues, e.g. "1,2"
+        let operator_user_ids: Vec<i32> = std::env::var("OPERATOR_USER_IDS")
+            .unwrap_or_default()
+            .split(',')
+            .filter(|entry| !entry.trim().is_empty())
+            .map(|entry| {
+                entry
+                    .trim()
+                    .parse()
+                    .expect("Invalid value in OPERATOR_USER_IDS")
+            })
+            .collect();
+
+        // Every transaction is allowed unless a JSON rules file is configured
+        let risk_rules = match std::env::var("RISK_RULES_FILE") {
+            Ok(path) => {
+                let json = std::fs::read_to_string(&path)
+                    .unwrap_or_else(|e| panic!("Failed to read RISK_RULES_FILE {}: {}", path, e));
+                RiskRules::from_json(&json).unwrap_or_else(|e| panic!("{}", e))
+            }
+            Err(_) => RiskRules::default(),
+        };
+
+        if !risk_rules.is_empty() && operator_user_ids.is_empty() {
+            panic!("OPERATOR_USER_IDS must be set when RISK_RULES_FILE is configured");
+        }
+
+        // Nobody is screened out unless a CSV or JSON watchlist is configured
+        let sanctions_list_file = std::env::var("SANCTIONS_LIST_FILE").ok();
+        let sanctions_watchlist = match &sanctions_list_file {
+            Some(path) => Watchlist::load(path).unwrap_or_else(|e| panic!("{}", e)),
+            None => Watchlist::default(),
+        };
+
+        if !sanctions_watchlist.is_empty() && operator_user_ids.is_empty() {
+            panic!("OPERATOR_USER_IDS must be set when SANCTIONS_LIST_FILE is configured");
+        }
+
+        // Jaro-Winkler similarity a name must reach to count as a match
+        let sanctions_match_threshold: f64 = std::env::var("SANCTIONS_MATCH_THRESHOLD")
+            .unwrap_or_else(|_| "0.9".to_string())
+            .parse()
+            .expect("Invalid value for SANCTIONS_MATCH_THRESHOLD");
+
+        if !(sanctions_match_threshold > 0.0 && sanctions_match_threshold <= 1.0) {
+            panic!("SANCTIONS_MATCH_THRESHOLD must be greater than 0 and at most 1");
+        }
+
+        // RFC 3339 times v1 responses announce through their Deprecation and Sunset headers
+        let api_v1_deprecation = DeprecationPolicy {
+            deprecated_at: optional_datetime("API_V1_DEPRECATED_AT"),
+            sunset_at: optional_datetime("API_V1_SUNSET_AT"),
+        };
+
+        // Login, registration and transfers are throttled by the built-in rules unless a JSON
+        // rules file replaces them
+        let rate_limit_rules = match std::env::var("RATE_LIMIT_RULES_FILE") {
+            Ok(path) => {
+                let json = std::fs::read_to_string(&path).unwrap_or_else(|e| {
+                    panic!("Failed to read RATE_LIMIT_RULES_FILE {}: {}", path, e)
+                });
+                RateLimitRules::from_json(&json).unwrap_or_else(|e| panic!("{}", e))
+            }
+            Err(_) => RateLimitRules::default(),
+        };
+
+        // Only behind a proxy that sets Forwarded / X-Forwarded-For can the client IP be taken
+        // from them; otherwise anyone could pick their own IP bucket
+        let rate_limit_trust_proxy = match std::env::var("RATE_LIMIT_TRUST_PROXY")
+            .unwrap_or_else(|_| "false".to_string())
+            .as_str()
+        {
+            "true" => true,
+            "false" => false,
+            _ => panic!("RATE_LIMIT_TRUST_PROXY must be either 'true' or 'false'"),
+        };
+
+        // "json" for log collectors, "text" for reading in a terminal
+        let log_format = std::env::var("LOG_FORMAT")
+            .map(|format| format.parse().unwrap_or_else(|e: String| panic!("{}", e)))
+            .unwrap_or_default();
+
+        // Spans are only exported when a collector is configured, either by its traces URL or by
+        // the base URL the OTLP/HTTP traces path is appended to
+        let otlp = std::env::var("OTEL_EXPORTER_OTLP_TRACES_ENDPOINT")
+            .or_else(|_| {
+                std::env::var("OTEL_EXPORTER_OTLP_ENDPOINT")
+                    .map(|endpoint| format!("{}/v1/traces", endpoint.trim_end_matches('/')))
+            })
+            .ok()
+            .map(|endpoint| OtlpConfig {
+                endpoint,
+                service_name: std::env::var("OTEL_SERVICE_NAME")
+                    .unwrap_or_else(|_| "example-payment-gateway".to_string()),
+            });
+
+        // Time between readiness failing on SIGTERM and the server refusing new connections,
+        // for the orchestrator to take the instance out of rotation
+        let shutdown_delay_secs = std::env::var("SHUTDOWN_DELAY_SECS")
+            .unwrap_or_else(|_| "5".to_string())
+            .parse()
+            .expect("Invalid value for SHUTDOWN_DELAY_SECS");
 
         let run_migrations = match run_migrations_str.as_str() {
             "true" => true,
@@ -24,7 +245,77 @@ impl Config {
 
         let port = port_str.parse().expect("Invalid value for PORT");
 
-        Config { database_url, jwt_secret, run_migrations, port }
+        let va_bank_prefixes = va_bank_prefixes_str
+            .split(',')
+            .map(|entry| {
+                let (bank, prefix) = entry
+                    .trim()
+                    .split_once(':')
+                    .expect("VA_BANK_PREFIXES entries must be in the form bank:prefix");
+
+                if prefix.is_empty() || !prefix.chars().all(|c| c.is_ascii_digit()) {
+                    panic!("Invalid VA prefix for bank '{}'", bank);
+                }
+
+                (bank.to_lowercase(), prefix.to_string())
+            })
+            .collect();
+
+        let va_notification_secrets = va_notification_secrets_str
+            .split(',')
+            .filter(|entry| !entry.trim().is_empty())
+            .map(|entry| {
+                let (bank, secret) = entry
+                    .trim()
+                    .split_once(':')
+                    .expect("VA_NOTIFICATION_SECRETS entries must be in the form bank:secret");
+
+                if secret.is_empty() {
+                    panic!("Empty VA notification secret for bank '{}'", bank);
+                }
+
+                (bank.to_lowercase(), secret.to_string())
+            })
+            .collect();
+
+        Config {
+            database_url,
+            jwt_secret,
+            run_migrations,
+            port,
+            va_bank_prefixes,
+            va_notification_secrets,
+            qris_merchant_city,
+            qris_merchant_category_code,
+            scheduler_interval_secs,
+            dunning_retry_hours,
+            account_number_format,
+            account_number_scheme,
+            account_number_key,
+            account_number_max_attempts,
+            fee_schedule,
+            platform_revenue_user_id,
+            kyc_storage_dir,
+            operator_user_ids,
+            risk_rules,
+            sanctions_list_file,
+            sanctions_watchlist,
+            sanctions_match_threshold,
+            api_v1_deprecation,
+            rate_limit_rules,
+            rate_limit_trust_proxy,
+            log_format,
+            otlp,
+            shutdown_delay_secs,
+        }
  
     }
 }
+
+fn optional_datetime(name: &str) -> Option<DateTime<Utc>> {
+    std::env::var(name).ok().map(|value| {
+        DateTime::parse_from_rfc3339(value.trim())
+            .unwrap_or_else(|e| panic!("Invalid value for {}: {}", name, e))
+            .with_timezone(&Utc)
+    })
+}
@@ -1,12 +1,35 @@
-use sea_orm::{Database, DatabaseConnection};
-use sea_orm_migration::{MigrationTrait, SchemaManager};
+use std::collections::HashSet;
+
+use sea_orm::{Database, DatabaseConnection, EntityTrait};
+use sea_orm_migration::{seaql_migrations, MigratorTrait};
 
 use crate::utils::errors::ConnectionManagerError;
 
 pub struct ConnectionManager;
 
+// Connections held by the pool at one instant
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PoolStats {
+    pub size: u32,
+    pub idle: u32,
+    pub max_connections: u32,
+}
+
+impl PoolStats {
+    pub fn in_use(&self) -> u32 {
+        self.size.saturating_sub(self.idle)
+    }
+}
+
+// The migrations of a migrator, split by whether the database has recorded them
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MigrationStatus {
+    pub applied: Vec<String>,
+    pub pending: Vec<String>,
+}
+
 impl ConnectionManager {
-    pub async fn new_pool<M: MigrationTrait + Default>(
+    pub async fn new_pool<M: MigratorTrait>(
         connection_string: &str,
         run_migrations: bool
     ) -> Result<DatabaseConnection, ConnectionManagerError> {
@@ -15,13 +38,52 @@ impl ConnectionManager {
         
 
         if run_migrations {
-            let schema_manager = SchemaManager::new(&pool);
-            let migration = M::default();
-
-               migration.up(&schema_manager).await
+            M::up(&pool, None).await
                 .map_err(ConnectionManagerError::MigrationError)?;
         }
         
         Ok(pool)
     }
-}
\ No newline at end of file
+
+    pub async fn ping(pool: &DatabaseConnection) -> Result<(), ConnectionManagerError> {
+        pool.ping()
+            .await
+            .map_err(ConnectionManagerError::ConnectionError)
+    }
+
+    // Only reads the migration table; MigratorTrait's own status queries create it first
+    pub async fn migration_status<M: MigratorTrait>(
+        pool: &DatabaseConnection,
+    ) -> Result<MigrationStatus, ConnectionManagerError> {
+        let recorded: HashSet<String> = seaql_migrations::Entity::find()
+            .all(pool)
+            .await
+            .map_err(ConnectionManagerError::MigrationError)?
+            .into_iter()
+            .map(|migration| migration.version)
+            .collect();
+
+        let (applied, pending) = M::migrations()
+            .iter()
+            .map(|migration| migration.name().to_string())
+            .partition(|name| recorded.contains(name));
+
+        Ok(MigrationStatus { applied, pending })
+    }
+
+    // None for connections that are not a Postgres pool, such as a disconnected one
+    pub fn pool_stats(pool: &DatabaseConnection) -> Option<PoolStats> {
+        match pool {
+            DatabaseConnection::SqlxPostgresPoolConnection(_) => {
+                let pool = pool.get_postgres_connection_pool();
+
+                Some(PoolStats {
+                    size: pool.size(),
+                    idle: pool.num_idle() as u32,
+                    max_connections: pool.options().get_max_connections(),
+                })
+            }
+            _ => None,
+        }
+    }
+}
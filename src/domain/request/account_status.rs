@@ -0,0 +1,39 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+use crate::utils::{
+    account_status::AccountStatus,
+    validation::{validate_not_blank, MAX_REASON_LENGTH},
+};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate, ToSchema)]
+pub struct UpdateAccountStatusRequest {
+    #[serde(default)]
+    #[schema(ignore)]
+    pub user_id: i32,
+    #[serde(default)]
+    #[schema(ignore)]
+    pub changed_by: i32,
+    pub status: AccountStatus,
+    #[validate(
+        custom(
+            function = "validate_not_blank",
+            message = "A reason is required when changing an account's status"
+        ),
+        length(
+            max = MAX_REASON_LENGTH,
+            message = "Reason must be at most 500 characters"
+        )
+    )]
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CreateAccountStatusChangeRequest {
+    pub user_id: i32,
+    pub previous_status: AccountStatus,
+    pub new_status: AccountStatus,
+    pub reason: String,
+    pub changed_by: i32,
+}
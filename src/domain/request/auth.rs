@@ -1,64 +1,27 @@
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
 
-use regex::Regex;
+use crate::utils::validation::validate_not_blank;
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate, ToSchema)]
 pub struct RegisterRequest {
+    #[validate(custom(function = "validate_not_blank", message = "First name is required"))]
     pub firstname: String,
+    #[validate(custom(function = "validate_not_blank", message = "Last name is required"))]
     pub lastname: String,
+    #[validate(email(message = "Invalid email format"))]
     pub email: String,
+    #[validate(length(min = 8, message = "Password must be at least 8 characters long"))]
     pub password: String,
+    #[validate(must_match(other = "password", message = "Passwords do not match"))]
     pub confirm_password: String,
 }
 
-impl RegisterRequest {
-    pub fn validate(&self) -> Result<(), String> {
-        if self.firstname.is_empty() {
-            return Err("First name is required".to_string());
-        }
-
-        if self.lastname.is_empty() {
-            return Err("Last name is required".to_string());
-        }
-
-        // Validate email format (simple check using regex)
-        let email_regex = Regex::new(r"^[\w\.-]+@[\w\.-]+\.[a-zA-Z]{2,}$").unwrap();
-        if !email_regex.is_match(&self.email) {
-            return Err("Invalid email format".to_string());
-        }
-
-        // Check if passwords match
-        if self.password != self.confirm_password {
-            return Err("Passwords do not match".to_string());
-        }
-
-        // Check if password length is sufficient (e.g., at least 8 characters)
-        if self.password.len() < 8 {
-            return Err("Password must be at least 8 characters long".to_string());
-        }
-
-        Ok(())
-    }
-}
-
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate, ToSchema)]
 pub struct LoginRequest {
+    #[validate(email(message = "Invalid email format"))]
     pub email: String,
+    #[validate(length(min = 1, message = "Password is required"))]
     pub password: String,
 }
-
-impl LoginRequest {
-    pub fn validate(&self) -> Result<(), String> {
-        let email_regex = Regex::new(r"^[\w\.-]+@[\w\.-]+\.[a-zA-Z]{2,}$").unwrap();
-        if !email_regex.is_match(&self.email) {
-            return Err("Invalid email format".to_string());
-        }
-
-        // Check if password is empty
-        if self.password.is_empty() {
-            return Err("Password is required".to_string());
-        }
-
-        Ok(())
-    }
-}
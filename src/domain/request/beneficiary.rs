@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+use crate::utils::validation::{validate_bank_account_number, validate_not_blank};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate, ToSchema)]
+pub struct CreateBeneficiaryRequest {
+    #[serde(default)]
+    #[schema(ignore)]
+    pub user_id: i32,
+    #[validate(custom(function = "validate_not_blank", message = "Bank code is required"))]
+    pub bank_code: String,
+    #[validate(custom(function = "validate_bank_account_number"))]
+    pub account_number: String,
+    #[validate(custom(function = "validate_not_blank", message = "Account name is required"))]
+    pub account_name: String,
+}
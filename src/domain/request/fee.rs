@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+use utoipa::IntoParams;
+use validator::Validate;
+
+use crate::utils::fee::FeeOperation;
+
+pub const FEE_ENTRY_STATUS_BOOKED: &str = "booked";
+// Booking failed; the fee-retry job books it to platform revenue later
+pub const FEE_ENTRY_STATUS_PENDING: &str = "pending";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct FeePreviewRequest {
+    pub operation: FeeOperation,
+    // Topup method for topups, beneficiary bank code for withdraws
+    pub channel: Option<String>,
+    #[validate(range(min = 1, message = "Amount must be a positive integer"))]
+    pub amount: i32,
+}
@@ -0,0 +1,83 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+use crate::utils::validation::{validate_in_future, validate_not_blank};
+
+pub const INVOICE_STATUS_UNPAID: &str = "unpaid";
+pub const INVOICE_STATUS_PARTIALLY_PAID: &str = "partially_paid";
+pub const INVOICE_STATUS_PAID: &str = "paid";
+pub const INVOICE_STATUS_EXPIRED: &str = "expired";
+
+pub const INVOICE_PAYMENT_STATUS_PENDING: &str = "pending";
+pub const INVOICE_PAYMENT_STATUS_COMPLETED: &str = "completed";
+pub const INVOICE_PAYMENT_STATUS_FAILED: &str = "failed";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate, ToSchema)]
+pub struct CreateInvoiceItemRequest {
+    #[validate(custom(
+        function = "validate_not_blank",
+        message = "Item description is required"
+    ))]
+    pub description: String,
+    #[validate(range(min = 1, message = "Item quantity must be greater than zero"))]
+    pub quantity: i32,
+    #[validate(range(min = 1, message = "Item unit price must be greater than zero"))]
+    pub unit_price: i32,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate, ToSchema)]
+pub struct CreateInvoiceRequest {
+    #[serde(default)]
+    #[schema(ignore)]
+    pub merchant_id: i32,
+    #[validate(custom(function = "validate_not_blank", message = "Description is required"))]
+    pub description: String,
+    #[validate(custom(
+        function = "validate_in_future",
+        message = "Due date must be in the future"
+    ))]
+    pub due_date: DateTime<Utc>,
+    // An invoice without items is an open amount payment link
+    #[serde(default)]
+    #[validate(nested)]
+    pub items: Vec<CreateInvoiceItemRequest>,
+}
+
+impl CreateInvoiceRequest {
+    pub fn total_amount(&self) -> Option<i32> {
+        if self.items.is_empty() {
+            return None;
+        }
+
+        self.items.iter().try_fold(0i32, |total, item| {
+            item.quantity
+                .checked_mul(item.unit_price)
+                .and_then(|subtotal| total.checked_add(subtotal))
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate, ToSchema)]
+pub struct PayInvoiceRequest {
+    #[serde(default)]
+    #[schema(ignore)]
+    pub token: String,
+    #[serde(default)]
+    #[schema(ignore)]
+    pub payer_id: i32,
+    #[validate(range(min = 1, message = "Amount must be greater than zero"))]
+    pub amount: i32,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CreateInvoicePaymentRequest {
+    pub invoice_id: i32,
+    pub payer_id: i32,
+    pub amount: i32,
+    // The paid amount the invoice was read with; the payment is refused if it has changed since
+    pub paid_amount: i32,
+    // The invoice status once this payment is counted
+    pub status: String,
+}
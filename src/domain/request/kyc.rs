@@ -0,0 +1,177 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::{Validate, ValidationError};
+
+use crate::utils::{
+    kyc::KycTier,
+    validation::{invalid, validate_not_blank, MAX_REASON_LENGTH},
+};
+
+pub const KYC_DOCUMENT_STATUS_PENDING: &str = "pending";
+pub const KYC_DOCUMENT_STATUS_APPROVED: &str = "approved";
+pub const KYC_DOCUMENT_STATUS_REJECTED: &str = "rejected";
+
+pub const KYC_DOCUMENT_TYPES: [&str; 4] = ["national_id", "passport", "selfie", "proof_of_address"];
+pub const KYC_CONTENT_TYPES: [&str; 3] = ["image/jpeg", "image/png", "application/pdf"];
+
+pub const MAX_KYC_DOCUMENT_BYTES: usize = 5 * 1024 * 1024;
+// The upload form has exactly the document_type, requested_tier and file parts
+pub const KYC_UPLOAD_FIELDS: [&str; 3] = ["document_type", "requested_tier", "file"];
+pub const MAX_KYC_TEXT_FIELD_BYTES: usize = 64;
+
+fn validate_document_type(document_type: &str) -> Result<(), ValidationError> {
+    if !KYC_DOCUMENT_TYPES.contains(&document_type) {
+        return Err(invalid(
+            "unsupported_document_type",
+            format!(
+                "Document type must be one of: {}",
+                KYC_DOCUMENT_TYPES.join(", ")
+            ),
+        ));
+    }
+
+    Ok(())
+}
+
+fn validate_requested_tier(tier: &KycTier) -> Result<(), ValidationError> {
+    if *tier == KycTier::Unverified {
+        return Err(invalid(
+            "invalid_tier",
+            "Requested tier must be basic or full",
+        ));
+    }
+
+    Ok(())
+}
+
+fn validate_content_type(content_type: &str) -> Result<(), ValidationError> {
+    if !KYC_CONTENT_TYPES.contains(&content_type) {
+        return Err(invalid(
+            "unsupported_content_type",
+            format!("File must be one of: {}", KYC_CONTENT_TYPES.join(", ")),
+        ));
+    }
+
+    Ok(())
+}
+
+fn validate_content(content: &[u8]) -> Result<(), ValidationError> {
+    if content.is_empty() {
+        return Err(invalid("empty_file", "File must not be empty"));
+    }
+
+    if content.len() > MAX_KYC_DOCUMENT_BYTES {
+        return Err(invalid(
+            "file_too_large",
+            format!("File must be at most {} bytes", MAX_KYC_DOCUMENT_BYTES),
+        ));
+    }
+
+    Ok(())
+}
+
+fn validate_review_decision(decision: &str) -> Result<(), ValidationError> {
+    if decision != KYC_DOCUMENT_STATUS_APPROVED && decision != KYC_DOCUMENT_STATUS_REJECTED {
+        return Err(invalid(
+            "invalid_decision",
+            format!(
+                "Decision must be either '{}' or '{}'",
+                KYC_DOCUMENT_STATUS_APPROVED, KYC_DOCUMENT_STATUS_REJECTED
+            ),
+        ));
+    }
+
+    Ok(())
+}
+
+fn validate_rejection_reason(request: &ReviewKycDocumentRequest) -> Result<(), ValidationError> {
+    let reason = request.reason.as_deref().map(str::trim).unwrap_or_default();
+
+    if !request.is_approval() && reason.is_empty() {
+        return Err(invalid(
+            "reason_required",
+            "A reason is required when rejecting a document",
+        ));
+    }
+
+    Ok(())
+}
+
+// Built by the upload handler from the multipart form fields
+#[derive(Debug, Clone, PartialEq, Validate)]
+pub struct CreateKycDocumentRequest {
+    pub user_id: i32,
+    #[validate(custom(function = "validate_document_type"))]
+    pub document_type: String,
+    #[validate(custom(function = "validate_requested_tier"))]
+    pub requested_tier: KycTier,
+    #[validate(
+        custom(
+            function = "validate_not_blank",
+            message = "File name must be 1 to 255 characters"
+        ),
+        length(max = 255, message = "File name must be 1 to 255 characters")
+    )]
+    pub file_name: String,
+    #[validate(custom(function = "validate_content_type"))]
+    pub content_type: String,
+    #[validate(custom(function = "validate_content"))]
+    pub content: Vec<u8>,
+}
+
+// The multipart form read into CreateKycDocumentRequest; it only describes the upload body
+#[derive(ToSchema)]
+pub struct KycDocumentUploadForm {
+    pub document_type: String,
+    pub requested_tier: KycTier,
+    #[schema(value_type = String, format = Binary)]
+    pub file: Vec<u8>,
+}
+
+impl CreateKycDocumentRequest {
+    pub fn file_extension(&self) -> &'static str {
+        match self.content_type.as_str() {
+            "image/jpeg" => "jpg",
+            "image/png" => "png",
+            _ => "pdf",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate, ToSchema)]
+#[validate(schema(function = "validate_rejection_reason", skip_on_field_errors = false))]
+pub struct ReviewKycDocumentRequest {
+    #[serde(default)]
+    #[schema(ignore)]
+    pub document_id: i32,
+    #[serde(default)]
+    #[schema(ignore)]
+    pub reviewer_id: i32,
+    // Either "approved" or "rejected"
+    #[validate(custom(function = "validate_review_decision"))]
+    pub decision: String,
+    #[serde(default)]
+    #[validate(length(
+        max = MAX_REASON_LENGTH,
+        message = "Reason must be at most 500 characters"
+    ))]
+    pub reason: Option<String>,
+}
+
+impl ReviewKycDocumentRequest {
+    pub fn is_approval(&self) -> bool {
+        self.decision == KYC_DOCUMENT_STATUS_APPROVED
+    }
+}
+
+// Everything the repository writes for one review decision
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CreateKycReviewRequest {
+    pub document_id: i32,
+    pub user_id: i32,
+    pub reviewer_id: i32,
+    pub decision: String,
+    pub reason: Option<String>,
+    pub previous_tier: KycTier,
+    pub new_tier: KycTier,
+}
@@ -4,4 +4,17 @@ pub mod saldo;
 pub mod transfer;
 pub mod topup;
 pub mod withdraw;
-
+pub mod virtual_account;
+pub mod qris;
+pub mod invoice;
+pub mod subscription;
+pub mod scheduled_transfer;
+pub mod beneficiary;
+pub mod payout;
+pub mod payout_batch;
+pub mod fee;
+pub mod kyc;
+pub mod risk;
+pub mod screening;
+pub mod account_status;
+pub mod pagination;
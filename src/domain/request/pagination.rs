@@ -0,0 +1,199 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use validator::{Validate, ValidationError, ValidationErrors};
+
+use crate::utils::validation::{invalid, validate_not_blank};
+
+pub const DEFAULT_PAGE_SIZE: u64 = 20;
+pub const MAX_PAGE_SIZE: u64 = 100;
+
+pub const FILTER_USER_ID: &str = "user_id";
+pub const FILTER_DATE: &str = "date";
+pub const FILTER_AMOUNT: &str = "amount";
+pub const FILTER_STATUS: &str = "status";
+pub const FILTER_METHOD: &str = "method";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum SortDirection {
+    Asc,
+    #[default]
+    Desc,
+}
+
+// What a list endpoint accepts; the id field is the only sort order a cursor can follow
+#[derive(Debug, Clone, Copy)]
+pub struct ListFields {
+    pub id_field: &'static str,
+    pub sort_fields: &'static [&'static str],
+    pub filters: &'static [&'static str],
+}
+
+fn validate_ranges(request: &ListRequest) -> Result<(), ValidationError> {
+    if let (Some(from), Some(to)) = (request.from, request.to) {
+        if from > to {
+            return Err(invalid("invalid_range", "from must not be after to"));
+        }
+    }
+
+    if let (Some(min), Some(max)) = (request.min_amount, request.max_amount) {
+        if min > max {
+            return Err(invalid(
+                "invalid_range",
+                "min_amount must not be greater than max_amount",
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Query string of the list endpoints.
+///
+/// Offset pagination uses `page` (from 1) and `page_size`. Cursor pagination passes the
+/// `next_cursor` of the previous response as `cursor` and cannot be combined with `page`.
+/// `from` and `to` are inclusive dates; `min_amount` and `max_amount` are inclusive.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, Validate, IntoParams)]
+#[validate(schema(function = "validate_ranges", skip_on_field_errors = false))]
+#[into_params(parameter_in = Query)]
+pub struct ListRequest {
+    #[validate(range(min = 1, message = "Page must be at least 1"))]
+    pub page: Option<u64>,
+    #[validate(range(
+        min = 1,
+        max = MAX_PAGE_SIZE,
+        message = "Page size must be between 1 and 100"
+    ))]
+    pub page_size: Option<u64>,
+    pub cursor: Option<String>,
+    pub user_id: Option<i32>,
+    pub from: Option<NaiveDate>,
+    pub to: Option<NaiveDate>,
+    pub min_amount: Option<i64>,
+    pub max_amount: Option<i64>,
+    #[validate(custom(
+        function = "validate_not_blank",
+        message = "Status filter must not be empty"
+    ))]
+    pub status: Option<String>,
+    #[validate(custom(
+        function = "validate_not_blank",
+        message = "Method filter must not be empty"
+    ))]
+    pub method: Option<String>,
+    pub sort_by: Option<String>,
+    pub sort_dir: Option<SortDirection>,
+}
+
+impl ListRequest {
+    /// Runs the field rules and then the ones that depend on the endpoint, so every
+    /// problem with the query is reported together.
+    pub fn validate_fields(&self, fields: &ListFields) -> Result<(), ValidationErrors> {
+        let mut errors = match self.validate() {
+            Ok(()) => ValidationErrors::new(),
+            Err(errors) => errors,
+        };
+
+        if self.cursor.is_some() {
+            if self.page.is_some() {
+                errors.add(
+                    "cursor",
+                    invalid("page_and_cursor", "Use either page or cursor, not both"),
+                );
+            }
+
+            if let Err(e) = self.cursor_id() {
+                errors.add("cursor", invalid("invalid_cursor", e));
+            }
+
+            if self.sort_field(fields) != fields.id_field {
+                errors.add(
+                    "sort_by",
+                    invalid(
+                        "unsupported_cursor_sort",
+                        format!(
+                            "Cursor pagination can only be sorted by {}",
+                            fields.id_field
+                        ),
+                    ),
+                );
+            }
+        }
+
+        if let Some(sort_by) = &self.sort_by {
+            if !fields.sort_fields.contains(&sort_by.as_str()) {
+                errors.add(
+                    "sort_by",
+                    invalid(
+                        "unsupported_sort",
+                        format!(
+                            "Cannot sort by {}; expected one of: {}",
+                            sort_by,
+                            fields.sort_fields.join(", ")
+                        ),
+                    ),
+                );
+            }
+        }
+
+        let requested = [
+            (FILTER_USER_ID, self.user_id.is_some()),
+            (FILTER_DATE, self.from.is_some() || self.to.is_some()),
+            (
+                FILTER_AMOUNT,
+                self.min_amount.is_some() || self.max_amount.is_some(),
+            ),
+            (FILTER_STATUS, self.status.is_some()),
+            (FILTER_METHOD, self.method.is_some()),
+        ];
+
+        for (filter, present) in requested {
+            if present && !fields.filters.contains(&filter) {
+                errors.add(
+                    filter,
+                    invalid(
+                        "unsupported_filter",
+                        format!("Filtering by {} is not supported here", filter),
+                    ),
+                );
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    pub fn page(&self) -> u64 {
+        self.page.unwrap_or(1)
+    }
+
+    pub fn page_size(&self) -> u64 {
+        self.page_size.unwrap_or(DEFAULT_PAGE_SIZE)
+    }
+
+    pub fn sort_dir(&self) -> SortDirection {
+        self.sort_dir.unwrap_or_default()
+    }
+
+    pub fn sort_field<'a>(&'a self, fields: &'a ListFields) -> &'a str {
+        self.sort_by.as_deref().unwrap_or(fields.id_field)
+    }
+
+    // The cursor is the id of the last row of the previous page
+    pub fn cursor_id(&self) -> Result<Option<i32>, String> {
+        self.cursor
+            .as_deref()
+            .map(|cursor| {
+                cursor
+                    .parse::<i32>()
+                    .ok()
+                    .filter(|id| *id > 0)
+                    .ok_or_else(|| "Invalid cursor".to_string())
+            })
+            .transpose()
+    }
+}
@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PayoutRequest {
+    pub withdraw_id: i32,
+    pub amount: i32,
+    pub bank_code: String,
+    pub account_number: String,
+    pub account_name: String,
+}
@@ -0,0 +1,167 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::{Validate, ValidationError};
+
+use crate::{
+    domain::request::beneficiary::CreateBeneficiaryRequest,
+    utils::validation::{
+        invalid, validate_bank_account_number, validate_minimum_amount, validate_not_blank,
+        validate_recipient,
+    },
+};
+
+pub const PAYOUT_BATCH_STATUS_PENDING: &str = "pending";
+pub const PAYOUT_BATCH_STATUS_PROCESSING: &str = "processing";
+pub const PAYOUT_BATCH_STATUS_COMPLETED: &str = "completed";
+pub const PAYOUT_BATCH_STATUS_PARTIALLY_FAILED: &str = "partially_failed";
+pub const PAYOUT_BATCH_STATUS_FAILED: &str = "failed";
+
+pub const PAYOUT_ITEM_STATUS_PENDING: &str = "pending";
+pub const PAYOUT_ITEM_STATUS_REJECTED: &str = "rejected";
+pub const PAYOUT_ITEM_STATUS_PROCESSING: &str = "processing";
+pub const PAYOUT_ITEM_STATUS_SUBMITTED: &str = "submitted";
+pub const PAYOUT_ITEM_STATUS_COMPLETED: &str = "completed";
+pub const PAYOUT_ITEM_STATUS_FAILED: &str = "failed";
+
+pub const MAX_PAYOUT_BATCH_ITEMS: u64 = 1000;
+
+// An item pays out either to a wallet or to a complete bank account, never both
+fn validate_payout_destination(item: &PayoutBatchItemRequest) -> Result<(), ValidationError> {
+    if !item.is_bank_payout() {
+        if item.recipient.is_none() {
+            return Err(invalid(
+                "destination_required",
+                "Item must have a recipient or a bank account",
+            ));
+        }
+
+        return Ok(());
+    }
+
+    if item.recipient.is_some() {
+        return Err(invalid(
+            "ambiguous_destination",
+            "Item must have either a recipient or a bank account, not both",
+        ));
+    }
+
+    if item.bank_code.is_none() || item.account_number.is_none() || item.account_name.is_none() {
+        return Err(invalid(
+            "incomplete_bank_account",
+            "Bank payouts need a bank code, account number and account name",
+        ));
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate, ToSchema)]
+#[validate(schema(function = "validate_payout_destination", skip_on_field_errors = false))]
+pub struct PayoutBatchItemRequest {
+    // Wallet payouts name the recipient by noc_transfer account number or email
+    #[serde(default)]
+    #[validate(custom(function = "validate_recipient"))]
+    pub recipient: Option<String>,
+    // Bank payouts name the destination account instead
+    #[serde(default)]
+    #[validate(custom(function = "validate_not_blank", message = "Bank code is required"))]
+    pub bank_code: Option<String>,
+    #[serde(default)]
+    #[validate(custom(function = "validate_bank_account_number"))]
+    pub account_number: Option<String>,
+    #[serde(default)]
+    #[validate(custom(function = "validate_not_blank", message = "Account name is required"))]
+    pub account_name: Option<String>,
+    #[validate(custom(function = "validate_minimum_amount"))]
+    pub amount: i32,
+    #[serde(default)]
+    #[validate(length(max = 255, message = "Reference must be at most 255 characters"))]
+    pub reference: Option<String>,
+}
+
+impl PayoutBatchItemRequest {
+    pub fn is_bank_payout(&self) -> bool {
+        self.bank_code.is_some() || self.account_number.is_some() || self.account_name.is_some()
+    }
+
+    pub fn is_email_alias(&self) -> bool {
+        self.recipient.as_deref().is_some_and(|r| r.contains('@'))
+    }
+
+    pub fn beneficiary_request(&self, user_id: i32) -> CreateBeneficiaryRequest {
+        CreateBeneficiaryRequest {
+            user_id,
+            bank_code: self
+                .bank_code
+                .clone()
+                .unwrap_or_default()
+                .trim()
+                .to_string(),
+            account_number: self
+                .account_number
+                .clone()
+                .unwrap_or_default()
+                .trim()
+                .to_string(),
+            account_name: self
+                .account_name
+                .clone()
+                .unwrap_or_default()
+                .trim()
+                .to_string(),
+        }
+    }
+}
+
+// Items are validated one by one so a bad line rejects only itself, not the whole batch
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate, ToSchema)]
+pub struct CreatePayoutBatchRequest {
+    #[serde(default)]
+    #[schema(ignore)]
+    pub user_id: i32,
+    #[validate(length(
+        min = 1,
+        max = MAX_PAYOUT_BATCH_ITEMS,
+        message = "Batch must contain between 1 and 1000 items"
+    ))]
+    pub items: Vec<PayoutBatchItemRequest>,
+}
+
+impl CreatePayoutBatchRequest {
+    // Expects a header row naming the PayoutBatchItemRequest fields; blank cells are left unset
+    pub fn from_csv(data: &[u8]) -> Result<Self, String> {
+        let mut reader = csv::ReaderBuilder::new()
+            .trim(csv::Trim::All)
+            .from_reader(data);
+
+        let items = reader
+            .deserialize::<PayoutBatchItemRequest>()
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| format!("Invalid CSV: {}", e))?;
+
+        Ok(Self { user_id: 0, items })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CreatePayoutBatchItemRequest {
+    pub line_number: i32,
+    pub recipient: Option<String>,
+    pub recipient_user_id: Option<i32>,
+    pub bank_code: Option<String>,
+    pub account_number: Option<String>,
+    pub account_name: Option<String>,
+    pub amount: i32,
+    pub reference: Option<String>,
+    pub status: String,
+    pub failure_reason: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UpdatePayoutBatchItemRequest {
+    pub item_id: i32,
+    pub status: String,
+    pub failure_reason: Option<String>,
+    pub transfer_id: Option<i32>,
+    pub withdraw_id: Option<i32>,
+}
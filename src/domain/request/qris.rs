@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use validator::Validate;
+
+use crate::utils::validation::validate_not_blank;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate, ToSchema)]
+pub struct CreateQrisRequest {
+    #[validate(range(min = 1, message = "User ID must be a positive integer"))]
+    pub user_id: i32,
+    #[validate(range(min = 1, message = "Amount must be greater than zero"))]
+    pub amount: Option<i32>,
+    #[validate(
+        custom(
+            function = "validate_not_blank",
+            message = "Reference must be between 1 and 25 characters"
+        ),
+        length(max = 25, message = "Reference must be between 1 and 25 characters")
+    )]
+    pub reference: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ParseQrisRequest {
+    pub payload: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct RenderQrisRequest {
+    pub payload: String,
+    pub format: Option<String>,
+}
@@ -0,0 +1,85 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::{Validate, ValidationError};
+
+use crate::utils::{
+    risk::{RiskAction, RiskOperation},
+    validation::{invalid, MAX_REASON_LENGTH},
+};
+
+pub const RISK_REVIEW_STATUS_PENDING: &str = "pending";
+pub const RISK_REVIEW_STATUS_RELEASED: &str = "released";
+pub const RISK_REVIEW_STATUS_REJECTED: &str = "rejected";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RiskAssessmentRequest {
+    pub user_id: i32,
+    pub operation: RiskOperation,
+    pub amount: i32,
+    // The receiving user of a transfer
+    pub counterparty_user_id: Option<i32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CreateRiskDecisionRequest {
+    pub user_id: i32,
+    pub operation: RiskOperation,
+    pub amount: i32,
+    pub counterparty_user_id: Option<i32>,
+    pub action: RiskAction,
+    pub triggered_rules: Vec<String>,
+}
+
+fn validate_review_decision(decision: &str) -> Result<(), ValidationError> {
+    if decision != RISK_REVIEW_STATUS_RELEASED && decision != RISK_REVIEW_STATUS_REJECTED {
+        return Err(invalid(
+            "invalid_decision",
+            format!(
+                "Decision must be either '{}' or '{}'",
+                RISK_REVIEW_STATUS_RELEASED, RISK_REVIEW_STATUS_REJECTED
+            ),
+        ));
+    }
+
+    Ok(())
+}
+
+fn validate_rejection_reason(request: &ResolveRiskReviewRequest) -> Result<(), ValidationError> {
+    let reason = request.reason.as_deref().map(str::trim).unwrap_or_default();
+
+    if !request.is_release() && reason.is_empty() {
+        return Err(invalid(
+            "reason_required",
+            "A reason is required when rejecting a held transaction",
+        ));
+    }
+
+    Ok(())
+}
+
+// An operator's decision on a transaction held for review
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate, ToSchema)]
+#[validate(schema(function = "validate_rejection_reason", skip_on_field_errors = false))]
+pub struct ResolveRiskReviewRequest {
+    #[serde(default)]
+    #[schema(ignore)]
+    pub reference_id: i32,
+    #[serde(default)]
+    #[schema(ignore)]
+    pub reviewer_id: i32,
+    // Either "released" or "rejected"
+    #[validate(custom(function = "validate_review_decision"))]
+    pub decision: String,
+    #[serde(default)]
+    #[validate(length(
+        max = MAX_REASON_LENGTH,
+        message = "Reason must be at most 500 characters"
+    ))]
+    pub reason: Option<String>,
+}
+
+impl ResolveRiskReviewRequest {
+    pub fn is_release(&self) -> bool {
+        self.decision == RISK_REVIEW_STATUS_RELEASED
+    }
+}
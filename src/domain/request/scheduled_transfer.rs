@@ -0,0 +1,106 @@
+use chrono::{DateTime, NaiveDateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::{Validate, ValidationError};
+
+use crate::utils::{
+    recurrence::BillingInterval,
+    validation::{invalid, validate_in_future, validate_minimum_amount},
+};
+
+pub const SCHEDULED_TRANSFER_STATUS_ACTIVE: &str = "active";
+// A one-off schedule whose run has been claimed but whose transfer has not finished yet
+pub const SCHEDULED_TRANSFER_STATUS_PROCESSING: &str = "processing";
+pub const SCHEDULED_TRANSFER_STATUS_COMPLETED: &str = "completed";
+pub const SCHEDULED_TRANSFER_STATUS_FAILED: &str = "failed";
+pub const SCHEDULED_TRANSFER_STATUS_CANCELLED: &str = "cancelled";
+
+pub const EXECUTION_STATUS_SUCCEEDED: &str = "succeeded";
+pub const EXECUTION_STATUS_FAILED: &str = "failed";
+// The execution's transfer is held for review
+pub const EXECUTION_STATUS_PENDING: &str = "pending";
+
+fn validate_distinct_parties(transfer_from: i32, transfer_to: i32) -> Result<(), ValidationError> {
+    if transfer_from == transfer_to {
+        return Err(invalid(
+            "same_account",
+            "Cannot schedule a transfer to the same user",
+        ));
+    }
+
+    Ok(())
+}
+
+fn validate_create_parties(
+    request: &CreateScheduledTransferRequest,
+) -> Result<(), ValidationError> {
+    validate_distinct_parties(request.transfer_from, request.transfer_to)
+}
+
+fn validate_update_parties(
+    request: &UpdateScheduledTransferRequest,
+) -> Result<(), ValidationError> {
+    validate_distinct_parties(request.transfer_from, request.transfer_to)
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate, ToSchema)]
+#[validate(schema(function = "validate_create_parties", skip_on_field_errors = false))]
+pub struct CreateScheduledTransferRequest {
+    #[serde(default)]
+    #[schema(ignore)]
+    pub transfer_from: i32,
+    #[validate(range(min = 1, message = "Transfer to must be a positive integer"))]
+    pub transfer_to: i32,
+    #[validate(custom(
+        function = "validate_minimum_amount",
+        message = "Transfer amount must be at least 50,000"
+    ))]
+    pub transfer_amount: i32,
+    #[validate(custom(
+        function = "validate_in_future",
+        message = "Scheduled time must be in the future"
+    ))]
+    pub scheduled_at: DateTime<Utc>,
+    // A one-off transfer when omitted
+    pub recurrence: Option<BillingInterval>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate, ToSchema)]
+#[validate(schema(function = "validate_update_parties", skip_on_field_errors = false))]
+pub struct UpdateScheduledTransferRequest {
+    #[serde(default)]
+    #[schema(ignore)]
+    pub scheduled_transfer_id: i32,
+    #[serde(default)]
+    #[schema(ignore)]
+    pub transfer_from: i32,
+    #[validate(range(min = 1, message = "Transfer to must be a positive integer"))]
+    pub transfer_to: i32,
+    #[validate(custom(
+        function = "validate_minimum_amount",
+        message = "Transfer amount must be at least 50,000"
+    ))]
+    pub transfer_amount: i32,
+    #[validate(custom(
+        function = "validate_in_future",
+        message = "Scheduled time must be in the future"
+    ))]
+    pub scheduled_at: DateTime<Utc>,
+    pub recurrence: Option<BillingInterval>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UpdateScheduledTransferRunRequest {
+    pub scheduled_transfer_id: i32,
+    pub status: String,
+    pub next_run_at: NaiveDateTime,
+    pub last_run_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CreateScheduledTransferExecutionRequest {
+    pub scheduled_transfer_id: i32,
+    pub transfer_id: Option<i32>,
+    pub status: String,
+    pub failure_reason: Option<String>,
+}
@@ -0,0 +1,65 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::{Validate, ValidationError};
+
+use crate::utils::validation::{invalid, validate_not_blank, MAX_REASON_LENGTH};
+
+pub const SCREENING_MATCH_STATUS_PENDING: &str = "pending";
+pub const SCREENING_MATCH_STATUS_CLEARED: &str = "cleared";
+pub const SCREENING_MATCH_STATUS_CONFIRMED: &str = "confirmed";
+
+pub const SCREENING_CONTEXT_REGISTRATION: &str = "registration";
+pub const SCREENING_CONTEXT_TRANSFER: &str = "transfer";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CreateScreeningMatchRequest {
+    pub user_id: i32,
+    pub context: String,
+    pub screened_name: String,
+    pub entry_reference: String,
+    pub entry_name: String,
+    pub entry_source: Option<String>,
+    pub score: f64,
+}
+
+fn validate_review_decision(decision: &str) -> Result<(), ValidationError> {
+    if decision != SCREENING_MATCH_STATUS_CLEARED && decision != SCREENING_MATCH_STATUS_CONFIRMED {
+        return Err(invalid(
+            "invalid_decision",
+            format!(
+                "Decision must be either '{}' or '{}'",
+                SCREENING_MATCH_STATUS_CLEARED, SCREENING_MATCH_STATUS_CONFIRMED
+            ),
+        ));
+    }
+
+    Ok(())
+}
+
+// An operator's decision on a watchlist match
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate, ToSchema)]
+pub struct ReviewScreeningMatchRequest {
+    #[serde(default)]
+    #[schema(ignore)]
+    pub match_id: i32,
+    #[serde(default)]
+    #[schema(ignore)]
+    pub reviewer_id: i32,
+    // Either "cleared" (a false positive) or "confirmed"
+    #[validate(custom(function = "validate_review_decision"))]
+    pub decision: String,
+    // Required either way, since both outcomes are audited
+    #[serde(default)]
+    #[validate(
+        required(message = "A reason is required when reviewing a watchlist match"),
+        custom(
+            function = "validate_not_blank",
+            message = "A reason is required when reviewing a watchlist match"
+        ),
+        length(
+            max = MAX_REASON_LENGTH,
+            message = "Reason must be at most 500 characters"
+        )
+    )]
+    pub reason: Option<String>,
+}
@@ -0,0 +1,74 @@
+use chrono::{DateTime, NaiveDateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+use crate::utils::{
+    recurrence::BillingInterval,
+    validation::{validate_minimum_amount, validate_not_blank},
+};
+
+pub const SUBSCRIPTION_STATUS_ACTIVE: &str = "active";
+// Claimed by the billing job while its charge runs
+pub const SUBSCRIPTION_STATUS_PROCESSING: &str = "processing";
+pub const SUBSCRIPTION_STATUS_PAUSED: &str = "paused";
+pub const SUBSCRIPTION_STATUS_PAST_DUE: &str = "past_due";
+pub const SUBSCRIPTION_STATUS_CANCELLED: &str = "cancelled";
+
+pub const CHARGE_STATUS_SUCCEEDED: &str = "succeeded";
+pub const CHARGE_STATUS_FAILED: &str = "failed";
+// The charge's transfer is held for review; the subscription stays claimed until it settles
+pub const CHARGE_STATUS_PENDING: &str = "pending";
+
+fn default_interval_count() -> i32 {
+    1
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate, ToSchema)]
+pub struct CreateSubscriptionPlanRequest {
+    #[serde(default)]
+    #[schema(ignore)]
+    pub merchant_id: i32,
+    #[validate(custom(function = "validate_not_blank", message = "Plan name is required"))]
+    pub name: String,
+    // Charges are executed as transfers, which share the same minimum
+    #[validate(custom(
+        function = "validate_minimum_amount",
+        message = "Plan amount must be at least 50,000"
+    ))]
+    pub amount: i32,
+    pub billing_interval: BillingInterval,
+    #[serde(default = "default_interval_count")]
+    #[validate(range(min = 1, message = "Interval count must be greater than zero"))]
+    pub interval_count: i32,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate, ToSchema)]
+pub struct CreateSubscriptionRequest {
+    #[validate(range(min = 1, message = "Plan ID must be a positive integer"))]
+    pub plan_id: i32,
+    #[serde(default)]
+    #[schema(ignore)]
+    pub subscriber_id: i32,
+    // The first charge runs at this time, immediately when omitted
+    pub start_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UpdateSubscriptionScheduleRequest {
+    pub subscription_id: i32,
+    pub status: String,
+    pub next_charge_at: NaiveDateTime,
+    pub billed_periods: i32,
+    pub retry_count: i32,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CreateSubscriptionChargeRequest {
+    pub subscription_id: i32,
+    pub transfer_id: Option<i32>,
+    pub amount: i32,
+    pub status: String,
+    pub attempt: i32,
+    pub failure_reason: Option<String>,
+}
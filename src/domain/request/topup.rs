@@ -1,92 +1,82 @@
 use serde::{Deserialize, Serialize};
-
-use crate::utils::payment_method_validator::payment_method_validator;
-
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+use utoipa::ToSchema;
+use validator::Validate;
+
+use crate::{
+    domain::request::pagination::{
+        ListFields, FILTER_AMOUNT, FILTER_DATE, FILTER_METHOD, FILTER_STATUS, FILTER_USER_ID,
+    },
+    utils::validation::{validate_minimum_amount, validate_not_blank, validate_payment_method},
+};
+
+pub const TOPUP_STATUS_COMPLETED: &str = "completed";
+pub const TOPUP_STATUS_PENDING_REVIEW: &str = "pending_review";
+pub const TOPUP_STATUS_REJECTED: &str = "rejected";
+
+pub const TOPUP_LIST_FIELDS: ListFields = ListFields {
+    id_field: "topup_id",
+    sort_fields: &["topup_id", "topup_amount", "topup_time", "created_at"],
+    filters: &[
+        FILTER_USER_ID,
+        FILTER_DATE,
+        FILTER_AMOUNT,
+        FILTER_STATUS,
+        FILTER_METHOD,
+    ],
+};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate, ToSchema)]
 pub struct CreateTopupRequest {
+    #[validate(range(min = 1, message = "User ID must be a positive integer"))]
     pub user_id: i32,
+    #[validate(custom(function = "validate_not_blank", message = "Top-up number is required"))]
     pub topup_no: String,
+    #[validate(custom(
+        function = "validate_minimum_amount",
+        message = "Topup amount must be at least 50,000"
+    ))]
     pub topup_amount: i32,
+    #[validate(
+        length(min = 1, message = "Top-up method is required"),
+        custom(function = "validate_payment_method", message = "Topup method not found")
+    )]
     pub topup_method: String,
 }
 
-impl CreateTopupRequest {
-    pub fn validate(&self) -> Result<(), String> {
-        if self.user_id <= 0 {
-            return Err("User ID must be a positive integer".to_string());
-        }
-
-        if self.topup_no.is_empty() {
-            return Err("Top-up number is required".to_string());
-        }
-
-        if self.topup_amount <= 50000 {
-            return Err("Topup amount must be greater than or equal to 50000".to_string());
-        }
-
-        if self.topup_method.is_empty() {
-            return Err("Top-up method is required".to_string());
-        }
-
-        if payment_method_validator(&self.topup_method.to_owned()) {
-            return Err("Topup method not found".to_string());
-        }
-
-        Ok(())
-    }
+// The bank a virtual account payment came through and the reference it was notified with
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BankPaymentReference {
+    pub bank_code: String,
+    pub payment_reference: String,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate, ToSchema)]
 pub struct UpdateTopupRequest {
+    #[validate(range(min = 1, message = "User ID must be a positive integer"))]
     pub user_id: i32,
+    // Taken from the path
+    #[serde(default)]
+    #[schema(ignore)]
     pub topup_id: i32,
+    #[validate(custom(
+        function = "validate_minimum_amount",
+        message = "Topup amount must be at least 50,000"
+    ))]
     pub topup_amount: i32,
+    #[validate(
+        length(min = 1, message = "Top-up method is required"),
+        custom(function = "validate_payment_method", message = "Topup method not found")
+    )]
     pub topup_method: String,
 }
 
-impl UpdateTopupRequest {
-    pub fn validate(&self) -> Result<(), String> {
-        if self.user_id <= 0 {
-            return Err("User ID must be a positive integer".to_string());
-        }
-
-        if self.topup_id <= 0 {
-            return Err("Top-up ID must be a positive integer".to_string());
-        }
-
-        if self.topup_amount <= 50000 {
-            return Err("Topup amount must be greater than or equal to 50000".to_string());
-        }
-
-        if self.topup_method.is_empty() {
-            return Err("Top-up method is required".to_string());
-        }
-
-        if payment_method_validator(&self.topup_method.to_owned()) {
-            return Err("Topup method not found".to_string());
-        }
-
-        Ok(())
-    }
-}
-
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate)]
 pub struct UpdateTopupAmount {
+    #[validate(range(min = 1, message = "Top-up ID must be a positive integer"))]
     pub topup_id: i32,
+    #[validate(custom(
+        function = "validate_minimum_amount",
+        message = "Topup amount must be at least 50,000"
+    ))]
     pub topup_amount: i32,
 }
-
-
-impl UpdateTopupAmount {
-    pub fn validate(&self) -> Result<(), String> {
-        if self.topup_id <= 0 {
-            return Err("Top-up ID must be a positive integer".to_string());
-        }
-
-        if self.topup_amount <= 50000 {
-            return Err("Topup amount must be greater than or equal to 50000".to_string());
-        }
-
-        Ok(())
-    }
-}
@@ -1,76 +1,93 @@
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::{Validate, ValidationError};
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+use crate::{
+    domain::request::pagination::{
+        ListFields, FILTER_AMOUNT, FILTER_DATE, FILTER_STATUS, FILTER_USER_ID,
+    },
+    utils::validation::{invalid, validate_minimum_amount, validate_recipient},
+};
+
+pub const TRANSFER_STATUS_COMPLETED: &str = "completed";
+pub const TRANSFER_STATUS_PENDING_REVIEW: &str = "pending_review";
+pub const TRANSFER_STATUS_REJECTED: &str = "rejected";
+
+pub const TRANSFER_LIST_FIELDS: ListFields = ListFields {
+    id_field: "transfer_id",
+    sort_fields: &[
+        "transfer_id",
+        "transfer_amount",
+        "transfer_time",
+        "created_at",
+    ],
+    filters: &[FILTER_USER_ID, FILTER_DATE, FILTER_AMOUNT, FILTER_STATUS],
+};
+
+fn validate_transfer_recipient(request: &CreateTransferRequest) -> Result<(), ValidationError> {
+    match (request.transfer_to, &request.recipient) {
+        (0, None) => Err(invalid(
+            "recipient_required",
+            "Either transfer_to or recipient is required",
+        )),
+        (0, Some(_)) => Ok(()),
+        (_, Some(_)) => Err(invalid(
+            "ambiguous_recipient",
+            "Transfer must have either transfer_to or recipient, not both",
+        )),
+        (transfer_to, None) if transfer_to < 0 => {
+            Err(invalid("range", "Transfer to must be a positive integer"))
+        }
+        _ => Ok(()),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate, ToSchema)]
+#[validate(schema(function = "validate_transfer_recipient", skip_on_field_errors = false))]
 pub struct CreateTransferRequest {
+    #[validate(range(min = 1, message = "Transfer from must be a positive integer"))]
     pub transfer_from: i32,
+    // The recipient's user id, or left out when `recipient` is given
+    #[serde(default)]
     pub transfer_to: i32,
+    // The recipient's noc_transfer account number or email
+    #[serde(default)]
+    #[validate(custom(function = "validate_recipient"))]
+    pub recipient: Option<String>,
+    #[validate(custom(
+        function = "validate_minimum_amount",
+        message = "Transfer amount must be at least 50,000"
+    ))]
     pub transfer_amount: i32,
 }
 
 impl CreateTransferRequest {
-    pub fn validate(&self) -> Result<(), String> {
-        if self.transfer_from <= 0 {
-            return Err("Transfer from must be a positive integer".to_string());
-        }
-
-        if self.transfer_to <= 0 {
-            return Err("Transfer to must be a positive integer".to_string());
-        }
-
-        if self.transfer_amount < 50000 {
-            return Err("Transfer amount must be at least 50,000".to_string());
-        }
-
-        Ok(())
+    pub fn is_email_alias(&self) -> bool {
+        self.recipient.as_deref().is_some_and(|r| r.contains('@'))
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate, ToSchema)]
 pub struct UpdateTransferRequest {
+    // Taken from the path
+    #[serde(default)]
+    #[schema(ignore)]
     pub transfer_id: i32,
+    #[validate(range(min = 1, message = "Transfer from must be a positive integer"))]
     pub transfer_from: i32,
+    #[validate(range(min = 1, message = "Transfer to must be a positive integer"))]
     pub transfer_to: i32,
+    #[validate(custom(
+        function = "validate_minimum_amount",
+        message = "Transfer amount must be at least 50,000"
+    ))]
     pub transfer_amount: i32,
 }
 
-impl UpdateTransferRequest {
-    pub fn validate(&self) -> Result<(), String> {
-        if self.transfer_id <= 0 {
-            return Err("Transfer ID must be a positive integer".to_string());
-        }
-
-        if self.transfer_from <= 0 {
-            return Err("Transfer from must be a positive integer".to_string());
-        }
-
-        if self.transfer_to <= 0 {
-            return Err("Transfer to must be a positive integer".to_string());
-        }
-
-        if self.transfer_amount < 50000 {
-            return Err("Transfer amount must be at least 50,000".to_string());
-        }
-
-        Ok(())
-    }
-}
-
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate)]
 pub struct UpdateTransferAmountRequest {
+    #[validate(range(min = 1, message = "Transfer ID must be a positive integer"))]
     pub transfer_id: i32,
+    #[validate(range(min = 1, message = "Transfer amount must be greater than zero"))]
     pub transfer_amount: i32,
 }
-
-impl UpdateTransferAmountRequest {
-    pub fn validate(&self) -> Result<(), String> {
-        if self.transfer_id <= 0 {
-            return Err("Transfer ID must be a positive integer".to_string());
-        }
-
-        if self.transfer_amount <= 0 {
-            return Err("Transfer amount must be greater than zero".to_string());
-        }
-
-        Ok(())
-    }
-}
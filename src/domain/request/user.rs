@@ -1,106 +1,68 @@
-use regex::Regex;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::{Validate, ValidationError};
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+use crate::{
+    domain::request::pagination::{ListFields, FILTER_DATE, FILTER_STATUS},
+    utils::validation::{invalid, validate_not_blank},
+};
+
+// The status filter matches the account status
+pub const USER_LIST_FIELDS: ListFields = ListFields {
+    id_field: "user_id",
+    sort_fields: &["user_id", "firstname", "lastname", "email", "created_at"],
+    filters: &[FILTER_DATE, FILTER_STATUS],
+};
+
+fn validate_update_fields(request: &UpdateUserRequest) -> Result<(), ValidationError> {
+    if request.email.is_none()
+        && request.password.is_none()
+        && request.firstname.is_none()
+        && request.lastname.is_none()
+    {
+        return Err(invalid(
+            "no_fields",
+            "At least one field must be provided for update",
+        ));
+    }
+
+    // The confirmation is optional, but has to agree with the new password when sent
+    if let (Some(password), Some(confirm_password)) = (&request.password, &request.confirm_password)
+    {
+        if password != confirm_password {
+            return Err(invalid("password_mismatch", "Passwords do not match"));
+        }
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate)]
 pub struct CreateUserRequest {
+    #[validate(custom(function = "validate_not_blank", message = "First name is required"))]
     pub firstname: String,
+    #[validate(custom(function = "validate_not_blank", message = "Last name is required"))]
     pub lastname: String,
+    #[validate(email(message = "Invalid email format"))]
     pub email: String,
+    #[validate(length(min = 8, message = "Password must be at least 8 characters long"))]
     pub password: String,
     pub noc_transfer: Option<String>,
+    #[validate(must_match(other = "password", message = "Passwords do not match"))]
     pub confirm_password: String,
 }
 
-impl CreateUserRequest {
-    pub fn validate(&self) -> Result<(), String> {
-        // Check if the first name is not empty
-        if self.firstname.trim().is_empty() {
-            return Err("First name is required".to_string());
-        }
-
-        // Check if the last name is not empty
-        if self.lastname.trim().is_empty() {
-            return Err("Last name is required".to_string());
-        }
-
-        // Validate email format (simple check using regex)
-        let email_regex = Regex::new(r"^[\w\.-]+@[\w\.-]+\.[a-zA-Z]{2,}$").unwrap();
-        if !email_regex.is_match(&self.email) {
-            return Err("Invalid email format".to_string());
-        }
-
-        // Ensure passwords match
-        if self.password != self.confirm_password {
-            return Err("Passwords do not match".to_string());
-        }
-
-        // Ensure password is at least 8 characters long
-        if self.password.len() < 8 {
-            return Err("Password must be at least 8 characters long".to_string());
-        }
-
-        Ok(())
-    }
-}
-
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate, ToSchema)]
+#[validate(schema(function = "validate_update_fields", skip_on_field_errors = false))]
 pub struct UpdateUserRequest {
     pub id: Option<i32>,
+    #[validate(custom(function = "validate_not_blank", message = "First name cannot be empty"))]
     pub firstname: Option<String>,
+    #[validate(custom(function = "validate_not_blank", message = "Last name cannot be empty"))]
     pub lastname: Option<String>,
+    #[validate(email(message = "Invalid email format"))]
     pub email: Option<String>,
+    #[validate(length(min = 8, message = "Password must be at least 8 characters long"))]
     pub password: Option<String>,
     pub confirm_password: Option<String>,
 }
-
-impl UpdateUserRequest {
-    pub fn validate(&self) -> Result<(), String> {
-        if self.email.is_none()
-            && self.password.is_none()
-            && self.firstname.is_none()
-            && self.lastname.is_none()
-        {
-            return Err("At least one field must be provided for update".to_string());
-        }
-
-        // Validate firstname if provided
-        if let Some(ref firstname) = self.firstname {
-            if firstname.trim().is_empty() {
-                return Err("First name cannot be empty".to_string());
-            }
-        }
-
-        // Validate lastname if provided
-        if let Some(ref lastname) = self.lastname {
-            if lastname.trim().is_empty() {
-                return Err("Last name cannot be empty".to_string());
-            }
-        }
-
-        // Validate email if provided
-        if let Some(ref email) = self.email {
-            let email_regex = Regex::new(r"^[\w\.-]+@[\w\.-]+\.[a-zA-Z]{2,}$").unwrap();
-            if !email_regex.is_match(email) {
-                return Err("Invalid email format".to_string());
-            }
-        }
-
-        // Validate password if provided
-        if let Some(ref password) = self.password {
-            if password.len() < 8 {
-                return Err("Password must be at least 8 characters long".to_string());
-            }
-        }
-
-       
-        if let (Some(ref password), Some(ref confirm_password)) =
-            (self.password.as_ref(), self.confirm_password.as_ref())
-        {
-            if password != confirm_password {
-                return Err("Passwords do not match".to_string());
-            }
-        }
-
-        Ok(())
-    }
-}
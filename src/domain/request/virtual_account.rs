@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+use crate::utils::validation::{validate_not_blank, validate_va_number};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CreateVirtualAccountRequest {
+    pub user_id: i32,
+    pub bank_code: String,
+    pub va_number: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate, ToSchema)]
+pub struct VirtualAccountNotificationRequest {
+    #[validate(custom(function = "validate_va_number"))]
+    pub va_number: String,
+    #[validate(range(min = 1, message = "Amount must be greater than zero"))]
+    pub amount: i32,
+    #[validate(custom(
+        function = "validate_not_blank",
+        message = "Payment reference is required"
+    ))]
+    pub payment_reference: String,
+}
@@ -1,57 +1,71 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+use crate::{
+    domain::request::pagination::{
+        ListFields, FILTER_AMOUNT, FILTER_DATE, FILTER_STATUS, FILTER_USER_ID,
+    },
+    utils::validation::{validate_minimum_amount, validate_not_in_future},
+};
+
+pub const WITHDRAW_STATUS_PENDING_REVIEW: &str = "pending_review";
+pub const WITHDRAW_STATUS_PENDING: &str = "pending";
+pub const WITHDRAW_STATUS_PROCESSING: &str = "processing";
+pub const WITHDRAW_STATUS_PAID: &str = "paid";
+pub const WITHDRAW_STATUS_FAILED: &str = "failed";
+
+pub const WITHDRAW_LIST_FIELDS: ListFields = ListFields {
+    id_field: "withdraw_id",
+    sort_fields: &["withdraw_id", "withdraw_amount", "withdraw_time", "created_at"],
+    filters: &[FILTER_USER_ID, FILTER_DATE, FILTER_AMOUNT, FILTER_STATUS],
+};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate, ToSchema)]
 pub struct CreateWithdrawRequest {
+    #[validate(range(min = 1, message = "User ID must be positive"))]
     pub user_id: i32,
+    #[validate(range(min = 1, message = "Beneficiary ID must be positive"))]
+    pub beneficiary_id: i32,
+    #[validate(custom(
+        function = "validate_minimum_amount",
+        message = "Withdraw amount must be at least 50,000"
+    ))]
     pub withdraw_amount: i32,
+    #[validate(custom(
+        function = "validate_not_in_future",
+        message = "Withdraw time cannot be in the future"
+    ))]
     pub withdraw_time: DateTime<Utc>,
 }
 
-impl CreateWithdrawRequest {
-    pub fn validate(&self) -> Result<(), String> {
-        if self.user_id <= 0 {
-            return Err("User ID must be positive".to_string());
-        }
-
-        if self.withdraw_amount <= 50000 {
-            return Err("Withdraw amount must be at least 50,000".to_string());
-        }
-
-        if self.withdraw_time > Utc::now() {
-            return Err("Withdraw time cannot be in the future".to_string());
-        }
-
-        Ok(())
-    }
-}
-
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate, ToSchema)]
 pub struct UpdateWithdrawRequest {
+    #[validate(range(min = 1, message = "User ID must be positive"))]
     pub user_id: i32,
+    // Taken from the path
+    #[serde(default)]
+    #[schema(ignore)]
     pub withdraw_id: i32,
+    #[validate(custom(
+        function = "validate_minimum_amount",
+        message = "Withdraw amount must be at least 50,000"
+    ))]
     pub withdraw_amount: i32,
+    #[validate(custom(
+        function = "validate_not_in_future",
+        message = "Withdraw time cannot be in the future"
+    ))]
     pub withdraw_time: DateTime<Utc>,
 }
 
-impl UpdateWithdrawRequest {
-    pub fn validate(&self) -> Result<(), String> {
-        if self.user_id <= 0 {
-            return Err("User ID must be positive".to_string());
-        }
-
-        if self.withdraw_id <= 0 {
-            return Err("Withdraw ID must be positive".to_string());
-        }
-
-        if self.withdraw_amount <= 50000 {
-            return Err("Withdraw amount must be at least 50,000".to_string());
-        }
-
-        if self.withdraw_time > Utc::now() {
-            return Err("Withdraw time cannot be in the future".to_string());
-        }
-
-        Ok(())
-    }
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UpdateWithdrawPayoutRequest {
+    pub withdraw_id: i32,
+    // The update only applies while the withdraw is still in this status
+    pub from_status: String,
+    pub status: String,
+    pub payout_reference: Option<String>,
+    pub failure_reason: Option<String>,
 }
@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::{entities::users, utils::mask::mask_name};
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct AccountLookupResponse {
+    pub noc_transfer: String,
+    pub account_name: String,
+}
+
+impl From<users::Model> for AccountLookupResponse {
+    fn from(value: users::Model) -> Self {
+        AccountLookupResponse {
+            noc_transfer: value.noc_transfer,
+            account_name: mask_name(&format!("{} {}", value.firstname, value.lastname)),
+        }
+    }
+}
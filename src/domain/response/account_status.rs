@@ -0,0 +1,30 @@
+use chrono::{DateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::entities::account_status_changes;
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct AccountStatusChangeResponse {
+    pub change_id: i32,
+    pub user_id: i32,
+    pub previous_status: String,
+    pub new_status: String,
+    pub reason: String,
+    pub changed_by: i32,
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+impl From<account_status_changes::Model> for AccountStatusChangeResponse {
+    fn from(value: account_status_changes::Model) -> Self {
+        AccountStatusChangeResponse {
+            change_id: value.change_id,
+            user_id: value.user_id,
+            previous_status: value.previous_status,
+            new_status: value.new_status,
+            reason: value.reason,
+            changed_by: value.changed_by,
+            created_at: value.created_at.map(|dt| Utc.from_utc_datetime(&dt)),
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use chrono::{DateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::entities::beneficiaries;
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct BeneficiaryResponse {
+    pub beneficiary_id: i32,
+    pub user_id: i32,
+    pub bank_code: String,
+    pub account_number: String,
+    pub account_name: String,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl From<beneficiaries::Model> for BeneficiaryResponse {
+    fn from(value: beneficiaries::Model) -> Self {
+        BeneficiaryResponse {
+            beneficiary_id: value.beneficiary_id,
+            user_id: value.user_id,
+            bank_code: value.bank_code,
+            account_number: value.account_number,
+            account_name: value.account_name,
+            created_at: value.created_at.map(|dt| Utc.from_utc_datetime(&dt)),
+            updated_at: value.updated_at.map(|dt| Utc.from_utc_datetime(&dt)),
+        }
+    }
+}
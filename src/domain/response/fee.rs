@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::utils::fee::FeeOperation;
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct FeePreviewResponse {
+    pub operation: FeeOperation,
+    pub channel: Option<String>,
+    pub amount: i32,
+    pub fee_amount: i32,
+    pub fee_rule: Option<String>,
+    // What leaves the payer's saldo and what reaches the destination
+    pub total_debit: i64,
+    pub net_amount: i64,
+}
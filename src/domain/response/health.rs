@@ -0,0 +1,54 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::config::database::{MigrationStatus, PoolStats};
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct PoolStatsResponse {
+    pub size: u32,
+    pub idle: u32,
+    pub in_use: u32,
+    pub max_connections: u32,
+}
+
+impl From<PoolStats> for PoolStatsResponse {
+    fn from(value: PoolStats) -> Self {
+        PoolStatsResponse {
+            size: value.size,
+            idle: value.idle,
+            in_use: value.in_use(),
+            max_connections: value.max_connections,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct MigrationStatusResponse {
+    pub applied: Vec<String>,
+    pub pending: Vec<String>,
+}
+
+impl From<MigrationStatus> for MigrationStatusResponse {
+    fn from(value: MigrationStatus) -> Self {
+        MigrationStatusResponse {
+            applied: value.applied,
+            pending: value.pending,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct HealthDetailsResponse {
+    pub version: String,
+    pub started_at: DateTime<Utc>,
+    pub uptime_secs: u64,
+    // Whether /health/ready currently passes
+    pub ready: bool,
+    pub shutting_down: bool,
+    pub database_reachable: bool,
+    // None when the connection is not a pool
+    pub pool: Option<PoolStatsResponse>,
+    // None when the migration table could not be read
+    pub migrations: Option<MigrationStatusResponse>,
+}
@@ -0,0 +1,91 @@
+use chrono::{DateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::entities::{invoice_items, invoice_payments, invoices};
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct InvoiceItemResponse {
+    pub item_id: i32,
+    pub description: String,
+    pub quantity: i32,
+    pub unit_price: i32,
+    pub subtotal: i32,
+}
+
+impl From<invoice_items::Model> for InvoiceItemResponse {
+    fn from(value: invoice_items::Model) -> Self {
+        InvoiceItemResponse {
+            item_id: value.item_id,
+            description: value.description,
+            quantity: value.quantity,
+            unit_price: value.unit_price,
+            subtotal: value.quantity * value.unit_price,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct InvoiceResponse {
+    pub invoice_id: i32,
+    pub merchant_id: i32,
+    pub token: String,
+    pub payment_url: String,
+    pub description: String,
+    pub total_amount: Option<i32>,
+    pub paid_amount: i32,
+    pub amount_due: Option<i32>,
+    pub status: String,
+    pub due_date: DateTime<Utc>,
+    pub items: Vec<InvoiceItemResponse>,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl InvoiceResponse {
+    pub fn new(value: invoices::Model, items: Vec<invoice_items::Model>) -> Self {
+        InvoiceResponse {
+            invoice_id: value.invoice_id,
+            merchant_id: value.merchant_id,
+            payment_url: format!("/pay/{}", value.token),
+            token: value.token,
+            description: value.description,
+            total_amount: value.total_amount,
+            paid_amount: value.paid_amount,
+            amount_due: value
+                .total_amount
+                .map(|total| (total - value.paid_amount).max(0)),
+            status: value.status,
+            due_date: Utc.from_utc_datetime(&value.due_date),
+            items: items.into_iter().map(InvoiceItemResponse::from).collect(),
+            created_at: value.created_at.map(|dt| Utc.from_utc_datetime(&dt)),
+            updated_at: value.updated_at.map(|dt| Utc.from_utc_datetime(&dt)),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct InvoicePaymentResponse {
+    pub payment_id: i32,
+    pub invoice_id: i32,
+    pub payer_id: i32,
+    // None until the payment's transfer has run
+    pub transfer_id: Option<i32>,
+    pub amount: i32,
+    pub status: String,
+    pub paid_at: DateTime<Utc>,
+}
+
+impl From<invoice_payments::Model> for InvoicePaymentResponse {
+    fn from(value: invoice_payments::Model) -> Self {
+        InvoicePaymentResponse {
+            payment_id: value.payment_id,
+            invoice_id: value.invoice_id,
+            payer_id: value.payer_id,
+            transfer_id: value.transfer_id,
+            amount: value.amount,
+            status: value.status,
+            paid_at: Utc.from_utc_datetime(&value.paid_at),
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use chrono::{DateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::entities::{kyc_documents, kyc_reviews};
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct KycDocumentResponse {
+    pub document_id: i32,
+    pub user_id: i32,
+    pub document_type: String,
+    pub requested_tier: String,
+    pub file_name: String,
+    pub content_type: String,
+    pub file_size: i32,
+    pub status: String,
+    pub rejection_reason: Option<String>,
+    pub reviewed_by: Option<i32>,
+    pub reviewed_at: Option<DateTime<Utc>>,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl From<kyc_documents::Model> for KycDocumentResponse {
+    fn from(value: kyc_documents::Model) -> Self {
+        KycDocumentResponse {
+            document_id: value.document_id,
+            user_id: value.user_id,
+            document_type: value.document_type,
+            requested_tier: value.requested_tier,
+            file_name: value.file_name,
+            content_type: value.content_type,
+            file_size: value.file_size,
+            status: value.status,
+            rejection_reason: value.rejection_reason,
+            reviewed_by: value.reviewed_by,
+            reviewed_at: value.reviewed_at.map(|dt| Utc.from_utc_datetime(&dt)),
+            created_at: value.created_at.map(|dt| Utc.from_utc_datetime(&dt)),
+            updated_at: value.updated_at.map(|dt| Utc.from_utc_datetime(&dt)),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct KycReviewResponse {
+    pub review_id: i32,
+    pub document_id: i32,
+    pub user_id: i32,
+    pub reviewer_id: i32,
+    pub decision: String,
+    pub reason: Option<String>,
+    pub previous_tier: String,
+    pub new_tier: String,
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+impl From<kyc_reviews::Model> for KycReviewResponse {
+    fn from(value: kyc_reviews::Model) -> Self {
+        KycReviewResponse {
+            review_id: value.review_id,
+            document_id: value.document_id,
+            user_id: value.user_id,
+            reviewer_id: value.reviewer_id,
+            decision: value.decision,
+            reason: value.reason,
+            previous_tier: value.previous_tier,
+            new_tier: value.new_tier,
+            created_at: value.created_at.map(|dt| Utc.from_utc_datetime(&dt)),
+        }
+    }
+}
+
+// Raw document content served to operators during review
+#[derive(Debug)]
+pub struct KycDocumentFile {
+    pub file_name: String,
+    pub content_type: String,
+    pub content: Vec<u8>,
+}
@@ -0,0 +1,50 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::utils::kyc::{KycTier, OperationLimits, TierLimits};
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct OperationUsageResponse {
+    pub limits: OperationLimits,
+    // Rolling 24 hour and 30 day totals
+    pub daily_used: i64,
+    pub monthly_used: i64,
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct UserLimitResponse {
+    pub user_id: i32,
+    pub kyc_tier: KycTier,
+    pub max_balance: i64,
+    pub balance: i64,
+    pub transfer: OperationUsageResponse,
+    pub withdraw: OperationUsageResponse,
+}
+
+impl UserLimitResponse {
+    pub fn new(
+        user_id: i32,
+        kyc_tier: KycTier,
+        limits: TierLimits,
+        balance: i64,
+        transfer_used: (i64, i64),
+        withdraw_used: (i64, i64),
+    ) -> Self {
+        UserLimitResponse {
+            user_id,
+            kyc_tier,
+            max_balance: limits.max_balance,
+            balance,
+            transfer: OperationUsageResponse {
+                limits: limits.transfer,
+                daily_used: transfer_used.0,
+                monthly_used: transfer_used.1,
+            },
+            withdraw: OperationUsageResponse {
+                limits: limits.withdraw,
+                daily_used: withdraw_used.0,
+                monthly_used: withdraw_used.1,
+            },
+        }
+    }
+}
@@ -1,17 +1,40 @@
 use core::fmt;
 
+use actix_web::{http::StatusCode, HttpResponse, ResponseError};
 use serde::Serialize;
+use utoipa::ToSchema;
+use serde_json::Value;
+use tracing::error;
 
-use crate::utils::errors::AppError;
+use crate::utils::{errors::AppError, validation::validation_details};
+
+use self::pagination::Pagination;
 
 pub mod user;
 pub mod saldo;
 pub mod topup;
 pub mod transfer;
 pub mod withdraw;
+pub mod virtual_account;
+pub mod qris;
+pub mod invoice;
+pub mod subscription;
+pub mod scheduled_transfer;
+pub mod account;
+pub mod beneficiary;
+pub mod payout;
+pub mod payout_batch;
+pub mod fee;
+pub mod limit;
+pub mod kyc;
+pub mod risk;
+pub mod screening;
+pub mod account_status;
+pub mod health;
+pub mod pagination;
 
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 
 pub struct  ApiResponse<T>{
     pub status: String,
@@ -30,35 +53,159 @@ impl<T: std::fmt::Debug> fmt::Display for ApiResponse<T>{
         )
     }
 }
-#[derive(Debug, Serialize)]
-pub struct ErrorResponse {
+// Body of the endpoints that only confirm an action, such as deletes
+#[derive(Debug, Serialize, ToSchema)]
+pub struct MessageResponse {
+    pub status: String,
+    pub message: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ApiResponsePagination<T> {
     pub status: String,
     pub message: String,
+    pub data: T,
+    pub pagination: Pagination,
+}
+
+impl<T: std::fmt::Debug> fmt::Display for ApiResponsePagination<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ApiResponsePagination {{ status: {}, message: {}, data: {:?}, pagination: {:?} }}",
+            self.status,
+            self.message,
+            self.data,
+            self.pagination
+        )
+    }
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ErrorResponse {
+    #[serde(skip)]
+    pub status_code: StatusCode,
+    pub code: String,
+    pub message: String,
+    pub details: Option<Value>,
+    pub request_id: Option<String>,
+}
+
+impl ErrorResponse {
+    pub fn with_details(mut self, details: Value) -> Self {
+        self.details = Some(details);
+        self
+    }
+
+    // Set by the request id middleware on the way out, so callers can quote it to support
+    pub fn with_request_id(mut self, request_id: &str) -> Self {
+        self.request_id = Some(request_id.to_string());
+        self
+    }
 }
 
 impl From<AppError> for ErrorResponse {
     fn from(error: AppError) -> Self {
-        let (status, message) = match error {
-            AppError::DbError(_) => ("error".to_string(), "Database error occurred".to_string()),
-            AppError::PasswordError(ref msg) => ("error".to_string(), msg.clone()),
-
-            AppError::ValidationError(ref msg) => ("Error Validation".to_string(), msg.clone()),
-
-            AppError::HashingError(_) => ("error".to_string(), "Error during password hashing".to_string()),
-            AppError::NotFound(ref msg) => ("error".to_string(), msg.clone()),
-            AppError::TokenExpiredError => ("error".to_string(), "Token has expired".to_string()),
-            AppError::TokenValidationError => ("error".to_string(), "Token validation failed".to_string()),
-            AppError::TokenGenerationError(_) => ("error".to_string(), "Token generation failed".to_string()),
-            AppError::BcryptError(ref msg) => ("error".to_string(), format!("Bcrypt error: {}", msg)),
-            AppError::InvalidCredentials => ("error".to_string(), "Invalid credentials".to_string()),
-            AppError::EmailAlreadyExists => ("error".to_string(), "Email already exists".to_string()),
+        let status_code = error.status_code();
+
+        // Internal failures are logged in full but only described generically to the client
+        if status_code.is_server_error() {
+            error!("{}", error);
+        }
+
+        let message = match error {
+            AppError::DbError(_) => "Database error occurred".to_string(),
+            AppError::HashingError(_) | AppError::BcryptError(_) => "Error during password hashing".to_string(),
+            AppError::TokenExpiredError => "Token has expired".to_string(),
+            AppError::TokenValidationError => "Token validation failed".to_string(),
+            AppError::TokenGenerationError(_) => "Token generation failed".to_string(),
+            AppError::InvalidCredentials => "Invalid credentials".to_string(),
+            AppError::EmailAlreadyExists => "Email already exists".to_string(),
+            AppError::StorageError(_) => "Document storage failed".to_string(),
+            AppError::InternalError(_) => "Internal server error".to_string(),
+            AppError::InvalidFields(_) => "One or more fields are invalid".to_string(),
+            AppError::NotFound(ref msg)
+            | AppError::UserNotFound(ref msg)
+            | AppError::ValidationError(ref msg)
+            | AppError::PasswordError(ref msg)
+            | AppError::LimitExceeded(ref msg)
+            | AppError::RiskBlocked(ref msg)
+            | AppError::ScreeningHold(ref msg)
+            | AppError::AccountRestricted(ref msg)
+            | AppError::InsufficientFunds(ref msg)
+            | AppError::Unauthorized(ref msg)
+            | AppError::Forbidden(ref msg)
+            | AppError::RateLimited(ref msg)
+            | AppError::ServiceUnavailable(ref msg) => msg.clone(),
+        };
+
+        let details = match &error {
+            AppError::InvalidFields(errors) => Some(validation_details(errors)),
+            _ => None,
         };
-        ErrorResponse { status, message }
+
+        ErrorResponse {
+            status_code,
+            code: error.code().to_string(),
+            message,
+            details,
+            request_id: None,
+        }
     }
 }
 
 impl fmt::Display for ErrorResponse {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "Status: {}, Message: {}", self.status, self.message)
+        write!(f, "Code: {}, Message: {}", self.code, self.message)
     }
-}
\ No newline at end of file
+}
+
+impl ResponseError for ErrorResponse {
+    fn status_code(&self) -> StatusCode {
+        self.status_code
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(self.status_code).json(self)
+    }
+}
+
+// The error body of v1, from before errors had codes; v1 clients still parse this shape
+#[derive(Debug, Serialize, ToSchema)]
+pub struct LegacyErrorResponse {
+    pub status: String,
+    pub message: String,
+}
+
+impl From<&ErrorResponse> for LegacyErrorResponse {
+    fn from(error: &ErrorResponse) -> Self {
+        // Without a details object the invalid fields have to be spelled out in the message
+        let fields = error
+            .details
+            .as_ref()
+            .and_then(|details| details.get("fields"))
+            .and_then(Value::as_object)
+            .map(|fields| {
+                fields
+                    .iter()
+                    .flat_map(|(path, entries)| {
+                        entries.as_array().into_iter().flatten().filter_map(move |entry| {
+                            let message = entry.get("message")?.as_str()?;
+                            Some(match path.as_str() {
+                                "__all__" => message.to_string(),
+                                _ => format!("{}: {}", path, message),
+                            })
+                        })
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .filter(|messages| !messages.is_empty());
+
+        LegacyErrorResponse {
+            status: "error".to_string(),
+            message: fields
+                .map(|messages| messages.join("; "))
+                .unwrap_or_else(|| error.message.clone()),
+        }
+    }
+}
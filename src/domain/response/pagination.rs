@@ -0,0 +1,15 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+// Offset pages report the totals; cursor pages report where the next page starts
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, ToSchema)]
+pub struct Pagination {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<u64>,
+    pub page_size: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_items: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_pages: Option<u64>,
+    pub next_cursor: Option<String>,
+}
@@ -0,0 +1,6 @@
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PayoutStatus {
+    Processing,
+    Paid,
+    Failed(String),
+}
@@ -0,0 +1,116 @@
+use chrono::{DateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::{
+    domain::request::payout_batch::{
+        PAYOUT_ITEM_STATUS_COMPLETED, PAYOUT_ITEM_STATUS_FAILED, PAYOUT_ITEM_STATUS_PENDING,
+        PAYOUT_ITEM_STATUS_PROCESSING, PAYOUT_ITEM_STATUS_REJECTED, PAYOUT_ITEM_STATUS_SUBMITTED,
+    },
+    entities::{payout_batch_items, payout_batches},
+};
+
+#[derive(Debug, Default, Deserialize, Serialize, ToSchema)]
+pub struct PayoutBatchSummary {
+    pub pending: i32,
+    pub rejected: i32,
+    pub submitted: i32,
+    pub completed: i32,
+    pub failed: i32,
+    pub completed_amount: i32,
+    pub failed_amount: i32,
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct PayoutBatchItemResponse {
+    pub item_id: i32,
+    pub line_number: i32,
+    pub recipient: Option<String>,
+    pub recipient_user_id: Option<i32>,
+    pub bank_code: Option<String>,
+    pub account_number: Option<String>,
+    pub account_name: Option<String>,
+    pub amount: i32,
+    pub reference: Option<String>,
+    pub status: String,
+    pub failure_reason: Option<String>,
+    pub transfer_id: Option<i32>,
+    pub withdraw_id: Option<i32>,
+    pub processed_at: Option<DateTime<Utc>>,
+}
+
+impl From<payout_batch_items::Model> for PayoutBatchItemResponse {
+    fn from(value: payout_batch_items::Model) -> Self {
+        PayoutBatchItemResponse {
+            item_id: value.item_id,
+            line_number: value.line_number,
+            recipient: value.recipient,
+            recipient_user_id: value.recipient_user_id,
+            bank_code: value.bank_code,
+            account_number: value.account_number,
+            account_name: value.account_name,
+            amount: value.amount,
+            reference: value.reference,
+            status: value.status,
+            failure_reason: value.failure_reason,
+            transfer_id: value.transfer_id,
+            withdraw_id: value.withdraw_id,
+            processed_at: value.processed_at.map(|dt| Utc.from_utc_datetime(&dt)),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct PayoutBatchResponse {
+    pub batch_id: i32,
+    pub user_id: i32,
+    pub status: String,
+    pub item_count: i32,
+    pub total_amount: i32,
+    pub reserved_amount: i32,
+    pub summary: PayoutBatchSummary,
+    pub items: Vec<PayoutBatchItemResponse>,
+    pub completed_at: Option<DateTime<Utc>>,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl PayoutBatchResponse {
+    pub fn new(batch: payout_batches::Model, items: Vec<PayoutBatchItemResponse>) -> Self {
+        let summary = items
+            .iter()
+            .fold(PayoutBatchSummary::default(), |mut summary, item| {
+                match item.status.as_str() {
+                    PAYOUT_ITEM_STATUS_PENDING | PAYOUT_ITEM_STATUS_PROCESSING => {
+                        summary.pending += 1
+                    }
+                    PAYOUT_ITEM_STATUS_REJECTED => summary.rejected += 1,
+                    PAYOUT_ITEM_STATUS_SUBMITTED => summary.submitted += 1,
+                    PAYOUT_ITEM_STATUS_COMPLETED => {
+                        summary.completed += 1;
+                        summary.completed_amount += item.amount;
+                    }
+                    PAYOUT_ITEM_STATUS_FAILED => {
+                        summary.failed += 1;
+                        summary.failed_amount += item.amount;
+                    }
+                    _ => {}
+                }
+                summary
+            });
+
+        PayoutBatchResponse {
+            batch_id: batch.batch_id,
+            user_id: batch.user_id,
+            status: batch.status,
+            item_count: batch.item_count,
+            total_amount: batch.total_amount,
+            reserved_amount: batch.reserved_amount,
+            summary,
+            items,
+            completed_at: batch.completed_at.map(|dt| Utc.from_utc_datetime(&dt)),
+            created_at: batch.created_at.map(|dt| Utc.from_utc_datetime(&dt)),
+            updated_at: batch.updated_at.map(|dt| Utc.from_utc_datetime(&dt)),
+        }
+    }
+}
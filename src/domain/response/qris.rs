@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::utils::qris::QrisPayload;
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct QrisResponse {
+    pub payload: String,
+    pub point_of_initiation: String,
+    pub merchant_name: String,
+    pub merchant_city: String,
+    pub merchant_id: String,
+    pub merchant_pan: String,
+    pub merchant_category_code: String,
+    pub postal_code: Option<String>,
+    pub amount: Option<i32>,
+    pub reference: Option<String>,
+}
+
+impl QrisResponse {
+    pub fn new(payload: String, value: QrisPayload) -> Self {
+        let point_of_initiation = if value.is_dynamic() { "dynamic" } else { "static" };
+
+        QrisResponse {
+            payload,
+            point_of_initiation: point_of_initiation.to_string(),
+            merchant_name: value.merchant_name,
+            merchant_city: value.merchant_city,
+            merchant_id: value.merchant_id,
+            merchant_pan: value.merchant_pan,
+            merchant_category_code: value.merchant_category_code,
+            postal_code: value.postal_code,
+            amount: value.amount,
+            reference: value.reference,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct QrisImage {
+    pub content_type: String,
+    pub body: Vec<u8>,
+}
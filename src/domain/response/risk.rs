@@ -0,0 +1,53 @@
+use chrono::{DateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::{entities::risk_decisions, utils::risk::RiskAction};
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct RiskDecisionResponse {
+    pub decision_id: i32,
+    pub user_id: i32,
+    pub operation: String,
+    pub amount: i32,
+    pub counterparty_user_id: Option<i32>,
+    pub action: String,
+    pub triggered_rules: Vec<String>,
+    pub reference_id: Option<i32>,
+    pub review_status: Option<String>,
+    pub reviewed_by: Option<i32>,
+    pub review_reason: Option<String>,
+    pub reviewed_at: Option<DateTime<Utc>>,
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+impl From<risk_decisions::Model> for RiskDecisionResponse {
+    fn from(value: risk_decisions::Model) -> Self {
+        RiskDecisionResponse {
+            decision_id: value.decision_id,
+            user_id: value.user_id,
+            operation: value.operation,
+            amount: value.amount,
+            counterparty_user_id: value.counterparty_user_id,
+            action: value.action,
+            triggered_rules: value
+                .triggered_rules
+                .map(|rules| rules.split(',').map(str::to_string).collect())
+                .unwrap_or_default(),
+            reference_id: value.reference_id,
+            review_status: value.review_status,
+            reviewed_by: value.reviewed_by,
+            review_reason: value.review_reason,
+            reviewed_at: value.reviewed_at.map(|dt| Utc.from_utc_datetime(&dt)),
+            created_at: value.created_at.map(|dt| Utc.from_utc_datetime(&dt)),
+        }
+    }
+}
+
+// Outcome handed back to the service that asked, once the decision is recorded
+#[derive(Debug, Clone)]
+pub struct RiskAssessment {
+    pub decision_id: i32,
+    pub action: RiskAction,
+    pub triggered_rules: Vec<String>,
+}
@@ -0,0 +1,59 @@
+use chrono::{DateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::entities::{scheduled_transfer_executions, scheduled_transfers};
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct ScheduledTransferResponse {
+    pub scheduled_transfer_id: i32,
+    pub transfer_from: i32,
+    pub transfer_to: i32,
+    pub transfer_amount: i32,
+    pub recurrence: Option<String>,
+    pub next_run_at: DateTime<Utc>,
+    pub last_run_at: Option<DateTime<Utc>>,
+    pub status: String,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl From<scheduled_transfers::Model> for ScheduledTransferResponse {
+    fn from(value: scheduled_transfers::Model) -> Self {
+        ScheduledTransferResponse {
+            scheduled_transfer_id: value.scheduled_transfer_id,
+            transfer_from: value.transfer_from,
+            transfer_to: value.transfer_to,
+            transfer_amount: value.transfer_amount,
+            recurrence: value.recurrence,
+            next_run_at: Utc.from_utc_datetime(&value.next_run_at),
+            last_run_at: value.last_run_at.map(|dt| Utc.from_utc_datetime(&dt)),
+            status: value.status,
+            created_at: value.created_at.map(|dt| Utc.from_utc_datetime(&dt)),
+            updated_at: value.updated_at.map(|dt| Utc.from_utc_datetime(&dt)),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct ScheduledTransferExecutionResponse {
+    pub execution_id: i32,
+    pub scheduled_transfer_id: i32,
+    pub transfer_id: Option<i32>,
+    pub status: String,
+    pub failure_reason: Option<String>,
+    pub executed_at: DateTime<Utc>,
+}
+
+impl From<scheduled_transfer_executions::Model> for ScheduledTransferExecutionResponse {
+    fn from(value: scheduled_transfer_executions::Model) -> Self {
+        ScheduledTransferExecutionResponse {
+            execution_id: value.execution_id,
+            scheduled_transfer_id: value.scheduled_transfer_id,
+            transfer_id: value.transfer_id,
+            status: value.status,
+            failure_reason: value.failure_reason,
+            executed_at: Utc.from_utc_datetime(&value.executed_at),
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use chrono::{DateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::entities::screening_matches;
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct ScreeningMatchResponse {
+    pub match_id: i32,
+    pub user_id: i32,
+    pub context: String,
+    pub screened_name: String,
+    pub entry_reference: String,
+    pub entry_name: String,
+    pub entry_source: Option<String>,
+    pub score: f64,
+    pub status: String,
+    pub reviewed_by: Option<i32>,
+    pub review_reason: Option<String>,
+    pub reviewed_at: Option<DateTime<Utc>>,
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+impl From<screening_matches::Model> for ScreeningMatchResponse {
+    fn from(value: screening_matches::Model) -> Self {
+        ScreeningMatchResponse {
+            match_id: value.match_id,
+            user_id: value.user_id,
+            context: value.context,
+            screened_name: value.screened_name,
+            entry_reference: value.entry_reference,
+            entry_name: value.entry_name,
+            entry_source: value.entry_source,
+            score: value.score,
+            status: value.status,
+            reviewed_by: value.reviewed_by,
+            review_reason: value.review_reason,
+            reviewed_at: value.reviewed_at.map(|dt| Utc.from_utc_datetime(&dt)),
+            created_at: value.created_at.map(|dt| Utc.from_utc_datetime(&dt)),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct WatchlistStatusResponse {
+    pub source_file: Option<String>,
+    pub entries: usize,
+    pub match_threshold: f64,
+    pub loaded_at: DateTime<Utc>,
+}
@@ -0,0 +1,97 @@
+use chrono::{DateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::entities::{subscription_charges, subscription_plans, subscriptions};
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct SubscriptionPlanResponse {
+    pub plan_id: i32,
+    pub merchant_id: i32,
+    pub name: String,
+    pub amount: i32,
+    pub billing_interval: String,
+    pub interval_count: i32,
+    pub is_active: bool,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl From<subscription_plans::Model> for SubscriptionPlanResponse {
+    fn from(value: subscription_plans::Model) -> Self {
+        SubscriptionPlanResponse {
+            plan_id: value.plan_id,
+            merchant_id: value.merchant_id,
+            name: value.name,
+            amount: value.amount,
+            billing_interval: value.billing_interval,
+            interval_count: value.interval_count,
+            is_active: value.is_active,
+            created_at: value.created_at.map(|dt| Utc.from_utc_datetime(&dt)),
+            updated_at: value.updated_at.map(|dt| Utc.from_utc_datetime(&dt)),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct SubscriptionResponse {
+    pub subscription_id: i32,
+    pub plan_id: i32,
+    pub subscriber_id: i32,
+    pub status: String,
+    pub next_charge_at: DateTime<Utc>,
+    // Charge dates are this time plus whole billing intervals
+    pub billing_anchor_at: DateTime<Utc>,
+    pub billed_periods: i32,
+    pub retry_count: i32,
+    pub mandate_accepted_at: DateTime<Utc>,
+    pub cancelled_at: Option<DateTime<Utc>>,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl From<subscriptions::Model> for SubscriptionResponse {
+    fn from(value: subscriptions::Model) -> Self {
+        SubscriptionResponse {
+            subscription_id: value.subscription_id,
+            plan_id: value.plan_id,
+            subscriber_id: value.subscriber_id,
+            status: value.status,
+            next_charge_at: Utc.from_utc_datetime(&value.next_charge_at),
+            billing_anchor_at: Utc.from_utc_datetime(&value.billing_anchor_at),
+            billed_periods: value.billed_periods,
+            retry_count: value.retry_count,
+            mandate_accepted_at: Utc.from_utc_datetime(&value.mandate_accepted_at),
+            cancelled_at: value.cancelled_at.map(|dt| Utc.from_utc_datetime(&dt)),
+            created_at: value.created_at.map(|dt| Utc.from_utc_datetime(&dt)),
+            updated_at: value.updated_at.map(|dt| Utc.from_utc_datetime(&dt)),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct SubscriptionChargeResponse {
+    pub charge_id: i32,
+    pub subscription_id: i32,
+    pub transfer_id: Option<i32>,
+    pub amount: i32,
+    pub status: String,
+    pub attempt: i32,
+    pub failure_reason: Option<String>,
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+impl From<subscription_charges::Model> for SubscriptionChargeResponse {
+    fn from(value: subscription_charges::Model) -> Self {
+        SubscriptionChargeResponse {
+            charge_id: value.charge_id,
+            subscription_id: value.subscription_id,
+            transfer_id: value.transfer_id,
+            amount: value.amount,
+            status: value.status,
+            attempt: value.attempt,
+            failure_reason: value.failure_reason,
+            created_at: value.created_at.map(|dt| Utc.from_utc_datetime(&dt)),
+        }
+    }
+}
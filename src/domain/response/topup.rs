@@ -1,9 +1,10 @@
 use chrono::{DateTime, TimeZone, Utc};
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 use crate::entities::topups;
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
 pub struct TopupResponse {
     pub topup_id: i32,
     pub user_id: i32,
@@ -11,6 +12,11 @@ pub struct TopupResponse {
     pub topup_amount: i32,
     pub topup_method: String,
     pub topup_time: DateTime<Utc>,
+    pub status: String,
+    pub fee_amount: i32,
+    pub fee_rule: Option<String>,
+    // What was credited to the saldo after the fee
+    pub net_amount: i32,
     pub created_at: Option<DateTime<Utc>>,
     pub updated_at: Option<DateTime<Utc>>,
 }
@@ -24,6 +30,10 @@ impl From<topups::Model> for TopupResponse {
             topup_amount: value.topup_amount,
             topup_method: value.topup_method,
             topup_time: Utc.from_utc_datetime(&value.topup_time),
+            status: value.status,
+            fee_amount: value.fee_amount,
+            fee_rule: value.fee_rule,
+            net_amount: value.topup_amount - value.fee_amount,
             created_at: value.created_at.map(|dt| Utc.from_utc_datetime(&dt)),
             updated_at: value.updated_at.map(|dt| Utc.from_utc_datetime(&dt)),
         }
@@ -1,15 +1,21 @@
 use chrono::{DateTime, TimeZone, Utc};
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 use crate::entities::transfers;
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
 pub struct TransferResponse {
     pub transfer_id: i32,
     pub transfer_from: i32,
     pub transfer_to: i32,
     pub transfer_amount: i32,
     pub transfer_time: DateTime<Utc>,
+    pub status: String,
+    pub fee_amount: i32,
+    pub fee_rule: Option<String>,
+    // What left the sender's saldo, fee included
+    pub total_debit: i64,
     pub created_at: Option<DateTime<Utc>>,
     pub updated_at: Option<DateTime<Utc>>,
 }
@@ -22,6 +28,10 @@ impl From<transfers::Model> for TransferResponse {
             transfer_to: value.transfer_to,
             transfer_amount: value.transfer_amount,
             transfer_time: Utc.from_utc_datetime(&value.transfer_time),
+            status: value.status,
+            fee_amount: value.fee_amount,
+            fee_rule: value.fee_rule,
+            total_debit: value.transfer_amount as i64 + value.fee_amount as i64,
             created_at: value.created_at.map(|dt| Utc.from_utc_datetime(&dt)),
             updated_at: value.updated_at.map(|dt| Utc.from_utc_datetime(&dt)),
         }
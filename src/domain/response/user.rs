@@ -1,18 +1,27 @@
 use chrono::{DateTime, TimeZone, Utc};
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use crate::entities::users;
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
 pub struct UserResponse {
     pub id: i32,
     pub firstname: String,
     pub lastname: String,
     pub email: String,
     pub noc_transfer: String,
+    pub kyc_tier: String,
+    pub account_status: String,
     pub created_at: Option<DateTime<Utc>>,
     pub updated_at: Option<DateTime<Utc>>,
 }
 
+// Data of the current user endpoint, where a deleted account reads as null
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct CurrentUserResponse {
+    pub user: Option<UserResponse>,
+}
+
 impl From<users::Model> for UserResponse {
     fn from(value: users::Model) -> Self {
         UserResponse {
@@ -21,6 +30,8 @@ impl From<users::Model> for UserResponse {
             lastname: value.lastname,
             email: value.email,
             noc_transfer: value.noc_transfer,
+            kyc_tier: value.kyc_tier,
+            account_status: value.account_status,
             created_at: value.created_at.map(|dt| Utc.from_utc_datetime(&dt)),
             updated_at: value.updated_at.map(|dt| Utc.from_utc_datetime(&dt)),
         }
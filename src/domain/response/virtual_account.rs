@@ -0,0 +1,28 @@
+use chrono::{DateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::entities::virtual_accounts;
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct VirtualAccountResponse {
+    pub va_id: i32,
+    pub user_id: i32,
+    pub bank_code: String,
+    pub va_number: String,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl From<virtual_accounts::Model> for VirtualAccountResponse {
+    fn from(value: virtual_accounts::Model) -> Self {
+        VirtualAccountResponse {
+            va_id: value.va_id,
+            user_id: value.user_id,
+            bank_code: value.bank_code,
+            va_number: value.va_number,
+            created_at: value.created_at.map(|dt| Utc.from_utc_datetime(&dt)),
+            updated_at: value.updated_at.map(|dt| Utc.from_utc_datetime(&dt)),
+        }
+    }
+}
@@ -1,14 +1,23 @@
 use chrono::{DateTime, TimeZone, Utc};
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 use crate::entities::withdraws;
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
 pub struct WithdrawResponse {
     pub withdraw_id: i32,
     pub user_id: i32,
     pub withdraw_amount: i32,
     pub withdraw_time: DateTime<Utc>,
+    pub beneficiary_id: Option<i32>,
+    pub status: String,
+    pub payout_reference: Option<String>,
+    pub failure_reason: Option<String>,
+    pub fee_amount: i32,
+    pub fee_rule: Option<String>,
+    // What left the user's saldo, fee included
+    pub total_debit: i64,
     pub created_at: Option<DateTime<Utc>>,
     pub updated_at: Option<DateTime<Utc>>,
 }
@@ -20,6 +29,13 @@ impl From<withdraws::Model> for WithdrawResponse {
             user_id: value.user_id,
             withdraw_amount: value.withdraw_amount,
             withdraw_time: Utc.from_utc_datetime(&value.withdraw_time),
+            beneficiary_id: value.beneficiary_id,
+            status: value.status,
+            payout_reference: value.payout_reference,
+            failure_reason: value.failure_reason,
+            fee_amount: value.fee_amount,
+            fee_rule: value.fee_rule,
+            total_debit: value.withdraw_amount as i64 + value.fee_amount as i64,
             created_at: value.created_at.map(|dt| Utc.from_utc_datetime(&dt)),
             updated_at: value.updated_at.map(|dt| Utc.from_utc_datetime(&dt)),
         }
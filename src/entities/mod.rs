@@ -7,6 +7,25 @@ pub mod topups;
 pub mod transfers;
 pub mod users;
 pub mod withdraws;
+pub mod virtual_accounts;
+pub mod invoices;
+pub mod invoice_items;
+pub mod invoice_payments;
+pub mod subscription_plans;
+pub mod subscriptions;
+pub mod subscription_charges;
+pub mod scheduled_transfers;
+pub mod scheduled_transfer_executions;
+pub mod beneficiaries;
+pub mod payout_batches;
+pub mod payout_batch_items;
+pub mod kyc_documents;
+pub mod kyc_reviews;
+pub mod risk_decisions;
+pub mod screening_matches;
+pub mod account_status_changes;
+pub mod virtual_account_payments;
+pub mod fee_entries;
 
 
 pub use users::Entity as Users;
@@ -14,4 +33,23 @@ pub use saldo::Entity as Saldo;
 pub use transfers::Entity as Transfer;
 pub use topups::Entity as Topup;
 pub use withdraws::Entity as Withdraws;
+pub use virtual_accounts::Entity as VirtualAccounts;
+pub use invoices::Entity as Invoices;
+pub use invoice_items::Entity as InvoiceItems;
+pub use invoice_payments::Entity as InvoicePayments;
+pub use subscription_plans::Entity as SubscriptionPlans;
+pub use subscriptions::Entity as Subscriptions;
+pub use subscription_charges::Entity as SubscriptionCharges;
+pub use scheduled_transfers::Entity as ScheduledTransfers;
+pub use scheduled_transfer_executions::Entity as ScheduledTransferExecutions;
+pub use beneficiaries::Entity as Beneficiaries;
+pub use payout_batches::Entity as PayoutBatches;
+pub use payout_batch_items::Entity as PayoutBatchItems;
+pub use kyc_documents::Entity as KycDocuments;
+pub use kyc_reviews::Entity as KycReviews;
+pub use risk_decisions::Entity as RiskDecisions;
+pub use screening_matches::Entity as ScreeningMatches;
+pub use account_status_changes::Entity as AccountStatusChanges;
+pub use virtual_account_payments::Entity as VirtualAccountPayments;
+pub use fee_entries::Entity as FeeEntries;
 
@@ -5,3 +5,22 @@ pub use super::topups::Entity as Topups;
 pub use super::transfers::Entity as Transfers;
 pub use super::users::Entity as Users;
 pub use super::withdraws::Entity as Withdraws;
+pub use super::virtual_accounts::Entity as VirtualAccounts;
+pub use super::invoices::Entity as Invoices;
+pub use super::invoice_items::Entity as InvoiceItems;
+pub use super::invoice_payments::Entity as InvoicePayments;
+pub use super::subscription_plans::Entity as SubscriptionPlans;
+pub use super::subscriptions::Entity as Subscriptions;
+pub use super::subscription_charges::Entity as SubscriptionCharges;
+pub use super::scheduled_transfers::Entity as ScheduledTransfers;
+pub use super::scheduled_transfer_executions::Entity as ScheduledTransferExecutions;
+pub use super::beneficiaries::Entity as Beneficiaries;
+pub use super::payout_batches::Entity as PayoutBatches;
+pub use super::payout_batch_items::Entity as PayoutBatchItems;
+pub use super::kyc_documents::Entity as KycDocuments;
+pub use super::kyc_reviews::Entity as KycReviews;
+pub use super::risk_decisions::Entity as RiskDecisions;
+pub use super::screening_matches::Entity as ScreeningMatches;
+pub use super::account_status_changes::Entity as AccountStatusChanges;
+pub use super::virtual_account_payments::Entity as VirtualAccountPayments;
+pub use super::fee_entries::Entity as FeeEntries;
@@ -14,6 +14,9 @@ pub struct Model {
     #[sea_orm(column_type = "Text")]
     pub topup_method: String,
     pub topup_time: DateTime,
+    pub fee_amount: i32,
+    pub fee_rule: Option<String>,
+    pub status: String,
     pub created_at: Option<DateTime>,
     pub updated_at: Option<DateTime>,
 }
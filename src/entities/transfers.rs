@@ -11,6 +11,9 @@ pub struct Model {
     pub transfer_to: i32,
     pub transfer_amount: i32,
     pub transfer_time: DateTime,
+    pub fee_amount: i32,
+    pub fee_rule: Option<String>,
+    pub status: String,
     pub created_at: Option<DateTime>,
     pub updated_at: Option<DateTime>,
 }
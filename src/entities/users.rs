@@ -14,6 +14,8 @@ pub struct Model {
     pub password: String,
     #[sea_orm(unique)]
     pub noc_transfer: String,
+    pub kyc_tier: String,
+    pub account_status: String,
     pub created_at: Option<DateTime>,
     pub updated_at: Option<DateTime>,
 }
@@ -26,6 +28,8 @@ pub enum Relation {
     Topups,
     #[sea_orm(has_many = "super::withdraws::Entity")]
     Withdraws,
+    #[sea_orm(has_many = "super::virtual_accounts::Entity")]
+    VirtualAccounts,
 }
 
 impl Related<super::saldo::Entity> for Entity {
@@ -46,4 +50,10 @@ impl Related<super::withdraws::Entity> for Entity {
     }
 }
 
+impl Related<super::virtual_accounts::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::VirtualAccounts.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
@@ -10,12 +10,27 @@ pub struct Model {
     pub user_id: i32,
     pub withdraw_amount: i32,
     pub withdraw_time: DateTime,
+    pub beneficiary_id: Option<i32>,
+    pub status: String,
+    pub payout_reference: Option<String>,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub failure_reason: Option<String>,
+    pub fee_amount: i32,
+    pub fee_rule: Option<String>,
     pub created_at: Option<DateTime>,
     pub updated_at: Option<DateTime>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::beneficiaries::Entity",
+        from = "Column::BeneficiaryId",
+        to = "super::beneficiaries::Column::BeneficiaryId",
+        on_update = "Cascade",
+        on_delete = "SetNull"
+    )]
+    Beneficiaries,
     #[sea_orm(
         belongs_to = "super::users::Entity",
         from = "Column::UserId",
@@ -26,6 +41,12 @@ pub enum Relation {
     Users,
 }
 
+impl Related<super::beneficiaries::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Beneficiaries.def()
+    }
+}
+
 impl Related<super::users::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Users.def()
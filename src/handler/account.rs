@@ -0,0 +1,29 @@
+use actix_web::{get, web, HttpResponse};
+
+use crate::{
+    domain::response::{account::AccountLookupResponse, ApiResponse, ErrorResponse},
+    middleware::auth::JwtMiddleware,
+    state::AppState,
+};
+use tracing::instrument;
+
+#[utoipa::path(
+    tag = "Accounts",
+    responses((status = 200, description = "OK", body = ApiResponse<AccountLookupResponse>)),
+    security(("bearer_auth" = [])),
+)]
+#[get("/accounts/lookup/{noc}")]
+#[instrument(skip_all)]
+async fn lookup_account(
+    data: web::Data<AppState>,
+    noc: web::Path<String>,
+    _jwt_guard: JwtMiddleware,
+) -> Result<HttpResponse, ErrorResponse> {
+    let response = data
+        .di_container
+        .account_service
+        .lookup_account(&noc.into_inner())
+        .await?;
+
+    Ok(HttpResponse::Ok().json(response))
+}
@@ -1,45 +1,61 @@
 use actix_web::{
     
-    get, post, web,  HttpResponse, Responder,
+    get, post, web,  HttpResponse,
 };
 use serde_json::json;
 
-use crate::{domain::request::auth::{LoginRequest, RegisterRequest}, middleware::auth::JwtMiddleware, state::AppState};
+use crate::{domain::{request::auth::{LoginRequest, RegisterRequest}, response::{user::{CurrentUserResponse, UserResponse}, ApiResponse, ErrorResponse}}, middleware::{auth::JwtMiddleware, rate_limit::RateLimit, validated_json::ValidatedJson}, state::AppState, utils::rate_limit::RateLimitGroup};
+use tracing::instrument;
 
-#[post("/auth/register")]
+#[utoipa::path(
+    tag = "Auth",
+    request_body = RegisterRequest,
+    responses((status = 200, description = "OK", body = ApiResponse<UserResponse>)),
+)]
+#[post("/auth/register", wrap = "RateLimit::new(RateLimitGroup::Auth)")]
+#[instrument(skip_all)]
 async fn register_user_handler(
-    body: web::Json<RegisterRequest>,
+    body: ValidatedJson<RegisterRequest>,
     data: web::Data<AppState>,
-) -> impl Responder {
-    match data.di_container.auth_service.register_user(&body).await {
-        Ok(response) => HttpResponse::Ok().json(response),
-        Err(e) => HttpResponse::InternalServerError().json(e),
-    }
+) -> Result<HttpResponse, ErrorResponse> {
+    let response = data.di_container.auth_service.register_user(&body).await?;
+
+    Ok(HttpResponse::Ok().json(response))
 }
 
-#[post("/auth/login")]
+#[utoipa::path(
+    tag = "Auth",
+    request_body = LoginRequest,
+    responses((status = 200, description = "OK", body = ApiResponse<String>)),
+)]
+#[post("/auth/login", wrap = "RateLimit::new(RateLimitGroup::Auth)")]
+#[instrument(skip_all)]
 async fn login_user_handler(
-    body: web::Json<LoginRequest>,
+    body: ValidatedJson<LoginRequest>,
     data: web::Data<AppState>,
-) -> impl Responder {
-    match data.di_container.auth_service.login_user(&body).await {
-        Ok(response) => HttpResponse::Ok().json(response),
-        Err(e) => HttpResponse::Unauthorized().json(e),
-    }
+) -> Result<HttpResponse, ErrorResponse> {
+    let response = data.di_container.auth_service.login_user(&body).await?;
+
+    Ok(HttpResponse::Ok().json(response))
 }
 
+#[utoipa::path(
+    tag = "Auth",
+    operation_id = "get_current_user",
+    responses((status = 200, description = "OK", body = ApiResponse<CurrentUserResponse>)),
+    security(("bearer_auth" = [])),
+)]
 #[get("/auth/user")]
-async fn get_user(data: web::Data<AppState>, jwt_guard: JwtMiddleware) -> impl Responder {
-    let user = match data.di_container.user_service.find_by_id(jwt_guard.user_id).await {
-        Ok(user) => user,  
-        
-        Err(_) => {
-            return HttpResponse::InternalServerError().json(json!({
-                "status": "error",
-                "message": "Could not fetch user",
-            }));
-        },
-    };
+#[instrument(skip_all)]
+async fn get_user(
+    data: web::Data<AppState>,
+    jwt_guard: JwtMiddleware,
+) -> Result<HttpResponse, ErrorResponse> {
+    let user = data
+        .di_container
+        .user_service
+        .find_by_id(jwt_guard.user_id)
+        .await?;
 
    
     let response = json!({
@@ -50,5 +66,5 @@ async fn get_user(data: web::Data<AppState>, jwt_guard: JwtMiddleware) -> impl R
         }
     });
 
-    HttpResponse::Ok().json(response)
+    Ok(HttpResponse::Ok().json(response))
 }
@@ -0,0 +1,81 @@
+use actix_web::{delete, get, post, web, HttpResponse};
+use serde_json::json;
+
+use crate::{
+    domain::{
+        request::beneficiary::CreateBeneficiaryRequest,
+        response::{beneficiary::BeneficiaryResponse, ApiResponse, ErrorResponse, MessageResponse},
+    },
+    middleware::{auth::JwtMiddleware, validated_json::ValidatedJson},
+    state::AppState,
+};
+use tracing::instrument;
+
+#[utoipa::path(
+    tag = "Beneficiaries",
+    responses((status = 200, description = "OK", body = ApiResponse<Vec<BeneficiaryResponse>>)),
+    security(("bearer_auth" = [])),
+)]
+#[get("/beneficiaries/users/{id}")]
+#[instrument(skip_all)]
+async fn get_beneficiary_users(
+    data: web::Data<AppState>,
+    id: web::Path<i32>,
+    _jwt_guard: JwtMiddleware,
+) -> Result<HttpResponse, ErrorResponse> {
+    let response = data
+        .di_container
+        .beneficiary_service
+        .get_beneficiary_users(id.into_inner())
+        .await?;
+
+    Ok(HttpResponse::Ok().json(response))
+}
+
+#[utoipa::path(
+    tag = "Beneficiaries",
+    request_body = CreateBeneficiaryRequest,
+    responses((status = 201, description = "Created", body = ApiResponse<BeneficiaryResponse>)),
+    security(("bearer_auth" = [])),
+)]
+#[post("/beneficiaries")]
+#[instrument(skip_all)]
+async fn create_beneficiary(
+    data: web::Data<AppState>,
+    body: ValidatedJson<CreateBeneficiaryRequest>,
+    jwt_guard: JwtMiddleware,
+) -> Result<HttpResponse, ErrorResponse> {
+    let mut create_request = body.into_inner();
+    create_request.user_id = jwt_guard.user_id;
+
+    let response = data
+        .di_container
+        .beneficiary_service
+        .create_beneficiary(&create_request)
+        .await?;
+
+    Ok(HttpResponse::Created().json(response))
+}
+
+#[utoipa::path(
+    tag = "Beneficiaries",
+    responses((status = 200, description = "OK", body = MessageResponse)),
+    security(("bearer_auth" = [])),
+)]
+#[delete("/beneficiaries/{id}")]
+#[instrument(skip_all)]
+async fn delete_beneficiary(
+    data: web::Data<AppState>,
+    id: web::Path<i32>,
+    jwt_guard: JwtMiddleware,
+) -> Result<HttpResponse, ErrorResponse> {
+    data.di_container
+        .beneficiary_service
+        .delete_beneficiary(id.into_inner(), jwt_guard.user_id)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(json!({
+        "status": "success",
+        "message": "Beneficiary deleted successfully",
+    })))
+}
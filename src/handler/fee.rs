@@ -0,0 +1,30 @@
+use actix_web::{get, web, HttpResponse};
+
+use crate::{
+    domain::{
+        request::fee::FeePreviewRequest,
+        response::{fee::FeePreviewResponse, ApiResponse, ErrorResponse},
+    },
+    state::AppState,
+};
+use tracing::instrument;
+
+#[utoipa::path(
+    tag = "Fees",
+    params(FeePreviewRequest),
+    responses((status = 200, description = "OK", body = ApiResponse<FeePreviewResponse>)),
+)]
+#[get("/fees/preview")]
+#[instrument(skip_all)]
+async fn preview_fee(
+    data: web::Data<AppState>,
+    query: web::Query<FeePreviewRequest>,
+) -> Result<HttpResponse, ErrorResponse> {
+    let response = data
+        .di_container
+        .fee_service
+        .preview_fee(&query.into_inner())
+        .await?;
+
+    Ok(HttpResponse::Ok().json(response))
+}
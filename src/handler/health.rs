@@ -0,0 +1,55 @@
+use actix_web::{get, web, HttpResponse};
+
+use crate::{
+    domain::response::{
+        health::HealthDetailsResponse, ApiResponse, ErrorResponse, MessageResponse,
+    },
+    middleware::operator::OperatorMiddleware,
+    state::AppState,
+};
+use tracing::instrument;
+
+// Answers as long as the process can serve requests; nothing it depends on is checked
+#[utoipa::path(
+    tag = "Monitoring",
+    responses((status = 200, description = "OK", body = MessageResponse)),
+)]
+#[get("/health/live")]
+#[instrument(skip_all)]
+async fn get_liveness() -> HttpResponse {
+    HttpResponse::Ok().json(MessageResponse {
+        status: "success".to_string(),
+        message: "Alive".to_string(),
+    })
+}
+
+#[utoipa::path(
+    tag = "Monitoring",
+    responses(
+        (status = 200, description = "OK", body = MessageResponse),
+        (status = 503, description = "Not ready", body = ErrorResponse),
+    ),
+)]
+#[get("/health/ready")]
+#[instrument(skip_all)]
+async fn get_readiness(data: web::Data<AppState>) -> Result<HttpResponse, ErrorResponse> {
+    let response = data.di_container.health_service.check_ready().await?;
+
+    Ok(HttpResponse::Ok().json(response))
+}
+
+#[utoipa::path(
+    tag = "Monitoring",
+    responses((status = 200, description = "OK", body = ApiResponse<HealthDetailsResponse>)),
+    security(("bearer_auth" = [])),
+)]
+#[get("/health/details")]
+#[instrument(skip_all)]
+async fn get_health_details(
+    data: web::Data<AppState>,
+    _operator_guard: OperatorMiddleware,
+) -> Result<HttpResponse, ErrorResponse> {
+    let response = data.di_container.health_service.get_details().await?;
+
+    Ok(HttpResponse::Ok().json(response))
+}
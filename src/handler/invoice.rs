@@ -0,0 +1,179 @@
+use actix_web::{get, post, web, HttpResponse};
+
+use crate::{
+    domain::{
+        request::invoice::{CreateInvoiceRequest, PayInvoiceRequest},
+        response::{
+            invoice::{InvoicePaymentResponse, InvoiceResponse},
+            ApiResponse, ErrorResponse,
+        },
+    },
+    middleware::{auth::JwtMiddleware, rate_limit::RateLimit, validated_json::ValidatedJson},
+    state::AppState,
+    utils::{errors::AppError, rate_limit::RateLimitGroup},
+};
+use tracing::instrument;
+
+fn ensure_merchant_or_operator(
+    data: &AppState,
+    jwt_guard: &JwtMiddleware,
+    merchant_id: i32,
+) -> Result<(), ErrorResponse> {
+    if jwt_guard.user_id != merchant_id && !data.operator_user_ids.contains(&jwt_guard.user_id) {
+        return Err(ErrorResponse::from(AppError::Forbidden(
+            "Only the merchant or an operator can view its invoices".to_string(),
+        )));
+    }
+
+    Ok(())
+}
+
+#[utoipa::path(
+    tag = "Invoices",
+    responses((status = 200, description = "OK", body = ApiResponse<InvoiceResponse>)),
+    security(("bearer_auth" = [])),
+)]
+#[get("/invoices/{id}")]
+#[instrument(skip_all)]
+async fn get_invoice(
+    data: web::Data<AppState>,
+    id: web::Path<i32>,
+    jwt_guard: JwtMiddleware,
+) -> Result<HttpResponse, ErrorResponse> {
+    let response = data
+        .di_container
+        .invoice_service
+        .get_invoice(id.into_inner())
+        .await?;
+
+    ensure_merchant_or_operator(&data, &jwt_guard, response.data.merchant_id)?;
+
+    Ok(HttpResponse::Ok().json(response))
+}
+
+#[utoipa::path(
+    tag = "Invoices",
+    responses((status = 200, description = "OK", body = ApiResponse<Vec<InvoiceResponse>>)),
+    security(("bearer_auth" = [])),
+)]
+#[get("/invoices/users/{id}")]
+#[instrument(skip_all)]
+async fn get_invoice_users(
+    data: web::Data<AppState>,
+    id: web::Path<i32>,
+    jwt_guard: JwtMiddleware,
+) -> Result<HttpResponse, ErrorResponse> {
+    let merchant_id = id.into_inner();
+
+    ensure_merchant_or_operator(&data, &jwt_guard, merchant_id)?;
+
+    let response = data
+        .di_container
+        .invoice_service
+        .get_invoice_users(merchant_id)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(response))
+}
+
+#[utoipa::path(
+    tag = "Invoices",
+    responses((status = 200, description = "OK", body = ApiResponse<Vec<InvoicePaymentResponse>>)),
+    security(("bearer_auth" = [])),
+)]
+#[get("/invoices/{id}/payments")]
+#[instrument(skip_all)]
+async fn get_invoice_payments(
+    data: web::Data<AppState>,
+    id: web::Path<i32>,
+    jwt_guard: JwtMiddleware,
+) -> Result<HttpResponse, ErrorResponse> {
+    let invoice_id = id.into_inner();
+
+    let invoice = data
+        .di_container
+        .invoice_service
+        .get_invoice(invoice_id)
+        .await?;
+
+    ensure_merchant_or_operator(&data, &jwt_guard, invoice.data.merchant_id)?;
+
+    let response = data
+        .di_container
+        .invoice_service
+        .get_invoice_payments(invoice_id)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(response))
+}
+
+#[utoipa::path(
+    tag = "Invoices",
+    request_body = CreateInvoiceRequest,
+    responses((status = 201, description = "Created", body = ApiResponse<InvoiceResponse>)),
+    security(("bearer_auth" = [])),
+)]
+#[post("/invoices")]
+#[instrument(skip_all)]
+async fn create_invoice(
+    data: web::Data<AppState>,
+    body: ValidatedJson<CreateInvoiceRequest>,
+    jwt_guard: JwtMiddleware,
+) -> Result<HttpResponse, ErrorResponse> {
+    let mut create_request = body.into_inner();
+    create_request.merchant_id = jwt_guard.user_id;
+
+    let response = data
+        .di_container
+        .invoice_service
+        .create_invoice(&create_request)
+        .await?;
+
+    Ok(HttpResponse::Created().json(response))
+}
+
+#[utoipa::path(
+    tag = "Invoices",
+    responses((status = 200, description = "OK", body = ApiResponse<InvoiceResponse>)),
+)]
+#[get("/pay/{token}")]
+#[instrument(skip_all)]
+async fn get_payment_link(
+    data: web::Data<AppState>,
+    token: web::Path<String>,
+) -> Result<HttpResponse, ErrorResponse> {
+    let response = data
+        .di_container
+        .invoice_service
+        .get_payment_link(&token.into_inner())
+        .await?;
+
+    Ok(HttpResponse::Ok().json(response))
+}
+
+#[utoipa::path(
+    tag = "Invoices",
+    request_body = PayInvoiceRequest,
+    responses((status = 200, description = "OK", body = ApiResponse<InvoicePaymentResponse>)),
+    security(("bearer_auth" = [])),
+)]
+#[post("/pay/{token}", wrap = "RateLimit::new(RateLimitGroup::Transfer)")]
+#[instrument(skip_all)]
+async fn pay_payment_link(
+    data: web::Data<AppState>,
+    token: web::Path<String>,
+    body: ValidatedJson<PayInvoiceRequest>,
+    jwt_guard: JwtMiddleware,
+) -> Result<HttpResponse, ErrorResponse> {
+    let mut pay_request = body.into_inner();
+    pay_request.token = token.into_inner();
+    pay_request.payer_id = jwt_guard.user_id;
+
+    let response = data
+        .di_container
+        .invoice_service
+        .pay_invoice(&pay_request)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(response))
+}
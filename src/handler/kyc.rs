@@ -0,0 +1,263 @@
+use actix_multipart::Multipart;
+use actix_web::{get, http::header, post, web, HttpResponse};
+use futures_util::StreamExt;
+
+use crate::{
+    domain::{
+        request::kyc::{
+            CreateKycDocumentRequest, KycDocumentUploadForm, ReviewKycDocumentRequest,
+            KYC_UPLOAD_FIELDS, MAX_KYC_DOCUMENT_BYTES, MAX_KYC_TEXT_FIELD_BYTES,
+        },
+        response::{
+            kyc::{KycDocumentResponse, KycReviewResponse},
+            ApiResponse, ErrorResponse,
+        },
+    },
+    middleware::{
+        auth::JwtMiddleware, operator::OperatorMiddleware, validated_json::ValidatedJson,
+    },
+    state::AppState,
+    utils::errors::AppError,
+};
+use tracing::instrument;
+
+// Reads the document_type, requested_tier and file parts of a KYC upload form
+async fn read_kyc_upload(
+    mut payload: Multipart,
+    user_id: i32,
+) -> Result<CreateKycDocumentRequest, String> {
+    let mut document_type = None;
+    let mut requested_tier = None;
+    let mut file = None;
+    let mut seen = Vec::with_capacity(KYC_UPLOAD_FIELDS.len());
+
+    while let Some(field) = payload.next().await {
+        let mut field = field.map_err(|e| format!("Invalid multipart body: {}", e))?;
+        let name = field.name().unwrap_or_default().to_string();
+
+        // Unknown and repeated parts are refused before they are read, which also bounds the part count
+        if !KYC_UPLOAD_FIELDS.contains(&name.as_str()) {
+            return Err(format!("Unexpected '{}' part", name));
+        }
+        if seen.contains(&name) {
+            return Err(format!("Duplicate '{}' part", name));
+        }
+        seen.push(name.clone());
+
+        let max_bytes = if name == "file" {
+            MAX_KYC_DOCUMENT_BYTES
+        } else {
+            MAX_KYC_TEXT_FIELD_BYTES
+        };
+
+        let file_name = field
+            .content_disposition()
+            .and_then(|disposition| disposition.get_filename())
+            .map(str::to_string);
+        let content_type = field
+            .content_type()
+            .map(|mime| mime.essence_str().to_string());
+
+        let mut content = Vec::new();
+        while let Some(chunk) = field.next().await {
+            let chunk = chunk.map_err(|e| format!("Invalid multipart body: {}", e))?;
+
+            // Stop reading as soon as the part is too large rather than buffering it all
+            if content.len() + chunk.len() > max_bytes {
+                return Err(format!("'{}' must be at most {} bytes", name, max_bytes));
+            }
+            content.extend_from_slice(&chunk);
+        }
+
+        match name.as_str() {
+            "document_type" => {
+                document_type = Some(String::from_utf8_lossy(&content).trim().to_string())
+            }
+            "requested_tier" => {
+                requested_tier = Some(String::from_utf8_lossy(&content).trim().to_string())
+            }
+            "file" => {
+                file = Some((
+                    file_name.unwrap_or_default(),
+                    content_type.unwrap_or_default(),
+                    content,
+                ))
+            }
+            _ => {}
+        }
+    }
+
+    let (file_name, content_type, content) =
+        file.ok_or_else(|| "Missing 'file' part".to_string())?;
+
+    Ok(CreateKycDocumentRequest {
+        user_id,
+        document_type: document_type.ok_or_else(|| "Missing 'document_type' part".to_string())?,
+        requested_tier: requested_tier
+            .ok_or_else(|| "Missing 'requested_tier' part".to_string())?
+            .parse()?,
+        file_name,
+        content_type,
+        content,
+    })
+}
+
+#[utoipa::path(
+    tag = "KYC",
+    request_body(content = KycDocumentUploadForm, content_type = "multipart/form-data"),
+    responses((status = 201, description = "Created", body = ApiResponse<KycDocumentResponse>)),
+    security(("bearer_auth" = [])),
+)]
+#[post("/kyc/documents")]
+#[instrument(skip_all)]
+async fn submit_kyc_document(
+    data: web::Data<AppState>,
+    payload: Multipart,
+    jwt_guard: JwtMiddleware,
+) -> Result<HttpResponse, ErrorResponse> {
+    let create_request = read_kyc_upload(payload, jwt_guard.user_id)
+        .await
+        .map_err(|e| ErrorResponse::from(AppError::ValidationError(e)))?;
+
+    let response = data
+        .di_container
+        .kyc_service
+        .submit_document(&create_request)
+        .await?;
+
+    Ok(HttpResponse::Created().json(response))
+}
+
+#[utoipa::path(
+    tag = "KYC",
+    responses((status = 200, description = "OK", body = ApiResponse<Vec<KycDocumentResponse>>)),
+    security(("bearer_auth" = [])),
+)]
+#[get("/kyc/documents/users/{id}")]
+#[instrument(skip_all)]
+async fn get_kyc_document_users(
+    data: web::Data<AppState>,
+    id: web::Path<i32>,
+    jwt_guard: JwtMiddleware,
+) -> Result<HttpResponse, ErrorResponse> {
+    let user_id = id.into_inner();
+
+    if jwt_guard.user_id != user_id && !data.operator_user_ids.contains(&jwt_guard.user_id) {
+        return Err(ErrorResponse::from(AppError::Forbidden(
+            "Only the account holder or an operator can view its KYC documents".to_string(),
+        )));
+    }
+
+    let response = data
+        .di_container
+        .kyc_service
+        .get_document_users(user_id)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(response))
+}
+
+#[utoipa::path(
+    tag = "KYC",
+    responses((status = 200, description = "OK", body = ApiResponse<Vec<KycDocumentResponse>>)),
+    security(("bearer_auth" = [])),
+)]
+#[get("/kyc/documents/pending")]
+#[instrument(skip_all)]
+async fn get_pending_kyc_documents(
+    data: web::Data<AppState>,
+    _operator_guard: OperatorMiddleware,
+) -> Result<HttpResponse, ErrorResponse> {
+    let response = data
+        .di_container
+        .kyc_service
+        .get_pending_documents()
+        .await?;
+
+    Ok(HttpResponse::Ok().json(response))
+}
+
+#[utoipa::path(
+    tag = "KYC",
+    responses(
+        (
+            status = 200,
+            description = "The uploaded document",
+            content(
+                (Vec<u8> = "image/jpeg"),
+                (Vec<u8> = "image/png"),
+                (Vec<u8> = "application/pdf"),
+            ),
+        ),
+    ),
+    security(("bearer_auth" = [])),
+)]
+#[get("/kyc/documents/{id}/file")]
+#[instrument(skip_all)]
+async fn get_kyc_document_file(
+    data: web::Data<AppState>,
+    id: web::Path<i32>,
+    _operator_guard: OperatorMiddleware,
+) -> Result<HttpResponse, ErrorResponse> {
+    let file = data
+        .di_container
+        .kyc_service
+        .get_document_file(id.into_inner())
+        .await?;
+
+    Ok(HttpResponse::Ok()
+        .content_type(file.content_type)
+        .insert_header((
+            header::CONTENT_DISPOSITION,
+            format!("inline; filename=\"{}\"", file.file_name.replace('"', "")),
+        ))
+        .body(file.content))
+}
+
+#[utoipa::path(
+    tag = "KYC",
+    request_body = ReviewKycDocumentRequest,
+    responses((status = 200, description = "OK", body = ApiResponse<KycDocumentResponse>)),
+    security(("bearer_auth" = [])),
+)]
+#[post("/kyc/documents/{id}/review")]
+#[instrument(skip_all)]
+async fn review_kyc_document(
+    data: web::Data<AppState>,
+    id: web::Path<i32>,
+    body: ValidatedJson<ReviewKycDocumentRequest>,
+    operator_guard: OperatorMiddleware,
+) -> Result<HttpResponse, ErrorResponse> {
+    let mut review_request = body.into_inner();
+    review_request.document_id = id.into_inner();
+    review_request.reviewer_id = operator_guard.user_id;
+
+    let response = data
+        .di_container
+        .kyc_service
+        .review_document(&review_request)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(response))
+}
+
+#[utoipa::path(
+    tag = "KYC",
+    responses((status = 200, description = "OK", body = ApiResponse<Vec<KycReviewResponse>>)),
+    security(("bearer_auth" = [])),
+)]
+#[get("/kyc/reviews/users/{id}")]
+#[instrument(skip_all)]
+async fn get_kyc_review_users(
+    data: web::Data<AppState>,
+    id: web::Path<i32>,
+    _operator_guard: OperatorMiddleware,
+) -> Result<HttpResponse, ErrorResponse> {
+    let response = data
+        .di_container
+        .kyc_service
+        .get_review_users(id.into_inner())
+        .await?;
+
+    Ok(HttpResponse::Ok().json(response))
+}
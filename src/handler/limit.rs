@@ -0,0 +1,29 @@
+use actix_web::{get, web, HttpResponse};
+
+use crate::{
+    domain::response::{limit::UserLimitResponse, ApiResponse, ErrorResponse},
+    middleware::auth::JwtMiddleware,
+    state::AppState,
+};
+use tracing::instrument;
+
+#[utoipa::path(
+    tag = "Limits",
+    responses((status = 200, description = "OK", body = ApiResponse<UserLimitResponse>)),
+    security(("bearer_auth" = [])),
+)]
+#[get("/limits/users/{id}")]
+#[instrument(skip_all)]
+async fn get_limit_user(
+    data: web::Data<AppState>,
+    id: web::Path<i32>,
+    _jwt_guard: JwtMiddleware,
+) -> Result<HttpResponse, ErrorResponse> {
+    let response = data
+        .di_container
+        .limit_service
+        .get_limits(id.into_inner())
+        .await?;
+
+    Ok(HttpResponse::Ok().json(response))
+}
@@ -0,0 +1,22 @@
+use actix_web::{get, web, HttpResponse};
+
+use crate::{domain::response::ErrorResponse, state::AppState, utils::errors::AppError};
+use tracing::instrument;
+
+#[utoipa::path(
+    tag = "Monitoring",
+    responses((status = 200, description = "OK", body = String, content_type = "text/plain")),
+)]
+#[get("/metrics")]
+#[instrument(skip_all)]
+async fn get_metrics(data: web::Data<AppState>) -> Result<HttpResponse, ErrorResponse> {
+    let body = data
+        .di_container
+        .metrics
+        .render()
+        .map_err(|e| ErrorResponse::from(AppError::InternalError(e)))?;
+
+    Ok(HttpResponse::Ok()
+        .content_type("text/plain; version=0.0.4")
+        .body(body))
+}
@@ -4,6 +4,23 @@ mod saldo;
 mod topup;
 mod transfer;
 mod withdraw;
+mod beneficiary;
+mod payout_batch;
+mod virtual_account;
+mod qris;
+mod invoice;
+mod subscription;
+mod scheduled_transfer;
+mod account;
+mod fee;
+mod limit;
+mod kyc;
+mod risk;
+mod screening;
+mod metrics;
+mod health;
+
+pub mod openapi;
 
 use self::auth::{get_user, login_user_handler, register_user_handler};
 use self::user::{
@@ -11,7 +28,9 @@ use self::user::{
     get_user as get_user_,
     create_user,
     update_user,
-    delete_user
+    delete_user,
+    update_user_status,
+    get_user_status_history
 };
 use self::saldo::{
     get_saldos,
@@ -31,6 +50,7 @@ use self::topup::{
     get_topup_user,
     create_topup,
     update_topup,
+    review_topup,
     delete_topup
 };
 
@@ -42,6 +62,7 @@ use self::transfer::{
     get_transfer_user,
     create_transfer,
     update_transfer,
+    review_transfer,
     delete_transfer
 };
 
@@ -53,13 +74,175 @@ use self::withdraw::{
     get_withdraw_user,
     create_withdraw,
     update_withdraw,
+    review_withdraw,
     delete_withdraw
 };
 
-use actix_web::web;
+use self::beneficiary::{
+    get_beneficiary_users,
+    create_beneficiary,
+    delete_beneficiary
+};
+
+use self::payout_batch::{
+    get_payout_batch_users,
+    get_payout_batch,
+    create_payout_batch
+};
+
+use self::virtual_account::{
+    get_virtual_accounts,
+    issue_virtual_accounts,
+    virtual_account_notification
+};
+
+use self::qris::{
+    generate_qris,
+    parse_qris,
+    render_qris
+};
+
+use self::invoice::{
+    get_invoice,
+    get_invoice_users,
+    get_invoice_payments,
+    create_invoice,
+    get_payment_link,
+    pay_payment_link
+};
+
+use self::subscription::{
+    get_subscription_plan,
+    get_subscription_plan_users,
+    create_subscription_plan,
+    get_subscription,
+    get_subscription_users,
+    get_subscription_charges,
+    create_subscription,
+    pause_subscription,
+    resume_subscription,
+    cancel_subscription
+};
+
+use self::scheduled_transfer::{
+    get_scheduled_transfer,
+    get_scheduled_transfer_users,
+    get_scheduled_transfer_executions,
+    create_scheduled_transfer,
+    update_scheduled_transfer,
+    delete_scheduled_transfer
+};
+
+use self::account::lookup_account;
+
+use self::fee::preview_fee;
+
+use self::limit::get_limit_user;
+
+use self::kyc::{
+    submit_kyc_document,
+    get_kyc_document_users,
+    get_pending_kyc_documents,
+    get_kyc_document_file,
+    review_kyc_document,
+    get_kyc_review_users
+};
+
+use self::risk::{
+    get_risk_reviews,
+    get_risk_decision_users
+};
+
+use self::screening::{
+    get_pending_screening_matches,
+    get_screening_match_users,
+    review_screening_match,
+    get_watchlist,
+    reload_watchlist
+};
+
+use self::metrics::get_metrics;
+
+use self::health::{
+    get_liveness,
+    get_readiness,
+    get_health_details
+};
 
+use actix_web::{middleware::from_fn, web};
+use utoipa::OpenApi;
+use utoipa_swagger_ui::SwaggerUi;
+
+use crate::{
+    domain::response::ErrorResponse,
+    middleware::{api_version::v1_compat, rate_limit::RateLimit},
+    utils::{
+        api_version::{API_LEGACY_PREFIX, API_V1_PREFIX, API_V2_PREFIX},
+        errors::AppError,
+        rate_limit::RateLimitGroup,
+    },
+};
+
+use self::openapi::ApiDoc;
+
+// Malformed bodies, query strings and path segments get the same error body as service errors
+fn extractor_error(err: impl std::fmt::Display) -> actix_web::Error {
+    ErrorResponse::from(AppError::ValidationError(err.to_string())).into()
+}
+
+/// Mounts every route.
+///
+/// The API is served as `/api/v2` and `/api/v1`, with the unversioned `/api` paths kept as
+/// an alias of v1. Both versions share `api_routes`; v1 differs only in its response shape and
+/// deprecation headers, which `v1_compat` applies on the way out.
+///
+/// Every API route falls in the `api` rate limit group; login, registration and transfers are
+/// additionally wrapped in their own group on the handler.
 pub fn router_config(conf: &mut web::ServiceConfig) {
-    let router = web::scope("/api")
+    conf.app_data(web::JsonConfig::default().error_handler(|err, _| extractor_error(err)))
+        .app_data(web::QueryConfig::default().error_handler(|err, _| extractor_error(err)))
+        .app_data(web::PathConfig::default().error_handler(|err, _| extractor_error(err)));
+
+    // The spec and its Swagger UI go ahead of the /api scope, which would otherwise claim them
+    conf.service(SwaggerUi::new("/api/docs/{_:.*}").url("/api/openapi.json", ApiDoc::openapi()));
+
+    conf.service(
+        web::scope(API_V2_PREFIX)
+            .wrap(RateLimit::new(RateLimitGroup::Api))
+            .configure(api_routes),
+    );
+
+    // v1_compat goes on last so it also reshapes rate limit errors
+    conf.service(
+        web::scope(API_V1_PREFIX)
+            .wrap(RateLimit::new(RateLimitGroup::Api))
+            .wrap(from_fn(v1_compat))
+            .configure(api_routes),
+    );
+
+    // Registered after the versioned scopes so it does not swallow their paths
+    conf.service(
+        web::scope(API_LEGACY_PREFIX)
+            .wrap(RateLimit::new(RateLimitGroup::Api))
+            .wrap(from_fn(v1_compat))
+            .configure(api_routes),
+    );
+
+    // Public payment link routes
+    conf.service(get_payment_link)
+        .service(pay_payment_link);
+
+    // Prometheus scrape endpoint
+    conf.service(get_metrics);
+
+    // Orchestrator probes, outside the API scopes so they are never rate limited
+    conf.service(get_liveness)
+        .service(get_readiness)
+        .service(get_health_details);
+}
+
+fn api_routes(conf: &mut web::ServiceConfig) {
+    conf
         // Auth routes
         .service(register_user_handler)
         .service(login_user_handler)
@@ -71,6 +254,8 @@ pub fn router_config(conf: &mut web::ServiceConfig) {
         .service(create_user)
         .service(update_user)
         .service(delete_user)
+        .service(update_user_status)
+        .service(get_user_status_history)
 
         // Saldo routes
         .service(get_saldos)
@@ -88,6 +273,7 @@ pub fn router_config(conf: &mut web::ServiceConfig) {
         .service(get_topup_user)
         .service(create_topup)
         .service(update_topup)
+        .service(review_topup)
         .service(delete_topup)
 
         // Transfer routes
@@ -97,6 +283,7 @@ pub fn router_config(conf: &mut web::ServiceConfig) {
         .service(get_transfer_user)
         .service(create_transfer)
         .service(update_transfer)
+        .service(review_transfer)
         .service(delete_transfer)
 
         // Withdraw routes
@@ -106,7 +293,80 @@ pub fn router_config(conf: &mut web::ServiceConfig) {
         .service(get_withdraw_user)
         .service(create_withdraw)
         .service(update_withdraw)
-        .service(delete_withdraw);
+        .service(review_withdraw)
+        .service(delete_withdraw)
+
+        // Beneficiary routes
+        .service(get_beneficiary_users)
+        .service(create_beneficiary)
+        .service(delete_beneficiary)
+
+        // Payout batch routes
+        .service(get_payout_batch_users)
+        .service(get_payout_batch)
+        .service(create_payout_batch)
+
+        // Virtual account routes
+        .service(get_virtual_accounts)
+        .service(issue_virtual_accounts)
+        .service(virtual_account_notification)
+
+        // Qris routes
+        .service(generate_qris)
+        .service(parse_qris)
+        .service(render_qris)
+
+        // Invoice routes
+        .service(get_invoice_users)
+        .service(get_invoice_payments)
+        .service(get_invoice)
+        .service(create_invoice)
+
+        // Subscription routes
+        .service(get_subscription_plan_users)
+        .service(get_subscription_plan)
+        .service(create_subscription_plan)
+        .service(get_subscription_users)
+        .service(get_subscription_charges)
+        .service(get_subscription)
+        .service(create_subscription)
+        .service(pause_subscription)
+        .service(resume_subscription)
+        .service(cancel_subscription)
+
+        // Scheduled transfer routes
+        .service(get_scheduled_transfer_users)
+        .service(get_scheduled_transfer_executions)
+        .service(get_scheduled_transfer)
+        .service(create_scheduled_transfer)
+        .service(update_scheduled_transfer)
+        .service(delete_scheduled_transfer)
+
+        // Account routes
+        .service(lookup_account)
+
+        // Fee routes
+        .service(preview_fee)
+
+        // Limit routes
+        .service(get_limit_user)
+
+        // KYC routes
+        .service(get_pending_kyc_documents)
+        .service(get_kyc_document_users)
+        .service(get_kyc_document_file)
+        .service(submit_kyc_document)
+        .service(review_kyc_document)
+        .service(get_kyc_review_users)
+
+        // Risk routes
+        .service(get_risk_reviews)
+        .service(get_risk_decision_users)
 
-    conf.service(router);
-}
\ No newline at end of file
+        // Screening routes
+        .service(get_pending_screening_matches)
+        .service(get_screening_match_users)
+        .service(review_screening_match)
+        .service(get_watchlist)
+        .service(reload_watchlist);
+}
@@ -0,0 +1,254 @@
+use utoipa::{
+    openapi::{
+        security::{HttpAuthScheme, HttpBuilder, SecurityScheme},
+        ContentBuilder, Deprecated, Ref, RefOr, Response, ResponseBuilder,
+    },
+    Modify, OpenApi,
+};
+
+use super::{
+    account, auth, beneficiary, fee, health, invoice, kyc, limit, metrics, payout_batch, qris,
+    risk, saldo, scheduled_transfer, screening, subscription, topup, transfer, user,
+    virtual_account, withdraw,
+};
+use crate::{
+    domain::response::{ErrorResponse, LegacyErrorResponse},
+    utils::api_version::API_V1_PREFIX,
+};
+
+// Everything api_routes registers, which each version scope mounts
+#[derive(OpenApi)]
+#[openapi(paths(
+    // Auth routes
+    auth::register_user_handler,
+    auth::login_user_handler,
+    auth::get_user,
+
+    // User routes
+    user::get_users,
+    user::get_user,
+    user::create_user,
+    user::update_user,
+    user::delete_user,
+    user::update_user_status,
+    user::get_user_status_history,
+
+    // Saldo routes
+    saldo::get_saldos,
+    saldo::get_saldo,
+    saldo::get_saldo_users,
+    saldo::get_saldo_user,
+    saldo::create_saldo,
+    saldo::update_saldo,
+    saldo::delete_saldo,
+
+    // Topup routes
+    topup::get_topups,
+    topup::get_topup,
+    topup::get_topup_users,
+    topup::get_topup_user,
+    topup::create_topup,
+    topup::update_topup,
+    topup::review_topup,
+    topup::delete_topup,
+
+    // Transfer routes
+    transfer::get_transfers,
+    transfer::get_transfer,
+    transfer::get_transfer_users,
+    transfer::get_transfer_user,
+    transfer::create_transfer,
+    transfer::update_transfer,
+    transfer::review_transfer,
+    transfer::delete_transfer,
+
+    // Withdraw routes
+    withdraw::get_withdraws,
+    withdraw::get_withdraw,
+    withdraw::get_withdraw_users,
+    withdraw::get_withdraw_user,
+    withdraw::create_withdraw,
+    withdraw::update_withdraw,
+    withdraw::review_withdraw,
+    withdraw::delete_withdraw,
+
+    // Beneficiary routes
+    beneficiary::get_beneficiary_users,
+    beneficiary::create_beneficiary,
+    beneficiary::delete_beneficiary,
+
+    // Payout batch routes
+    payout_batch::get_payout_batch_users,
+    payout_batch::get_payout_batch,
+    payout_batch::create_payout_batch,
+
+    // Virtual account routes
+    virtual_account::get_virtual_accounts,
+    virtual_account::issue_virtual_accounts,
+    virtual_account::virtual_account_notification,
+
+    // Qris routes
+    qris::generate_qris,
+    qris::parse_qris,
+    qris::render_qris,
+
+    // Invoice routes
+    invoice::get_invoice_users,
+    invoice::get_invoice_payments,
+    invoice::get_invoice,
+    invoice::create_invoice,
+
+    // Subscription routes
+    subscription::get_subscription_plan_users,
+    subscription::get_subscription_plan,
+    subscription::create_subscription_plan,
+    subscription::get_subscription_users,
+    subscription::get_subscription_charges,
+    subscription::get_subscription,
+    subscription::create_subscription,
+    subscription::pause_subscription,
+    subscription::resume_subscription,
+    subscription::cancel_subscription,
+
+    // Scheduled transfer routes
+    scheduled_transfer::get_scheduled_transfer_users,
+    scheduled_transfer::get_scheduled_transfer_executions,
+    scheduled_transfer::get_scheduled_transfer,
+    scheduled_transfer::create_scheduled_transfer,
+    scheduled_transfer::update_scheduled_transfer,
+    scheduled_transfer::delete_scheduled_transfer,
+
+    // Account routes
+    account::lookup_account,
+
+    // Fee routes
+    fee::preview_fee,
+
+    // Limit routes
+    limit::get_limit_user,
+
+    // KYC routes
+    kyc::get_pending_kyc_documents,
+    kyc::get_kyc_document_users,
+    kyc::get_kyc_document_file,
+    kyc::submit_kyc_document,
+    kyc::review_kyc_document,
+    kyc::get_kyc_review_users,
+
+    // Risk routes
+    risk::get_risk_reviews,
+    risk::get_risk_decision_users,
+
+    // Screening routes
+    screening::get_pending_screening_matches,
+    screening::get_screening_match_users,
+    screening::review_screening_match,
+    screening::get_watchlist,
+    screening::reload_watchlist,
+))]
+struct ApiRoutes;
+
+/// The OpenAPI document of the routes registered in `router_config`.
+///
+/// Handlers describe themselves with `#[utoipa::path]`; a handler added to the router
+/// without being listed here, or the other way round, fails the openapi drift test. Both
+/// versions are documented, v1 as deprecated; the unversioned alias of v1 is left out.
+#[derive(OpenApi)]
+#[openapi(
+    info(
+        title = "Payment Gateway API",
+        description = "Wallet, transfer and merchant payment endpoints"
+    ),
+    paths(
+        // Public payment link routes
+        invoice::get_payment_link,
+        invoice::pay_payment_link,
+
+        // Prometheus scrape endpoint
+        metrics::get_metrics,
+
+        // Orchestrator probes
+        health::get_liveness,
+        health::get_readiness,
+        health::get_health_details,
+    ),
+    nest(
+        (path = "/api/v2", api = ApiRoutes),
+        (path = "/api/v1", api = ApiRoutes)
+    ),
+    components(schemas(ErrorResponse, LegacyErrorResponse)),
+    modifiers(&BearerAuth, &ErrorResponses)
+)]
+pub struct ApiDoc;
+
+struct BearerAuth;
+
+impl Modify for BearerAuth {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let components = openapi.components.get_or_insert_with(Default::default);
+
+        components.add_security_scheme(
+            "bearer_auth",
+            SecurityScheme::Http(
+                HttpBuilder::new()
+                    .scheme(HttpAuthScheme::Bearer)
+                    .bearer_format("JWT")
+                    .build(),
+            ),
+        );
+    }
+}
+
+// Every failure, whichever status it maps to, carries the same error body; v1 keeps its
+// older shape, and its operations are marked deprecated under ids of their own
+struct ErrorResponses;
+
+fn error_response(description: &str, schema: &str) -> RefOr<Response> {
+    ResponseBuilder::new()
+        .description(description)
+        .content(
+            "application/json",
+            ContentBuilder::new()
+                .schema(Some(Ref::from_schema_name(schema)))
+                .build(),
+        )
+        .build()
+        .into()
+}
+
+impl Modify for ErrorResponses {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let error = error_response(
+            "The request failed; `code` tells the cases apart",
+            "ErrorResponse",
+        );
+        let legacy_error = error_response("The request failed", "LegacyErrorResponse");
+
+        for (path, item) in openapi.paths.paths.iter_mut() {
+            let is_v1 = path.starts_with(API_V1_PREFIX);
+            let operations = [
+                &mut item.get,
+                &mut item.put,
+                &mut item.post,
+                &mut item.delete,
+                &mut item.patch,
+            ];
+
+            for operation in operations.into_iter().flatten() {
+                let default = if is_v1 { &legacy_error } else { &error };
+
+                operation
+                    .responses
+                    .responses
+                    .entry("default".to_string())
+                    .or_insert_with(|| default.clone());
+
+                if is_v1 {
+                    operation.deprecated = Some(Deprecated::True);
+                    operation.operation_id =
+                        operation.operation_id.take().map(|id| format!("v1_{}", id));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,100 @@
+use actix_web::{get, http::header, post, web, HttpRequest, HttpResponse};
+
+use crate::{
+    domain::{
+        request::payout_batch::CreatePayoutBatchRequest,
+        response::{payout_batch::PayoutBatchResponse, ApiResponse, ErrorResponse},
+    },
+    middleware::auth::JwtMiddleware,
+    state::AppState,
+    utils::errors::AppError,
+};
+use tracing::instrument;
+
+#[utoipa::path(
+    tag = "Payout batches",
+    responses((status = 200, description = "OK", body = ApiResponse<Vec<PayoutBatchResponse>>)),
+    security(("bearer_auth" = [])),
+)]
+#[get("/payouts/batch/users/{id}")]
+#[instrument(skip_all)]
+async fn get_payout_batch_users(
+    data: web::Data<AppState>,
+    id: web::Path<i32>,
+    _jwt_guard: JwtMiddleware,
+) -> Result<HttpResponse, ErrorResponse> {
+    let response = data
+        .di_container
+        .payout_batch_service
+        .get_payout_batch_users(id.into_inner())
+        .await?;
+
+    Ok(HttpResponse::Ok().json(response))
+}
+
+#[utoipa::path(
+    tag = "Payout batches",
+    responses((status = 200, description = "OK", body = ApiResponse<PayoutBatchResponse>)),
+    security(("bearer_auth" = [])),
+)]
+#[get("/payouts/batch/{id}")]
+#[instrument(skip_all)]
+async fn get_payout_batch(
+    data: web::Data<AppState>,
+    id: web::Path<i32>,
+    jwt_guard: JwtMiddleware,
+) -> Result<HttpResponse, ErrorResponse> {
+    let response = data
+        .di_container
+        .payout_batch_service
+        .get_payout_batch(id.into_inner(), jwt_guard.user_id)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(response))
+}
+
+// Accepts either a JSON body or a text/csv upload with one recipient per row
+#[utoipa::path(
+    tag = "Payout batches",
+    request_body(
+        content(
+            (CreatePayoutBatchRequest = "application/json"),
+            (String = "text/csv"),
+        ),
+    ),
+    responses((status = 202, description = "Accepted", body = ApiResponse<PayoutBatchResponse>)),
+    security(("bearer_auth" = [])),
+)]
+#[post("/payouts/batch")]
+#[instrument(skip_all)]
+async fn create_payout_batch(
+    data: web::Data<AppState>,
+    req: HttpRequest,
+    body: web::Bytes,
+    jwt_guard: JwtMiddleware,
+) -> Result<HttpResponse, ErrorResponse> {
+    let is_csv = req
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.starts_with("text/csv"));
+
+    let parsed = if is_csv {
+        CreatePayoutBatchRequest::from_csv(&body)
+    } else {
+        serde_json::from_slice::<CreatePayoutBatchRequest>(&body)
+            .map_err(|e| format!("Invalid JSON: {}", e))
+    };
+
+    let mut create_request =
+        parsed.map_err(|e| ErrorResponse::from(AppError::ValidationError(e)))?;
+    create_request.user_id = jwt_guard.user_id;
+
+    let response = data
+        .di_container
+        .payout_batch_service
+        .create_payout_batch(&create_request)
+        .await?;
+
+    Ok(HttpResponse::Accepted().json(response))
+}
@@ -0,0 +1,72 @@
+use actix_web::{get, post, web, HttpResponse};
+
+use crate::{
+    domain::{
+        request::qris::{CreateQrisRequest, ParseQrisRequest, RenderQrisRequest},
+        response::{qris::QrisResponse, ApiResponse, ErrorResponse},
+    },
+    middleware::{auth::JwtMiddleware, validated_json::ValidatedJson},
+    state::AppState,
+};
+use tracing::instrument;
+
+#[utoipa::path(
+    tag = "QRIS",
+    request_body = CreateQrisRequest,
+    responses((status = 201, description = "Created", body = ApiResponse<QrisResponse>)),
+    security(("bearer_auth" = [])),
+)]
+#[post("/qris")]
+#[instrument(skip_all)]
+async fn generate_qris(
+    data: web::Data<AppState>,
+    body: ValidatedJson<CreateQrisRequest>,
+    _jwt_guard: JwtMiddleware,
+) -> Result<HttpResponse, ErrorResponse> {
+    let response = data.di_container.qris_service.generate_qris(&body).await?;
+
+    Ok(HttpResponse::Created().json(response))
+}
+
+#[utoipa::path(
+    tag = "QRIS",
+    request_body = ParseQrisRequest,
+    responses((status = 200, description = "OK", body = ApiResponse<QrisResponse>)),
+)]
+#[post("/qris/parse")]
+#[instrument(skip_all)]
+async fn parse_qris(
+    data: web::Data<AppState>,
+    body: web::Json<ParseQrisRequest>,
+) -> Result<HttpResponse, ErrorResponse> {
+    let response = data.di_container.qris_service.parse_qris(&body).await?;
+
+    Ok(HttpResponse::Ok().json(response))
+}
+
+#[utoipa::path(
+    tag = "QRIS",
+    params(RenderQrisRequest),
+    responses(
+        (
+            status = 200,
+            description = "The QR code image",
+            content(
+                (Vec<u8> = "image/png"),
+                (String = "image/svg+xml"),
+            ),
+        ),
+    ),
+)]
+#[get("/qris/render")]
+#[instrument(skip_all)]
+async fn render_qris(
+    data: web::Data<AppState>,
+    query: web::Query<RenderQrisRequest>,
+) -> Result<HttpResponse, ErrorResponse> {
+    let image = data.di_container.qris_service.render_qris(&query).await?;
+
+    Ok(HttpResponse::Ok()
+        .content_type(image.content_type)
+        .body(image.body))
+}
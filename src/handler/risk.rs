@@ -0,0 +1,45 @@
+use actix_web::{get, web, HttpResponse};
+
+use crate::{
+    domain::response::{risk::RiskDecisionResponse, ApiResponse, ErrorResponse},
+    middleware::operator::OperatorMiddleware,
+    state::AppState,
+};
+use tracing::instrument;
+
+#[utoipa::path(
+    tag = "Risk",
+    responses((status = 200, description = "OK", body = ApiResponse<Vec<RiskDecisionResponse>>)),
+    security(("bearer_auth" = [])),
+)]
+#[get("/risk/reviews")]
+#[instrument(skip_all)]
+async fn get_risk_reviews(
+    data: web::Data<AppState>,
+    _operator_guard: OperatorMiddleware,
+) -> Result<HttpResponse, ErrorResponse> {
+    let response = data.di_container.risk_engine.get_pending_reviews().await?;
+
+    Ok(HttpResponse::Ok().json(response))
+}
+
+#[utoipa::path(
+    tag = "Risk",
+    responses((status = 200, description = "OK", body = ApiResponse<Vec<RiskDecisionResponse>>)),
+    security(("bearer_auth" = [])),
+)]
+#[get("/risk/decisions/users/{id}")]
+#[instrument(skip_all)]
+async fn get_risk_decision_users(
+    data: web::Data<AppState>,
+    id: web::Path<i32>,
+    _operator_guard: OperatorMiddleware,
+) -> Result<HttpResponse, ErrorResponse> {
+    let response = data
+        .di_container
+        .risk_engine
+        .get_decision_users(id.into_inner())
+        .await?;
+
+    Ok(HttpResponse::Ok().json(response))
+}
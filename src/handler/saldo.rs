@@ -1,123 +1,151 @@
 use crate::{
-    domain::request::saldo::{CreateSaldoRequest, UpdateSaldoRequest},
+    domain::{
+        request::{
+            pagination::ListRequest,
+            saldo::{CreateSaldoRequest, UpdateSaldoRequest},
+        },
+        response::{
+            saldo::SaldoResponse, ApiResponse, ApiResponsePagination, ErrorResponse, MessageResponse,
+        },
+    },
+    middleware::validated_json::ValidatedJson,
     state::AppState,
 };
-use actix_web::{delete, get, post, put, web, HttpResponse, Responder};
+use actix_web::{delete, get, post, put, web, HttpResponse};
 use serde_json::json;
+use tracing::instrument;
 
+#[utoipa::path(
+    tag = "Saldo",
+    params(ListRequest),
+    responses((status = 200, description = "OK", body = ApiResponsePagination<Vec<SaldoResponse>>)),
+)]
 #[get("/saldos")]
-async fn get_saldos(data: web::Data<AppState>) -> impl Responder {
-    match data.di_container.saldo_service.get_saldos().await {
-        Ok(response) => HttpResponse::Ok().json(response),
-        Err(e) => HttpResponse::InternalServerError().json(json!({
-            "status": "error",
-            "message": format!("Failed to fetch saldos: {}", e),
-        })),
-    }
+#[instrument(skip_all)]
+async fn get_saldos(
+    data: web::Data<AppState>,
+    query: web::Query<ListRequest>,
+) -> Result<HttpResponse, ErrorResponse> {
+    let response = data.di_container.saldo_service.get_saldos(&query).await?;
+
+    Ok(HttpResponse::Ok().json(response))
 }
 
+#[utoipa::path(
+    tag = "Saldo",
+    responses((status = 200, description = "OK", body = ApiResponse<Option<SaldoResponse>>)),
+)]
 #[get("/saldos/{id}")]
-async fn get_saldo(data: web::Data<AppState>, id: web::Path<i32>) -> impl Responder {
-    match data
+#[instrument(skip_all)]
+async fn get_saldo(
+    data: web::Data<AppState>,
+    id: web::Path<i32>,
+) -> Result<HttpResponse, ErrorResponse> {
+    let response = data
         .di_container
         .saldo_service
         .get_saldo(id.into_inner())
-        .await
-    {
-        Ok(response) => HttpResponse::Ok().json(response),
-        Err(e) => HttpResponse::InternalServerError().json(json!({
-            "status": "error",
-            "message": format!("Failed to fetch saldo: {}", e),
-        })),
-    }
+        .await?;
+
+    Ok(HttpResponse::Ok().json(response))
 }
 
+#[utoipa::path(
+    tag = "Saldo",
+    responses((status = 200, description = "OK", body = ApiResponse<Option<Vec<SaldoResponse>>>)),
+)]
 #[get("/saldos/users/{id}")]
-async fn get_saldo_users(data: web::Data<AppState>, id: web::Path<i32>) -> impl Responder {
-    match data
+#[instrument(skip_all)]
+async fn get_saldo_users(
+    data: web::Data<AppState>,
+    id: web::Path<i32>,
+) -> Result<HttpResponse, ErrorResponse> {
+    let response = data
         .di_container
         .saldo_service
         .get_saldo_users(id.into_inner())
-        .await
-    {
-        Ok(response) => HttpResponse::Ok().json(response),
-        Err(e) => HttpResponse::InternalServerError().json(json!({
-            "status": "error",
-            "message": format!("Failed to fetch saldo users: {}", e),
-        })),
-    }
+        .await?;
+
+    Ok(HttpResponse::Ok().json(response))
 }
 
+#[utoipa::path(
+    tag = "Saldo",
+    responses((status = 200, description = "OK", body = ApiResponse<Option<SaldoResponse>>)),
+)]
 #[get("/saldos/user/{id}")]
-async fn get_saldo_user(data: web::Data<AppState>, id: web::Path<i32>) -> impl Responder {
-    match data
+#[instrument(skip_all)]
+async fn get_saldo_user(
+    data: web::Data<AppState>,
+    id: web::Path<i32>,
+) -> Result<HttpResponse, ErrorResponse> {
+    let response = data
         .di_container
         .saldo_service
         .get_saldo_user(id.into_inner())
-        .await
-    {
-        Ok(response) => HttpResponse::Ok().json(response),
-        Err(e) => HttpResponse::InternalServerError().json(json!({
-            "status": "error",
-            "message": format!("Failed to fetch saldo: {}", e),
-        })),
-    }
+        .await?;
+
+    Ok(HttpResponse::Ok().json(response))
 }
 
 
+#[utoipa::path(
+    tag = "Saldo",
+    request_body = CreateSaldoRequest,
+    responses((status = 201, description = "Created", body = ApiResponse<SaldoResponse>)),
+)]
 #[post("/saldos")]
+#[instrument(skip_all)]
 async fn create_saldo(
     data: web::Data<AppState>,
-    body: web::Json<CreateSaldoRequest>,
-) -> impl Responder {
-    match data.di_container.saldo_service.create_saldo(&body).await {
-        Ok(response) => HttpResponse::Created().json(response),
-        Err(e) => HttpResponse::InternalServerError().json(json!({
-            "status": "error",
-            "message": format!("Failed to create saldo: {}", e),
-        })),
-    }
+    body: ValidatedJson<CreateSaldoRequest>,
+) -> Result<HttpResponse, ErrorResponse> {
+    let response = data.di_container.saldo_service.create_saldo(&body).await?;
+
+    Ok(HttpResponse::Created().json(response))
 }
 
+#[utoipa::path(
+    tag = "Saldo",
+    request_body = UpdateSaldoRequest,
+    responses((status = 200, description = "OK", body = ApiResponse<Option<SaldoResponse>>)),
+)]
 #[put("/saldos/{id}")]
+#[instrument(skip_all)]
 async fn update_saldo(
     data: web::Data<AppState>,
     id: web::Path<i32>,
-    body: web::Json<UpdateSaldoRequest>,
-) -> impl Responder {
+    body: ValidatedJson<UpdateSaldoRequest>,
+) -> Result<HttpResponse, ErrorResponse> {
     let mut update_request = body.into_inner();
     update_request.saldo_id = id.into_inner();
 
-    match data
+    let response = data
         .di_container
         .saldo_service
         .update_saldo(&update_request)
-        .await
-    {
-        Ok(response) => HttpResponse::Ok().json(response),
-
-        Err(e) => HttpResponse::InternalServerError().json(json!({
-            "status": "error",
-            "message": format!("Failed to update saldo: {}", e),
-        })),
-    }
+        .await?;
+
+    Ok(HttpResponse::Ok().json(response))
 }
 
+#[utoipa::path(
+    tag = "Saldo",
+    responses((status = 200, description = "OK", body = MessageResponse)),
+)]
 #[delete("/saldos/{id}")]
-async fn delete_saldo(data: web::Data<AppState>, id: web::Path<i32>) -> impl Responder {
-    match data
-        .di_container
+#[instrument(skip_all)]
+async fn delete_saldo(
+    data: web::Data<AppState>,
+    id: web::Path<i32>,
+) -> Result<HttpResponse, ErrorResponse> {
+    data.di_container
         .saldo_service
         .delete_saldo(id.into_inner())
-        .await
-    {
-        Ok(_) => HttpResponse::Ok().json(json!({
-            "status": "success",
-            "message": "Saldo deleted successfully",
-        })),
-        Err(e) => HttpResponse::InternalServerError().json(json!({
-            "status": "error",
-            "message": format!("Failed to delete saldo: {}", e),
-        })),
-    }
+        .await?;
+
+    Ok(HttpResponse::Ok().json(json!({
+        "status": "success",
+        "message": "Saldo deleted successfully",
+    })))
 }
@@ -0,0 +1,186 @@
+use actix_web::{delete, get, post, put, web, HttpResponse};
+use serde_json::json;
+
+use crate::{
+    domain::{
+        request::scheduled_transfer::{
+            CreateScheduledTransferRequest, UpdateScheduledTransferRequest,
+        },
+        response::{
+            scheduled_transfer::{ScheduledTransferExecutionResponse, ScheduledTransferResponse},
+            ApiResponse, ErrorResponse, MessageResponse,
+        },
+    },
+    middleware::{auth::JwtMiddleware, validated_json::ValidatedJson},
+    state::AppState,
+    utils::errors::AppError,
+};
+use tracing::instrument;
+
+fn ensure_owner_or_operator(
+    data: &AppState,
+    jwt_guard: &JwtMiddleware,
+    user_id: i32,
+) -> Result<(), ErrorResponse> {
+    if jwt_guard.user_id != user_id && !data.operator_user_ids.contains(&jwt_guard.user_id) {
+        return Err(ErrorResponse::from(AppError::Forbidden(
+            "Only the sender or an operator can view its scheduled transfers".to_string(),
+        )));
+    }
+
+    Ok(())
+}
+
+#[utoipa::path(
+    tag = "Scheduled transfers",
+    responses((status = 200, description = "OK", body = ApiResponse<ScheduledTransferResponse>)),
+    security(("bearer_auth" = [])),
+)]
+#[get("/scheduled-transfers/{id}")]
+#[instrument(skip_all)]
+async fn get_scheduled_transfer(
+    data: web::Data<AppState>,
+    id: web::Path<i32>,
+    jwt_guard: JwtMiddleware,
+) -> Result<HttpResponse, ErrorResponse> {
+    let response = data
+        .di_container
+        .scheduled_transfer_service
+        .get_scheduled_transfer(id.into_inner())
+        .await?;
+
+    ensure_owner_or_operator(&data, &jwt_guard, response.data.transfer_from)?;
+
+    Ok(HttpResponse::Ok().json(response))
+}
+
+#[utoipa::path(
+    tag = "Scheduled transfers",
+    responses((status = 200, description = "OK", body = ApiResponse<Vec<ScheduledTransferResponse>>)),
+    security(("bearer_auth" = [])),
+)]
+#[get("/scheduled-transfers/users/{id}")]
+#[instrument(skip_all)]
+async fn get_scheduled_transfer_users(
+    data: web::Data<AppState>,
+    id: web::Path<i32>,
+    jwt_guard: JwtMiddleware,
+) -> Result<HttpResponse, ErrorResponse> {
+    let user_id = id.into_inner();
+
+    ensure_owner_or_operator(&data, &jwt_guard, user_id)?;
+
+    let response = data
+        .di_container
+        .scheduled_transfer_service
+        .get_scheduled_transfer_users(user_id)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(response))
+}
+
+#[utoipa::path(
+    tag = "Scheduled transfers",
+    responses((status = 200, description = "OK", body = ApiResponse<Vec<ScheduledTransferExecutionResponse>>)),
+    security(("bearer_auth" = [])),
+)]
+#[get("/scheduled-transfers/{id}/executions")]
+#[instrument(skip_all)]
+async fn get_scheduled_transfer_executions(
+    data: web::Data<AppState>,
+    id: web::Path<i32>,
+    jwt_guard: JwtMiddleware,
+) -> Result<HttpResponse, ErrorResponse> {
+    let scheduled_transfer_id = id.into_inner();
+
+    let scheduled_transfer = data
+        .di_container
+        .scheduled_transfer_service
+        .get_scheduled_transfer(scheduled_transfer_id)
+        .await?;
+
+    ensure_owner_or_operator(&data, &jwt_guard, scheduled_transfer.data.transfer_from)?;
+
+    let response = data
+        .di_container
+        .scheduled_transfer_service
+        .get_scheduled_transfer_executions(scheduled_transfer_id)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(response))
+}
+
+#[utoipa::path(
+    tag = "Scheduled transfers",
+    request_body = CreateScheduledTransferRequest,
+    responses((status = 201, description = "Created", body = ApiResponse<ScheduledTransferResponse>)),
+    security(("bearer_auth" = [])),
+)]
+#[post("/scheduled-transfers")]
+#[instrument(skip_all)]
+async fn create_scheduled_transfer(
+    data: web::Data<AppState>,
+    body: ValidatedJson<CreateScheduledTransferRequest>,
+    jwt_guard: JwtMiddleware,
+) -> Result<HttpResponse, ErrorResponse> {
+    let mut create_request = body.into_inner();
+    create_request.transfer_from = jwt_guard.user_id;
+
+    let response = data
+        .di_container
+        .scheduled_transfer_service
+        .create_scheduled_transfer(&create_request)
+        .await?;
+
+    Ok(HttpResponse::Created().json(response))
+}
+
+#[utoipa::path(
+    tag = "Scheduled transfers",
+    request_body = UpdateScheduledTransferRequest,
+    responses((status = 200, description = "OK", body = ApiResponse<ScheduledTransferResponse>)),
+    security(("bearer_auth" = [])),
+)]
+#[put("/scheduled-transfers/{id}")]
+#[instrument(skip_all)]
+async fn update_scheduled_transfer(
+    data: web::Data<AppState>,
+    id: web::Path<i32>,
+    body: ValidatedJson<UpdateScheduledTransferRequest>,
+    jwt_guard: JwtMiddleware,
+) -> Result<HttpResponse, ErrorResponse> {
+    let mut update_request = body.into_inner();
+    update_request.scheduled_transfer_id = id.into_inner();
+    update_request.transfer_from = jwt_guard.user_id;
+
+    let response = data
+        .di_container
+        .scheduled_transfer_service
+        .update_scheduled_transfer(&update_request)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(response))
+}
+
+#[utoipa::path(
+    tag = "Scheduled transfers",
+    responses((status = 200, description = "OK", body = MessageResponse)),
+    security(("bearer_auth" = [])),
+)]
+#[delete("/scheduled-transfers/{id}")]
+#[instrument(skip_all)]
+async fn delete_scheduled_transfer(
+    data: web::Data<AppState>,
+    id: web::Path<i32>,
+    jwt_guard: JwtMiddleware,
+) -> Result<HttpResponse, ErrorResponse> {
+    data.di_container
+        .scheduled_transfer_service
+        .delete_scheduled_transfer(id.into_inner(), jwt_guard.user_id)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(json!({
+        "status": "success",
+        "message": "Scheduled transfer deleted successfully",
+    })))
+}
@@ -0,0 +1,118 @@
+use actix_web::{get, post, web, HttpResponse};
+
+use crate::{
+    domain::{
+        request::screening::ReviewScreeningMatchRequest,
+        response::{
+            screening::{ScreeningMatchResponse, WatchlistStatusResponse},
+            ApiResponse, ErrorResponse,
+        },
+    },
+    middleware::{operator::OperatorMiddleware, validated_json::ValidatedJson},
+    state::AppState,
+};
+use tracing::instrument;
+
+#[utoipa::path(
+    tag = "Screening",
+    responses((status = 200, description = "OK", body = ApiResponse<Vec<ScreeningMatchResponse>>)),
+    security(("bearer_auth" = [])),
+)]
+#[get("/screening/matches/pending")]
+#[instrument(skip_all)]
+async fn get_pending_screening_matches(
+    data: web::Data<AppState>,
+    _operator_guard: OperatorMiddleware,
+) -> Result<HttpResponse, ErrorResponse> {
+    let response = data
+        .di_container
+        .screening_service
+        .get_pending_matches()
+        .await?;
+
+    Ok(HttpResponse::Ok().json(response))
+}
+
+#[utoipa::path(
+    tag = "Screening",
+    responses((status = 200, description = "OK", body = ApiResponse<Vec<ScreeningMatchResponse>>)),
+    security(("bearer_auth" = [])),
+)]
+#[get("/screening/matches/users/{id}")]
+#[instrument(skip_all)]
+async fn get_screening_match_users(
+    data: web::Data<AppState>,
+    id: web::Path<i32>,
+    _operator_guard: OperatorMiddleware,
+) -> Result<HttpResponse, ErrorResponse> {
+    let response = data
+        .di_container
+        .screening_service
+        .get_match_users(id.into_inner())
+        .await?;
+
+    Ok(HttpResponse::Ok().json(response))
+}
+
+#[utoipa::path(
+    tag = "Screening",
+    request_body = ReviewScreeningMatchRequest,
+    responses((status = 200, description = "OK", body = ApiResponse<ScreeningMatchResponse>)),
+    security(("bearer_auth" = [])),
+)]
+#[post("/screening/matches/{id}/review")]
+#[instrument(skip_all)]
+async fn review_screening_match(
+    data: web::Data<AppState>,
+    id: web::Path<i32>,
+    body: ValidatedJson<ReviewScreeningMatchRequest>,
+    operator_guard: OperatorMiddleware,
+) -> Result<HttpResponse, ErrorResponse> {
+    let mut review_request = body.into_inner();
+    review_request.match_id = id.into_inner();
+    review_request.reviewer_id = operator_guard.user_id;
+
+    let response = data
+        .di_container
+        .screening_service
+        .review_match(&review_request)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(response))
+}
+
+#[utoipa::path(
+    tag = "Screening",
+    responses((status = 200, description = "OK", body = ApiResponse<WatchlistStatusResponse>)),
+    security(("bearer_auth" = [])),
+)]
+#[get("/screening/watchlist")]
+#[instrument(skip_all)]
+async fn get_watchlist(
+    data: web::Data<AppState>,
+    _operator_guard: OperatorMiddleware,
+) -> Result<HttpResponse, ErrorResponse> {
+    let response = data.di_container.screening_service.get_watchlist().await?;
+
+    Ok(HttpResponse::Ok().json(response))
+}
+
+#[utoipa::path(
+    tag = "Screening",
+    responses((status = 200, description = "OK", body = ApiResponse<WatchlistStatusResponse>)),
+    security(("bearer_auth" = [])),
+)]
+#[post("/screening/watchlist/reload")]
+#[instrument(skip_all)]
+async fn reload_watchlist(
+    data: web::Data<AppState>,
+    _operator_guard: OperatorMiddleware,
+) -> Result<HttpResponse, ErrorResponse> {
+    let response = data
+        .di_container
+        .screening_service
+        .reload_watchlist()
+        .await?;
+
+    Ok(HttpResponse::Ok().json(response))
+}
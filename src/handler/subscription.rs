@@ -0,0 +1,285 @@
+use actix_web::{get, post, web, HttpResponse};
+
+use crate::{
+    domain::{
+        request::subscription::{CreateSubscriptionPlanRequest, CreateSubscriptionRequest},
+        response::{
+            subscription::{
+                SubscriptionChargeResponse, SubscriptionPlanResponse, SubscriptionResponse,
+            },
+            ApiResponse, ErrorResponse,
+        },
+    },
+    middleware::{auth::JwtMiddleware, validated_json::ValidatedJson},
+    state::AppState,
+    utils::errors::AppError,
+};
+use tracing::instrument;
+
+fn is_operator(data: &AppState, jwt_guard: &JwtMiddleware) -> bool {
+    data.operator_user_ids.contains(&jwt_guard.user_id)
+}
+
+// The subscriber, the merchant billing it and operators can see a subscription
+async fn ensure_party_or_operator(
+    data: &AppState,
+    jwt_guard: &JwtMiddleware,
+    subscription: &SubscriptionResponse,
+) -> Result<(), ErrorResponse> {
+    if jwt_guard.user_id == subscription.subscriber_id || is_operator(data, jwt_guard) {
+        return Ok(());
+    }
+
+    let plan = data
+        .di_container
+        .subscription_service
+        .get_plan(subscription.plan_id)
+        .await?;
+
+    if plan.data.merchant_id != jwt_guard.user_id {
+        return Err(ErrorResponse::from(AppError::Forbidden(
+            "Only the subscriber, the plan's merchant or an operator can view this subscription"
+                .to_string(),
+        )));
+    }
+
+    Ok(())
+}
+
+#[utoipa::path(
+    tag = "Subscriptions",
+    responses((status = 200, description = "OK", body = ApiResponse<SubscriptionPlanResponse>)),
+    security(("bearer_auth" = [])),
+)]
+#[get("/subscription-plans/{id}")]
+#[instrument(skip_all)]
+async fn get_subscription_plan(
+    data: web::Data<AppState>,
+    id: web::Path<i32>,
+    _jwt_guard: JwtMiddleware,
+) -> Result<HttpResponse, ErrorResponse> {
+    let response = data
+        .di_container
+        .subscription_service
+        .get_plan(id.into_inner())
+        .await?;
+
+    Ok(HttpResponse::Ok().json(response))
+}
+
+#[utoipa::path(
+    tag = "Subscriptions",
+    responses((status = 200, description = "OK", body = ApiResponse<Vec<SubscriptionPlanResponse>>)),
+    security(("bearer_auth" = [])),
+)]
+#[get("/subscription-plans/users/{id}")]
+#[instrument(skip_all)]
+async fn get_subscription_plan_users(
+    data: web::Data<AppState>,
+    id: web::Path<i32>,
+    _jwt_guard: JwtMiddleware,
+) -> Result<HttpResponse, ErrorResponse> {
+    let response = data
+        .di_container
+        .subscription_service
+        .get_plan_users(id.into_inner())
+        .await?;
+
+    Ok(HttpResponse::Ok().json(response))
+}
+
+#[utoipa::path(
+    tag = "Subscriptions",
+    request_body = CreateSubscriptionPlanRequest,
+    responses((status = 201, description = "Created", body = ApiResponse<SubscriptionPlanResponse>)),
+    security(("bearer_auth" = [])),
+)]
+#[post("/subscription-plans")]
+#[instrument(skip_all)]
+async fn create_subscription_plan(
+    data: web::Data<AppState>,
+    body: ValidatedJson<CreateSubscriptionPlanRequest>,
+    jwt_guard: JwtMiddleware,
+) -> Result<HttpResponse, ErrorResponse> {
+    let mut create_request = body.into_inner();
+    create_request.merchant_id = jwt_guard.user_id;
+
+    let response = data
+        .di_container
+        .subscription_service
+        .create_plan(&create_request)
+        .await?;
+
+    Ok(HttpResponse::Created().json(response))
+}
+
+#[utoipa::path(
+    tag = "Subscriptions",
+    responses((status = 200, description = "OK", body = ApiResponse<SubscriptionResponse>)),
+    security(("bearer_auth" = [])),
+)]
+#[get("/subscriptions/{id}")]
+#[instrument(skip_all)]
+async fn get_subscription(
+    data: web::Data<AppState>,
+    id: web::Path<i32>,
+    jwt_guard: JwtMiddleware,
+) -> Result<HttpResponse, ErrorResponse> {
+    let response = data
+        .di_container
+        .subscription_service
+        .get_subscription(id.into_inner())
+        .await?;
+
+    ensure_party_or_operator(&data, &jwt_guard, &response.data).await?;
+
+    Ok(HttpResponse::Ok().json(response))
+}
+
+#[utoipa::path(
+    tag = "Subscriptions",
+    responses((status = 200, description = "OK", body = ApiResponse<Vec<SubscriptionResponse>>)),
+    security(("bearer_auth" = [])),
+)]
+#[get("/subscriptions/users/{id}")]
+#[instrument(skip_all)]
+async fn get_subscription_users(
+    data: web::Data<AppState>,
+    id: web::Path<i32>,
+    jwt_guard: JwtMiddleware,
+) -> Result<HttpResponse, ErrorResponse> {
+    let subscriber_id = id.into_inner();
+
+    if jwt_guard.user_id != subscriber_id && !is_operator(&data, &jwt_guard) {
+        return Err(ErrorResponse::from(AppError::Forbidden(
+            "Only the subscriber or an operator can view its subscriptions".to_string(),
+        )));
+    }
+
+    let response = data
+        .di_container
+        .subscription_service
+        .get_subscription_users(subscriber_id)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(response))
+}
+
+#[utoipa::path(
+    tag = "Subscriptions",
+    responses((status = 200, description = "OK", body = ApiResponse<Vec<SubscriptionChargeResponse>>)),
+    security(("bearer_auth" = [])),
+)]
+#[get("/subscriptions/{id}/charges")]
+#[instrument(skip_all)]
+async fn get_subscription_charges(
+    data: web::Data<AppState>,
+    id: web::Path<i32>,
+    jwt_guard: JwtMiddleware,
+) -> Result<HttpResponse, ErrorResponse> {
+    let subscription_id = id.into_inner();
+
+    let subscription = data
+        .di_container
+        .subscription_service
+        .get_subscription(subscription_id)
+        .await?;
+
+    ensure_party_or_operator(&data, &jwt_guard, &subscription.data).await?;
+
+    let response = data
+        .di_container
+        .subscription_service
+        .get_subscription_charges(subscription_id)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(response))
+}
+
+#[utoipa::path(
+    tag = "Subscriptions",
+    request_body = CreateSubscriptionRequest,
+    responses((status = 201, description = "Created", body = ApiResponse<SubscriptionResponse>)),
+    security(("bearer_auth" = [])),
+)]
+#[post("/subscriptions")]
+#[instrument(skip_all)]
+async fn create_subscription(
+    data: web::Data<AppState>,
+    body: ValidatedJson<CreateSubscriptionRequest>,
+    jwt_guard: JwtMiddleware,
+) -> Result<HttpResponse, ErrorResponse> {
+    let mut create_request = body.into_inner();
+    create_request.subscriber_id = jwt_guard.user_id;
+
+    let response = data
+        .di_container
+        .subscription_service
+        .create_subscription(&create_request)
+        .await?;
+
+    Ok(HttpResponse::Created().json(response))
+}
+
+#[utoipa::path(
+    tag = "Subscriptions",
+    responses((status = 200, description = "OK", body = ApiResponse<SubscriptionResponse>)),
+    security(("bearer_auth" = [])),
+)]
+#[post("/subscriptions/{id}/pause")]
+#[instrument(skip_all)]
+async fn pause_subscription(
+    data: web::Data<AppState>,
+    id: web::Path<i32>,
+    jwt_guard: JwtMiddleware,
+) -> Result<HttpResponse, ErrorResponse> {
+    let response = data
+        .di_container
+        .subscription_service
+        .pause_subscription(id.into_inner(), jwt_guard.user_id)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(response))
+}
+
+#[utoipa::path(
+    tag = "Subscriptions",
+    responses((status = 200, description = "OK", body = ApiResponse<SubscriptionResponse>)),
+    security(("bearer_auth" = [])),
+)]
+#[post("/subscriptions/{id}/resume")]
+#[instrument(skip_all)]
+async fn resume_subscription(
+    data: web::Data<AppState>,
+    id: web::Path<i32>,
+    jwt_guard: JwtMiddleware,
+) -> Result<HttpResponse, ErrorResponse> {
+    let response = data
+        .di_container
+        .subscription_service
+        .resume_subscription(id.into_inner(), jwt_guard.user_id)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(response))
+}
+
+#[utoipa::path(
+    tag = "Subscriptions",
+    responses((status = 200, description = "OK", body = ApiResponse<SubscriptionResponse>)),
+    security(("bearer_auth" = [])),
+)]
+#[post("/subscriptions/{id}/cancel")]
+#[instrument(skip_all)]
+async fn cancel_subscription(
+    data: web::Data<AppState>,
+    id: web::Path<i32>,
+    jwt_guard: JwtMiddleware,
+) -> Result<HttpResponse, ErrorResponse> {
+    let response = data
+        .di_container
+        .subscription_service
+        .cancel_subscription(id.into_inner(), jwt_guard.user_id)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(response))
+}
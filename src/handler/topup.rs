@@ -1,123 +1,179 @@
 use crate::{
-    domain::request::topup::{CreateTopupRequest, UpdateTopupRequest},
+    domain::{
+        request::{
+            pagination::ListRequest,
+            risk::ResolveRiskReviewRequest,
+            topup::{CreateTopupRequest, UpdateTopupRequest},
+        },
+        response::{
+            topup::TopupResponse, ApiResponse, ApiResponsePagination, ErrorResponse, MessageResponse,
+        },
+    },
+    middleware::{operator::OperatorMiddleware, validated_json::ValidatedJson},
     state::AppState,
 };
-use actix_web::{delete, get, post, put, web, HttpResponse, Responder};
+use actix_web::{delete, get, post, put, web, HttpResponse};
 use serde_json::json;
+use tracing::instrument;
 
+#[utoipa::path(
+    tag = "Topups",
+    params(ListRequest),
+    responses((status = 200, description = "OK", body = ApiResponsePagination<Vec<TopupResponse>>)),
+)]
 #[get("/topups")]
-async fn get_topups(data: web::Data<AppState>) -> impl Responder {
-    match data.di_container.topup_service.get_topups().await {
-        Ok(response) => HttpResponse::Ok().json(response),
-        Err(e) => HttpResponse::InternalServerError().json(json!({
-            "status": "error",
-            "message": format!("Failed to fetch topups: {}", e),
-        })),
-    }
+#[instrument(skip_all)]
+async fn get_topups(
+    data: web::Data<AppState>,
+    query: web::Query<ListRequest>,
+) -> Result<HttpResponse, ErrorResponse> {
+    let response = data.di_container.topup_service.get_topups(&query).await?;
+
+    Ok(HttpResponse::Ok().json(response))
 }
 
+#[utoipa::path(
+    tag = "Topups",
+    responses((status = 200, description = "OK", body = ApiResponse<Option<TopupResponse>>)),
+)]
 #[get("/topups/{id}")]
-async fn get_topup(data: web::Data<AppState>, id: web::Path<i32>) -> impl Responder {
-    match data
+#[instrument(skip_all)]
+async fn get_topup(
+    data: web::Data<AppState>,
+    id: web::Path<i32>,
+) -> Result<HttpResponse, ErrorResponse> {
+    let response = data
         .di_container
         .topup_service
         .get_topup(id.into_inner())
-        .await
-    {
-        Ok(response) => HttpResponse::Ok().json(response),
-        Err(e) => HttpResponse::InternalServerError().json(json!({
-            "status": "error",
-            "message": format!("Failed to fetch topup: {}", e),
-        })),
-    }
+        .await?;
+
+    Ok(HttpResponse::Ok().json(response))
 }
 
+#[utoipa::path(
+    tag = "Topups",
+    responses((status = 200, description = "OK", body = ApiResponse<Option<Vec<TopupResponse>>>)),
+)]
 #[get("/topups/users/{id}")]
-async fn get_topup_users(data: web::Data<AppState>, id: web::Path<i32>) -> impl Responder {
-    match data
+#[instrument(skip_all)]
+async fn get_topup_users(
+    data: web::Data<AppState>,
+    id: web::Path<i32>,
+) -> Result<HttpResponse, ErrorResponse> {
+    let response = data
         .di_container
         .topup_service
         .get_topup_users(id.into_inner())
-        .await
-    {
-        Ok(response) => HttpResponse::Ok().json(response),
-        Err(e) => HttpResponse::InternalServerError().json(json!({
-            "status": "error",
-            "message": format!("Failed to fetch topup users: {}", e),
-        })),
-    }
+        .await?;
+
+    Ok(HttpResponse::Ok().json(response))
 }
 
+#[utoipa::path(
+    tag = "Topups",
+    responses((status = 200, description = "OK", body = ApiResponse<Option<TopupResponse>>)),
+)]
 #[get("/topups/user/{id}")]
-async fn get_topup_user(data: web::Data<AppState>, id: web::Path<i32>) -> impl Responder {
-    match data
+#[instrument(skip_all)]
+async fn get_topup_user(
+    data: web::Data<AppState>,
+    id: web::Path<i32>,
+) -> Result<HttpResponse, ErrorResponse> {
+    let response = data
         .di_container
         .topup_service
         .get_topup_user(id.into_inner())
-        .await
-    {
-        Ok(response) => HttpResponse::Ok().json(response),
-        Err(e) => HttpResponse::InternalServerError().json(json!({
-            "status": "error",
-            "message": format!("Failed to fetch topup: {}", e),
-        })),
-    }
+        .await?;
+
+    Ok(HttpResponse::Ok().json(response))
 }
 
 
+#[utoipa::path(
+    tag = "Topups",
+    request_body = CreateTopupRequest,
+    responses((status = 201, description = "Created", body = ApiResponse<TopupResponse>)),
+)]
 #[post("/topups")]
+#[instrument(skip_all)]
 async fn create_topup(
     data: web::Data<AppState>,
-    body: web::Json<CreateTopupRequest>,
-) -> impl Responder {
-    match data.di_container.topup_service.create_topup(&body).await {
-        Ok(response) => HttpResponse::Created().json(response),
-        Err(e) => HttpResponse::InternalServerError().json(json!({
-            "status": "error",
-            "message": format!("Failed to create topup: {}", e),
-        })),
-    }
+    body: ValidatedJson<CreateTopupRequest>,
+) -> Result<HttpResponse, ErrorResponse> {
+    let response = data.di_container.topup_service.create_topup(&body).await?;
+
+    Ok(HttpResponse::Created().json(response))
 }
 
+#[utoipa::path(
+    tag = "Topups",
+    request_body = UpdateTopupRequest,
+    responses((status = 200, description = "OK", body = ApiResponse<Option<TopupResponse>>)),
+)]
 #[put("/topups/{id}")]
+#[instrument(skip_all)]
 async fn update_topup(
     data: web::Data<AppState>,
     id: web::Path<i32>,
-    body: web::Json<UpdateTopupRequest>,
-) -> impl Responder {
+    body: ValidatedJson<UpdateTopupRequest>,
+) -> Result<HttpResponse, ErrorResponse> {
     let mut update_request = body.into_inner();
     update_request.topup_id = id.into_inner();
 
-    match data
+    let response = data
         .di_container
         .topup_service
         .update_topup(&update_request)
-        .await
-    {
-        Ok(response) => HttpResponse::Ok().json(response),
-
-        Err(e) => HttpResponse::InternalServerError().json(json!({
-            "status": "error",
-           "message": format!("Failed to update topup: {}", e),
-        })),
-    }
+        .await?;
+
+    Ok(HttpResponse::Ok().json(response))
 }
 
-#[delete("/topups/{id}")]
-async fn delete_topup(data: web::Data<AppState>, id: web::Path<i32>) -> impl Responder {
-    match data
+#[utoipa::path(
+    tag = "Topups",
+    request_body = ResolveRiskReviewRequest,
+    responses((status = 200, description = "OK", body = ApiResponse<TopupResponse>)),
+    security(("bearer_auth" = [])),
+)]
+#[post("/topups/{id}/review")]
+#[instrument(skip_all)]
+async fn review_topup(
+    data: web::Data<AppState>,
+    id: web::Path<i32>,
+    body: ValidatedJson<ResolveRiskReviewRequest>,
+    operator_guard: OperatorMiddleware,
+) -> Result<HttpResponse, ErrorResponse> {
+    let mut review_request = body.into_inner();
+    review_request.reference_id = id.into_inner();
+    review_request.reviewer_id = operator_guard.user_id;
+
+    let response = data
         .di_container
+        .topup_service
+        .review_topup(&review_request)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(response))
+}
+
+#[utoipa::path(
+    tag = "Topups",
+    responses((status = 200, description = "OK", body = MessageResponse)),
+)]
+#[delete("/topups/{id}")]
+#[instrument(skip_all)]
+async fn delete_topup(
+    data: web::Data<AppState>,
+    id: web::Path<i32>,
+) -> Result<HttpResponse, ErrorResponse> {
+    data.di_container
         .topup_service
         .delete_topup(id.into_inner())
-        .await
-    {
-        Ok(_) => HttpResponse::Ok().json(json!({
-            "status": "success",
-            "message": "Topup deleted successfully",
-        })),
-        Err(e) => HttpResponse::InternalServerError().json(json!({
-            "status": "error",
-            "message": format!("Failed to delete topup: {}", e),
-        })),
-    }
+        .await?;
+
+    Ok(HttpResponse::Ok().json(json!({
+        "status": "success",
+        "message": "Topup deleted successfully",
+    })))
 }
@@ -1,124 +1,191 @@
 use crate::{
-    domain::request::transfer::{CreateTransferRequest, UpdateTransferRequest},
+    domain::{
+        request::{
+            pagination::ListRequest,
+            risk::ResolveRiskReviewRequest,
+            transfer::{CreateTransferRequest, UpdateTransferRequest},
+        },
+        response::{
+            transfer::TransferResponse, ApiResponse, ApiResponsePagination, ErrorResponse, MessageResponse,
+        },
+    },
+    middleware::{
+        operator::OperatorMiddleware, rate_limit::RateLimit, validated_json::ValidatedJson,
+    },
     state::AppState,
+    utils::rate_limit::RateLimitGroup,
 };
-use actix_web::{delete, get, post, put, web, HttpResponse, Responder};
+use actix_web::{delete, get, post, put, web, HttpResponse};
 use serde_json::json;
+use tracing::instrument;
 
+#[utoipa::path(
+    tag = "Transfers",
+    params(ListRequest),
+    responses((status = 200, description = "OK", body = ApiResponsePagination<Vec<TransferResponse>>)),
+)]
 #[get("/transfer")]
-async fn get_transfers(data: web::Data<AppState>) -> impl Responder {
-    match data.di_container.transfer_service.get_transfers().await {
-        Ok(response) => HttpResponse::Ok().json(response),
-        Err(e) => HttpResponse::InternalServerError().json(json!({
-            "status": "error",
-            "message": format!("Failed to fetch transfers: {}", e),
-        })),
-    }
+#[instrument(skip_all)]
+async fn get_transfers(
+    data: web::Data<AppState>,
+    query: web::Query<ListRequest>,
+) -> Result<HttpResponse, ErrorResponse> {
+    let response = data
+        .di_container
+        .transfer_service
+        .get_transfers(&query)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(response))
 }
 
+#[utoipa::path(
+    tag = "Transfers",
+    responses((status = 200, description = "OK", body = ApiResponse<Option<TransferResponse>>)),
+)]
 #[get("/transfer/{id}")]
-async fn get_transfer(data: web::Data<AppState>, id: web::Path<i32>) -> impl Responder {
-    match data
+#[instrument(skip_all)]
+async fn get_transfer(
+    data: web::Data<AppState>,
+    id: web::Path<i32>,
+) -> Result<HttpResponse, ErrorResponse> {
+    let response = data
         .di_container
         .transfer_service
         .get_transfer(id.into_inner())
-        .await
-    {
-        Ok(response) => HttpResponse::Ok().json(response),
-        Err(e) => HttpResponse::InternalServerError().json(json!({
-            "status": "error",
-            "message": format!("Failed to fetch transfer: {}", e),
-        })),
-    }
+        .await?;
+
+    Ok(HttpResponse::Ok().json(response))
 }
 
+#[utoipa::path(
+    tag = "Transfers",
+    responses((status = 200, description = "OK", body = ApiResponse<Option<Vec<TransferResponse>>>)),
+)]
 #[get("/transfer/users/{id}")]
-async fn get_transfer_users(data: web::Data<AppState>, id: web::Path<i32>) -> impl Responder {
-    match data
+#[instrument(skip_all)]
+async fn get_transfer_users(
+    data: web::Data<AppState>,
+    id: web::Path<i32>,
+) -> Result<HttpResponse, ErrorResponse> {
+    let response = data
         .di_container
         .transfer_service
         .get_transfer_users(id.into_inner())
-        .await
-    {
-        Ok(response) => HttpResponse::Ok().json(response),
-        Err(e) => HttpResponse::InternalServerError().json(json!({
-            "status": "error",
-            "message": format!("Failed to fetch transfer users: {}", e),
-        })),
-    }
+        .await?;
+
+    Ok(HttpResponse::Ok().json(response))
 }
 
 
+#[utoipa::path(
+    tag = "Transfers",
+    responses((status = 200, description = "OK", body = ApiResponse<Option<TransferResponse>>)),
+)]
 #[get("/transfer/user/{id}")]
-async fn get_transfer_user(data: web::Data<AppState>, id: web::Path<i32>) -> impl Responder {
-    match data
+#[instrument(skip_all)]
+async fn get_transfer_user(
+    data: web::Data<AppState>,
+    id: web::Path<i32>,
+) -> Result<HttpResponse, ErrorResponse> {
+    let response = data
         .di_container
         .transfer_service
         .get_transfer_user(id.into_inner())
-        .await
-    {
-        Ok(response) => HttpResponse::Ok().json(response),
-        Err(e) => HttpResponse::InternalServerError().json(json!({
-            "status": "error",
-            "message": format!("Failed to fetch transfer: {}", e),
-        })),
-    }
+        .await?;
+
+    Ok(HttpResponse::Ok().json(response))
 }
 
 
-#[post("/transfer")]
+#[utoipa::path(
+    tag = "Transfers",
+    request_body = CreateTransferRequest,
+    responses((status = 201, description = "Created", body = ApiResponse<TransferResponse>)),
+)]
+#[post("/transfer", wrap = "RateLimit::new(RateLimitGroup::Transfer)")]
+#[instrument(skip_all)]
 async fn create_transfer(
     data: web::Data<AppState>,
-    body: web::Json<CreateTransferRequest>,
-) -> impl Responder {
-    match data.di_container.transfer_service.create_transfer(&body).await {
-        Ok(response) => HttpResponse::Created().json(response),
-        Err(e) => HttpResponse::InternalServerError().json(json!({
-            "status": "error",
-            "message": format!("Failed to create transfer: {}", e),
-        })),
-    }
+    body: ValidatedJson<CreateTransferRequest>,
+) -> Result<HttpResponse, ErrorResponse> {
+    let response = data
+        .di_container
+        .transfer_service
+        .create_transfer(&body)
+        .await?;
+
+    Ok(HttpResponse::Created().json(response))
 }
 
+#[utoipa::path(
+    tag = "Transfers",
+    request_body = UpdateTransferRequest,
+    responses((status = 200, description = "OK", body = ApiResponse<TransferResponse>)),
+)]
 #[put("/transfer/{id}")]
+#[instrument(skip_all)]
 async fn update_transfer(
     data: web::Data<AppState>,
     id: web::Path<i32>,
-    body: web::Json<UpdateTransferRequest>,
-) -> impl Responder {
+    body: ValidatedJson<UpdateTransferRequest>,
+) -> Result<HttpResponse, ErrorResponse> {
     let mut update_request = body.into_inner();
     update_request.transfer_id = id.into_inner();
 
-    match data
+    let response = data
         .di_container
         .transfer_service
         .update_transfer(&update_request)
-        .await
-    {
-        Ok(response) => HttpResponse::Ok().json(response),
-
-        Err(e) => HttpResponse::InternalServerError().json(json!({
-            "status": "error",
-           "message": format!("Failed to update transfer: {}", e),
-        })),
-    }
+        .await?;
+
+    Ok(HttpResponse::Ok().json(response))
 }
 
-#[delete("/transfer/{id}")]
-async fn delete_transfer(data: web::Data<AppState>, id: web::Path<i32>) -> impl Responder {
-    match data
+#[utoipa::path(
+    tag = "Transfers",
+    request_body = ResolveRiskReviewRequest,
+    responses((status = 200, description = "OK", body = ApiResponse<TransferResponse>)),
+    security(("bearer_auth" = [])),
+)]
+#[post("/transfer/{id}/review")]
+#[instrument(skip_all)]
+async fn review_transfer(
+    data: web::Data<AppState>,
+    id: web::Path<i32>,
+    body: ValidatedJson<ResolveRiskReviewRequest>,
+    operator_guard: OperatorMiddleware,
+) -> Result<HttpResponse, ErrorResponse> {
+    let mut review_request = body.into_inner();
+    review_request.reference_id = id.into_inner();
+    review_request.reviewer_id = operator_guard.user_id;
+
+    let response = data
         .di_container
+        .transfer_service
+        .review_transfer(&review_request)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(response))
+}
+
+#[utoipa::path(
+    tag = "Transfers",
+    responses((status = 200, description = "OK", body = MessageResponse)),
+)]
+#[delete("/transfer/{id}")]
+#[instrument(skip_all)]
+async fn delete_transfer(
+    data: web::Data<AppState>,
+    id: web::Path<i32>,
+) -> Result<HttpResponse, ErrorResponse> {
+    data.di_container
         .transfer_service
         .delete_transfer(id.into_inner())
-        .await
-    {
-        Ok(_) => HttpResponse::Ok().json(json!({
-            "status": "success",
-            "message": "Transfer deleted successfully",
-        })),
-        Err(e) => HttpResponse::InternalServerError().json(json!({
-            "status": "error",
-            "message": format!("Failed to delete transfer: {}", e),
-        })),
-    }
+        .await?;
+
+    Ok(HttpResponse::Ok().json(json!({
+        "status": "success",
+        "message": "Transfer deleted successfully",
+    })))
 }
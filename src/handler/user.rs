@@ -1,102 +1,174 @@
-use actix_web::{delete, get, post, put, web, HttpResponse, Responder};
-use serde_json::json;
+use actix_web::{delete, get, post, put, web, HttpResponse};
 
-use crate::{domain::request::{auth::RegisterRequest, user::UpdateUserRequest}, middleware::auth::JwtMiddleware, state::AppState};
+use crate::{domain::{request::{account_status::UpdateAccountStatusRequest, auth::RegisterRequest, pagination::ListRequest, user::UpdateUserRequest}, response::{account_status::AccountStatusChangeResponse, user::UserResponse, ApiResponse, ApiResponsePagination, ErrorResponse}}, middleware::{auth::JwtMiddleware, operator::OperatorMiddleware, validated_json::ValidatedJson}, state::AppState, utils::{account_status::AccountStatus, errors::AppError}};
+use tracing::instrument;
 
 
+#[utoipa::path(
+    tag = "Users",
+    params(ListRequest),
+    responses((status = 200, description = "OK", body = ApiResponsePagination<Vec<UserResponse>>)),
+)]
 #[get("/users")]
-async fn get_users(data: web::Data<AppState>) -> impl Responder{
-    match data.di_container.user_service.get_users().await{
-        Ok(users) => HttpResponse::Ok().json(users),
-        Err(_) => HttpResponse::InternalServerError().json(json!({
-            "status": "error",
-            "message": "Failed to fetch users",
-        })),
-    }
+#[instrument(skip_all)]
+async fn get_users(
+    data: web::Data<AppState>,
+    query: web::Query<ListRequest>,
+) -> Result<HttpResponse, ErrorResponse>{
+    let users = data.di_container.user_service.get_users(&query).await?;
+
+    Ok(HttpResponse::Ok().json(users))
 }
 
+#[utoipa::path(
+    tag = "Users",
+    responses((status = 200, description = "OK", body = ApiResponse<Option<UserResponse>>)),
+    security(("bearer_auth" = [])),
+)]
 #[get("/users/{id}")]
-async fn get_user(data: web::Data<AppState>, id: web::Path<i32>, _jwt_guard: JwtMiddleware) -> impl Responder {
-    match data
+#[instrument(skip_all)]
+async fn get_user(
+    data: web::Data<AppState>,
+    id: web::Path<i32>,
+    _jwt_guard: JwtMiddleware,
+) -> Result<HttpResponse, ErrorResponse> {
+    let user = data
         .di_container
         .user_service
         .find_by_id(id.into_inner())
-        .await
-    {
-        Ok(user) => HttpResponse::Ok().json(user),
-       
-        Err(_) => HttpResponse::InternalServerError().json(json!({
-            "status": "error",
-            "message": "Failed to fetch user",
-        })),
-    }
+        .await?;
+
+    Ok(HttpResponse::Ok().json(user))
 }
 
 
+#[utoipa::path(
+    tag = "Users",
+    request_body = RegisterRequest,
+    responses((status = 201, description = "Created", body = ApiResponse<UserResponse>)),
+    security(("bearer_auth" = [])),
+)]
 #[post("/users")]
+#[instrument(skip_all)]
 async fn create_user(
     data: web::Data<AppState>,
-    body: web::Json<RegisterRequest>,
+    body: ValidatedJson<RegisterRequest>,
     _jwt_guard: JwtMiddleware
-) -> impl Responder {
-    match data
-        .di_container
-        .user_service
-        .create_user(&body)
-        .await
-    {
-        Ok(user) => HttpResponse::Created().json(user),
-        Err(_) => HttpResponse::InternalServerError().json(json!({
-            "status": "error",
-            "message": "Failed to create user",
-        })),
-    }
+) -> Result<HttpResponse, ErrorResponse> {
+    let user = data.di_container.user_service.create_user(&body).await?;
+
+    Ok(HttpResponse::Created().json(user))
 }
 
+#[utoipa::path(
+    tag = "Users",
+    request_body = UpdateUserRequest,
+    responses((status = 200, description = "OK", body = ApiResponse<UserResponse>)),
+    security(("bearer_auth" = [])),
+)]
 #[put("/users/{id}")]
+#[instrument(skip_all)]
 async fn update_user(
     data: web::Data<AppState>,
     id: web::Path<i32>,
-    body: web::Json<UpdateUserRequest>,
+    body: ValidatedJson<UpdateUserRequest>,
     _jwt_guard: JwtMiddleware
-) -> impl Responder {
+) -> Result<HttpResponse, ErrorResponse> {
     let mut update_request = body.into_inner();
 
     update_request.id = Some(id.into_inner());
 
-    match data
+    let user = data
         .di_container
         .user_service
         .update_user(&update_request)
-        .await
-    {
-        Ok(Some(user)) => HttpResponse::Ok().json(user),
-        Ok(None) => HttpResponse::NotFound().json(json!({
-            "status": "fail",
-            "message": "User not found",
-        })),
-        Err(_) => HttpResponse::InternalServerError().json(json!({
-            "status": "error",
-            "message": "Failed to update User",
-        })),
-    }
+        .await?
+        .ok_or_else(|| ErrorResponse::from(AppError::UserNotFound("User not found".to_string())))?;
+
+    Ok(HttpResponse::Ok().json(user))
 }
 
+// Closes the account rather than deleting it, so its financial history is kept
+#[utoipa::path(
+    tag = "Users",
+    responses((status = 200, description = "OK", body = ApiResponse<UserResponse>)),
+    security(("bearer_auth" = [])),
+)]
 #[delete("/users/{id}")]
-async fn delete_user(data: web::Data<AppState>, id: web::Path<i32>, _jwt_guard: JwtMiddleware) -> impl Responder {
-    match data
-        .di_container
-        .user_service
-        .delete_user(id.into_inner())
-        .await
-    {
-        Ok(_) => HttpResponse::Ok().json(json!({
-            "status": "success",
-            "message": "User deleted successfully",
-        })),
-        Err(_) => HttpResponse::InternalServerError().json(json!({
-            "status": "error",
-            "message": "Failed to delete User",
-        })),
+#[instrument(skip_all)]
+async fn delete_user(
+    data: web::Data<AppState>,
+    id: web::Path<i32>,
+    jwt_guard: JwtMiddleware,
+) -> Result<HttpResponse, ErrorResponse> {
+    let user_id = id.into_inner();
+
+    if jwt_guard.user_id != user_id && !data.operator_user_ids.contains(&jwt_guard.user_id) {
+        return Err(ErrorResponse::from(AppError::Forbidden(
+            "Only the account holder or an operator can close an account".to_string(),
+        )));
     }
+
+    let close_request = UpdateAccountStatusRequest {
+        user_id,
+        changed_by: jwt_guard.user_id,
+        status: AccountStatus::Closed,
+        reason: "Closed at the account holder's request".to_string(),
+    };
+
+    let response = data
+        .di_container
+        .account_status_service
+        .update_status(&close_request)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(response))
+}
+
+#[utoipa::path(
+    tag = "Users",
+    request_body = UpdateAccountStatusRequest,
+    responses((status = 200, description = "OK", body = ApiResponse<UserResponse>)),
+    security(("bearer_auth" = [])),
+)]
+#[put("/users/{id}/status")]
+#[instrument(skip_all)]
+async fn update_user_status(
+    data: web::Data<AppState>,
+    id: web::Path<i32>,
+    body: ValidatedJson<UpdateAccountStatusRequest>,
+    operator_guard: OperatorMiddleware,
+) -> Result<HttpResponse, ErrorResponse> {
+    let mut status_request = body.into_inner();
+    status_request.user_id = id.into_inner();
+    status_request.changed_by = operator_guard.user_id;
+
+    let response = data
+        .di_container
+        .account_status_service
+        .update_status(&status_request)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(response))
+}
+
+#[utoipa::path(
+    tag = "Users",
+    responses((status = 200, description = "OK", body = ApiResponse<Vec<AccountStatusChangeResponse>>)),
+    security(("bearer_auth" = [])),
+)]
+#[get("/users/{id}/status/history")]
+#[instrument(skip_all)]
+async fn get_user_status_history(
+    data: web::Data<AppState>,
+    id: web::Path<i32>,
+    _operator_guard: OperatorMiddleware,
+) -> Result<HttpResponse, ErrorResponse> {
+    let response = data
+        .di_container
+        .account_status_service
+        .get_status_history(id.into_inner())
+        .await?;
+
+    Ok(HttpResponse::Ok().json(response))
 }
@@ -0,0 +1,124 @@
+use actix_web::{get, post, web, HttpRequest, HttpResponse};
+
+use crate::{
+    domain::{
+        request::virtual_account::VirtualAccountNotificationRequest,
+        response::{
+            topup::TopupResponse, virtual_account::VirtualAccountResponse, ApiResponse,
+            ErrorResponse,
+        },
+    },
+    middleware::auth::JwtMiddleware,
+    state::AppState,
+    utils::errors::AppError,
+};
+
+// Sent by the bank with every payment notification
+const BANK_CODE_HEADER: &str = "X-Bank-Code";
+const SIGNATURE_HEADER: &str = "X-Signature";
+use tracing::instrument;
+
+fn ensure_owner_or_operator(
+    data: &AppState,
+    jwt_guard: &JwtMiddleware,
+    user_id: i32,
+) -> Result<(), ErrorResponse> {
+    if jwt_guard.user_id != user_id && !data.operator_user_ids.contains(&jwt_guard.user_id) {
+        return Err(ErrorResponse::from(AppError::Forbidden(
+            "Only the account holder or an operator can access its virtual accounts".to_string(),
+        )));
+    }
+
+    Ok(())
+}
+
+#[utoipa::path(
+    tag = "Virtual accounts",
+    responses((status = 200, description = "OK", body = ApiResponse<Vec<VirtualAccountResponse>>)),
+    security(("bearer_auth" = [])),
+)]
+#[get("/users/{id}/virtual-accounts")]
+#[instrument(skip_all)]
+async fn get_virtual_accounts(
+    data: web::Data<AppState>,
+    id: web::Path<i32>,
+    jwt_guard: JwtMiddleware,
+) -> Result<HttpResponse, ErrorResponse> {
+    let user_id = id.into_inner();
+    ensure_owner_or_operator(&data, &jwt_guard, user_id)?;
+
+    let response = data
+        .di_container
+        .virtual_account_service
+        .get_virtual_accounts(user_id)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(response))
+}
+
+#[utoipa::path(
+    tag = "Virtual accounts",
+    responses((status = 201, description = "Created", body = ApiResponse<Vec<VirtualAccountResponse>>)),
+    security(("bearer_auth" = [])),
+)]
+#[post("/users/{id}/virtual-accounts")]
+#[instrument(skip_all)]
+async fn issue_virtual_accounts(
+    data: web::Data<AppState>,
+    id: web::Path<i32>,
+    jwt_guard: JwtMiddleware,
+) -> Result<HttpResponse, ErrorResponse> {
+    let user_id = id.into_inner();
+    ensure_owner_or_operator(&data, &jwt_guard, user_id)?;
+
+    let response = data
+        .di_container
+        .virtual_account_service
+        .issue_virtual_accounts(user_id)
+        .await?;
+
+    Ok(HttpResponse::Created().json(response))
+}
+
+// The body is only parsed once its signature has been verified against the bank's secret
+#[utoipa::path(
+    tag = "Virtual accounts",
+    request_body = VirtualAccountNotificationRequest,
+    params(
+        ("X-Bank-Code" = String, Header, description = "Bank that sent the notification"),
+        ("X-Signature" = String, Header, description = "Hex HMAC-SHA256 of the raw body"),
+    ),
+    responses(
+        (status = 200, description = "OK", body = ApiResponse<TopupResponse>),
+        (status = 401, description = "Missing or invalid signature", body = ErrorResponse),
+    ),
+)]
+#[post("/virtual-accounts/notifications")]
+#[instrument(skip_all)]
+async fn virtual_account_notification(
+    data: web::Data<AppState>,
+    req: HttpRequest,
+    body: web::Bytes,
+) -> Result<HttpResponse, ErrorResponse> {
+    let header = |name: &str| {
+        req.headers()
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or_default()
+    };
+    let bank_code = header(BANK_CODE_HEADER);
+
+    let service = &data.di_container.virtual_account_service;
+    service.verify_notification(bank_code, header(SIGNATURE_HEADER), &body)?;
+
+    let notification =
+        serde_json::from_slice::<VirtualAccountNotificationRequest>(&body).map_err(|e| {
+            ErrorResponse::from(AppError::ValidationError(format!("Invalid JSON: {}", e)))
+        })?;
+
+    let response = service
+        .handle_payment_notification(bank_code, &notification)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(response))
+}
@@ -1,124 +1,188 @@
 use crate::{
-    domain::request::withdraw::{CreateWithdrawRequest, UpdateWithdrawRequest},
+    domain::{
+        request::{
+            pagination::ListRequest,
+            risk::ResolveRiskReviewRequest,
+            withdraw::{CreateWithdrawRequest, UpdateWithdrawRequest},
+        },
+        response::{
+            withdraw::WithdrawResponse, ApiResponse, ApiResponsePagination, ErrorResponse, MessageResponse,
+        },
+    },
+    middleware::{operator::OperatorMiddleware, validated_json::ValidatedJson},
     state::AppState,
 };
-use actix_web::{delete, get, post, put, web, HttpResponse, Responder};
+use actix_web::{delete, get, post, put, web, HttpResponse};
 use serde_json::json;
+use tracing::instrument;
 
+#[utoipa::path(
+    tag = "Withdraws",
+    params(ListRequest),
+    responses((status = 200, description = "OK", body = ApiResponsePagination<Vec<WithdrawResponse>>)),
+)]
 #[get("/withdraw")]
-async fn get_withdraws(data: web::Data<AppState>) -> impl Responder {
-    match data.di_container.withdraw_service.get_withdraws().await {
-        Ok(response) => HttpResponse::Ok().json(response),
-        Err(e) => HttpResponse::InternalServerError().json(json!({
-            "status": "error",
-            "message": format!("Failed to fetch withdraws: {}", e),
-        })),
-    }
+#[instrument(skip_all)]
+async fn get_withdraws(
+    data: web::Data<AppState>,
+    query: web::Query<ListRequest>,
+) -> Result<HttpResponse, ErrorResponse> {
+    let response = data
+        .di_container
+        .withdraw_service
+        .get_withdraws(&query)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(response))
 }
 
+#[utoipa::path(
+    tag = "Withdraws",
+    responses((status = 200, description = "OK", body = ApiResponse<Option<WithdrawResponse>>)),
+)]
 #[get("/withdraw/{id}")]
-async fn get_withdraw(data: web::Data<AppState>, id: web::Path<i32>) -> impl Responder {
-    match data
+#[instrument(skip_all)]
+async fn get_withdraw(
+    data: web::Data<AppState>,
+    id: web::Path<i32>,
+) -> Result<HttpResponse, ErrorResponse> {
+    let response = data
         .di_container
         .withdraw_service
         .get_withdraw(id.into_inner())
-        .await
-    {
-        Ok(response) => HttpResponse::Ok().json(response),
-        Err(e) => HttpResponse::InternalServerError().json(json!({
-            "status": "error",
-            "message": format!("Failed to fetch withdraw: {}", e),
-        })),
-    }
+        .await?;
+
+    Ok(HttpResponse::Ok().json(response))
 }
 
+#[utoipa::path(
+    tag = "Withdraws",
+    responses((status = 200, description = "OK", body = ApiResponse<Option<Vec<WithdrawResponse>>>)),
+)]
 #[get("/withdraw/users/{id}")]
-async fn get_withdraw_users(data: web::Data<AppState>, id: web::Path<i32>) -> impl Responder {
-    match data
+#[instrument(skip_all)]
+async fn get_withdraw_users(
+    data: web::Data<AppState>,
+    id: web::Path<i32>,
+) -> Result<HttpResponse, ErrorResponse> {
+    let response = data
         .di_container
         .withdraw_service
         .get_withdraw_users(id.into_inner())
-        .await
-    {
-        Ok(response) => HttpResponse::Ok().json(response),
-        Err(e) => HttpResponse::InternalServerError().json(json!({
-            "status": "error",
-            "message": format!("Failed to fetch withdraw users: {}", e),
-        })),
-    }
+        .await?;
+
+    Ok(HttpResponse::Ok().json(response))
 }
 
 
-#[get("/transfer/user/{id}")]
-async fn get_withdraw_user(data: web::Data<AppState>, id: web::Path<i32>) -> impl Responder {
-    match data
+#[utoipa::path(
+    tag = "Withdraws",
+    responses((status = 200, description = "OK", body = ApiResponse<Option<WithdrawResponse>>)),
+)]
+#[get("/withdraw/user/{id}")]
+#[instrument(skip_all)]
+async fn get_withdraw_user(
+    data: web::Data<AppState>,
+    id: web::Path<i32>,
+) -> Result<HttpResponse, ErrorResponse> {
+    let response = data
         .di_container
         .withdraw_service
         .get_withdraw_user(id.into_inner())
-        .await
-    {
-        Ok(response) => HttpResponse::Ok().json(response),
-        Err(e) => HttpResponse::InternalServerError().json(json!({
-            "status": "error",
-            "message": format!("Failed to fetch withdraw: {}", e),
-        })),
-    }
+        .await?;
+
+    Ok(HttpResponse::Ok().json(response))
 }
 
 
-#[post("/transfer")]
+#[utoipa::path(
+    tag = "Withdraws",
+    request_body = CreateWithdrawRequest,
+    responses((status = 201, description = "Created", body = ApiResponse<WithdrawResponse>)),
+)]
+#[post("/withdraw")]
+#[instrument(skip_all)]
 async fn create_withdraw(
     data: web::Data<AppState>,
-    body: web::Json<CreateWithdrawRequest>,
-) -> impl Responder {
-    match data.di_container.withdraw_service.create_withdraw(&body).await {
-        Ok(response) => HttpResponse::Created().json(response),
-        Err(e) => HttpResponse::InternalServerError().json(json!({
-            "status": "error",
-            "message": format!("Failed to create withdraw: {}", e),
-        })),
-    }
+    body: ValidatedJson<CreateWithdrawRequest>,
+) -> Result<HttpResponse, ErrorResponse> {
+    let response = data
+        .di_container
+        .withdraw_service
+        .create_withdraw(&body)
+        .await?;
+
+    Ok(HttpResponse::Created().json(response))
 }
 
+#[utoipa::path(
+    tag = "Withdraws",
+    request_body = UpdateWithdrawRequest,
+    responses((status = 200, description = "OK", body = ApiResponse<Option<WithdrawResponse>>)),
+)]
 #[put("/withdraw/{id}")]
+#[instrument(skip_all)]
 async fn update_withdraw(
     data: web::Data<AppState>,
     id: web::Path<i32>,
-    body: web::Json<UpdateWithdrawRequest>,
-) -> impl Responder {
+    body: ValidatedJson<UpdateWithdrawRequest>,
+) -> Result<HttpResponse, ErrorResponse> {
     let mut update_request = body.into_inner();
     update_request.withdraw_id = id.into_inner();
 
-    match data
+    let response = data
         .di_container
         .withdraw_service
         .update_withdraw(&update_request)
-        .await
-    {
-        Ok(response) => HttpResponse::Ok().json(response),
-
-        Err(e) => HttpResponse::InternalServerError().json(json!({
-            "status": "error",
-           "message": format!("Failed to update withdraw: {}", e),
-        })),
-    }
+        .await?;
+
+    Ok(HttpResponse::Ok().json(response))
 }
 
-#[delete("/withdraw/{id}")]
-async fn delete_withdraw(data: web::Data<AppState>, id: web::Path<i32>) -> impl Responder {
-    match data
+#[utoipa::path(
+    tag = "Withdraws",
+    request_body = ResolveRiskReviewRequest,
+    responses((status = 200, description = "OK", body = ApiResponse<WithdrawResponse>)),
+    security(("bearer_auth" = [])),
+)]
+#[post("/withdraw/{id}/review")]
+#[instrument(skip_all)]
+async fn review_withdraw(
+    data: web::Data<AppState>,
+    id: web::Path<i32>,
+    body: ValidatedJson<ResolveRiskReviewRequest>,
+    operator_guard: OperatorMiddleware,
+) -> Result<HttpResponse, ErrorResponse> {
+    let mut review_request = body.into_inner();
+    review_request.reference_id = id.into_inner();
+    review_request.reviewer_id = operator_guard.user_id;
+
+    let response = data
         .di_container
+        .withdraw_service
+        .review_withdraw(&review_request)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(response))
+}
+
+#[utoipa::path(
+    tag = "Withdraws",
+    responses((status = 200, description = "OK", body = MessageResponse)),
+)]
+#[delete("/withdraw/{id}")]
+#[instrument(skip_all)]
+async fn delete_withdraw(
+    data: web::Data<AppState>,
+    id: web::Path<i32>,
+) -> Result<HttpResponse, ErrorResponse> {
+    data.di_container
         .withdraw_service
         .delete_withdraw(id.into_inner())
-        .await
-    {
-        Ok(_) => HttpResponse::Ok().json(json!({
-            "status": "success",
-            "message": "Withdraw deleted successfully",
-        })),
-        Err(e) => HttpResponse::InternalServerError().json(json!({
-            "status": "error",
-            "message": format!("Failed to delete Withdraw: {}", e),
-        })),
-    }
+        .await?;
+
+    Ok(HttpResponse::Ok().json(json!({
+        "status": "success",
+        "message": "Withdraw cancelled and refunded",
+    })))
 }
@@ -1,8 +1,10 @@
 use actix_cors::Cors;
-use actix_web::{http::header, middleware::Logger, web::Data, App, HttpServer};
+use actix_web::{http::header, middleware::from_fn, web::Data, App, HttpServer};
 use dotenv::dotenv;
-use example_payment_gateway::{config::{config::Config, database::ConnectionManager}, handler::router_config, migration::m20220101_000001_create_table::Migration, state::AppState};
-use example_payment_gateway::utils::log_tracing;
+use example_payment_gateway::{config::{config::Config, database::ConnectionManager}, handler::router_config, migration::Migrator, state::AppState};
+use example_payment_gateway::middleware::{metrics::track_requests, request_id::{request_id, REQUEST_ID}};
+use example_payment_gateway::utils::{log_tracing, scheduler::spawn_job};
+use std::time::Duration;
 
 
 #[tokio::main]
@@ -19,17 +21,71 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     dotenv().ok();
     // env_logger::init();
 
-    log_tracing::tracing();
-
     let config = Config::init();
 
-    let db_pool = ConnectionManager::new_pool::<Migration>(&config.database_url, config.run_migrations).await?;
+    let tracer_provider = log_tracing::tracing(config.log_format, config.otlp.as_ref());
+
+    let db_pool = ConnectionManager::new_pool::<Migrator>(&config.database_url, config.run_migrations).await?;
 
     let port = config.port;
 
-    let state = AppState::new(db_pool, &config.jwt_secret);
+    let state = AppState::new(db_pool, &config);
+
+    let scheduler_period = Duration::from_secs(config.scheduler_interval_secs);
+
+    let invoice_service = state.di_container.invoice_service.clone();
+    spawn_job("invoice-expiry", scheduler_period, move || {
+        let invoice_service = invoice_service.clone();
+        async move {
+            let _ = invoice_service.expire_invoices().await;
+            let _ = invoice_service.settle_held_payments().await;
+        }
+    });
+
+    let subscription_service = state.di_container.subscription_service.clone();
+    spawn_job("subscription-billing", scheduler_period, move || {
+        let subscription_service = subscription_service.clone();
+        async move {
+            let _ = subscription_service.run_due_charges().await;
+        }
+    });
+
+    let scheduled_transfer_service = state.di_container.scheduled_transfer_service.clone();
+    spawn_job("scheduled-transfers", scheduler_period, move || {
+        let scheduled_transfer_service = scheduled_transfer_service.clone();
+        async move {
+            let _ = scheduled_transfer_service.run_due_transfers().await;
+        }
+    });
+
+    let payout_batch_service = state.di_container.payout_batch_service.clone();
+    spawn_job("payout-batches", scheduler_period, move || {
+        let payout_batch_service = payout_batch_service.clone();
+        async move {
+            let _ = payout_batch_service.run_pending_batches().await;
+        }
+    });
+
+    let withdraw_service = state.di_container.withdraw_service.clone();
+    spawn_job("payouts", scheduler_period, move || {
+        let withdraw_service = withdraw_service.clone();
+        async move {
+            let _ = withdraw_service.process_payouts().await;
+        }
+    });
+
+    let fee_service = state.di_container.fee_service.clone();
+    spawn_job("fee-retry", scheduler_period, move || {
+        let fee_service = fee_service.clone();
+        async move {
+            let _ = fee_service.retry_pending_fees().await;
+        }
+    });
 
-    HttpServer::new(move || {
+    let health_service = state.di_container.health_service.clone();
+    let shutdown_delay = Duration::from_secs(config.shutdown_delay_secs);
+
+    let server = HttpServer::new(move || {
         let cors = Cors::default()
             .allowed_origin("http://localhost:3000")
             .allowed_methods(vec!["GET", "POST", "PATCH", "DELETE"])
@@ -37,19 +93,56 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 header::CONTENT_TYPE,
                 header::AUTHORIZATION,
                 header::ACCEPT,
+                REQUEST_ID,
             ])
+            .expose_headers(vec![REQUEST_ID])
             .supports_credentials();
 
         App::new()
             .configure(router_config)
             .app_data(Data::new(state.clone()))
             .wrap(cors)
-            .wrap(Logger::default())
+            .wrap(from_fn(track_requests))
+            .wrap(from_fn(request_id))
     })
     .bind(("127.0.0.1", port))?
-    .run()
-    .await
-    .expect("Failed");
+    // Signals are handled below, so readiness fails before the server stops accepting
+    .disable_signals()
+    .run();
+
+    let server_handle = server.handle();
+
+    tokio::spawn(async move {
+        shutdown_signal().await;
+        health_service.begin_shutdown();
+        tokio::time::sleep(shutdown_delay).await;
+        server_handle.stop(true).await;
+    });
+
+    server.await.expect("Failed");
+
+    if let Some(tracer_provider) = tracer_provider {
+        let _ = tracer_provider.shutdown();
+    }
 
     Ok(())
 }
+
+// Ctrl-C, or SIGTERM from the orchestrator
+async fn shutdown_signal() {
+    let terminate = async {
+        #[cfg(unix)]
+        tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())
+            .expect("Failed to listen for SIGTERM")
+            .recv()
+            .await;
+
+        #[cfg(not(unix))]
+        std::future::pending::<()>().await;
+    };
+
+    tokio::select! {
+        _ = tokio::signal::ctrl_c() => {}
+        _ = terminate => {}
+    }
+}
@@ -0,0 +1,78 @@
+use actix_web::{
+    body::{BoxBody, MessageBody},
+    dev::{ServiceRequest, ServiceResponse},
+    http::header::{HeaderName, HeaderValue, CONTENT_LENGTH, CONTENT_TYPE, LINK},
+    middleware::Next,
+    web, Error as ActixWebError, HttpResponse,
+};
+
+use crate::{
+    domain::response::{ErrorResponse, LegacyErrorResponse},
+    state::AppState,
+    utils::api_version::successor_path,
+};
+
+const DEPRECATION: HeaderName = HeaderName::from_static("deprecation");
+const SUNSET: HeaderName = HeaderName::from_static("sunset");
+
+/// Keeps v1 responses in their v1 shape and marks them as deprecated.
+///
+/// Errors go out as `{status, message}` instead of the coded error body, and every response
+/// carries `Deprecation`, `Sunset` once configured, and a `Link` to the v2 route.
+pub async fn v1_compat(
+    req: ServiceRequest,
+    next: Next<impl MessageBody + 'static>,
+) -> Result<ServiceResponse<BoxBody>, ActixWebError> {
+    let path = req.path().to_string();
+    let policy = req
+        .app_data::<web::Data<AppState>>()
+        .map(|data| data.api_v1_deprecation)
+        .unwrap_or_default();
+
+    // Handler and extractor failures arrive as responses that still carry their error
+    let mut res = next.call(req).await?.map_into_boxed_body();
+
+    let legacy_error = res
+        .response()
+        .error()
+        .and_then(|err| err.as_error::<ErrorResponse>())
+        .map(LegacyErrorResponse::from);
+
+    if let Some(legacy_error) = legacy_error {
+        let mut legacy = HttpResponse::build(res.status()).json(legacy_error);
+
+        // Headers such as Retry-After belong to the error whatever shape its body takes
+        for (name, value) in res.headers() {
+            if name != CONTENT_TYPE && name != CONTENT_LENGTH {
+                legacy.headers_mut().append(name.clone(), value.clone());
+            }
+        }
+
+        res = res.into_response(legacy);
+    }
+
+    // Only a route that exists has a v2 counterpart to point at
+    let matched = res.request().match_pattern().is_some();
+    let successor = successor_path(&path).filter(|_| matched).and_then(|path| {
+        HeaderValue::from_str(&format!("<{}>; rel=\"successor-version\"", path)).ok()
+    });
+
+    let headers = res.headers_mut();
+
+    if let Ok(value) = HeaderValue::from_str(&policy.deprecation_header()) {
+        headers.insert(DEPRECATION, value);
+    }
+
+    if let Some(Ok(value)) = policy
+        .sunset_header()
+        .map(|sunset| HeaderValue::from_str(&sunset))
+    {
+        headers.insert(SUNSET, value);
+    }
+
+    if let Some(value) = successor {
+        headers.insert(LINK, value);
+    }
+
+    Ok(res)
+}
@@ -1,12 +1,25 @@
 use std::future::{ready, Ready};
-use actix_web::error::ErrorUnauthorized;
 use actix_web::{dev::Payload, Error as ActixWebError};
 use actix_web::{http, web, FromRequest, HttpMessage, HttpRequest};
 
 use crate::domain::response::ErrorResponse;
 use crate::state::AppState;
+use crate::utils::errors::AppError;
 
 
+// The JWT from the "token" cookie, or else from the Authorization header
+pub fn request_token(req: &HttpRequest) -> Option<String> {
+    req.cookie("token")
+        .map(|c| c.value().to_string())
+        .or_else(|| {
+            req.headers()
+                .get(http::header::AUTHORIZATION)
+                .and_then(|h| h.to_str().ok())
+                .and_then(|h| h.get(7..))
+                .map(|token| token.to_string())
+        })
+}
+
 pub struct JwtMiddleware {
     pub user_id: i32,
 }
@@ -19,21 +32,13 @@ impl FromRequest for JwtMiddleware {
         let data = req.app_data::<web::Data<AppState>>().unwrap();
 
       
-        let token = req
-            .cookie("token")
-            .map(|c| c.value().to_string())
-            .or_else(|| {
-                req.headers()
-                    .get(http::header::AUTHORIZATION)
-                    .map(|h| h.to_str().unwrap().split_at(7).1.to_string())
-            });
+        let token = request_token(req);
 
         if token.is_none() {
-            let json_error = ErrorResponse {
-                status: "fail".to_string(),
-                message: "You are not logged in, please provide token".to_string(),
-            };
-            return ready(Err(ErrorUnauthorized(json_error)));
+            let json_error = ErrorResponse::from(AppError::Unauthorized(
+                "You are not logged in, please provide token".to_string(),
+            ));
+            return ready(Err(json_error.into()));
         }
 
         let token = token.unwrap(); 
@@ -45,16 +50,16 @@ impl FromRequest for JwtMiddleware {
         let user_id = match user_id_result {
             Ok(id) => id, 
             Err(_) => {
-                let json_error = ErrorResponse {
-                    status: "fail".to_string(),
-                    message: "Invalid token".to_string(),
-                };
-                return ready(Err(ErrorUnauthorized(json_error)));
+                let json_error = ErrorResponse::from(AppError::TokenValidationError);
+                return ready(Err(json_error.into()));
             }
         };
 
         req.extensions_mut().insert::<i32>(user_id as i32);
 
+        // Everything logged for the rest of the request carries who made it
+        tracing::Span::current().record("user_id", user_id);
+
         ready(Ok(JwtMiddleware { user_id: user_id as i32 })) 
     }
 }
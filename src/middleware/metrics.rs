@@ -0,0 +1,41 @@
+use std::time::Instant;
+
+use actix_web::{
+    body::MessageBody,
+    dev::{ServiceRequest, ServiceResponse},
+    middleware::Next,
+    web, Error as ActixWebError,
+};
+
+use crate::state::AppState;
+
+/// Counts every request and its latency by method, route pattern and status.
+///
+/// Unmatched paths share one `unmatched` route so scanners cannot grow the label set.
+pub async fn track_requests<B: MessageBody>(
+    req: ServiceRequest,
+    next: Next<B>,
+) -> Result<ServiceResponse<B>, ActixWebError> {
+    let metrics = req
+        .app_data::<web::Data<AppState>>()
+        .map(|data| data.di_container.metrics.clone());
+    let method = req.method().to_string();
+    let route = req
+        .match_pattern()
+        .unwrap_or_else(|| "unmatched".to_string());
+
+    let started_at = Instant::now();
+    let result = next.call(req).await;
+
+    if let Some(metrics) = metrics {
+        // Errors that escape the middleware chain are rendered by actix with their own status
+        let status = match &result {
+            Ok(res) => res.status().as_u16(),
+            Err(err) => err.as_response_error().status_code().as_u16(),
+        };
+
+        metrics.observe_request(&method, &route, status, started_at.elapsed());
+    }
+
+    result
+}
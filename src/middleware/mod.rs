@@ -1 +1,7 @@
-pub mod auth;
\ No newline at end of file
+pub mod auth;
+pub mod operator;
+pub mod validated_json;
+pub mod api_version;
+pub mod rate_limit;
+pub mod request_id;
+pub mod metrics;
@@ -0,0 +1,36 @@
+use std::future::{ready, Ready};
+use actix_web::{dev::Payload, Error as ActixWebError};
+use actix_web::{web, FromRequest, HttpRequest};
+
+use crate::domain::response::ErrorResponse;
+use crate::middleware::auth::JwtMiddleware;
+use crate::state::AppState;
+use crate::utils::errors::AppError;
+
+
+// Authenticates like JwtMiddleware and additionally requires the user to be a configured operator
+pub struct OperatorMiddleware {
+    pub user_id: i32,
+}
+
+impl FromRequest for OperatorMiddleware {
+    type Error = ActixWebError;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let jwt_guard = match JwtMiddleware::from_request(req, payload).into_inner() {
+            Ok(jwt_guard) => jwt_guard,
+            Err(e) => return ready(Err(e)),
+        };
+
+        let data = req.app_data::<web::Data<AppState>>().unwrap();
+
+        if !data.operator_user_ids.contains(&jwt_guard.user_id) {
+            let json_error =
+                ErrorResponse::from(AppError::Forbidden("Operator access required".to_string()));
+            return ready(Err(json_error.into()));
+        }
+
+        ready(Ok(OperatorMiddleware { user_id: jwt_guard.user_id }))
+    }
+}
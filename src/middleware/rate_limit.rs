@@ -0,0 +1,168 @@
+use std::{
+    future::{ready, Ready},
+    net::SocketAddr,
+    rc::Rc,
+};
+
+use actix_web::{
+    body::{BoxBody, MessageBody},
+    dev::{forward_ready, Service, ServiceRequest, ServiceResponse, Transform},
+    http::header::{HeaderMap, HeaderName, HeaderValue, RETRY_AFTER},
+    web, Error as ActixWebError,
+};
+use futures_util::future::LocalBoxFuture;
+
+use crate::{
+    domain::response::ErrorResponse,
+    middleware::auth::request_token,
+    state::AppState,
+    utils::{
+        errors::AppError,
+        rate_limit::{RateLimitDecision, RateLimitGroup, RateLimitIdentities},
+    },
+};
+
+const API_KEY: HeaderName = HeaderName::from_static("x-api-key");
+const RATELIMIT_LIMIT: HeaderName = HeaderName::from_static("ratelimit-limit");
+const RATELIMIT_REMAINING: HeaderName = HeaderName::from_static("ratelimit-remaining");
+const RATELIMIT_RESET: HeaderName = HeaderName::from_static("ratelimit-reset");
+
+/// Throttles the wrapped routes with the token buckets configured for `group`.
+///
+/// Refused requests get `429` with `Retry-After`; every limited response carries
+/// `RateLimit-Limit`, `RateLimit-Remaining` and `RateLimit-Reset`. When groups are nested the
+/// innermost one reports its headers.
+#[derive(Debug, Clone, Copy)]
+pub struct RateLimit {
+    group: RateLimitGroup,
+}
+
+impl RateLimit {
+    pub fn new(group: RateLimitGroup) -> Self {
+        Self { group }
+    }
+}
+
+impl<S, B> Transform<S, ServiceRequest> for RateLimit
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = ActixWebError> + 'static,
+    B: MessageBody + 'static,
+{
+    type Response = ServiceResponse<BoxBody>;
+    type Error = ActixWebError;
+    type Transform = RateLimitMiddleware<S>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(RateLimitMiddleware {
+            service: Rc::new(service),
+            group: self.group,
+        }))
+    }
+}
+
+pub struct RateLimitMiddleware<S> {
+    service: Rc<S>,
+    group: RateLimitGroup,
+}
+
+impl<S, B> Service<ServiceRequest> for RateLimitMiddleware<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = ActixWebError> + 'static,
+    B: MessageBody + 'static,
+{
+    type Response = ServiceResponse<BoxBody>;
+    type Error = ActixWebError;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let service = Rc::clone(&self.service);
+        let group = self.group;
+
+        Box::pin(async move {
+            let data = req.app_data::<web::Data<AppState>>().cloned();
+            let limiter = data
+                .as_ref()
+                .map(|data| data.di_container.rate_limit_service.clone())
+                .filter(|limiter| limiter.is_limited(group));
+
+            let (Some(data), Some(limiter)) = (data, limiter) else {
+                return Ok(service.call(req).await?.map_into_boxed_body());
+            };
+
+            let decision = limiter.check(group, &identities(&req, &data)).await;
+
+            if let Some(decision) = decision.filter(|decision| !decision.allowed) {
+                let retry_after = whole_seconds(decision.retry_after);
+                let error = ErrorResponse::from(AppError::RateLimited(format!(
+                    "Too many requests, retry in {} seconds",
+                    retry_after
+                )));
+
+                let mut res = req.error_response(error);
+                let headers = res.headers_mut();
+                headers.insert(RETRY_AFTER, HeaderValue::from(retry_after));
+                set_rate_limit_headers(headers, &decision);
+
+                return Ok(res);
+            }
+
+            let mut res = service.call(req).await?.map_into_boxed_body();
+
+            if let Some(decision) = decision {
+                if !res.headers().contains_key(RATELIMIT_LIMIT) {
+                    set_rate_limit_headers(res.headers_mut(), &decision);
+                }
+            }
+
+            Ok(res)
+        })
+    }
+}
+
+fn identities(req: &ServiceRequest, data: &AppState) -> RateLimitIdentities {
+    let ip = if data.rate_limit_trust_proxy {
+        req.connection_info().realip_remote_addr().map(|addr| {
+            addr.parse::<SocketAddr>()
+                .map(|addr| addr.ip().to_string())
+                .unwrap_or_else(|_| addr.to_string())
+        })
+    } else {
+        req.peer_addr().map(|addr| addr.ip().to_string())
+    };
+
+    // Only a token that verifies counts; anything else is limited by IP alone
+    let user_id = request_token(req.request())
+        .and_then(|token| data.jwt_config.verify_token(&token).ok())
+        .map(|id| id as i32);
+
+    let api_key = req
+        .headers()
+        .get(API_KEY)
+        .and_then(|value| value.to_str().ok())
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty());
+
+    RateLimitIdentities {
+        ip,
+        user_id,
+        api_key,
+    }
+}
+
+fn set_rate_limit_headers(headers: &mut HeaderMap, decision: &RateLimitDecision) {
+    headers.insert(RATELIMIT_LIMIT, HeaderValue::from(decision.limit));
+    headers.insert(RATELIMIT_REMAINING, HeaderValue::from(decision.remaining));
+    headers.insert(
+        RATELIMIT_RESET,
+        HeaderValue::from(whole_seconds(decision.reset_after)),
+    );
+}
+
+// Headers count whole seconds; rounding up never invites a retry that is still too early
+fn whole_seconds(duration: std::time::Duration) -> u64 {
+    duration.as_secs() + u64::from(duration.subsec_nanos() > 0)
+}
@@ -0,0 +1,145 @@
+use std::time::Instant;
+
+use actix_web::{
+    body::{BoxBody, MessageBody},
+    dev::{ServiceRequest, ServiceResponse},
+    http::header::{HeaderMap, HeaderName, HeaderValue, CONTENT_LENGTH, CONTENT_TYPE},
+    middleware::Next,
+    Error as ActixWebError, HttpMessage, HttpResponse,
+};
+use opentelemetry::{global, propagation::Extractor};
+use tracing::{field::Empty, info, info_span, warn, Instrument, Span};
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+use uuid::Uuid;
+
+use crate::domain::response::ErrorResponse;
+
+pub const REQUEST_ID: HeaderName = HeaderName::from_static("x-request-id");
+
+// Longer or odd-looking ids from clients are replaced rather than copied into the logs
+const MAX_REQUEST_ID_LEN: usize = 128;
+
+/// The id of the request being served, available from the request extensions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestId(pub String);
+
+/// Gives every request an id and a tracing span, and writes its access log line.
+///
+/// The id is taken from `X-Request-Id` when the caller sent a usable one and generated
+/// otherwise. It is echoed in the response header and in coded error bodies. The span records
+/// the route pattern, and the user id once a handler authenticates the caller. When traces are
+/// exported it is the server span, continuing the trace of a caller that sent `traceparent`.
+pub async fn request_id(
+    req: ServiceRequest,
+    next: Next<impl MessageBody + 'static>,
+) -> Result<ServiceResponse<BoxBody>, ActixWebError> {
+    let request_id = req
+        .headers()
+        .get(REQUEST_ID)
+        .and_then(|value| value.to_str().ok())
+        .filter(|value| is_valid_request_id(value))
+        .map(str::to_string)
+        .unwrap_or_else(|| Uuid::new_v4().to_string());
+
+    // The pattern rather than the path, so ids and payment link tokens stay out of the logs
+    let route = req
+        .match_pattern()
+        .unwrap_or_else(|| "unmatched".to_string());
+
+    let span = info_span!(
+        "request",
+        otel.name = %format!("{} {}", req.method(), route),
+        otel.kind = "server",
+        otel.status_code = Empty,
+        request_id = %request_id,
+        method = %req.method(),
+        route = %route,
+        user_id = Empty,
+        http.response.status_code = Empty,
+    );
+
+    // A no-op unless OTLP export installed the W3C trace context propagator
+    let parent = global::get_text_map_propagator(|propagator| {
+        propagator.extract(&HeaderExtractor(req.headers()))
+    });
+    let _ = span.set_parent(parent);
+
+    req.extensions_mut().insert(RequestId(request_id.clone()));
+
+    let started_at = Instant::now();
+    let result = next.call(req).instrument(span.clone()).await;
+    let latency_ms = started_at.elapsed().as_millis() as u64;
+
+    let mut res = match result {
+        Ok(res) => res.map_into_boxed_body(),
+        Err(err) => {
+            span.record("otel.status_code", "error");
+            warn!(parent: &span, latency_ms, "request failed: {}", err);
+            return Err(err);
+        }
+    };
+
+    let with_request_id = res
+        .response()
+        .error()
+        .and_then(|err| err.as_error::<ErrorResponse>())
+        .filter(|err| err.request_id.is_none())
+        .map(|err| err.clone().with_request_id(&request_id));
+
+    if let Some(error) = with_request_id {
+        let mut response = HttpResponse::from_error(error);
+
+        for (name, value) in res.headers() {
+            if name != CONTENT_TYPE && name != CONTENT_LENGTH {
+                response.headers_mut().append(name.clone(), value.clone());
+            }
+        }
+
+        res = res.into_response(response);
+    }
+
+    if let Ok(value) = HeaderValue::from_str(&request_id) {
+        res.headers_mut().insert(REQUEST_ID, value);
+    }
+
+    record_status(&span, res.status().as_u16());
+
+    info!(
+        parent: &span,
+        status = res.status().as_u16(),
+        latency_ms,
+        "request completed"
+    );
+
+    Ok(res)
+}
+
+fn record_status(span: &Span, status: u16) {
+    // As i64, which OpenTelemetry exports as an integer rather than a string
+    span.record("http.response.status_code", i64::from(status));
+
+    // Client errors are the caller's doing; only server errors mark the span as failed
+    if status >= 500 {
+        span.record("otel.status_code", "error");
+    }
+}
+
+struct HeaderExtractor<'a>(&'a HeaderMap);
+
+impl Extractor for HeaderExtractor<'_> {
+    fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).and_then(|value| value.to_str().ok())
+    }
+
+    fn keys(&self) -> Vec<&str> {
+        self.0.keys().map(|name| name.as_str()).collect()
+    }
+}
+
+fn is_valid_request_id(value: &str) -> bool {
+    !value.is_empty()
+        && value.len() <= MAX_REQUEST_ID_LEN
+        && value
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.' | ':'))
+}
@@ -0,0 +1,50 @@
+use std::ops::Deref;
+
+use actix_web::{dev::Payload, web, Error as ActixWebError, FromRequest, HttpRequest};
+use futures_util::future::LocalBoxFuture;
+use serde::de::DeserializeOwned;
+use validator::Validate;
+
+use crate::domain::response::ErrorResponse;
+use crate::utils::errors::AppError;
+
+// A JSON body that has passed its field validation; every invalid field is reported at once
+pub struct ValidatedJson<T>(pub T);
+
+impl<T> ValidatedJson<T> {
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> Deref for ValidatedJson<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> FromRequest for ValidatedJson<T>
+where
+    T: DeserializeOwned + Validate + 'static,
+{
+    type Error = ActixWebError;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        // Parsing goes through web::Json so the configured JsonConfig still applies
+        let json = web::Json::<T>::from_request(req, payload);
+
+        Box::pin(async move {
+            let body = json.await?.into_inner();
+
+            if let Err(errors) = body.validate() {
+                let json_error = ErrorResponse::from(AppError::InvalidFields(errors));
+                return Err(json_error.into());
+            }
+
+            Ok(ValidatedJson(body))
+        })
+    }
+}
@@ -287,6 +287,7 @@ enum Topups {
     UpdatedAt,
 }
 
+#[allow(clippy::enum_variant_names)]
 #[derive(Iden)]
 enum Saldo {
     Table,
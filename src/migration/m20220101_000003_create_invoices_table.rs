@@ -0,0 +1,220 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Create Invoices Table
+        let invoices_table = Table::create()
+            .table(Invoices::Table)
+            .if_not_exists()
+            .col(
+                ColumnDef::new(Invoices::InvoiceId)
+                    .integer()
+                    .not_null()
+                    .auto_increment()
+                    .primary_key(),
+            )
+            .col(ColumnDef::new(Invoices::MerchantId).integer().not_null())
+            .col(
+                ColumnDef::new(Invoices::Token)
+                    .string_len(64)
+                    .unique_key()
+                    .not_null(),
+            )
+            .col(ColumnDef::new(Invoices::Description).text().not_null())
+            .col(ColumnDef::new(Invoices::TotalAmount).integer())
+            .col(
+                ColumnDef::new(Invoices::PaidAmount)
+                    .integer()
+                    .not_null()
+                    .default(0),
+            )
+            .col(
+                ColumnDef::new(Invoices::Status)
+                    .string_len(20)
+                    .not_null()
+                    .default("unpaid"),
+            )
+            .col(ColumnDef::new(Invoices::DueDate).timestamp().not_null())
+            .col(
+                ColumnDef::new(Invoices::CreatedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .col(
+                ColumnDef::new(Invoices::UpdatedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .name("fk-invoices-merchant_id")
+                    .from(Invoices::Table, Invoices::MerchantId)
+                    .to(Users::Table, Users::UserId)
+                    .on_update(ForeignKeyAction::Cascade)
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .to_owned();
+        manager.create_table(invoices_table).await?;
+
+        // Create Invoice Items Table
+        let invoice_items_table = Table::create()
+            .table(InvoiceItems::Table)
+            .if_not_exists()
+            .col(
+                ColumnDef::new(InvoiceItems::ItemId)
+                    .integer()
+                    .not_null()
+                    .auto_increment()
+                    .primary_key(),
+            )
+            .col(ColumnDef::new(InvoiceItems::InvoiceId).integer().not_null())
+            .col(ColumnDef::new(InvoiceItems::Description).text().not_null())
+            .col(ColumnDef::new(InvoiceItems::Quantity).integer().not_null())
+            .col(ColumnDef::new(InvoiceItems::UnitPrice).integer().not_null())
+            .col(
+                ColumnDef::new(InvoiceItems::CreatedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .col(
+                ColumnDef::new(InvoiceItems::UpdatedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .name("fk-invoice_items-invoice_id")
+                    .from(InvoiceItems::Table, InvoiceItems::InvoiceId)
+                    .to(Invoices::Table, Invoices::InvoiceId)
+                    .on_update(ForeignKeyAction::Cascade)
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .to_owned();
+        manager.create_table(invoice_items_table).await?;
+
+        // Create Invoice Payments Table
+        let invoice_payments_table = Table::create()
+            .table(InvoicePayments::Table)
+            .if_not_exists()
+            .col(
+                ColumnDef::new(InvoicePayments::PaymentId)
+                    .integer()
+                    .not_null()
+                    .auto_increment()
+                    .primary_key(),
+            )
+            .col(ColumnDef::new(InvoicePayments::InvoiceId).integer().not_null())
+            .col(ColumnDef::new(InvoicePayments::PayerId).integer().not_null())
+            .col(ColumnDef::new(InvoicePayments::TransferId).integer().not_null())
+            .col(ColumnDef::new(InvoicePayments::Amount).integer().not_null())
+            .col(ColumnDef::new(InvoicePayments::PaidAt).timestamp().not_null())
+            .col(
+                ColumnDef::new(InvoicePayments::CreatedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .col(
+                ColumnDef::new(InvoicePayments::UpdatedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .name("fk-invoice_payments-invoice_id")
+                    .from(InvoicePayments::Table, InvoicePayments::InvoiceId)
+                    .to(Invoices::Table, Invoices::InvoiceId)
+                    .on_update(ForeignKeyAction::Cascade)
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .name("fk-invoice_payments-payer_id")
+                    .from(InvoicePayments::Table, InvoicePayments::PayerId)
+                    .to(Users::Table, Users::UserId)
+                    .on_update(ForeignKeyAction::Cascade)
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .name("fk-invoice_payments-transfer_id")
+                    .from(InvoicePayments::Table, InvoicePayments::TransferId)
+                    .to(Transfers::Table, Transfers::TransferId)
+                    .on_update(ForeignKeyAction::Cascade)
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .to_owned();
+        manager.create_table(invoice_payments_table).await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(InvoicePayments::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(InvoiceItems::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(Invoices::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum Users {
+    Table,
+    UserId,
+}
+
+#[derive(Iden)]
+enum Transfers {
+    Table,
+    TransferId,
+}
+
+#[derive(Iden)]
+enum Invoices {
+    Table,
+    InvoiceId,
+    MerchantId,
+    Token,
+    Description,
+    TotalAmount,
+    PaidAmount,
+    Status,
+    DueDate,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(Iden)]
+enum InvoiceItems {
+    Table,
+    ItemId,
+    InvoiceId,
+    Description,
+    Quantity,
+    UnitPrice,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(Iden)]
+enum InvoicePayments {
+    Table,
+    PaymentId,
+    InvoiceId,
+    PayerId,
+    TransferId,
+    Amount,
+    PaidAt,
+    CreatedAt,
+    UpdatedAt,
+}
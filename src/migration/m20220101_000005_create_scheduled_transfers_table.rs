@@ -0,0 +1,189 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Create Scheduled Transfers Table
+        let scheduled_transfers_table = Table::create()
+            .table(ScheduledTransfers::Table)
+            .if_not_exists()
+            .col(
+                ColumnDef::new(ScheduledTransfers::ScheduledTransferId)
+                    .integer()
+                    .not_null()
+                    .auto_increment()
+                    .primary_key(),
+            )
+            .col(ColumnDef::new(ScheduledTransfers::TransferFrom).integer().not_null())
+            .col(ColumnDef::new(ScheduledTransfers::TransferTo).integer().not_null())
+            .col(ColumnDef::new(ScheduledTransfers::TransferAmount).integer().not_null())
+            .col(ColumnDef::new(ScheduledTransfers::Recurrence).string_len(20))
+            .col(ColumnDef::new(ScheduledTransfers::NextRunAt).timestamp().not_null())
+            .col(ColumnDef::new(ScheduledTransfers::LastRunAt).timestamp())
+            .col(
+                ColumnDef::new(ScheduledTransfers::Status)
+                    .string_len(20)
+                    .not_null()
+                    .default("active"),
+            )
+            .col(
+                ColumnDef::new(ScheduledTransfers::CreatedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .col(
+                ColumnDef::new(ScheduledTransfers::UpdatedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .name("fk-scheduled_transfers-transfer_from")
+                    .from(ScheduledTransfers::Table, ScheduledTransfers::TransferFrom)
+                    .to(Users::Table, Users::UserId)
+                    .on_update(ForeignKeyAction::Cascade)
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .name("fk-scheduled_transfers-transfer_to")
+                    .from(ScheduledTransfers::Table, ScheduledTransfers::TransferTo)
+                    .to(Users::Table, Users::UserId)
+                    .on_update(ForeignKeyAction::Cascade)
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .to_owned();
+        manager.create_table(scheduled_transfers_table).await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx-scheduled_transfers-status-next_run_at")
+                    .table(ScheduledTransfers::Table)
+                    .col(ScheduledTransfers::Status)
+                    .col(ScheduledTransfers::NextRunAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        // Create Scheduled Transfer Executions Table
+        let executions_table = Table::create()
+            .table(ScheduledTransferExecutions::Table)
+            .if_not_exists()
+            .col(
+                ColumnDef::new(ScheduledTransferExecutions::ExecutionId)
+                    .integer()
+                    .not_null()
+                    .auto_increment()
+                    .primary_key(),
+            )
+            .col(
+                ColumnDef::new(ScheduledTransferExecutions::ScheduledTransferId)
+                    .integer()
+                    .not_null(),
+            )
+            .col(ColumnDef::new(ScheduledTransferExecutions::TransferId).integer())
+            .col(
+                ColumnDef::new(ScheduledTransferExecutions::Status)
+                    .string_len(20)
+                    .not_null(),
+            )
+            .col(ColumnDef::new(ScheduledTransferExecutions::FailureReason).text())
+            .col(
+                ColumnDef::new(ScheduledTransferExecutions::ExecutedAt)
+                    .timestamp()
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(ScheduledTransferExecutions::CreatedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .col(
+                ColumnDef::new(ScheduledTransferExecutions::UpdatedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .name("fk-scheduled_transfer_executions-scheduled_transfer_id")
+                    .from(
+                        ScheduledTransferExecutions::Table,
+                        ScheduledTransferExecutions::ScheduledTransferId,
+                    )
+                    .to(ScheduledTransfers::Table, ScheduledTransfers::ScheduledTransferId)
+                    .on_update(ForeignKeyAction::Cascade)
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .name("fk-scheduled_transfer_executions-transfer_id")
+                    .from(
+                        ScheduledTransferExecutions::Table,
+                        ScheduledTransferExecutions::TransferId,
+                    )
+                    .to(Transfers::Table, Transfers::TransferId)
+                    .on_update(ForeignKeyAction::Cascade)
+                    .on_delete(ForeignKeyAction::SetNull),
+            )
+            .to_owned();
+        manager.create_table(executions_table).await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(ScheduledTransferExecutions::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(ScheduledTransfers::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum Users {
+    Table,
+    UserId,
+}
+
+#[derive(Iden)]
+enum Transfers {
+    Table,
+    TransferId,
+}
+
+#[derive(Iden)]
+enum ScheduledTransfers {
+    Table,
+    ScheduledTransferId,
+    TransferFrom,
+    TransferTo,
+    TransferAmount,
+    Recurrence,
+    NextRunAt,
+    LastRunAt,
+    Status,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(Iden)]
+enum ScheduledTransferExecutions {
+    Table,
+    ExecutionId,
+    ScheduledTransferId,
+    TransferId,
+    Status,
+    FailureReason,
+    ExecutedAt,
+    CreatedAt,
+    UpdatedAt,
+}
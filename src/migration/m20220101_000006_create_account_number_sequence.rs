@@ -0,0 +1,26 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Backs the sequential and encrypted-sequence account number schemes
+        manager
+            .get_connection()
+            .execute_unprepared("CREATE SEQUENCE IF NOT EXISTS account_number_seq START WITH 1")
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared("DROP SEQUENCE IF EXISTS account_number_seq")
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,147 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Create Beneficiaries Table
+        let beneficiaries_table = Table::create()
+            .table(Beneficiaries::Table)
+            .if_not_exists()
+            .col(
+                ColumnDef::new(Beneficiaries::BeneficiaryId)
+                    .integer()
+                    .not_null()
+                    .auto_increment()
+                    .primary_key(),
+            )
+            .col(ColumnDef::new(Beneficiaries::UserId).integer().not_null())
+            .col(ColumnDef::new(Beneficiaries::BankCode).string_len(50).not_null())
+            .col(ColumnDef::new(Beneficiaries::AccountNumber).string_len(50).not_null())
+            .col(ColumnDef::new(Beneficiaries::AccountName).string_len(100).not_null())
+            .col(
+                ColumnDef::new(Beneficiaries::CreatedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .col(
+                ColumnDef::new(Beneficiaries::UpdatedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .name("fk-beneficiaries-user_id")
+                    .from(Beneficiaries::Table, Beneficiaries::UserId)
+                    .to(Users::Table, Users::UserId)
+                    .on_update(ForeignKeyAction::Cascade)
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .to_owned();
+        manager.create_table(beneficiaries_table).await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx-beneficiaries-user_id-bank_code-account_number")
+                    .table(Beneficiaries::Table)
+                    .col(Beneficiaries::UserId)
+                    .col(Beneficiaries::BankCode)
+                    .col(Beneficiaries::AccountNumber)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        // Track the payout lifecycle on withdraws
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Withdraws::Table)
+                    .add_column(ColumnDef::new(Withdraws::BeneficiaryId).integer())
+                    .add_column(
+                        ColumnDef::new(Withdraws::Status)
+                            .string_len(20)
+                            .not_null()
+                            .default("pending"),
+                    )
+                    .add_column(ColumnDef::new(Withdraws::PayoutReference).string_len(100))
+                    .add_column(ColumnDef::new(Withdraws::FailureReason).text())
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("fk-withdraws-beneficiary_id")
+                            .from_tbl(Withdraws::Table)
+                            .from_col(Withdraws::BeneficiaryId)
+                            .to_tbl(Beneficiaries::Table)
+                            .to_col(Beneficiaries::BeneficiaryId)
+                            .on_update(ForeignKeyAction::Cascade)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Withdraws created before payouts existed were settled immediately
+        manager
+            .exec_stmt(
+                Query::update()
+                    .table(Withdraws::Table)
+                    .value(Withdraws::Status, "paid")
+                    .and_where(Expr::col(Withdraws::BeneficiaryId).is_null())
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Withdraws::Table)
+                    .drop_foreign_key(Alias::new("fk-withdraws-beneficiary_id"))
+                    .drop_column(Withdraws::BeneficiaryId)
+                    .drop_column(Withdraws::Status)
+                    .drop_column(Withdraws::PayoutReference)
+                    .drop_column(Withdraws::FailureReason)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_table(Table::drop().table(Beneficiaries::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum Users {
+    Table,
+    UserId,
+}
+
+#[derive(Iden)]
+enum Beneficiaries {
+    Table,
+    BeneficiaryId,
+    UserId,
+    BankCode,
+    AccountNumber,
+    AccountName,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(Iden)]
+enum Withdraws {
+    Table,
+    BeneficiaryId,
+    Status,
+    PayoutReference,
+    FailureReason,
+}
@@ -0,0 +1,112 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Every money movement records the fee it was charged and the rule that priced it
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Topups::Table)
+                    .add_column(
+                        ColumnDef::new(Topups::FeeAmount)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .add_column(ColumnDef::new(Topups::FeeRule).string_len(100))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Transfers::Table)
+                    .add_column(
+                        ColumnDef::new(Transfers::FeeAmount)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .add_column(ColumnDef::new(Transfers::FeeRule).string_len(100))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Withdraws::Table)
+                    .add_column(
+                        ColumnDef::new(Withdraws::FeeAmount)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .add_column(ColumnDef::new(Withdraws::FeeRule).string_len(100))
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Withdraws::Table)
+                    .drop_column(Withdraws::FeeRule)
+                    .drop_column(Withdraws::FeeAmount)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Transfers::Table)
+                    .drop_column(Transfers::FeeRule)
+                    .drop_column(Transfers::FeeAmount)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Topups::Table)
+                    .drop_column(Topups::FeeRule)
+                    .drop_column(Topups::FeeAmount)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum Topups {
+    Table,
+    FeeAmount,
+    FeeRule,
+}
+
+#[derive(Iden)]
+enum Transfers {
+    Table,
+    FeeAmount,
+    FeeRule,
+}
+
+#[derive(Iden)]
+enum Withdraws {
+    Table,
+    FeeAmount,
+    FeeRule,
+}
@@ -0,0 +1,186 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Transfers and topups can now be held for review before they settle
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Transfers::Table)
+                    .add_column(
+                        ColumnDef::new(Transfers::Status)
+                            .string_len(20)
+                            .not_null()
+                            .default("completed"),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Topups::Table)
+                    .add_column(
+                        ColumnDef::new(Topups::Status)
+                            .string_len(20)
+                            .not_null()
+                            .default("completed"),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Create Risk Decisions Table
+        let risk_decisions_table = Table::create()
+            .table(RiskDecisions::Table)
+            .if_not_exists()
+            .col(
+                ColumnDef::new(RiskDecisions::DecisionId)
+                    .integer()
+                    .not_null()
+                    .auto_increment()
+                    .primary_key(),
+            )
+            .col(ColumnDef::new(RiskDecisions::UserId).integer().not_null())
+            .col(ColumnDef::new(RiskDecisions::Operation).string_len(20).not_null())
+            .col(ColumnDef::new(RiskDecisions::Amount).integer().not_null())
+            .col(ColumnDef::new(RiskDecisions::CounterpartyUserId).integer())
+            .col(ColumnDef::new(RiskDecisions::Action).string_len(20).not_null())
+            .col(ColumnDef::new(RiskDecisions::TriggeredRules).text())
+            .col(ColumnDef::new(RiskDecisions::ReferenceId).integer())
+            .col(ColumnDef::new(RiskDecisions::ReviewStatus).string_len(20))
+            .col(ColumnDef::new(RiskDecisions::ReviewedBy).integer())
+            .col(ColumnDef::new(RiskDecisions::ReviewReason).text())
+            .col(ColumnDef::new(RiskDecisions::ReviewedAt).timestamp())
+            .col(
+                ColumnDef::new(RiskDecisions::CreatedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .name("fk-risk_decisions-user_id")
+                    .from(RiskDecisions::Table, RiskDecisions::UserId)
+                    .to(Users::Table, Users::UserId)
+                    .on_update(ForeignKeyAction::Cascade)
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .to_owned();
+        manager.create_table(risk_decisions_table).await?;
+
+        // Velocity rules count a user's recent decisions per operation
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx-risk_decisions-user_id-operation-created_at")
+                    .table(RiskDecisions::Table)
+                    .col(RiskDecisions::UserId)
+                    .col(RiskDecisions::Operation)
+                    .col(RiskDecisions::CreatedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx-risk_decisions-review_status")
+                    .table(RiskDecisions::Table)
+                    .col(RiskDecisions::ReviewStatus)
+                    .to_owned(),
+            )
+            .await?;
+
+        // New-recipient and round-trip rules look up transfers between two users
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx-transfers-transfer_from-transfer_to")
+                    .table(Transfers::Table)
+                    .col(Transfers::TransferFrom)
+                    .col(Transfers::TransferTo)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx-transfers-transfer_from-transfer_to")
+                    .table(Transfers::Table)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_table(Table::drop().table(RiskDecisions::Table).to_owned())
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Topups::Table)
+                    .drop_column(Topups::Status)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Transfers::Table)
+                    .drop_column(Transfers::Status)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum Users {
+    Table,
+    UserId,
+}
+
+#[derive(Iden)]
+enum Transfers {
+    Table,
+    TransferFrom,
+    TransferTo,
+    Status,
+}
+
+#[derive(Iden)]
+enum Topups {
+    Table,
+    Status,
+}
+
+#[derive(Iden)]
+enum RiskDecisions {
+    Table,
+    DecisionId,
+    UserId,
+    Operation,
+    Amount,
+    CounterpartyUserId,
+    Action,
+    TriggeredRules,
+    ReferenceId,
+    ReviewStatus,
+    ReviewedBy,
+    ReviewReason,
+    ReviewedAt,
+    CreatedAt,
+}
@@ -0,0 +1,111 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Create Screening Matches Table, the watchlist review queue
+        let screening_matches_table = Table::create()
+            .table(ScreeningMatches::Table)
+            .if_not_exists()
+            .col(
+                ColumnDef::new(ScreeningMatches::MatchId)
+                    .integer()
+                    .not_null()
+                    .auto_increment()
+                    .primary_key(),
+            )
+            .col(ColumnDef::new(ScreeningMatches::UserId).integer().not_null())
+            .col(ColumnDef::new(ScreeningMatches::Context).string_len(20).not_null())
+            .col(ColumnDef::new(ScreeningMatches::ScreenedName).string_len(255).not_null())
+            .col(ColumnDef::new(ScreeningMatches::EntryReference).string_len(255).not_null())
+            .col(ColumnDef::new(ScreeningMatches::EntryName).string_len(255).not_null())
+            .col(ColumnDef::new(ScreeningMatches::EntrySource).string_len(100))
+            .col(ColumnDef::new(ScreeningMatches::Score).double().not_null())
+            .col(
+                ColumnDef::new(ScreeningMatches::Status)
+                    .string_len(20)
+                    .not_null()
+                    .default("pending"),
+            )
+            .col(ColumnDef::new(ScreeningMatches::ReviewedBy).integer())
+            .col(ColumnDef::new(ScreeningMatches::ReviewReason).text())
+            .col(ColumnDef::new(ScreeningMatches::ReviewedAt).timestamp())
+            .col(
+                ColumnDef::new(ScreeningMatches::CreatedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .name("fk-screening_matches-user_id")
+                    .from(ScreeningMatches::Table, ScreeningMatches::UserId)
+                    .to(Users::Table, Users::UserId)
+                    .on_update(ForeignKeyAction::Cascade)
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .to_owned();
+        manager.create_table(screening_matches_table).await?;
+
+        // A listed party is matched against a user at most once
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx-screening_matches-user_id-entry_reference")
+                    .table(ScreeningMatches::Table)
+                    .col(ScreeningMatches::UserId)
+                    .col(ScreeningMatches::EntryReference)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx-screening_matches-status")
+                    .table(ScreeningMatches::Table)
+                    .col(ScreeningMatches::Status)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(ScreeningMatches::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum Users {
+    Table,
+    UserId,
+}
+
+#[derive(Iden)]
+enum ScreeningMatches {
+    Table,
+    MatchId,
+    UserId,
+    Context,
+    ScreenedName,
+    EntryReference,
+    EntryName,
+    EntrySource,
+    Score,
+    Status,
+    ReviewedBy,
+    ReviewReason,
+    ReviewedAt,
+    CreatedAt,
+}
@@ -0,0 +1,104 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Users::Table)
+                    .add_column(
+                        ColumnDef::new(Users::AccountStatus)
+                            .string_len(20)
+                            .not_null()
+                            .default("active"),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Create Account Status Changes Table, the audit history of every state change
+        let account_status_changes_table = Table::create()
+            .table(AccountStatusChanges::Table)
+            .if_not_exists()
+            .col(
+                ColumnDef::new(AccountStatusChanges::ChangeId)
+                    .integer()
+                    .not_null()
+                    .auto_increment()
+                    .primary_key(),
+            )
+            .col(ColumnDef::new(AccountStatusChanges::UserId).integer().not_null())
+            .col(ColumnDef::new(AccountStatusChanges::PreviousStatus).string_len(20).not_null())
+            .col(ColumnDef::new(AccountStatusChanges::NewStatus).string_len(20).not_null())
+            .col(ColumnDef::new(AccountStatusChanges::Reason).text().not_null())
+            .col(ColumnDef::new(AccountStatusChanges::ChangedBy).integer().not_null())
+            .col(
+                ColumnDef::new(AccountStatusChanges::CreatedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .name("fk-account_status_changes-user_id")
+                    .from(AccountStatusChanges::Table, AccountStatusChanges::UserId)
+                    .to(Users::Table, Users::UserId)
+                    .on_update(ForeignKeyAction::Cascade)
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .to_owned();
+        manager.create_table(account_status_changes_table).await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx-account_status_changes-user_id")
+                    .table(AccountStatusChanges::Table)
+                    .col(AccountStatusChanges::UserId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(AccountStatusChanges::Table).to_owned())
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Users::Table)
+                    .drop_column(Users::AccountStatus)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum Users {
+    Table,
+    UserId,
+    AccountStatus,
+}
+
+#[derive(Iden)]
+enum AccountStatusChanges {
+    Table,
+    ChangeId,
+    UserId,
+    PreviousStatus,
+    NewStatus,
+    Reason,
+    ChangedBy,
+    CreatedAt,
+}
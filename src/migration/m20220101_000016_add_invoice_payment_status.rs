@@ -0,0 +1,52 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // A payment is recorded before its transfer runs, so it has no transfer until then
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(InvoicePayments::Table)
+                    .modify_column(ColumnDef::new(InvoicePayments::TransferId).integer().null())
+                    .add_column(
+                        ColumnDef::new(InvoicePayments::Status)
+                            .string_len(20)
+                            .not_null()
+                            .default("completed"),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(InvoicePayments::Table)
+                    .drop_column(InvoicePayments::Status)
+                    .modify_column(
+                        ColumnDef::new(InvoicePayments::TransferId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum InvoicePayments {
+    Table,
+    TransferId,
+    Status,
+}
@@ -0,0 +1,74 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Create Fee Entries Table, one row per fee booked to (or refunded from) platform revenue
+        let fee_entries_table = Table::create()
+            .table(FeeEntries::Table)
+            .if_not_exists()
+            .col(
+                ColumnDef::new(FeeEntries::FeeEntryId)
+                    .integer()
+                    .not_null()
+                    .auto_increment()
+                    .primary_key(),
+            )
+            .col(ColumnDef::new(FeeEntries::Amount).integer().not_null())
+            .col(ColumnDef::new(FeeEntries::FeeRule).string_len(100).null())
+            .col(
+                ColumnDef::new(FeeEntries::Status)
+                    .string_len(20)
+                    .not_null()
+                    .default("booked"),
+            )
+            .col(
+                ColumnDef::new(FeeEntries::CreatedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .col(
+                ColumnDef::new(FeeEntries::UpdatedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .to_owned();
+        manager.create_table(fee_entries_table).await?;
+
+        // The retry job only ever looks for entries that are still pending
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx-fee_entries-status")
+                    .table(FeeEntries::Table)
+                    .col(FeeEntries::Status)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(FeeEntries::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum FeeEntries {
+    Table,
+    FeeEntryId,
+    Amount,
+    FeeRule,
+    Status,
+    CreatedAt,
+    UpdatedAt,
+}
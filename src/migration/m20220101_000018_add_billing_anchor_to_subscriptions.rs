@@ -0,0 +1,80 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Charge dates are derived from a fixed anchor and the number of periods billed, so
+        // dunning retries and month-end clamping never move the billing day
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Subscriptions::Table)
+                    .add_column(
+                        ColumnDef::new(Subscriptions::BillingAnchorAt)
+                            .timestamp()
+                            .null(),
+                    )
+                    .add_column(
+                        ColumnDef::new(Subscriptions::BilledPeriods)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Existing subscriptions are anchored on their upcoming charge
+        manager
+            .exec_stmt(
+                Query::update()
+                    .table(Subscriptions::Table)
+                    .value(
+                        Subscriptions::BillingAnchorAt,
+                        Expr::col(Subscriptions::NextChargeAt),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Subscriptions::Table)
+                    .modify_column(
+                        ColumnDef::new(Subscriptions::BillingAnchorAt)
+                            .timestamp()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Subscriptions::Table)
+                    .drop_column(Subscriptions::BillingAnchorAt)
+                    .drop_column(Subscriptions::BilledPeriods)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum Subscriptions {
+    Table,
+    NextChargeAt,
+    BillingAnchorAt,
+    BilledPeriods,
+}
@@ -0,0 +1,45 @@
+use async_trait::async_trait;
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, DatabaseConnection, DbErr, EntityTrait, PaginatorTrait,
+    QueryFilter, Statement,
+};
+
+use crate::{abstract_trait::account_number::AccountNumberRepositoryTrait, entities::users};
+use tracing::instrument;
+
+pub struct AccountNumberRepository {
+    db_pool: DatabaseConnection,
+}
+
+impl AccountNumberRepository {
+    pub fn new(db_pool: DatabaseConnection) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl AccountNumberRepositoryTrait for AccountNumberRepository {
+    #[instrument(skip_all, fields(db.operation = "SELECT", db.sql.table = "account_number_seq"))]
+    async fn next_sequence(&self) -> Result<i64, DbErr> {
+        let row = self
+            .db_pool
+            .query_one(Statement::from_string(
+                self.db_pool.get_database_backend(),
+                "SELECT nextval('account_number_seq') AS value",
+            ))
+            .await?
+            .ok_or(DbErr::RecordNotFound("account_number_seq".to_owned()))?;
+
+        row.try_get("", "value")
+    }
+
+    #[instrument(skip_all, fields(db.operation = "SELECT", db.sql.table = "users"))]
+    async fn exists(&self, noc_transfer: &str) -> Result<bool, DbErr> {
+        let count = users::Entity::find()
+            .filter(users::Column::NocTransfer.eq(noc_transfer))
+            .count(&self.db_pool)
+            .await?;
+
+        Ok(count > 0)
+    }
+}
@@ -0,0 +1,89 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use sea_orm::{
+    sea_query::Expr, ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait,
+    QueryFilter, QueryOrder, Set, TransactionTrait,
+};
+
+use crate::{
+    abstract_trait::account_status::AccountStatusRepositoryTrait,
+    domain::request::account_status::CreateAccountStatusChangeRequest,
+    entities::{account_status_changes, users},
+};
+use tracing::instrument;
+
+pub struct AccountStatusRepository {
+    db_pool: DatabaseConnection,
+}
+
+impl AccountStatusRepository {
+    pub fn new(db_pool: DatabaseConnection) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl AccountStatusRepositoryTrait for AccountStatusRepository {
+    #[instrument(
+        skip_all,
+        fields(db.operation = "SELECT", db.sql.table = "account_status_changes", user_id = user_id)
+    )]
+    async fn find_by_user(
+        &self,
+        user_id: i32,
+    ) -> Result<Vec<account_status_changes::Model>, DbErr> {
+        account_status_changes::Entity::find()
+            .filter(account_status_changes::Column::UserId.eq(user_id))
+            .order_by_desc(account_status_changes::Column::ChangeId)
+            .all(&self.db_pool)
+            .await
+    }
+
+    #[instrument(
+        skip_all,
+        fields(db.operation = "UPDATE", db.sql.table = "users", user_id = input.user_id)
+    )]
+    async fn change(
+        &self,
+        input: &CreateAccountStatusChangeRequest,
+    ) -> Result<Option<users::Model>, DbErr> {
+        let txn = self.db_pool.begin().await?;
+
+        // Guarding on the previous status keeps concurrent changes from overwriting each other
+        let result = users::Entity::update_many()
+            .col_expr(
+                users::Column::AccountStatus,
+                Expr::value(input.new_status.as_str()),
+            )
+            .col_expr(
+                users::Column::UpdatedAt,
+                Expr::value(Utc::now().naive_utc()),
+            )
+            .filter(users::Column::UserId.eq(input.user_id))
+            .filter(users::Column::AccountStatus.eq(input.previous_status.as_str()))
+            .exec(&txn)
+            .await?;
+
+        if result.rows_affected == 0 {
+            txn.rollback().await?;
+            return Ok(None);
+        }
+
+        account_status_changes::ActiveModel {
+            user_id: Set(input.user_id),
+            previous_status: Set(input.previous_status.to_string()),
+            new_status: Set(input.new_status.to_string()),
+            reason: Set(input.reason.trim().to_string()),
+            changed_by: Set(input.changed_by),
+            ..Default::default()
+        }
+        .insert(&txn)
+        .await?;
+
+        let user = users::Entity::find_by_id(input.user_id).one(&txn).await?;
+
+        txn.commit().await?;
+
+        Ok(user)
+    }
+}
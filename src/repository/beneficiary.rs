@@ -0,0 +1,79 @@
+use async_trait::async_trait;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter,
+    QueryOrder, Set,
+};
+
+use crate::{
+    abstract_trait::beneficiary::BeneficiaryRepositoryTrait,
+    domain::request::beneficiary::CreateBeneficiaryRequest, entities::beneficiaries,
+};
+use tracing::instrument;
+
+pub struct BeneficiaryRepository {
+    db_pool: DatabaseConnection,
+}
+
+impl BeneficiaryRepository {
+    pub fn new(db_pool: DatabaseConnection) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl BeneficiaryRepositoryTrait for BeneficiaryRepository {
+    #[instrument(
+        skip_all,
+        fields(db.operation = "SELECT", db.sql.table = "beneficiaries", beneficiary_id = id)
+    )]
+    async fn find_by_id(&self, id: i32) -> Result<Option<beneficiaries::Model>, DbErr> {
+        beneficiaries::Entity::find_by_id(id).one(&self.db_pool).await
+    }
+
+    #[instrument(
+        skip_all,
+        fields(db.operation = "SELECT", db.sql.table = "beneficiaries", user_id = user_id)
+    )]
+    async fn find_by_user(&self, user_id: i32) -> Result<Vec<beneficiaries::Model>, DbErr> {
+        beneficiaries::Entity::find()
+            .filter(beneficiaries::Column::UserId.eq(user_id))
+            .order_by_asc(beneficiaries::Column::BeneficiaryId)
+            .all(&self.db_pool)
+            .await
+    }
+
+    #[instrument(
+        skip_all,
+        fields(db.operation = "INSERT", db.sql.table = "beneficiaries", user_id = input.user_id)
+    )]
+    async fn create(
+        &self,
+        input: &CreateBeneficiaryRequest,
+    ) -> Result<beneficiaries::Model, DbErr> {
+        let new_beneficiary = beneficiaries::ActiveModel {
+            user_id: Set(input.user_id),
+            bank_code: Set(input.bank_code.trim().to_lowercase()),
+            account_number: Set(input.account_number.trim().to_string()),
+            account_name: Set(input.account_name.trim().to_string()),
+            ..Default::default()
+        };
+
+        new_beneficiary.insert(&self.db_pool).await
+    }
+
+    #[instrument(
+        skip_all,
+        fields(db.operation = "DELETE", db.sql.table = "beneficiaries", beneficiary_id = id)
+    )]
+    async fn delete(&self, id: i32) -> Result<(), DbErr> {
+        let result = beneficiaries::Entity::delete_by_id(id)
+            .exec(&self.db_pool)
+            .await?;
+
+        if result.rows_affected > 0 {
+            Ok(())
+        } else {
+            Err(DbErr::RecordNotFound("Beneficiary not found".to_owned()))
+        }
+    }
+}
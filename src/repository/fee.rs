@@ -0,0 +1,148 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use sea_orm::{
+    sea_query::Expr, ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, DbErr,
+    EntityTrait, QueryFilter, QueryOrder, Set, TransactionTrait,
+};
+
+use crate::{
+    abstract_trait::fee::FeeRepositoryTrait,
+    domain::request::fee::{FEE_ENTRY_STATUS_BOOKED, FEE_ENTRY_STATUS_PENDING},
+    entities::{fee_entries, saldo},
+};
+use tracing::instrument;
+
+pub struct FeeRepository {
+    db_pool: DatabaseConnection,
+}
+
+impl FeeRepository {
+    pub fn new(db_pool: DatabaseConnection) -> Self {
+        Self { db_pool }
+    }
+
+    // Adds the amount to the revenue account, opening its saldo on the first fee
+    async fn credit_revenue<C: ConnectionTrait>(
+        conn: &C,
+        revenue_user_id: i32,
+        amount: i32,
+    ) -> Result<(), DbErr> {
+        let credited = saldo::Entity::update_many()
+            .col_expr(
+                saldo::Column::TotalBalance,
+                Expr::col(saldo::Column::TotalBalance).add(amount),
+            )
+            .col_expr(
+                saldo::Column::UpdatedAt,
+                Expr::value(Utc::now().naive_utc()),
+            )
+            .filter(saldo::Column::UserId.eq(revenue_user_id))
+            .exec(conn)
+            .await?;
+
+        if credited.rows_affected == 0 {
+            saldo::ActiveModel {
+                user_id: Set(revenue_user_id),
+                total_balance: Set(amount),
+                ..Default::default()
+            }
+            .insert(conn)
+            .await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl FeeRepositoryTrait for FeeRepository {
+    #[instrument(skip_all, fields(db.operation = "SELECT", db.sql.table = "fee_entries"))]
+    async fn find_by_status(&self, status: &str) -> Result<Vec<fee_entries::Model>, DbErr> {
+        fee_entries::Entity::find()
+            .filter(fee_entries::Column::Status.eq(status))
+            .order_by_asc(fee_entries::Column::FeeEntryId)
+            .all(&self.db_pool)
+            .await
+    }
+
+    #[instrument(
+        skip_all,
+        fields(db.operation = "INSERT", db.sql.table = "fee_entries", amount = amount)
+    )]
+    async fn book(
+        &self,
+        revenue_user_id: i32,
+        amount: i32,
+        fee_rule: Option<String>,
+    ) -> Result<fee_entries::Model, DbErr> {
+        let txn = self.db_pool.begin().await?;
+
+        let entry = fee_entries::ActiveModel {
+            amount: Set(amount),
+            fee_rule: Set(fee_rule),
+            status: Set(FEE_ENTRY_STATUS_BOOKED.to_string()),
+            ..Default::default()
+        }
+        .insert(&txn)
+        .await?;
+
+        Self::credit_revenue(&txn, revenue_user_id, amount).await?;
+
+        txn.commit().await?;
+
+        Ok(entry)
+    }
+
+    #[instrument(
+        skip_all,
+        fields(db.operation = "INSERT", db.sql.table = "fee_entries", amount = amount)
+    )]
+    async fn queue(
+        &self,
+        amount: i32,
+        fee_rule: Option<String>,
+    ) -> Result<fee_entries::Model, DbErr> {
+        fee_entries::ActiveModel {
+            amount: Set(amount),
+            fee_rule: Set(fee_rule),
+            status: Set(FEE_ENTRY_STATUS_PENDING.to_string()),
+            ..Default::default()
+        }
+        .insert(&self.db_pool)
+        .await
+    }
+
+    #[instrument(
+        skip_all,
+        fields(db.operation = "UPDATE", db.sql.table = "fee_entries", fee_entry_id = fee_entry_id)
+    )]
+    async fn book_pending(&self, fee_entry_id: i32, revenue_user_id: i32) -> Result<bool, DbErr> {
+        let txn = self.db_pool.begin().await?;
+
+        // Only the run that moves the entry out of pending credits the revenue account
+        let claimed = fee_entries::Entity::update_many()
+            .col_expr(
+                fee_entries::Column::Status,
+                Expr::value(FEE_ENTRY_STATUS_BOOKED),
+            )
+            .col_expr(
+                fee_entries::Column::UpdatedAt,
+                Expr::value(Utc::now().naive_utc()),
+            )
+            .filter(fee_entries::Column::FeeEntryId.eq(fee_entry_id))
+            .filter(fee_entries::Column::Status.eq(FEE_ENTRY_STATUS_PENDING))
+            .exec_with_returning(&txn)
+            .await?;
+
+        let Some(entry) = claimed.into_iter().next() else {
+            txn.rollback().await?;
+            return Ok(false);
+        };
+
+        Self::credit_revenue(&txn, revenue_user_id, entry.amount).await?;
+
+        txn.commit().await?;
+
+        Ok(true)
+    }
+}
@@ -0,0 +1,291 @@
+use async_trait::async_trait;
+use chrono::{NaiveDateTime, Utc};
+use sea_orm::{
+    sea_query::Expr, ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait,
+    QueryFilter, QueryOrder, QuerySelect, Set, TransactionTrait,
+};
+
+use crate::{
+    abstract_trait::invoice::InvoiceRepositoryTrait,
+    domain::request::invoice::{
+        CreateInvoicePaymentRequest, CreateInvoiceRequest, INVOICE_PAYMENT_STATUS_FAILED,
+        INVOICE_PAYMENT_STATUS_PENDING, INVOICE_STATUS_EXPIRED, INVOICE_STATUS_PARTIALLY_PAID,
+        INVOICE_STATUS_UNPAID,
+    },
+    entities::{invoice_items, invoice_payments, invoices},
+};
+use tracing::instrument;
+
+pub struct InvoiceRepository {
+    db_pool: DatabaseConnection,
+}
+
+impl InvoiceRepository {
+    pub fn new(db_pool: DatabaseConnection) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl InvoiceRepositoryTrait for InvoiceRepository {
+    #[instrument(
+        skip_all,
+        fields(db.operation = "SELECT", db.sql.table = "invoices", invoice_id = id)
+    )]
+    async fn find_by_id(&self, id: i32) -> Result<Option<invoices::Model>, DbErr> {
+        invoices::Entity::find_by_id(id).one(&self.db_pool).await
+    }
+
+    #[instrument(skip_all, fields(db.operation = "SELECT", db.sql.table = "invoices"))]
+    async fn find_by_token(&self, token: &str) -> Result<Option<invoices::Model>, DbErr> {
+        invoices::Entity::find()
+            .filter(invoices::Column::Token.eq(token))
+            .one(&self.db_pool)
+            .await
+    }
+
+    #[instrument(
+        skip_all,
+        fields(db.operation = "SELECT", db.sql.table = "invoices", merchant_id = merchant_id)
+    )]
+    async fn find_by_merchant(&self, merchant_id: i32) -> Result<Vec<invoices::Model>, DbErr> {
+        invoices::Entity::find()
+            .filter(invoices::Column::MerchantId.eq(merchant_id))
+            .order_by_desc(invoices::Column::InvoiceId)
+            .all(&self.db_pool)
+            .await
+    }
+
+    #[instrument(
+        skip_all,
+        fields(db.operation = "SELECT", db.sql.table = "invoice_items", invoice_id = invoice_id)
+    )]
+    async fn find_items(&self, invoice_id: i32) -> Result<Vec<invoice_items::Model>, DbErr> {
+        invoice_items::Entity::find()
+            .filter(invoice_items::Column::InvoiceId.eq(invoice_id))
+            .order_by_asc(invoice_items::Column::ItemId)
+            .all(&self.db_pool)
+            .await
+    }
+
+    #[instrument(
+        skip_all,
+        fields(db.operation = "SELECT", db.sql.table = "invoice_payments", invoice_id = invoice_id)
+    )]
+    async fn find_payments(
+        &self,
+        invoice_id: i32,
+    ) -> Result<Vec<invoice_payments::Model>, DbErr> {
+        invoice_payments::Entity::find()
+            .filter(invoice_payments::Column::InvoiceId.eq(invoice_id))
+            .order_by_asc(invoice_payments::Column::PaymentId)
+            .all(&self.db_pool)
+            .await
+    }
+
+    #[instrument(skip_all, fields(db.operation = "SELECT", db.sql.table = "invoice_payments"))]
+    async fn find_payments_by_status(
+        &self,
+        status: &str,
+    ) -> Result<Vec<invoice_payments::Model>, DbErr> {
+        invoice_payments::Entity::find()
+            .filter(invoice_payments::Column::Status.eq(status))
+            .filter(invoice_payments::Column::TransferId.is_not_null())
+            .order_by_asc(invoice_payments::Column::PaymentId)
+            .all(&self.db_pool)
+            .await
+    }
+
+    #[instrument(
+        skip_all,
+        fields(db.operation = "INSERT", db.sql.table = "invoices", merchant_id = input.merchant_id)
+    )]
+    async fn create(
+        &self,
+        input: &CreateInvoiceRequest,
+        token: &str,
+    ) -> Result<invoices::Model, DbErr> {
+        let txn = self.db_pool.begin().await?;
+
+        let new_invoice = invoices::ActiveModel {
+            merchant_id: Set(input.merchant_id),
+            token: Set(token.to_string()),
+            description: Set(input.description.clone()),
+            total_amount: Set(input.total_amount()),
+            paid_amount: Set(0),
+            status: Set(INVOICE_STATUS_UNPAID.to_string()),
+            due_date: Set(input.due_date.naive_utc()),
+            ..Default::default()
+        }
+        .insert(&txn)
+        .await?;
+
+        for item in &input.items {
+            invoice_items::ActiveModel {
+                invoice_id: Set(new_invoice.invoice_id),
+                description: Set(item.description.clone()),
+                quantity: Set(item.quantity),
+                unit_price: Set(item.unit_price),
+                ..Default::default()
+            }
+            .insert(&txn)
+            .await?;
+        }
+
+        txn.commit().await?;
+
+        Ok(new_invoice)
+    }
+
+    #[instrument(
+        skip_all,
+        fields(
+            db.operation = "INSERT",
+            db.sql.table = "invoice_payments",
+            invoice_id = input.invoice_id,
+            payer_id = input.payer_id,
+        )
+    )]
+    async fn reserve_payment(
+        &self,
+        input: &CreateInvoicePaymentRequest,
+    ) -> Result<Option<invoice_payments::Model>, DbErr> {
+        let txn = self.db_pool.begin().await?;
+        let now = Utc::now().naive_utc();
+
+        // Guarded on the paid amount the payment was priced against, so two concurrent payments
+        // cannot both take the same amount due
+        let reserved = invoices::Entity::update_many()
+            .col_expr(
+                invoices::Column::PaidAmount,
+                Expr::value(input.paid_amount + input.amount),
+            )
+            .col_expr(invoices::Column::Status, Expr::value(input.status.clone()))
+            .col_expr(invoices::Column::UpdatedAt, Expr::value(now))
+            .filter(invoices::Column::InvoiceId.eq(input.invoice_id))
+            .filter(invoices::Column::PaidAmount.eq(input.paid_amount))
+            .filter(
+                invoices::Column::Status
+                    .is_in([INVOICE_STATUS_UNPAID, INVOICE_STATUS_PARTIALLY_PAID]),
+            )
+            .filter(invoices::Column::DueDate.gte(now))
+            .exec(&txn)
+            .await?;
+
+        if reserved.rows_affected == 0 {
+            txn.rollback().await?;
+            return Ok(None);
+        }
+
+        let payment = invoice_payments::ActiveModel {
+            invoice_id: Set(input.invoice_id),
+            payer_id: Set(input.payer_id),
+            transfer_id: Set(None),
+            amount: Set(input.amount),
+            paid_at: Set(now),
+            status: Set(INVOICE_PAYMENT_STATUS_PENDING.to_string()),
+            ..Default::default()
+        }
+        .insert(&txn)
+        .await?;
+
+        txn.commit().await?;
+
+        Ok(Some(payment))
+    }
+
+    #[instrument(
+        skip_all,
+        fields(
+            db.operation = "UPDATE",
+            db.sql.table = "invoice_payments",
+            payment_id = payment_id,
+            transfer_id = transfer_id,
+        )
+    )]
+    async fn complete_payment(
+        &self,
+        payment_id: i32,
+        transfer_id: i32,
+        status: &str,
+    ) -> Result<invoice_payments::Model, DbErr> {
+        let mut payment_record: invoice_payments::ActiveModel =
+            invoice_payments::Entity::find_by_id(payment_id)
+                .one(&self.db_pool)
+                .await?
+                .ok_or(DbErr::RecordNotFound("Invoice payment not found".to_owned()))?
+                .into();
+
+        payment_record.transfer_id = Set(Some(transfer_id));
+        payment_record.status = Set(status.to_string());
+        payment_record.updated_at = Set(Some(Utc::now().naive_utc()));
+
+        payment_record.update(&self.db_pool).await
+    }
+
+    #[instrument(
+        skip_all,
+        fields(db.operation = "UPDATE", db.sql.table = "invoices", payment_id = payment_id)
+    )]
+    async fn release_payment(&self, payment_id: i32) -> Result<(), DbErr> {
+        let txn = self.db_pool.begin().await?;
+
+        let payment = invoice_payments::Entity::find_by_id(payment_id)
+            .one(&txn)
+            .await?
+            .ok_or(DbErr::RecordNotFound("Invoice payment not found".to_owned()))?;
+
+        // Already taken off, for example by an earlier attempt
+        if payment.status == INVOICE_PAYMENT_STATUS_FAILED {
+            txn.rollback().await?;
+            return Ok(());
+        }
+
+        // Locked, since other payments may be moving the paid amount at the same time
+        let invoice = invoices::Entity::find_by_id(payment.invoice_id)
+            .lock_exclusive()
+            .one(&txn)
+            .await?
+            .ok_or(DbErr::RecordNotFound("Invoice not found".to_owned()))?;
+
+        let paid_amount = invoice.paid_amount - payment.amount;
+        let status = if invoice.status == INVOICE_STATUS_EXPIRED {
+            INVOICE_STATUS_EXPIRED
+        } else if paid_amount > 0 {
+            INVOICE_STATUS_PARTIALLY_PAID
+        } else {
+            INVOICE_STATUS_UNPAID
+        };
+
+        let mut invoice_record: invoices::ActiveModel = invoice.into();
+        invoice_record.paid_amount = Set(paid_amount);
+        invoice_record.status = Set(status.to_string());
+        invoice_record.updated_at = Set(Some(Utc::now().naive_utc()));
+        invoice_record.update(&txn).await?;
+
+        let mut payment_record: invoice_payments::ActiveModel = payment.into();
+        payment_record.status = Set(INVOICE_PAYMENT_STATUS_FAILED.to_string());
+        payment_record.updated_at = Set(Some(Utc::now().naive_utc()));
+        payment_record.update(&txn).await?;
+
+        txn.commit().await?;
+
+        Ok(())
+    }
+
+    #[instrument(skip_all, fields(db.operation = "UPDATE", db.sql.table = "invoices"))]
+    async fn expire_overdue(&self, now: NaiveDateTime) -> Result<u64, DbErr> {
+        let result = invoices::Entity::update_many()
+            .col_expr(invoices::Column::Status, Expr::value(INVOICE_STATUS_EXPIRED))
+            .col_expr(invoices::Column::UpdatedAt, Expr::value(now))
+            .filter(
+                invoices::Column::Status
+                    .is_in([INVOICE_STATUS_UNPAID, INVOICE_STATUS_PARTIALLY_PAID]),
+            )
+            .filter(invoices::Column::DueDate.lt(now))
+            .exec(&self.db_pool)
+            .await?;
+
+        Ok(result.rows_affected)
+    }
+}
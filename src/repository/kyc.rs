@@ -0,0 +1,166 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use sea_orm::{
+    sea_query::Expr, ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait,
+    QueryFilter, QueryOrder, Set, TransactionTrait,
+};
+
+use crate::{
+    abstract_trait::kyc::KycRepositoryTrait,
+    domain::request::kyc::{
+        CreateKycDocumentRequest, CreateKycReviewRequest, KYC_DOCUMENT_STATUS_PENDING,
+    },
+    entities::{kyc_documents, kyc_reviews, users},
+};
+use tracing::instrument;
+
+pub struct KycRepository {
+    db_pool: DatabaseConnection,
+}
+
+impl KycRepository {
+    pub fn new(db_pool: DatabaseConnection) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl KycRepositoryTrait for KycRepository {
+    #[instrument(
+        skip_all,
+        fields(db.operation = "SELECT", db.sql.table = "kyc_documents", kyc_document_id = id)
+    )]
+    async fn find_by_id(&self, id: i32) -> Result<Option<kyc_documents::Model>, DbErr> {
+        kyc_documents::Entity::find_by_id(id)
+            .one(&self.db_pool)
+            .await
+    }
+
+    #[instrument(
+        skip_all,
+        fields(db.operation = "SELECT", db.sql.table = "kyc_documents", user_id = user_id)
+    )]
+    async fn find_by_user(&self, user_id: i32) -> Result<Vec<kyc_documents::Model>, DbErr> {
+        kyc_documents::Entity::find()
+            .filter(kyc_documents::Column::UserId.eq(user_id))
+            .order_by_desc(kyc_documents::Column::DocumentId)
+            .all(&self.db_pool)
+            .await
+    }
+
+    #[instrument(skip_all, fields(db.operation = "SELECT", db.sql.table = "kyc_documents"))]
+    async fn find_by_status(&self, status: &str) -> Result<Vec<kyc_documents::Model>, DbErr> {
+        kyc_documents::Entity::find()
+            .filter(kyc_documents::Column::Status.eq(status))
+            .order_by_asc(kyc_documents::Column::DocumentId)
+            .all(&self.db_pool)
+            .await
+    }
+
+    #[instrument(
+        skip_all,
+        fields(db.operation = "SELECT", db.sql.table = "kyc_reviews", user_id = user_id)
+    )]
+    async fn find_reviews_by_user(&self, user_id: i32) -> Result<Vec<kyc_reviews::Model>, DbErr> {
+        kyc_reviews::Entity::find()
+            .filter(kyc_reviews::Column::UserId.eq(user_id))
+            .order_by_desc(kyc_reviews::Column::ReviewId)
+            .all(&self.db_pool)
+            .await
+    }
+
+    #[instrument(
+        skip_all,
+        fields(db.operation = "INSERT", db.sql.table = "kyc_documents", user_id = input.user_id)
+    )]
+    async fn create(
+        &self,
+        input: &CreateKycDocumentRequest,
+        storage_key: &str,
+    ) -> Result<kyc_documents::Model, DbErr> {
+        let new_document = kyc_documents::ActiveModel {
+            user_id: Set(input.user_id),
+            document_type: Set(input.document_type.clone()),
+            requested_tier: Set(input.requested_tier.to_string()),
+            file_name: Set(input.file_name.trim().to_string()),
+            content_type: Set(input.content_type.clone()),
+            file_size: Set(input.content.len() as i32),
+            storage_key: Set(storage_key.to_string()),
+            status: Set(KYC_DOCUMENT_STATUS_PENDING.to_string()),
+            ..Default::default()
+        };
+
+        new_document.insert(&self.db_pool).await
+    }
+
+    #[instrument(
+        skip_all,
+        fields(
+            db.operation = "UPDATE",
+            db.sql.table = "kyc_documents",
+            document_id = input.document_id,
+            user_id = input.user_id,
+            reviewer_id = input.reviewer_id,
+        )
+    )]
+    async fn review(
+        &self,
+        input: &CreateKycReviewRequest,
+    ) -> Result<Option<kyc_documents::Model>, DbErr> {
+        let now = Utc::now().naive_utc();
+        let txn = self.db_pool.begin().await?;
+
+        // Guarding on the pending status lets only one operator decide a document
+        let result = kyc_documents::Entity::update_many()
+            .col_expr(kyc_documents::Column::Status, Expr::value(&input.decision))
+            .col_expr(
+                kyc_documents::Column::RejectionReason,
+                Expr::value(input.reason.clone()),
+            )
+            .col_expr(
+                kyc_documents::Column::ReviewedBy,
+                Expr::value(input.reviewer_id),
+            )
+            .col_expr(kyc_documents::Column::ReviewedAt, Expr::value(now))
+            .col_expr(kyc_documents::Column::UpdatedAt, Expr::value(now))
+            .filter(kyc_documents::Column::DocumentId.eq(input.document_id))
+            .filter(kyc_documents::Column::Status.eq(KYC_DOCUMENT_STATUS_PENDING))
+            .exec(&txn)
+            .await?;
+
+        if result.rows_affected == 0 {
+            txn.rollback().await?;
+            return Ok(None);
+        }
+
+        kyc_reviews::ActiveModel {
+            document_id: Set(input.document_id),
+            user_id: Set(input.user_id),
+            reviewer_id: Set(input.reviewer_id),
+            decision: Set(input.decision.clone()),
+            reason: Set(input.reason.clone()),
+            previous_tier: Set(input.previous_tier.to_string()),
+            new_tier: Set(input.new_tier.to_string()),
+            ..Default::default()
+        }
+        .insert(&txn)
+        .await?;
+
+        if input.new_tier != input.previous_tier {
+            users::Entity::update_many()
+                .col_expr(users::Column::KycTier, Expr::value(input.new_tier.as_str()))
+                .col_expr(users::Column::UpdatedAt, Expr::value(now))
+                .filter(users::Column::UserId.eq(input.user_id))
+                .exec(&txn)
+                .await?;
+        }
+
+        let document = kyc_documents::Entity::find_by_id(input.document_id)
+            .one(&txn)
+            .await?;
+
+        txn.commit().await?;
+
+        Ok(document)
+    }
+}
@@ -2,4 +2,17 @@ pub mod user;
 pub mod transfer;
 pub mod saldo;
 pub mod topup;
-pub mod withdraw;
\ No newline at end of file
+pub mod withdraw;
+pub mod virtual_account;
+pub mod invoice;
+pub mod subscription;
+pub mod scheduled_transfer;
+pub mod account_number;
+pub mod beneficiary;
+pub mod payout_batch;
+pub mod kyc;
+pub mod risk;
+pub mod screening;
+pub mod account_status;
+pub mod fee;
+pub mod pagination;
@@ -0,0 +1,130 @@
+use chrono::{Days, NaiveTime};
+use sea_orm::{
+    ColumnTrait, DatabaseConnection, DbErr, EntityTrait, ItemsAndPagesNumber, Order,
+    PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, Select,
+};
+
+use crate::domain::{
+    request::pagination::{ListFields, ListRequest, SortDirection},
+    response::pagination::Pagination,
+};
+
+// Both bounds are inclusive dates, so `to` runs until the start of the following day
+pub fn filter_date_range<E: EntityTrait>(
+    mut query: Select<E>,
+    column: E::Column,
+    input: &ListRequest,
+) -> Select<E> {
+    if let Some(from) = input.from {
+        query = query.filter(column.gte(from.and_time(NaiveTime::MIN)));
+    }
+
+    if let Some(next_day) = input.to.and_then(|to| to.checked_add_days(Days::new(1))) {
+        query = query.filter(column.lt(next_day.and_time(NaiveTime::MIN)));
+    }
+
+    query
+}
+
+pub fn filter_amount_range<E: EntityTrait>(
+    mut query: Select<E>,
+    column: E::Column,
+    input: &ListRequest,
+) -> Select<E> {
+    if let Some(min_amount) = input.min_amount {
+        query = query.filter(column.gte(min_amount));
+    }
+
+    if let Some(max_amount) = input.max_amount {
+        query = query.filter(column.lte(max_amount));
+    }
+
+    query
+}
+
+/// Runs a filtered list query as one page.
+///
+/// Offset pages go through SeaORM's `Paginator` and report totals. A cursor page is a
+/// keyset query on the id column, so it stays cheap however deep the client scrolls.
+/// `next_cursor` is returned whenever the rows are in id order and more rows follow.
+pub async fn paginate<E, F, I>(
+    db: &DatabaseConnection,
+    query: Select<E>,
+    input: &ListRequest,
+    fields: &ListFields,
+    column_of: F,
+    id_of: I,
+) -> Result<(Vec<E::Model>, Pagination), DbErr>
+where
+    E: EntityTrait,
+    E::Model: Sync,
+    F: Fn(&str) -> E::Column,
+    I: Fn(&E::Model) -> i32,
+{
+    let page_size = input.page_size();
+    let id_column = column_of(fields.id_field);
+    let sort_field = input.sort_field(fields);
+    let order = match input.sort_dir() {
+        SortDirection::Asc => Order::Asc,
+        SortDirection::Desc => Order::Desc,
+    };
+
+    if let Some(cursor) = input.cursor_id().map_err(DbErr::Custom)? {
+        let query = match order {
+            Order::Asc => query.filter(id_column.gt(cursor)),
+            _ => query.filter(id_column.lt(cursor)),
+        };
+
+        let mut rows = query
+            .order_by(id_column, order)
+            .limit(page_size + 1)
+            .all(db)
+            .await?;
+
+        let next_cursor = if rows.len() as u64 > page_size {
+            rows.truncate(page_size as usize);
+            rows.last().map(|row| id_of(row).to_string())
+        } else {
+            None
+        };
+
+        return Ok((
+            rows,
+            Pagination {
+                page_size,
+                next_cursor,
+                ..Default::default()
+            },
+        ));
+    }
+
+    let page = input.page();
+    let paginator = query
+        .order_by(column_of(sort_field), order.clone())
+        .order_by(id_column, order)
+        .paginate(db, page_size);
+
+    let ItemsAndPagesNumber {
+        number_of_items,
+        number_of_pages,
+    } = paginator.num_items_and_pages().await?;
+
+    let rows = paginator.fetch_page(page - 1).await?;
+
+    let next_cursor = if sort_field == fields.id_field && page < number_of_pages {
+        rows.last().map(|row| id_of(row).to_string())
+    } else {
+        None
+    };
+
+    Ok((
+        rows,
+        Pagination {
+            page: Some(page),
+            page_size,
+            total_items: Some(number_of_items),
+            total_pages: Some(number_of_pages),
+            next_cursor,
+        },
+    ))
+}
@@ -0,0 +1,278 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use sea_orm::{
+    sea_query::{Expr, Query},
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter, QueryOrder,
+    Set, TransactionTrait,
+};
+
+use crate::{
+    abstract_trait::payout_batch::PayoutBatchRepositoryTrait,
+    domain::request::payout_batch::{
+        CreatePayoutBatchItemRequest, UpdatePayoutBatchItemRequest, PAYOUT_BATCH_STATUS_COMPLETED,
+        PAYOUT_BATCH_STATUS_FAILED, PAYOUT_BATCH_STATUS_PARTIALLY_FAILED,
+        PAYOUT_BATCH_STATUS_PENDING, PAYOUT_ITEM_STATUS_PENDING, PAYOUT_ITEM_STATUS_PROCESSING,
+    },
+    entities::{payout_batch_items, payout_batches, saldo, withdraws},
+};
+use tracing::instrument;
+
+pub struct PayoutBatchRepository {
+    db_pool: DatabaseConnection,
+}
+
+impl PayoutBatchRepository {
+    pub fn new(db_pool: DatabaseConnection) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl PayoutBatchRepositoryTrait for PayoutBatchRepository {
+    #[instrument(
+        skip_all,
+        fields(db.operation = "SELECT", db.sql.table = "payout_batches", payout_batch_id = id)
+    )]
+    async fn find_by_id(&self, id: i32) -> Result<Option<payout_batches::Model>, DbErr> {
+        payout_batches::Entity::find_by_id(id)
+            .one(&self.db_pool)
+            .await
+    }
+
+    #[instrument(
+        skip_all,
+        fields(db.operation = "SELECT", db.sql.table = "payout_batches", user_id = user_id)
+    )]
+    async fn find_by_user(&self, user_id: i32) -> Result<Vec<payout_batches::Model>, DbErr> {
+        payout_batches::Entity::find()
+            .filter(payout_batches::Column::UserId.eq(user_id))
+            .order_by_desc(payout_batches::Column::BatchId)
+            .all(&self.db_pool)
+            .await
+    }
+
+    #[instrument(skip_all, fields(db.operation = "SELECT", db.sql.table = "payout_batches"))]
+    async fn find_by_status(&self, status: &str) -> Result<Vec<payout_batches::Model>, DbErr> {
+        payout_batches::Entity::find()
+            .filter(payout_batches::Column::Status.eq(status))
+            .order_by_asc(payout_batches::Column::BatchId)
+            .all(&self.db_pool)
+            .await
+    }
+
+    #[instrument(
+        skip_all,
+        fields(db.operation = "SELECT", db.sql.table = "payout_batch_items", batch_id = batch_id)
+    )]
+    async fn find_items(&self, batch_id: i32) -> Result<Vec<payout_batch_items::Model>, DbErr> {
+        payout_batch_items::Entity::find()
+            .filter(payout_batch_items::Column::BatchId.eq(batch_id))
+            .order_by_asc(payout_batch_items::Column::LineNumber)
+            .all(&self.db_pool)
+            .await
+    }
+
+    #[instrument(
+        skip_all,
+        fields(db.operation = "SELECT", db.sql.table = "payout_batch_items", batch_id = batch_id)
+    )]
+    async fn find_items_by_status(
+        &self,
+        batch_id: i32,
+        status: &str,
+    ) -> Result<Vec<payout_batch_items::Model>, DbErr> {
+        payout_batch_items::Entity::find()
+            .filter(payout_batch_items::Column::BatchId.eq(batch_id))
+            .filter(payout_batch_items::Column::Status.eq(status))
+            .order_by_asc(payout_batch_items::Column::LineNumber)
+            .all(&self.db_pool)
+            .await
+    }
+
+    #[instrument(
+        skip_all,
+        fields(db.operation = "SELECT", db.sql.table = "withdraws", batch_id = batch_id)
+    )]
+    async fn find_withdraws(&self, batch_id: i32) -> Result<Vec<withdraws::Model>, DbErr> {
+        withdraws::Entity::find()
+            .filter(
+                withdraws::Column::WithdrawId.in_subquery(
+                    Query::select()
+                        .column(payout_batch_items::Column::WithdrawId)
+                        .from(payout_batch_items::Entity)
+                        .and_where(payout_batch_items::Column::BatchId.eq(batch_id))
+                        .to_owned(),
+                ),
+            )
+            .all(&self.db_pool)
+            .await
+    }
+
+    #[instrument(
+        skip_all,
+        fields(db.operation = "INSERT", db.sql.table = "payout_batches", user_id = user_id)
+    )]
+    async fn create(
+        &self,
+        user_id: i32,
+        total_amount: i32,
+        reserved_amount: i32,
+        items: &[CreatePayoutBatchItemRequest],
+    ) -> Result<payout_batches::Model, DbErr> {
+        let txn = self.db_pool.begin().await?;
+
+        let new_batch = payout_batches::ActiveModel {
+            user_id: Set(user_id),
+            status: Set(PAYOUT_BATCH_STATUS_PENDING.to_string()),
+            item_count: Set(items.len() as i32),
+            total_amount: Set(total_amount),
+            reserved_amount: Set(reserved_amount),
+            ..Default::default()
+        }
+        .insert(&txn)
+        .await?;
+
+        let new_items = items.iter().map(|item| payout_batch_items::ActiveModel {
+            batch_id: Set(new_batch.batch_id),
+            line_number: Set(item.line_number),
+            recipient: Set(item.recipient.clone()),
+            recipient_user_id: Set(item.recipient_user_id),
+            bank_code: Set(item.bank_code.clone()),
+            account_number: Set(item.account_number.clone()),
+            account_name: Set(item.account_name.clone()),
+            amount: Set(item.amount),
+            reference: Set(item.reference.clone()),
+            status: Set(item.status.clone()),
+            failure_reason: Set(item.failure_reason.clone()),
+            ..Default::default()
+        });
+
+        payout_batch_items::Entity::insert_many(new_items)
+            .exec(&txn)
+            .await?;
+
+        txn.commit().await?;
+
+        Ok(new_batch)
+    }
+
+    #[instrument(
+        skip_all,
+        fields(db.operation = "UPDATE", db.sql.table = "payout_batches", batch_id = batch_id)
+    )]
+    async fn update_status(
+        &self,
+        batch_id: i32,
+        from_status: &str,
+        status: &str,
+    ) -> Result<bool, DbErr> {
+        let now = Utc::now().naive_utc();
+
+        let mut update = payout_batches::Entity::update_many()
+            .col_expr(payout_batches::Column::Status, Expr::value(status))
+            .col_expr(payout_batches::Column::UpdatedAt, Expr::value(now));
+
+        if [
+            PAYOUT_BATCH_STATUS_COMPLETED,
+            PAYOUT_BATCH_STATUS_PARTIALLY_FAILED,
+            PAYOUT_BATCH_STATUS_FAILED,
+        ]
+        .contains(&status)
+        {
+            update = update.col_expr(payout_batches::Column::CompletedAt, Expr::value(now));
+        }
+
+        // Guarding on the current status lets only one worker claim a batch
+        let result = update
+            .filter(payout_batches::Column::BatchId.eq(batch_id))
+            .filter(payout_batches::Column::Status.eq(from_status))
+            .exec(&self.db_pool)
+            .await?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    #[instrument(
+        skip_all,
+        fields(
+            db.operation = "UPDATE",
+            db.sql.table = "payout_batch_items",
+            item_id = input.item_id,
+        )
+    )]
+    async fn update_item(
+        &self,
+        input: &UpdatePayoutBatchItemRequest,
+    ) -> Result<payout_batch_items::Model, DbErr> {
+        let now = Utc::now().naive_utc();
+
+        payout_batch_items::ActiveModel {
+            item_id: Set(input.item_id),
+            status: Set(input.status.clone()),
+            failure_reason: Set(input.failure_reason.clone()),
+            transfer_id: Set(input.transfer_id),
+            withdraw_id: Set(input.withdraw_id),
+            processed_at: Set(Some(now)),
+            updated_at: Set(Some(now)),
+            ..Default::default()
+        }
+        .update(&self.db_pool)
+        .await
+    }
+
+    #[instrument(
+        skip_all,
+        fields(
+            db.operation = "UPDATE",
+            db.sql.table = "payout_batch_items",
+            item_id = item_id,
+        )
+    )]
+    async fn claim_item(
+        &self,
+        item_id: i32,
+        user_id: i32,
+    ) -> Result<Option<payout_batch_items::Model>, DbErr> {
+        let txn = self.db_pool.begin().await?;
+        let now = Utc::now().naive_utc();
+
+        // Only the run that moves the item out of pending releases its share of the reservation
+        let claimed = payout_batch_items::Entity::update_many()
+            .col_expr(
+                payout_batch_items::Column::Status,
+                Expr::value(PAYOUT_ITEM_STATUS_PROCESSING),
+            )
+            .col_expr(payout_batch_items::Column::UpdatedAt, Expr::value(now))
+            .filter(payout_batch_items::Column::ItemId.eq(item_id))
+            .filter(payout_batch_items::Column::Status.eq(PAYOUT_ITEM_STATUS_PENDING))
+            .exec_with_returning(&txn)
+            .await?;
+
+        let Some(item) = claimed.into_iter().next() else {
+            txn.rollback().await?;
+            return Ok(None);
+        };
+
+        let released = saldo::Entity::update_many()
+            .col_expr(
+                saldo::Column::TotalBalance,
+                Expr::col(saldo::Column::TotalBalance).add(item.amount),
+            )
+            .col_expr(saldo::Column::UpdatedAt, Expr::value(now))
+            .filter(saldo::Column::UserId.eq(user_id))
+            .exec(&txn)
+            .await?;
+
+        if released.rows_affected == 0 {
+            txn.rollback().await?;
+            return Err(DbErr::RecordNotFound(format!(
+                "Saldo for user {} not found",
+                user_id
+            )));
+        }
+
+        txn.commit().await?;
+
+        Ok(Some(item))
+    }
+}
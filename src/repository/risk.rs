@@ -0,0 +1,188 @@
+use async_trait::async_trait;
+use chrono::{NaiveDateTime, Utc};
+use sea_orm::{
+    sea_query::Expr, ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait,
+    PaginatorTrait, QueryFilter, QueryOrder, Set,
+};
+
+use crate::{
+    abstract_trait::risk::RiskRepositoryTrait,
+    domain::request::risk::{
+        CreateRiskDecisionRequest, ResolveRiskReviewRequest, RISK_REVIEW_STATUS_PENDING,
+    },
+    entities::risk_decisions,
+    utils::risk::{RiskAction, RiskOperation},
+};
+use tracing::instrument;
+
+pub struct RiskRepository {
+    db_pool: DatabaseConnection,
+}
+
+impl RiskRepository {
+    pub fn new(db_pool: DatabaseConnection) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl RiskRepositoryTrait for RiskRepository {
+    #[instrument(
+        skip_all,
+        fields(db.operation = "SELECT", db.sql.table = "risk_decisions", user_id = user_id)
+    )]
+    async fn find_by_user(&self, user_id: i32) -> Result<Vec<risk_decisions::Model>, DbErr> {
+        risk_decisions::Entity::find()
+            .filter(risk_decisions::Column::UserId.eq(user_id))
+            .order_by_desc(risk_decisions::Column::DecisionId)
+            .all(&self.db_pool)
+            .await
+    }
+
+    #[instrument(skip_all, fields(db.operation = "SELECT", db.sql.table = "risk_decisions"))]
+    async fn find_by_review_status(
+        &self,
+        status: &str,
+    ) -> Result<Vec<risk_decisions::Model>, DbErr> {
+        risk_decisions::Entity::find()
+            .filter(risk_decisions::Column::ReviewStatus.eq(status))
+            .order_by_asc(risk_decisions::Column::DecisionId)
+            .all(&self.db_pool)
+            .await
+    }
+
+    #[instrument(
+        skip_all,
+        fields(
+            db.operation = "SELECT",
+            db.sql.table = "risk_decisions",
+            reference_id = reference_id,
+        )
+    )]
+    async fn find_by_reference(
+        &self,
+        operation: RiskOperation,
+        reference_id: i32,
+    ) -> Result<Option<risk_decisions::Model>, DbErr> {
+        risk_decisions::Entity::find()
+            .filter(risk_decisions::Column::Operation.eq(operation.as_str()))
+            .filter(risk_decisions::Column::ReferenceId.eq(reference_id))
+            .one(&self.db_pool)
+            .await
+    }
+
+    #[instrument(
+        skip_all,
+        fields(db.operation = "SELECT", db.sql.table = "risk_decisions", user_id = user_id)
+    )]
+    async fn count_since(
+        &self,
+        user_id: i32,
+        operation: RiskOperation,
+        since: NaiveDateTime,
+    ) -> Result<u64, DbErr> {
+        risk_decisions::Entity::find()
+            .filter(risk_decisions::Column::UserId.eq(user_id))
+            .filter(risk_decisions::Column::Operation.eq(operation.as_str()))
+            .filter(risk_decisions::Column::CreatedAt.gte(since))
+            .filter(risk_decisions::Column::ReferenceId.is_not_null())
+            .count(&self.db_pool)
+            .await
+    }
+
+    #[instrument(
+        skip_all,
+        fields(db.operation = "INSERT", db.sql.table = "risk_decisions", user_id = input.user_id)
+    )]
+    async fn create(
+        &self,
+        input: &CreateRiskDecisionRequest,
+    ) -> Result<risk_decisions::Model, DbErr> {
+        let triggered_rules = if input.triggered_rules.is_empty() {
+            None
+        } else {
+            Some(input.triggered_rules.join(","))
+        };
+
+        // Only held operations enter the review queue
+        let review_status = if input.action == RiskAction::Review {
+            Some(RISK_REVIEW_STATUS_PENDING.to_string())
+        } else {
+            None
+        };
+
+        let new_decision = risk_decisions::ActiveModel {
+            user_id: Set(input.user_id),
+            operation: Set(input.operation.to_string()),
+            amount: Set(input.amount),
+            counterparty_user_id: Set(input.counterparty_user_id),
+            action: Set(input.action.to_string()),
+            triggered_rules: Set(triggered_rules),
+            review_status: Set(review_status),
+            ..Default::default()
+        };
+
+        new_decision.insert(&self.db_pool).await
+    }
+
+    #[instrument(
+        skip_all,
+        fields(
+            db.operation = "UPDATE",
+            db.sql.table = "risk_decisions",
+            decision_id = decision_id,
+            reference_id = reference_id,
+        )
+    )]
+    async fn set_reference(&self, decision_id: i32, reference_id: i32) -> Result<(), DbErr> {
+        risk_decisions::Entity::update_many()
+            .col_expr(
+                risk_decisions::Column::ReferenceId,
+                Expr::value(reference_id),
+            )
+            .filter(risk_decisions::Column::DecisionId.eq(decision_id))
+            .exec(&self.db_pool)
+            .await
+            .map(|_| ())
+    }
+
+    #[instrument(
+        skip_all,
+        fields(
+            db.operation = "UPDATE",
+            db.sql.table = "risk_decisions",
+            decision_id = decision_id,
+            reference_id = input.reference_id,
+            reviewer_id = input.reviewer_id,
+        )
+    )]
+    async fn resolve(
+        &self,
+        decision_id: i32,
+        input: &ResolveRiskReviewRequest,
+    ) -> Result<bool, DbErr> {
+        let result = risk_decisions::Entity::update_many()
+            .col_expr(
+                risk_decisions::Column::ReviewStatus,
+                Expr::value(input.decision.clone()),
+            )
+            .col_expr(
+                risk_decisions::Column::ReviewedBy,
+                Expr::value(input.reviewer_id),
+            )
+            .col_expr(
+                risk_decisions::Column::ReviewReason,
+                Expr::value(input.reason.clone()),
+            )
+            .col_expr(
+                risk_decisions::Column::ReviewedAt,
+                Expr::value(Utc::now().naive_utc()),
+            )
+            .filter(risk_decisions::Column::DecisionId.eq(decision_id))
+            .filter(risk_decisions::Column::ReviewStatus.eq(RISK_REVIEW_STATUS_PENDING))
+            .exec(&self.db_pool)
+            .await?;
+
+        Ok(result.rows_affected > 0)
+    }
+}
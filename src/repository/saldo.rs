@@ -1,17 +1,25 @@
 use async_trait::async_trait;
 use chrono::Utc;
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, ModelTrait, QueryFilter,
-    Set,
+    sea_query::Expr, ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait,
+    ModelTrait, QueryFilter, Set,
 };
 
 use crate::{
     abstract_trait::saldo::SaldoRepositoryTrait,
-    domain::request::saldo::{
-        CreateSaldoRequest, UpdateSaldoBalance, UpdateSaldoRequest, UpdateSaldoWithdraw,
+    domain::{
+        request::{
+            pagination::ListRequest,
+            saldo::{
+                CreateSaldoRequest, UpdateSaldoBalance, UpdateSaldoRequest, SALDO_LIST_FIELDS,
+            },
+        },
+        response::pagination::Pagination,
     },
     entities::saldo,
+    repository::pagination::{filter_amount_range, filter_date_range, paginate},
 };
+use tracing::instrument;
 
 pub struct SaldoRepository {
     db_pool: DatabaseConnection,
@@ -21,14 +29,46 @@ impl SaldoRepository {
     pub fn new(db_pool: DatabaseConnection) -> Self {
         Self { db_pool }
     }
+
+    fn sort_column(field: &str) -> saldo::Column {
+        match field {
+            "total_balance" => saldo::Column::TotalBalance,
+            "created_at" => saldo::Column::CreatedAt,
+            "updated_at" => saldo::Column::UpdatedAt,
+            _ => saldo::Column::SaldoId,
+        }
+    }
 }
 
 #[async_trait]
 impl SaldoRepositoryTrait for SaldoRepository {
-    async fn find_all(&self) -> Result<Vec<saldo::Model>, DbErr> {
-        saldo::Entity::find().all(&self.db_pool).await
+    #[instrument(skip_all, fields(db.operation = "SELECT", db.sql.table = "saldo"))]
+    async fn find_paginated(
+        &self,
+        input: &ListRequest,
+    ) -> Result<(Vec<saldo::Model>, Pagination), DbErr> {
+        let mut query = saldo::Entity::find();
+
+        if let Some(user_id) = input.user_id {
+            query = query.filter(saldo::Column::UserId.eq(user_id));
+        }
+
+        // Saldos are filtered by when they were opened and by their current balance
+        let query = filter_date_range(query, saldo::Column::CreatedAt, input);
+        let query = filter_amount_range(query, saldo::Column::TotalBalance, input);
+
+        paginate(
+            &self.db_pool,
+            query,
+            input,
+            &SALDO_LIST_FIELDS,
+            Self::sort_column,
+            |saldo| saldo.saldo_id,
+        )
+        .await
     }
 
+    #[instrument(skip_all, fields(db.operation = "SELECT", db.sql.table = "saldo", saldo_id = id))]
     async fn find_by_id(&self, id: i32) -> Result<Option<saldo::Model>, DbErr> {
         saldo::Entity::find()
             .filter(saldo::Column::SaldoId.eq(id))
@@ -36,6 +76,7 @@ impl SaldoRepositoryTrait for SaldoRepository {
             .await
     }
 
+    #[instrument(skip_all, fields(db.operation = "SELECT", db.sql.table = "saldo", user_id = id))]
     async fn find_by_user_id(&self, id: i32) -> Result<Option<saldo::Model>, DbErr> {
         saldo::Entity::find()
             .filter(saldo::Column::UserId.eq(id))
@@ -43,6 +84,7 @@ impl SaldoRepositoryTrait for SaldoRepository {
             .await
     }
 
+    #[instrument(skip_all, fields(db.operation = "SELECT", db.sql.table = "saldo", user_id = id))]
     async fn find_by_users_id(&self, id: i32) -> Result<Vec<Option<saldo::Model>>, DbErr> {
         saldo::Entity::find()
             .filter(saldo::Column::UserId.eq(id))
@@ -51,6 +93,10 @@ impl SaldoRepositoryTrait for SaldoRepository {
             .map(|res| res.into_iter().map(Some).collect())
     }
 
+    #[instrument(
+        skip_all,
+        fields(db.operation = "INSERT", db.sql.table = "saldo", user_id = input.user_id)
+    )]
     async fn create(&self, input: &CreateSaldoRequest) -> Result<saldo::Model, DbErr> {
         let new_saldo = saldo::ActiveModel {
             user_id: Set(input.user_id),
@@ -60,6 +106,15 @@ impl SaldoRepositoryTrait for SaldoRepository {
         new_saldo.insert(&self.db_pool).await
     }
 
+    #[instrument(
+        skip_all,
+        fields(
+            db.operation = "UPDATE",
+            db.sql.table = "saldo",
+            saldo_id = input.saldo_id,
+            user_id = input.user_id,
+        )
+    )]
     async fn update(&self, input: &UpdateSaldoRequest) -> Result<saldo::Model, DbErr> {
         let mut saldo_record: saldo::ActiveModel = saldo::Entity::find_by_id(input.saldo_id)
             .one(&self.db_pool)
@@ -87,6 +142,10 @@ impl SaldoRepositoryTrait for SaldoRepository {
         saldo_record.update(&self.db_pool).await
     }
 
+    #[instrument(
+        skip_all,
+        fields(db.operation = "UPDATE", db.sql.table = "saldo", user_id = input.user_id)
+    )]
     async fn update_balance(&self, input: &UpdateSaldoBalance) -> Result<saldo::Model, DbErr> {
         let mut saldo_record: saldo::ActiveModel = saldo::Entity::find()
             .filter(saldo::Column::UserId.eq(input.user_id))
@@ -100,32 +159,52 @@ impl SaldoRepositoryTrait for SaldoRepository {
         saldo_record.update(&self.db_pool).await
     }
 
-    async fn update_saldo_withdraw(
-        &self,
-        input: &UpdateSaldoWithdraw,
-    ) -> Result<saldo::Model, DbErr> {
-        let mut saldo_record: saldo::ActiveModel = saldo::Entity::find()
-            .filter(saldo::Column::UserId.eq(input.user_id))
-            .one(&self.db_pool)
-            .await?
-            .ok_or(DbErr::RecordNotFound("Saldo not found".to_owned()))?
-            .into();
-
-        if let Some(withdraw_amount) = input.withdraw_amount {
-            let current_balance = saldo_record.total_balance.take().unwrap_or(0);
-
-            if current_balance < withdraw_amount {
-                return Err(DbErr::Custom("Insufficient balance".to_string()));
-            }
-
-            saldo_record.total_balance = Set(current_balance - withdraw_amount);
-            saldo_record.withdraw_amount = Set(Some(withdraw_amount));
-            saldo_record.withdraw_time = Set(input.withdraw_time.clone());
-        }
+    #[instrument(
+        skip_all,
+        fields(db.operation = "UPDATE", db.sql.table = "saldo", user_id = user_id)
+    )]
+    async fn debit(&self, user_id: i32, amount: i32) -> Result<bool, DbErr> {
+        // The balance guard and the subtraction run as one statement, so concurrent
+        // debits cannot both pass the check
+        let result = saldo::Entity::update_many()
+            .col_expr(
+                saldo::Column::TotalBalance,
+                Expr::col(saldo::Column::TotalBalance).sub(amount),
+            )
+            .col_expr(
+                saldo::Column::UpdatedAt,
+                Expr::value(Utc::now().naive_utc()),
+            )
+            .filter(saldo::Column::UserId.eq(user_id))
+            .filter(saldo::Column::TotalBalance.gte(amount))
+            .exec(&self.db_pool)
+            .await?;
+
+        Ok(result.rows_affected > 0)
+    }
 
-        saldo_record.update(&self.db_pool).await
+    #[instrument(
+        skip_all,
+        fields(db.operation = "UPDATE", db.sql.table = "saldo", user_id = user_id)
+    )]
+    async fn credit(&self, user_id: i32, amount: i32) -> Result<bool, DbErr> {
+        let result = saldo::Entity::update_many()
+            .col_expr(
+                saldo::Column::TotalBalance,
+                Expr::col(saldo::Column::TotalBalance).add(amount),
+            )
+            .col_expr(
+                saldo::Column::UpdatedAt,
+                Expr::value(Utc::now().naive_utc()),
+            )
+            .filter(saldo::Column::UserId.eq(user_id))
+            .exec(&self.db_pool)
+            .await?;
+
+        Ok(result.rows_affected > 0)
     }
 
+    #[instrument(skip_all, fields(db.operation = "DELETE", db.sql.table = "saldo", saldo_id = id))]
     async fn delete(&self, id: i32) -> Result<(), DbErr> {
         let saldo_record = saldo::Entity::find()
             .filter(saldo::Column::UserId.eq(id))
@@ -0,0 +1,313 @@
+use async_trait::async_trait;
+use chrono::{NaiveDateTime, Utc};
+use sea_orm::{
+    sea_query::Expr, ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait,
+    QueryFilter, QueryOrder, Set,
+};
+
+use crate::{
+    abstract_trait::scheduled_transfer::ScheduledTransferRepositoryTrait,
+    domain::request::scheduled_transfer::{
+        CreateScheduledTransferExecutionRequest, CreateScheduledTransferRequest,
+        UpdateScheduledTransferRequest, UpdateScheduledTransferRunRequest,
+        EXECUTION_STATUS_PENDING, SCHEDULED_TRANSFER_STATUS_ACTIVE,
+        SCHEDULED_TRANSFER_STATUS_CANCELLED, SCHEDULED_TRANSFER_STATUS_PROCESSING,
+    },
+    entities::{scheduled_transfer_executions, scheduled_transfers},
+};
+use tracing::instrument;
+
+pub struct ScheduledTransferRepository {
+    db_pool: DatabaseConnection,
+}
+
+impl ScheduledTransferRepository {
+    pub fn new(db_pool: DatabaseConnection) -> Self {
+        Self { db_pool }
+    }
+
+    #[instrument(
+        skip_all,
+        fields(
+            db.operation = "SELECT",
+            db.sql.table = "scheduled_transfers",
+            scheduled_transfer_id = id,
+        )
+    )]
+    async fn find_active_model(&self, id: i32) -> Result<scheduled_transfers::ActiveModel, DbErr> {
+        Ok(scheduled_transfers::Entity::find_by_id(id)
+            .one(&self.db_pool)
+            .await?
+            .ok_or(DbErr::RecordNotFound("Scheduled transfer not found".to_owned()))?
+            .into())
+    }
+}
+
+#[async_trait]
+impl ScheduledTransferRepositoryTrait for ScheduledTransferRepository {
+    #[instrument(
+        skip_all,
+        fields(
+            db.operation = "SELECT",
+            db.sql.table = "scheduled_transfers",
+            scheduled_transfer_id = id,
+        )
+    )]
+    async fn find_by_id(&self, id: i32) -> Result<Option<scheduled_transfers::Model>, DbErr> {
+        scheduled_transfers::Entity::find_by_id(id)
+            .one(&self.db_pool)
+            .await
+    }
+
+    #[instrument(
+        skip_all,
+        fields(db.operation = "SELECT", db.sql.table = "scheduled_transfers", user_id = user_id)
+    )]
+    async fn find_by_user(&self, user_id: i32) -> Result<Vec<scheduled_transfers::Model>, DbErr> {
+        scheduled_transfers::Entity::find()
+            .filter(scheduled_transfers::Column::TransferFrom.eq(user_id))
+            .order_by_asc(scheduled_transfers::Column::NextRunAt)
+            .all(&self.db_pool)
+            .await
+    }
+
+    #[instrument(skip_all, fields(db.operation = "SELECT", db.sql.table = "scheduled_transfers"))]
+    async fn find_due(&self, now: NaiveDateTime) -> Result<Vec<scheduled_transfers::Model>, DbErr> {
+        scheduled_transfers::Entity::find()
+            .filter(scheduled_transfers::Column::Status.eq(SCHEDULED_TRANSFER_STATUS_ACTIVE))
+            .filter(scheduled_transfers::Column::NextRunAt.lte(now))
+            .order_by_asc(scheduled_transfers::Column::NextRunAt)
+            .all(&self.db_pool)
+            .await
+    }
+
+    #[instrument(
+        skip_all,
+        fields(
+            db.operation = "SELECT",
+            db.sql.table = "scheduled_transfer_executions",
+            scheduled_transfer_id = id,
+        )
+    )]
+    async fn find_executions(
+        &self,
+        id: i32,
+    ) -> Result<Vec<scheduled_transfer_executions::Model>, DbErr> {
+        scheduled_transfer_executions::Entity::find()
+            .filter(scheduled_transfer_executions::Column::ScheduledTransferId.eq(id))
+            .order_by_desc(scheduled_transfer_executions::Column::ExecutionId)
+            .all(&self.db_pool)
+            .await
+    }
+
+    #[instrument(
+        skip_all,
+        fields(
+            db.operation = "INSERT",
+            db.sql.table = "scheduled_transfers",
+            transfer_from = input.transfer_from,
+            transfer_to = input.transfer_to,
+        )
+    )]
+    async fn create(
+        &self,
+        input: &CreateScheduledTransferRequest,
+    ) -> Result<scheduled_transfers::Model, DbErr> {
+        let new_scheduled_transfer = scheduled_transfers::ActiveModel {
+            transfer_from: Set(input.transfer_from),
+            transfer_to: Set(input.transfer_to),
+            transfer_amount: Set(input.transfer_amount),
+            recurrence: Set(input.recurrence.map(|recurrence| recurrence.to_string())),
+            next_run_at: Set(input.scheduled_at.naive_utc()),
+            anchor_at: Set(input.scheduled_at.naive_utc()),
+            status: Set(SCHEDULED_TRANSFER_STATUS_ACTIVE.to_string()),
+            ..Default::default()
+        };
+
+        new_scheduled_transfer.insert(&self.db_pool).await
+    }
+
+    #[instrument(
+        skip_all,
+        fields(
+            db.operation = "UPDATE",
+            db.sql.table = "scheduled_transfers",
+            scheduled_transfer_id = input.scheduled_transfer_id,
+            transfer_from = input.transfer_from,
+            transfer_to = input.transfer_to,
+        )
+    )]
+    async fn update(
+        &self,
+        input: &UpdateScheduledTransferRequest,
+    ) -> Result<scheduled_transfers::Model, DbErr> {
+        let mut scheduled_transfer = self.find_active_model(input.scheduled_transfer_id).await?;
+
+        scheduled_transfer.transfer_to = Set(input.transfer_to);
+        scheduled_transfer.transfer_amount = Set(input.transfer_amount);
+        scheduled_transfer.recurrence =
+            Set(input.recurrence.map(|recurrence| recurrence.to_string()));
+        scheduled_transfer.next_run_at = Set(input.scheduled_at.naive_utc());
+        scheduled_transfer.anchor_at = Set(input.scheduled_at.naive_utc());
+        scheduled_transfer.updated_at = Set(Some(Utc::now().naive_utc()));
+
+        scheduled_transfer.update(&self.db_pool).await
+    }
+
+    #[instrument(
+        skip_all,
+        fields(
+            db.operation = "UPDATE",
+            db.sql.table = "scheduled_transfers",
+            scheduled_transfer_id = id,
+        )
+    )]
+    async fn finish_run(&self, id: i32, status: &str) -> Result<bool, DbErr> {
+        // A one-off run that was cancelled while its transfer ran keeps the cancellation
+        let result = scheduled_transfers::Entity::update_many()
+            .col_expr(scheduled_transfers::Column::Status, Expr::value(status))
+            .col_expr(
+                scheduled_transfers::Column::UpdatedAt,
+                Expr::value(Utc::now().naive_utc()),
+            )
+            .filter(scheduled_transfers::Column::ScheduledTransferId.eq(id))
+            .filter(scheduled_transfers::Column::Status.eq(SCHEDULED_TRANSFER_STATUS_PROCESSING))
+            .exec(&self.db_pool)
+            .await?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    #[instrument(
+        skip_all,
+        fields(
+            db.operation = "UPDATE",
+            db.sql.table = "scheduled_transfers",
+            scheduled_transfer_id = input.scheduled_transfer_id,
+        )
+    )]
+    async fn claim_run(
+        &self,
+        input: &UpdateScheduledTransferRunRequest,
+        due_at: NaiveDateTime,
+    ) -> Result<bool, DbErr> {
+        // Concurrent executors race on the same row; only the first still sees `due_at`
+        let result = scheduled_transfers::Entity::update_many()
+            .col_expr(
+                scheduled_transfers::Column::Status,
+                Expr::value(input.status.clone()),
+            )
+            .col_expr(
+                scheduled_transfers::Column::NextRunAt,
+                Expr::value(input.next_run_at),
+            )
+            .col_expr(
+                scheduled_transfers::Column::LastRunAt,
+                Expr::value(input.last_run_at),
+            )
+            .col_expr(
+                scheduled_transfers::Column::UpdatedAt,
+                Expr::value(Utc::now().naive_utc()),
+            )
+            .filter(
+                scheduled_transfers::Column::ScheduledTransferId.eq(input.scheduled_transfer_id),
+            )
+            .filter(scheduled_transfers::Column::Status.eq(SCHEDULED_TRANSFER_STATUS_ACTIVE))
+            .filter(scheduled_transfers::Column::NextRunAt.eq(due_at))
+            .exec(&self.db_pool)
+            .await?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    #[instrument(
+        skip_all,
+        fields(
+            db.operation = "UPDATE",
+            db.sql.table = "scheduled_transfers",
+            scheduled_transfer_id = id,
+        )
+    )]
+    async fn cancel(&self, id: i32) -> Result<scheduled_transfers::Model, DbErr> {
+        let mut scheduled_transfer = self.find_active_model(id).await?;
+
+        scheduled_transfer.status = Set(SCHEDULED_TRANSFER_STATUS_CANCELLED.to_string());
+        scheduled_transfer.updated_at = Set(Some(Utc::now().naive_utc()));
+
+        scheduled_transfer.update(&self.db_pool).await
+    }
+
+    #[instrument(
+        skip_all,
+        fields(
+            db.operation = "INSERT",
+            db.sql.table = "scheduled_transfer_executions",
+            scheduled_transfer_id = input.scheduled_transfer_id,
+        )
+    )]
+    async fn record_execution(
+        &self,
+        input: &CreateScheduledTransferExecutionRequest,
+    ) -> Result<scheduled_transfer_executions::Model, DbErr> {
+        let new_execution = scheduled_transfer_executions::ActiveModel {
+            scheduled_transfer_id: Set(input.scheduled_transfer_id),
+            transfer_id: Set(input.transfer_id),
+            status: Set(input.status.clone()),
+            failure_reason: Set(input.failure_reason.clone()),
+            executed_at: Set(Utc::now().naive_utc()),
+            ..Default::default()
+        };
+
+        new_execution.insert(&self.db_pool).await
+    }
+
+    #[instrument(
+        skip_all,
+        fields(db.operation = "SELECT", db.sql.table = "scheduled_transfer_executions")
+    )]
+    async fn find_executions_by_status(
+        &self,
+        status: &str,
+    ) -> Result<Vec<scheduled_transfer_executions::Model>, DbErr> {
+        scheduled_transfer_executions::Entity::find()
+            .filter(scheduled_transfer_executions::Column::Status.eq(status))
+            .order_by_asc(scheduled_transfer_executions::Column::ExecutionId)
+            .all(&self.db_pool)
+            .await
+    }
+
+    #[instrument(
+        skip_all,
+        fields(
+            db.operation = "UPDATE",
+            db.sql.table = "scheduled_transfer_executions",
+            execution_id = execution_id,
+        )
+    )]
+    async fn settle_execution(
+        &self,
+        execution_id: i32,
+        status: &str,
+        failure_reason: Option<String>,
+    ) -> Result<bool, DbErr> {
+        let result = scheduled_transfer_executions::Entity::update_many()
+            .col_expr(
+                scheduled_transfer_executions::Column::Status,
+                Expr::value(status),
+            )
+            .col_expr(
+                scheduled_transfer_executions::Column::FailureReason,
+                Expr::value(failure_reason),
+            )
+            .col_expr(
+                scheduled_transfer_executions::Column::UpdatedAt,
+                Expr::value(Utc::now().naive_utc()),
+            )
+            .filter(scheduled_transfer_executions::Column::ExecutionId.eq(execution_id))
+            .filter(scheduled_transfer_executions::Column::Status.eq(EXECUTION_STATUS_PENDING))
+            .exec(&self.db_pool)
+            .await?;
+
+        Ok(result.rows_affected > 0)
+    }
+}
@@ -0,0 +1,156 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use sea_orm::{
+    sea_query::Expr, ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait,
+    PaginatorTrait, QueryFilter, QueryOrder, Set,
+};
+
+use crate::{
+    abstract_trait::screening::ScreeningRepositoryTrait,
+    domain::request::screening::{
+        CreateScreeningMatchRequest, ReviewScreeningMatchRequest, SCREENING_MATCH_STATUS_CONFIRMED,
+        SCREENING_MATCH_STATUS_PENDING,
+    },
+    entities::screening_matches,
+};
+use tracing::instrument;
+
+pub struct ScreeningRepository {
+    db_pool: DatabaseConnection,
+}
+
+impl ScreeningRepository {
+    pub fn new(db_pool: DatabaseConnection) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl ScreeningRepositoryTrait for ScreeningRepository {
+    #[instrument(
+        skip_all,
+        fields(db.operation = "SELECT", db.sql.table = "screening_matches", screening_match_id = id)
+    )]
+    async fn find_by_id(&self, id: i32) -> Result<Option<screening_matches::Model>, DbErr> {
+        screening_matches::Entity::find_by_id(id)
+            .one(&self.db_pool)
+            .await
+    }
+
+    #[instrument(
+        skip_all,
+        fields(db.operation = "SELECT", db.sql.table = "screening_matches", user_id = user_id)
+    )]
+    async fn find_by_user(&self, user_id: i32) -> Result<Vec<screening_matches::Model>, DbErr> {
+        screening_matches::Entity::find()
+            .filter(screening_matches::Column::UserId.eq(user_id))
+            .order_by_desc(screening_matches::Column::MatchId)
+            .all(&self.db_pool)
+            .await
+    }
+
+    #[instrument(skip_all, fields(db.operation = "SELECT", db.sql.table = "screening_matches"))]
+    async fn find_by_status(&self, status: &str) -> Result<Vec<screening_matches::Model>, DbErr> {
+        screening_matches::Entity::find()
+            .filter(screening_matches::Column::Status.eq(status))
+            .order_by_asc(screening_matches::Column::MatchId)
+            .all(&self.db_pool)
+            .await
+    }
+
+    #[instrument(
+        skip_all,
+        fields(db.operation = "SELECT", db.sql.table = "screening_matches", user_id = user_id)
+    )]
+    async fn find_by_user_entry(
+        &self,
+        user_id: i32,
+        entry_reference: &str,
+    ) -> Result<Option<screening_matches::Model>, DbErr> {
+        screening_matches::Entity::find()
+            .filter(screening_matches::Column::UserId.eq(user_id))
+            .filter(screening_matches::Column::EntryReference.eq(entry_reference))
+            .one(&self.db_pool)
+            .await
+    }
+
+    #[instrument(
+        skip_all,
+        fields(db.operation = "SELECT", db.sql.table = "screening_matches", user_id = user_id)
+    )]
+    async fn count_open(&self, user_id: i32) -> Result<u64, DbErr> {
+        screening_matches::Entity::find()
+            .filter(screening_matches::Column::UserId.eq(user_id))
+            .filter(screening_matches::Column::Status.is_in([
+                SCREENING_MATCH_STATUS_PENDING,
+                SCREENING_MATCH_STATUS_CONFIRMED,
+            ]))
+            .count(&self.db_pool)
+            .await
+    }
+
+    #[instrument(
+        skip_all,
+        fields(db.operation = "INSERT", db.sql.table = "screening_matches", user_id = input.user_id)
+    )]
+    async fn create(
+        &self,
+        input: &CreateScreeningMatchRequest,
+    ) -> Result<screening_matches::Model, DbErr> {
+        let new_match = screening_matches::ActiveModel {
+            user_id: Set(input.user_id),
+            context: Set(input.context.clone()),
+            screened_name: Set(input.screened_name.clone()),
+            entry_reference: Set(input.entry_reference.clone()),
+            entry_name: Set(input.entry_name.clone()),
+            entry_source: Set(input.entry_source.clone()),
+            score: Set(input.score),
+            status: Set(SCREENING_MATCH_STATUS_PENDING.to_string()),
+            ..Default::default()
+        };
+
+        new_match.insert(&self.db_pool).await
+    }
+
+    #[instrument(
+        skip_all,
+        fields(
+            db.operation = "UPDATE",
+            db.sql.table = "screening_matches",
+            match_id = input.match_id,
+            reviewer_id = input.reviewer_id,
+        )
+    )]
+    async fn review(
+        &self,
+        input: &ReviewScreeningMatchRequest,
+    ) -> Result<Option<screening_matches::Model>, DbErr> {
+        let result = screening_matches::Entity::update_many()
+            .col_expr(
+                screening_matches::Column::Status,
+                Expr::value(input.decision.clone()),
+            )
+            .col_expr(
+                screening_matches::Column::ReviewedBy,
+                Expr::value(input.reviewer_id),
+            )
+            .col_expr(
+                screening_matches::Column::ReviewReason,
+                Expr::value(input.reason.clone()),
+            )
+            .col_expr(
+                screening_matches::Column::ReviewedAt,
+                Expr::value(Utc::now().naive_utc()),
+            )
+            .filter(screening_matches::Column::MatchId.eq(input.match_id))
+            .filter(screening_matches::Column::Status.eq(SCREENING_MATCH_STATUS_PENDING))
+            .exec(&self.db_pool)
+            .await?;
+
+        if result.rows_affected == 0 {
+            return Ok(None);
+        }
+
+        self.find_by_id(input.match_id).await
+    }
+}
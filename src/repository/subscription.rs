@@ -0,0 +1,348 @@
+use async_trait::async_trait;
+use chrono::{NaiveDateTime, Utc};
+use sea_orm::{
+    sea_query::Expr, ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait,
+    QueryFilter, QueryOrder, Set,
+};
+
+use crate::{
+    abstract_trait::subscription::SubscriptionRepositoryTrait,
+    domain::request::subscription::{
+        CreateSubscriptionChargeRequest, CreateSubscriptionPlanRequest, CreateSubscriptionRequest,
+        UpdateSubscriptionScheduleRequest, CHARGE_STATUS_PENDING, SUBSCRIPTION_STATUS_ACTIVE,
+        SUBSCRIPTION_STATUS_CANCELLED, SUBSCRIPTION_STATUS_PAST_DUE,
+        SUBSCRIPTION_STATUS_PROCESSING,
+    },
+    entities::{subscription_charges, subscription_plans, subscriptions},
+};
+use tracing::instrument;
+
+pub struct SubscriptionRepository {
+    db_pool: DatabaseConnection,
+}
+
+impl SubscriptionRepository {
+    pub fn new(db_pool: DatabaseConnection) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl SubscriptionRepositoryTrait for SubscriptionRepository {
+    #[instrument(
+        skip_all,
+        fields(db.operation = "SELECT", db.sql.table = "subscription_plans", plan_id = id)
+    )]
+    async fn find_plan_by_id(&self, id: i32) -> Result<Option<subscription_plans::Model>, DbErr> {
+        subscription_plans::Entity::find_by_id(id)
+            .one(&self.db_pool)
+            .await
+    }
+
+    #[instrument(
+        skip_all,
+        fields(
+            db.operation = "SELECT",
+            db.sql.table = "subscription_plans",
+            merchant_id = merchant_id,
+        )
+    )]
+    async fn find_plans_by_merchant(
+        &self,
+        merchant_id: i32,
+    ) -> Result<Vec<subscription_plans::Model>, DbErr> {
+        subscription_plans::Entity::find()
+            .filter(subscription_plans::Column::MerchantId.eq(merchant_id))
+            .order_by_desc(subscription_plans::Column::PlanId)
+            .all(&self.db_pool)
+            .await
+    }
+
+    #[instrument(
+        skip_all,
+        fields(
+            db.operation = "INSERT",
+            db.sql.table = "subscription_plans",
+            merchant_id = input.merchant_id,
+        )
+    )]
+    async fn create_plan(
+        &self,
+        input: &CreateSubscriptionPlanRequest,
+    ) -> Result<subscription_plans::Model, DbErr> {
+        let new_plan = subscription_plans::ActiveModel {
+            merchant_id: Set(input.merchant_id),
+            name: Set(input.name.clone()),
+            amount: Set(input.amount),
+            billing_interval: Set(input.billing_interval.to_string()),
+            interval_count: Set(input.interval_count),
+            is_active: Set(true),
+            ..Default::default()
+        };
+
+        new_plan.insert(&self.db_pool).await
+    }
+
+    #[instrument(
+        skip_all,
+        fields(db.operation = "SELECT", db.sql.table = "subscriptions", subscription_id = id)
+    )]
+    async fn find_by_id(&self, id: i32) -> Result<Option<subscriptions::Model>, DbErr> {
+        subscriptions::Entity::find_by_id(id).one(&self.db_pool).await
+    }
+
+    #[instrument(
+        skip_all,
+        fields(
+            db.operation = "SELECT",
+            db.sql.table = "subscriptions",
+            subscriber_id = subscriber_id,
+        )
+    )]
+    async fn find_by_subscriber(
+        &self,
+        subscriber_id: i32,
+    ) -> Result<Vec<subscriptions::Model>, DbErr> {
+        subscriptions::Entity::find()
+            .filter(subscriptions::Column::SubscriberId.eq(subscriber_id))
+            .order_by_desc(subscriptions::Column::SubscriptionId)
+            .all(&self.db_pool)
+            .await
+    }
+
+    #[instrument(skip_all, fields(db.operation = "SELECT", db.sql.table = "subscriptions"))]
+    async fn find_due(&self, now: NaiveDateTime) -> Result<Vec<subscriptions::Model>, DbErr> {
+        subscriptions::Entity::find()
+            .filter(
+                subscriptions::Column::Status
+                    .is_in([SUBSCRIPTION_STATUS_ACTIVE, SUBSCRIPTION_STATUS_PAST_DUE]),
+            )
+            .filter(subscriptions::Column::NextChargeAt.lte(now))
+            .order_by_asc(subscriptions::Column::NextChargeAt)
+            .all(&self.db_pool)
+            .await
+    }
+
+    #[instrument(
+        skip_all,
+        fields(
+            db.operation = "INSERT",
+            db.sql.table = "subscriptions",
+            plan_id = input.plan_id,
+            subscriber_id = input.subscriber_id,
+        )
+    )]
+    async fn create(
+        &self,
+        input: &CreateSubscriptionRequest,
+        next_charge_at: NaiveDateTime,
+    ) -> Result<subscriptions::Model, DbErr> {
+        let new_subscription = subscriptions::ActiveModel {
+            plan_id: Set(input.plan_id),
+            subscriber_id: Set(input.subscriber_id),
+            status: Set(SUBSCRIPTION_STATUS_ACTIVE.to_string()),
+            next_charge_at: Set(next_charge_at),
+            billing_anchor_at: Set(next_charge_at),
+            billed_periods: Set(0),
+            retry_count: Set(0),
+            mandate_accepted_at: Set(Utc::now().naive_utc()),
+            ..Default::default()
+        };
+
+        new_subscription.insert(&self.db_pool).await
+    }
+
+    #[instrument(
+        skip_all,
+        fields(
+            db.operation = "UPDATE",
+            db.sql.table = "subscriptions",
+            subscription_id = subscription.subscription_id,
+        )
+    )]
+    async fn claim_charge(&self, subscription: &subscriptions::Model) -> Result<bool, DbErr> {
+        // Another run, or a pause or cancel, that changed the row first makes this a no-op
+        let result = subscriptions::Entity::update_many()
+            .col_expr(
+                subscriptions::Column::Status,
+                Expr::value(SUBSCRIPTION_STATUS_PROCESSING),
+            )
+            .col_expr(
+                subscriptions::Column::UpdatedAt,
+                Expr::value(Utc::now().naive_utc()),
+            )
+            .filter(subscriptions::Column::SubscriptionId.eq(subscription.subscription_id))
+            .filter(subscriptions::Column::Status.eq(subscription.status.as_str()))
+            .filter(subscriptions::Column::NextChargeAt.eq(subscription.next_charge_at))
+            .exec(&self.db_pool)
+            .await?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    #[instrument(
+        skip_all,
+        fields(
+            db.operation = "UPDATE",
+            db.sql.table = "subscriptions",
+            subscription_id = input.subscription_id,
+        )
+    )]
+    async fn update_schedule(
+        &self,
+        input: &UpdateSubscriptionScheduleRequest,
+        current_status: &str,
+    ) -> Result<Option<subscriptions::Model>, DbErr> {
+        let mut update = subscriptions::Entity::update_many()
+            .col_expr(
+                subscriptions::Column::Status,
+                Expr::value(input.status.clone()),
+            )
+            .col_expr(
+                subscriptions::Column::NextChargeAt,
+                Expr::value(input.next_charge_at),
+            )
+            .col_expr(
+                subscriptions::Column::BilledPeriods,
+                Expr::value(input.billed_periods),
+            )
+            .col_expr(
+                subscriptions::Column::RetryCount,
+                Expr::value(input.retry_count),
+            )
+            .col_expr(
+                subscriptions::Column::UpdatedAt,
+                Expr::value(Utc::now().naive_utc()),
+            );
+
+        if input.status == SUBSCRIPTION_STATUS_CANCELLED {
+            update = update.col_expr(
+                subscriptions::Column::CancelledAt,
+                Expr::value(Utc::now().naive_utc()),
+            );
+        }
+
+        // A subscription that left `current_status` meanwhile (e.g. was cancelled) is untouched
+        let updated = update
+            .filter(subscriptions::Column::SubscriptionId.eq(input.subscription_id))
+            .filter(subscriptions::Column::Status.eq(current_status))
+            .exec_with_returning(&self.db_pool)
+            .await?;
+
+        Ok(updated.into_iter().next())
+    }
+
+    #[instrument(
+        skip_all,
+        fields(db.operation = "UPDATE", db.sql.table = "subscriptions", subscription_id = id)
+    )]
+    async fn update_status(&self, id: i32, status: &str) -> Result<subscriptions::Model, DbErr> {
+        let mut subscription_record: subscriptions::ActiveModel =
+            subscriptions::Entity::find_by_id(id)
+                .one(&self.db_pool)
+                .await?
+                .ok_or(DbErr::RecordNotFound("Subscription not found".to_owned()))?
+                .into();
+
+        if status == SUBSCRIPTION_STATUS_CANCELLED {
+            subscription_record.cancelled_at = Set(Some(Utc::now().naive_utc()));
+        }
+
+        subscription_record.status = Set(status.to_string());
+        subscription_record.updated_at = Set(Some(Utc::now().naive_utc()));
+
+        subscription_record.update(&self.db_pool).await
+    }
+
+    #[instrument(
+        skip_all,
+        fields(
+            db.operation = "SELECT",
+            db.sql.table = "subscription_charges",
+            subscription_id = subscription_id,
+        )
+    )]
+    async fn find_charges(
+        &self,
+        subscription_id: i32,
+    ) -> Result<Vec<subscription_charges::Model>, DbErr> {
+        subscription_charges::Entity::find()
+            .filter(subscription_charges::Column::SubscriptionId.eq(subscription_id))
+            .order_by_desc(subscription_charges::Column::ChargeId)
+            .all(&self.db_pool)
+            .await
+    }
+
+    #[instrument(
+        skip_all,
+        fields(
+            db.operation = "INSERT",
+            db.sql.table = "subscription_charges",
+            subscription_id = input.subscription_id,
+        )
+    )]
+    async fn record_charge(
+        &self,
+        input: &CreateSubscriptionChargeRequest,
+    ) -> Result<subscription_charges::Model, DbErr> {
+        let new_charge = subscription_charges::ActiveModel {
+            subscription_id: Set(input.subscription_id),
+            transfer_id: Set(input.transfer_id),
+            amount: Set(input.amount),
+            status: Set(input.status.clone()),
+            attempt: Set(input.attempt),
+            failure_reason: Set(input.failure_reason.clone()),
+            ..Default::default()
+        };
+
+        new_charge.insert(&self.db_pool).await
+    }
+
+    #[instrument(
+        skip_all,
+        fields(db.operation = "SELECT", db.sql.table = "subscription_charges")
+    )]
+    async fn find_charges_by_status(
+        &self,
+        status: &str,
+    ) -> Result<Vec<subscription_charges::Model>, DbErr> {
+        subscription_charges::Entity::find()
+            .filter(subscription_charges::Column::Status.eq(status))
+            .order_by_asc(subscription_charges::Column::ChargeId)
+            .all(&self.db_pool)
+            .await
+    }
+
+    #[instrument(
+        skip_all,
+        fields(
+            db.operation = "UPDATE",
+            db.sql.table = "subscription_charges",
+            charge_id = charge_id,
+        )
+    )]
+    async fn settle_charge(
+        &self,
+        charge_id: i32,
+        status: &str,
+        failure_reason: Option<String>,
+    ) -> Result<bool, DbErr> {
+        // Only a charge that is still pending can be settled, and only once
+        let result = subscription_charges::Entity::update_many()
+            .col_expr(subscription_charges::Column::Status, Expr::value(status))
+            .col_expr(
+                subscription_charges::Column::FailureReason,
+                Expr::value(failure_reason),
+            )
+            .col_expr(
+                subscription_charges::Column::UpdatedAt,
+                Expr::value(Utc::now().naive_utc()),
+            )
+            .filter(subscription_charges::Column::ChargeId.eq(charge_id))
+            .filter(subscription_charges::Column::Status.eq(CHARGE_STATUS_PENDING))
+            .exec(&self.db_pool)
+            .await?;
+
+        Ok(result.rows_affected > 0)
+    }
+}
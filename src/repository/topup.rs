@@ -1,14 +1,28 @@
 use async_trait::async_trait;
 use chrono::Utc;
 use sea_orm::{
+    sea_query::{Expr, OnConflict},
     ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter, Set,
+    TransactionTrait,
 };
 
 use crate::{
     abstract_trait::topup::TopupRepositoryTrait,
-    domain::request::topup::{CreateTopupRequest, UpdateTopupAmount, UpdateTopupRequest},
-    entities::topups,
+    domain::{
+        request::{
+            pagination::ListRequest,
+            topup::{
+                BankPaymentReference, CreateTopupRequest, UpdateTopupAmount, UpdateTopupRequest,
+                TOPUP_LIST_FIELDS,
+            },
+        },
+        response::pagination::Pagination,
+    },
+    entities::{saldo, topups, virtual_account_payments},
+    repository::pagination::{filter_amount_range, filter_date_range, paginate},
+    utils::fee::FeeQuote,
 };
+use tracing::instrument;
 
 pub struct TopupRepository {
     db_pool: DatabaseConnection,
@@ -18,18 +32,72 @@ impl TopupRepository {
     pub fn new(db_pool: DatabaseConnection) -> Self {
         Self { db_pool }
     }
+
+    fn new_topup(input: &CreateTopupRequest, fee: &FeeQuote, status: &str) -> topups::ActiveModel {
+        topups::ActiveModel {
+            user_id: Set(input.user_id),
+            topup_no: Set(input.topup_no.clone()),
+            topup_amount: Set(input.topup_amount),
+            topup_method: Set(input.topup_method.clone()),
+            topup_time: Set(Utc::now().naive_utc()),
+            fee_amount: Set(fee.fee_amount),
+            fee_rule: Set(fee.fee_rule.clone()),
+            status: Set(status.to_string()),
+            ..Default::default()
+        }
+    }
+
+    fn sort_column(field: &str) -> topups::Column {
+        match field {
+            "topup_amount" => topups::Column::TopupAmount,
+            "topup_time" => topups::Column::TopupTime,
+            "created_at" => topups::Column::CreatedAt,
+            _ => topups::Column::TopupId,
+        }
+    }
 }
 
 #[async_trait]
 impl TopupRepositoryTrait for TopupRepository {
-    async fn find_all(&self) -> Result<Vec<topups::Model>, DbErr> {
-        topups::Entity::find().all(&self.db_pool).await
+    #[instrument(skip_all, fields(db.operation = "SELECT", db.sql.table = "topups"))]
+    async fn find_paginated(
+        &self,
+        input: &ListRequest,
+    ) -> Result<(Vec<topups::Model>, Pagination), DbErr> {
+        let mut query = topups::Entity::find();
+
+        if let Some(user_id) = input.user_id {
+            query = query.filter(topups::Column::UserId.eq(user_id));
+        }
+
+        if let Some(status) = &input.status {
+            query = query.filter(topups::Column::Status.eq(status.trim()));
+        }
+
+        if let Some(method) = &input.method {
+            query = query.filter(topups::Column::TopupMethod.eq(method.trim()));
+        }
+
+        let query = filter_date_range(query, topups::Column::TopupTime, input);
+        let query = filter_amount_range(query, topups::Column::TopupAmount, input);
+
+        paginate(
+            &self.db_pool,
+            query,
+            input,
+            &TOPUP_LIST_FIELDS,
+            Self::sort_column,
+            |topup| topup.topup_id,
+        )
+        .await
     }
 
+    #[instrument(skip_all, fields(db.operation = "SELECT", db.sql.table = "topups", topup_id = id))]
     async fn find_by_id(&self, id: i32) -> Result<Option<topups::Model>, DbErr> {
         topups::Entity::find_by_id(id).one(&self.db_pool).await
     }
 
+    #[instrument(skip_all, fields(db.operation = "SELECT", db.sql.table = "topups", user_id = id))]
     async fn find_by_users(&self, id: i32) -> Result<Vec<Option<topups::Model>>, DbErr> {
         topups::Entity::find()
             .filter(topups::Column::UserId.eq(id))
@@ -38,6 +106,7 @@ impl TopupRepositoryTrait for TopupRepository {
             .map(|res| res.into_iter().map(Some).collect())
     }
 
+    #[instrument(skip_all, fields(db.operation = "SELECT", db.sql.table = "topups", user_id = id))]
     async fn find_by_user(&self, id: i32) -> Result<Option<topups::Model>, DbErr> {
         topups::Entity::find()
             .filter(topups::Column::UserId.eq(id))
@@ -45,18 +114,121 @@ impl TopupRepositoryTrait for TopupRepository {
             .await
     }
 
-    async fn create(&self, input: &CreateTopupRequest) -> Result<topups::Model, DbErr> {
-        let new_topup = topups::ActiveModel {
-            user_id: Set(input.user_id),
-            topup_no: Set(input.topup_no.clone()),
-            topup_amount: Set(input.topup_amount),
-            topup_method: Set(input.topup_method.clone()),
-            topup_time: Set(Utc::now().naive_utc()),
-            ..Default::default()
-        };
-        new_topup.insert(&self.db_pool).await
+    #[instrument(
+        skip_all,
+        fields(db.operation = "SELECT", db.sql.table = "virtual_account_payments")
+    )]
+    async fn find_by_bank_payment(
+        &self,
+        payment: &BankPaymentReference,
+    ) -> Result<Option<topups::Model>, DbErr> {
+        let claim = virtual_account_payments::Entity::find()
+            .filter(virtual_account_payments::Column::BankCode.eq(&payment.bank_code))
+            .filter(
+                virtual_account_payments::Column::PaymentReference.eq(&payment.payment_reference),
+            )
+            .one(&self.db_pool)
+            .await?;
+
+        match claim {
+            Some(claim) => topups::Entity::find_by_id(claim.topup_id)
+                .one(&self.db_pool)
+                .await,
+            None => Ok(None),
+        }
+    }
+
+    #[instrument(
+        skip_all,
+        fields(db.operation = "INSERT", db.sql.table = "topups", user_id = input.user_id)
+    )]
+    async fn create(
+        &self,
+        input: &CreateTopupRequest,
+        fee: &FeeQuote,
+        status: &str,
+    ) -> Result<topups::Model, DbErr> {
+        Self::new_topup(input, fee, status).insert(&self.db_pool).await
+    }
+
+    #[instrument(
+        skip_all,
+        fields(db.operation = "INSERT", db.sql.table = "topups", user_id = input.user_id)
+    )]
+    async fn create_for_bank_payment(
+        &self,
+        input: &CreateTopupRequest,
+        fee: &FeeQuote,
+        status: &str,
+        payment: &BankPaymentReference,
+        credit: Option<i32>,
+    ) -> Result<Option<topups::Model>, DbErr> {
+        let txn = self.db_pool.begin().await?;
+
+        let topup = Self::new_topup(input, fee, status).insert(&txn).await?;
+
+        // A concurrent delivery of the same reference waits on the unique index and then
+        // inserts nothing, so only one of them goes on to credit the saldo
+        let claimed = virtual_account_payments::Entity::insert(
+            virtual_account_payments::ActiveModel {
+                bank_code: Set(payment.bank_code.clone()),
+                payment_reference: Set(payment.payment_reference.clone()),
+                topup_id: Set(topup.topup_id),
+                ..Default::default()
+            },
+        )
+        .on_conflict(
+            OnConflict::columns([
+                virtual_account_payments::Column::BankCode,
+                virtual_account_payments::Column::PaymentReference,
+            ])
+            .do_nothing()
+            .to_owned(),
+        )
+        .exec_without_returning(&txn)
+        .await?;
+
+        if claimed == 0 {
+            txn.rollback().await?;
+            return Ok(None);
+        }
+
+        if let Some(amount) = credit {
+            let credited = saldo::Entity::update_many()
+                .col_expr(
+                    saldo::Column::TotalBalance,
+                    Expr::col(saldo::Column::TotalBalance).add(amount),
+                )
+                .col_expr(saldo::Column::UpdatedAt, Expr::value(Utc::now().naive_utc()))
+                .filter(saldo::Column::UserId.eq(input.user_id))
+                .exec(&txn)
+                .await?;
+
+            if credited.rows_affected == 0 {
+                saldo::ActiveModel {
+                    user_id: Set(input.user_id),
+                    total_balance: Set(amount),
+                    ..Default::default()
+                }
+                .insert(&txn)
+                .await?;
+            }
+        }
+
+        txn.commit().await?;
+
+        Ok(Some(topup))
     }
 
+    #[instrument(
+        skip_all,
+        fields(
+            db.operation = "UPDATE",
+            db.sql.table = "topups",
+            user_id = input.user_id,
+            topup_id = input.topup_id,
+        )
+    )]
     async fn update(&self, input: &UpdateTopupRequest) -> Result<topups::Model, DbErr> {
         let mut topup_record: topups::ActiveModel = topups::Entity::find_by_id(input.topup_id)
             .one(&self.db_pool)
@@ -71,6 +243,10 @@ impl TopupRepositoryTrait for TopupRepository {
         topup_record.update(&self.db_pool).await
     }
 
+    #[instrument(
+        skip_all,
+        fields(db.operation = "UPDATE", db.sql.table = "topups", topup_id = input.topup_id)
+    )]
     async fn update_amount(&self, input: &UpdateTopupAmount) -> Result<topups::Model, DbErr> {
         let mut topup_record: topups::ActiveModel = topups::Entity::find_by_id(input.topup_id)
             .one(&self.db_pool)
@@ -84,6 +260,21 @@ impl TopupRepositoryTrait for TopupRepository {
     }
 
     // Delete a topup record by user ID
+    #[instrument(skip_all, fields(db.operation = "UPDATE", db.sql.table = "topups", topup_id = id))]
+    async fn update_status(&self, id: i32, from_status: &str, status: &str) -> Result<bool, DbErr> {
+        // Guarding on the current status keeps a held topup from being resolved twice
+        let result = topups::Entity::update_many()
+            .col_expr(topups::Column::Status, Expr::value(status))
+            .col_expr(topups::Column::UpdatedAt, Expr::value(Utc::now().naive_utc()))
+            .filter(topups::Column::TopupId.eq(id))
+            .filter(topups::Column::Status.eq(from_status))
+            .exec(&self.db_pool)
+            .await?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    #[instrument(skip_all, fields(db.operation = "DELETE", db.sql.table = "topups", topup_id = id))]
     async fn delete(&self, id: i32) -> Result<(), DbErr> {
         let result = topups::Entity::delete_many()
             .filter(topups::Column::UserId.eq(id))
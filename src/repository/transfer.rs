@@ -1,15 +1,26 @@
 use crate::{
     abstract_trait::transfer::TransferRepositoryTrait,
-    domain::request::transfer::{
-        CreateTransferRequest, UpdateTransferAmountRequest, UpdateTransferRequest,
+    domain::{
+        request::{
+            pagination::ListRequest,
+            transfer::{
+                CreateTransferRequest, UpdateTransferAmountRequest, UpdateTransferRequest,
+                TRANSFER_LIST_FIELDS, TRANSFER_STATUS_REJECTED,
+            },
+        },
+        response::pagination::Pagination,
     },
     entities::{transfers, Transfer},
+    repository::pagination::{filter_amount_range, filter_date_range, paginate},
+    utils::fee::FeeQuote,
 };
 use async_trait::async_trait;
-use chrono::Utc;
+use chrono::{NaiveDateTime, Utc};
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter, Set,
+    sea_query::Expr, ActiveModelTrait, ColumnTrait, Condition, DatabaseConnection, DbErr,
+    EntityTrait, PaginatorTrait, QueryFilter, QuerySelect, Set,
 };
+use tracing::instrument;
 
 pub struct TransferRepository {
     db_pool: DatabaseConnection,
@@ -19,18 +30,65 @@ impl TransferRepository {
     pub fn new(db_pool: DatabaseConnection) -> Self {
         Self { db_pool }
     }
+
+    fn sort_column(field: &str) -> transfers::Column {
+        match field {
+            "transfer_amount" => transfers::Column::TransferAmount,
+            "transfer_time" => transfers::Column::TransferTime,
+            "created_at" => transfers::Column::CreatedAt,
+            _ => transfers::Column::TransferId,
+        }
+    }
 }
 
 #[async_trait]
 impl TransferRepositoryTrait for TransferRepository {
-    async fn find_all(&self) -> Result<Vec<transfers::Model>, DbErr> {
-        Transfer::find().all(&self.db_pool).await
+    #[instrument(skip_all, fields(db.operation = "SELECT", db.sql.table = "transfers"))]
+    async fn find_paginated(
+        &self,
+        input: &ListRequest,
+    ) -> Result<(Vec<transfers::Model>, Pagination), DbErr> {
+        let mut query = Transfer::find();
+
+        // A user filter matches both sides of the transfer
+        if let Some(user_id) = input.user_id {
+            query = query.filter(
+                Condition::any()
+                    .add(transfers::Column::TransferFrom.eq(user_id))
+                    .add(transfers::Column::TransferTo.eq(user_id)),
+            );
+        }
+
+        if let Some(status) = &input.status {
+            query = query.filter(transfers::Column::Status.eq(status.trim()));
+        }
+
+        let query = filter_date_range(query, transfers::Column::TransferTime, input);
+        let query = filter_amount_range(query, transfers::Column::TransferAmount, input);
+
+        paginate(
+            &self.db_pool,
+            query,
+            input,
+            &TRANSFER_LIST_FIELDS,
+            Self::sort_column,
+            |transfer| transfer.transfer_id,
+        )
+        .await
     }
 
+    #[instrument(
+        skip_all,
+        fields(db.operation = "SELECT", db.sql.table = "transfers", transfer_id = id)
+    )]
     async fn find_by_id(&self, id: i32) -> Result<Option<transfers::Model>, DbErr> {
         Transfer::find_by_id(id).one(&self.db_pool).await
     }
 
+    #[instrument(
+        skip_all,
+        fields(db.operation = "SELECT", db.sql.table = "transfers", user_id = id)
+    )]
     async fn find_by_users(&self, id: i32) -> Result<Option<Vec<transfers::Model>>, DbErr> {
         let transfers = Transfer::find()
             .filter(
@@ -48,6 +106,10 @@ impl TransferRepositoryTrait for TransferRepository {
         }
     }
 
+    #[instrument(
+        skip_all,
+        fields(db.operation = "SELECT", db.sql.table = "transfers", user_id = id)
+    )]
     async fn find_by_user(&self, id: i32) -> Result<Option<transfers::Model>, DbErr> {
         Transfer::find()
             .filter(
@@ -59,29 +121,66 @@ impl TransferRepositoryTrait for TransferRepository {
             .await
     }
 
-    async fn create(&self, input: &CreateTransferRequest) -> Result<transfers::Model, DbErr> {
+    #[instrument(
+        skip_all,
+        fields(
+            db.operation = "INSERT",
+            db.sql.table = "transfers",
+            transfer_from = input.transfer_from,
+            transfer_to = input.transfer_to,
+        )
+    )]
+    async fn create(
+        &self,
+        input: &CreateTransferRequest,
+        fee: &FeeQuote,
+        status: &str,
+    ) -> Result<transfers::Model, DbErr> {
         let new_transfer = transfers::ActiveModel {
             transfer_from: Set(input.transfer_from),
             transfer_to: Set(input.transfer_to),
             transfer_amount: Set(input.transfer_amount),
             transfer_time: Set(Utc::now().naive_utc()),
+            fee_amount: Set(fee.fee_amount),
+            fee_rule: Set(fee.fee_rule.clone()),
+            status: Set(status.to_string()),
             ..Default::default()
         };
         new_transfer.insert(&self.db_pool).await
     }
 
-    async fn update(&self, input: &UpdateTransferRequest) -> Result<transfers::Model, DbErr> {
+    #[instrument(
+        skip_all,
+        fields(
+            db.operation = "UPDATE",
+            db.sql.table = "transfers",
+            transfer_id = input.transfer_id,
+            transfer_from = input.transfer_from,
+            transfer_to = input.transfer_to,
+        )
+    )]
+    async fn update(
+        &self,
+        input: &UpdateTransferRequest,
+        fee: &FeeQuote,
+    ) -> Result<transfers::Model, DbErr> {
         let transfer = transfers::ActiveModel {
             transfer_id: Set(input.transfer_id),
             transfer_from: Set(input.transfer_from),
             transfer_to: Set(input.transfer_to),
             transfer_amount: Set(input.transfer_amount),
+            fee_amount: Set(fee.fee_amount),
+            fee_rule: Set(fee.fee_rule.clone()),
             transfer_time: Set(Utc::now().naive_utc()),
             ..Default::default()
         };
         transfer.update(&self.db_pool).await
     }
 
+    #[instrument(
+        skip_all,
+        fields(db.operation = "UPDATE", db.sql.table = "transfers", transfer_id = input.transfer_id)
+    )]
     async fn update_amount(
         &self,
         input: &UpdateTransferAmountRequest,
@@ -95,6 +194,72 @@ impl TransferRepositoryTrait for TransferRepository {
         transfer.update(&self.db_pool).await
     }
 
+    #[instrument(
+        skip_all,
+        fields(db.operation = "SELECT", db.sql.table = "transfers", user_id = user_id)
+    )]
+    async fn sum_sent_since(&self, user_id: i32, since: NaiveDateTime) -> Result<i64, DbErr> {
+        let total: Option<Option<i64>> = Transfer::find()
+            .select_only()
+            .column_as(transfers::Column::TransferAmount.sum(), "total")
+            .filter(transfers::Column::TransferFrom.eq(user_id))
+            .filter(transfers::Column::TransferTime.gte(since))
+            .filter(transfers::Column::Status.ne(TRANSFER_STATUS_REJECTED))
+            .into_tuple()
+            .one(&self.db_pool)
+            .await?;
+
+        Ok(total.flatten().unwrap_or(0))
+    }
+
+    #[instrument(
+        skip_all,
+        fields(
+            db.operation = "SELECT",
+            db.sql.table = "transfers",
+            transfer_from = transfer_from,
+            transfer_to = transfer_to,
+        )
+    )]
+    async fn count_between(
+        &self,
+        transfer_from: i32,
+        transfer_to: i32,
+        since: Option<NaiveDateTime>,
+    ) -> Result<u64, DbErr> {
+        let mut query = Transfer::find()
+            .filter(transfers::Column::TransferFrom.eq(transfer_from))
+            .filter(transfers::Column::TransferTo.eq(transfer_to))
+            .filter(transfers::Column::Status.ne(TRANSFER_STATUS_REJECTED));
+
+        if let Some(since) = since {
+            query = query.filter(transfers::Column::TransferTime.gte(since));
+        }
+
+        query.count(&self.db_pool).await
+    }
+
+    #[instrument(
+        skip_all,
+        fields(db.operation = "UPDATE", db.sql.table = "transfers", transfer_id = id)
+    )]
+    async fn update_status(&self, id: i32, from_status: &str, status: &str) -> Result<bool, DbErr> {
+        // Guarding on the current status keeps a held transfer from being resolved twice
+        let result = Transfer::update_many()
+            .col_expr(transfers::Column::Status, Expr::value(status))
+            .col_expr(transfers::Column::UpdatedAt, Expr::value(Utc::now().naive_utc()))
+            .filter(transfers::Column::TransferId.eq(id))
+            .filter(transfers::Column::Status.eq(from_status))
+            .exec(&self.db_pool)
+            .await?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    #[instrument(
+        skip_all,
+        fields(db.operation = "DELETE", db.sql.table = "transfers", transfer_id = id)
+    )]
     async fn delete(&self, id: i32) -> Result<(), DbErr> {
         Transfer::delete_by_id(id)
             .exec(&self.db_pool)
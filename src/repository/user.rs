@@ -3,8 +3,12 @@ use sea_orm::{prelude::*, Set};
 use sea_orm::{DatabaseConnection, DbErr};
 
 use crate::abstract_trait::user::UserRepositoryTrait;
-use crate::domain::request::user::{CreateUserRequest, UpdateUserRequest};
+use crate::domain::request::pagination::ListRequest;
+use crate::domain::request::user::{CreateUserRequest, UpdateUserRequest, USER_LIST_FIELDS};
+use crate::domain::response::pagination::Pagination;
 use crate::entities::{users, Users};
+use crate::repository::pagination::{filter_date_range, paginate};
+use tracing::instrument;
 
 pub struct UserRepository {
     db_pool: DatabaseConnection,
@@ -14,10 +18,21 @@ impl UserRepository {
     pub fn new(db_pool: DatabaseConnection) -> Self {
         Self { db_pool }
     }
+
+    fn sort_column(field: &str) -> users::Column {
+        match field {
+            "firstname" => users::Column::Firstname,
+            "lastname" => users::Column::Lastname,
+            "email" => users::Column::Email,
+            "created_at" => users::Column::CreatedAt,
+            _ => users::Column::UserId,
+        }
+    }
 }
 
 #[async_trait]
 impl UserRepositoryTrait for UserRepository {
+    #[instrument(skip_all, fields(db.operation = "SELECT", db.sql.table = "users"))]
     async fn find_by_email(&self, email: &str) -> Result<Option<users::Model>, DbErr> {
         users::Entity::find()
             .filter(users::Column::Email.eq(email))
@@ -25,6 +40,7 @@ impl UserRepositoryTrait for UserRepository {
             .await
     }
 
+    #[instrument(skip_all, fields(db.operation = "SELECT", db.sql.table = "users"))]
     async fn find_by_email_exists(&self, email: &str) -> Result<bool, DbErr> {
         let user_count = users::Entity::find()
             .filter(users::Column::Email.eq(email))
@@ -33,31 +49,61 @@ impl UserRepositoryTrait for UserRepository {
         Ok(user_count > 0)
     }
 
-    async fn find_all(&self) -> Result<Vec<users::Model>, DbErr> {
-        Users::find().all(&self.db_pool).await
+    #[instrument(skip_all, fields(db.operation = "SELECT", db.sql.table = "users"))]
+    async fn find_paginated(
+        &self,
+        input: &ListRequest,
+    ) -> Result<(Vec<users::Model>, Pagination), DbErr> {
+        let mut query = Users::find();
+
+        if let Some(status) = &input.status {
+            query = query.filter(users::Column::AccountStatus.eq(status.trim()));
+        }
+
+        let query = filter_date_range(query, users::Column::CreatedAt, input);
+
+        paginate(
+            &self.db_pool,
+            query,
+            input,
+            &USER_LIST_FIELDS,
+            Self::sort_column,
+            |user| user.user_id,
+        )
+        .await
     }
 
+    #[instrument(skip_all, fields(db.operation = "SELECT", db.sql.table = "users", user_id = id))]
     async fn find_by_id(&self, id: i32) -> Result<Option<users::Model>, DbErr> {
         Users::find_by_id(id).one(&self.db_pool).await
     }
 
+    #[instrument(skip_all, fields(db.operation = "SELECT", db.sql.table = "users"))]
+    async fn find_by_noc_transfer(&self, noc_transfer: &str) -> Result<Option<users::Model>, DbErr> {
+        users::Entity::find()
+            .filter(users::Column::NocTransfer.eq(noc_transfer))
+            .one(&self.db_pool)
+            .await
+    }
+
+    #[instrument(skip_all, fields(db.operation = "INSERT", db.sql.table = "users"))]
     async fn create_user(&self, input: &CreateUserRequest) -> Result<users::Model, DbErr> {
         let user = users::ActiveModel {
             firstname: Set(input.firstname.clone()),
             lastname: Set(input.lastname.clone()),
             email: Set(input.email.clone()),
             password: Set(input.password.clone()),
-            noc_transfer: Set(input
-                .noc_transfer
-                .as_ref()
-                .map(|s| s.clone())
-                .unwrap_or_default()),
+            noc_transfer: Set(input.noc_transfer.clone().unwrap_or_default()),
             ..Default::default()
         };
 
         user.insert(&self.db_pool).await
     }
 
+    #[instrument(
+        skip_all,
+        fields(db.operation = "UPDATE", db.sql.table = "users", user_id = ?input.id)
+    )]
     async fn update_user(&self, input: &UpdateUserRequest) -> Result<users::Model, DbErr> {
         let id = match input.id {
             Some(id) => id,
@@ -84,15 +130,4 @@ impl UserRepositoryTrait for UserRepository {
 
         user.update(&self.db_pool).await
     }
-
-    async fn delete_user(&self, id: i32) -> Result<(), DbErr> {
-        let user: users::ActiveModel = users::Entity::find()
-            .filter(users::Column::UserId.eq(id))
-            .one(&self.db_pool)
-            .await?
-            .ok_or(DbErr::Custom("User not found".to_string()))?
-            .into();
-
-        user.delete(&self.db_pool).await.map(|_| ())
-    }
 }
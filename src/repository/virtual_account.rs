@@ -0,0 +1,63 @@
+use async_trait::async_trait;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter, Set,
+};
+
+use crate::{
+    abstract_trait::virtual_account::VirtualAccountRepositoryTrait,
+    domain::request::virtual_account::CreateVirtualAccountRequest,
+    entities::virtual_accounts,
+};
+use tracing::instrument;
+
+pub struct VirtualAccountRepository {
+    db_pool: DatabaseConnection,
+}
+
+impl VirtualAccountRepository {
+    pub fn new(db_pool: DatabaseConnection) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl VirtualAccountRepositoryTrait for VirtualAccountRepository {
+    #[instrument(
+        skip_all,
+        fields(db.operation = "SELECT", db.sql.table = "virtual_accounts", user_id = user_id)
+    )]
+    async fn find_by_user(&self, user_id: i32) -> Result<Vec<virtual_accounts::Model>, DbErr> {
+        virtual_accounts::Entity::find()
+            .filter(virtual_accounts::Column::UserId.eq(user_id))
+            .all(&self.db_pool)
+            .await
+    }
+
+    #[instrument(skip_all, fields(db.operation = "SELECT", db.sql.table = "virtual_accounts"))]
+    async fn find_by_va_number(
+        &self,
+        va_number: &str,
+    ) -> Result<Option<virtual_accounts::Model>, DbErr> {
+        virtual_accounts::Entity::find()
+            .filter(virtual_accounts::Column::VaNumber.eq(va_number))
+            .one(&self.db_pool)
+            .await
+    }
+
+    #[instrument(
+        skip_all,
+        fields(db.operation = "INSERT", db.sql.table = "virtual_accounts", user_id = input.user_id)
+    )]
+    async fn create(
+        &self,
+        input: &CreateVirtualAccountRequest,
+    ) -> Result<virtual_accounts::Model, DbErr> {
+        let new_virtual_account = virtual_accounts::ActiveModel {
+            user_id: Set(input.user_id),
+            bank_code: Set(input.bank_code.clone()),
+            va_number: Set(input.va_number.clone()),
+            ..Default::default()
+        };
+        new_virtual_account.insert(&self.db_pool).await
+    }
+}
@@ -1,13 +1,27 @@
 use async_trait::async_trait;
+use chrono::{NaiveDateTime, Utc};
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter, Set,
+    sea_query::Expr, ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait,
+    QueryFilter, QueryOrder, QuerySelect, Set,
 };
 
 use crate::{
     abstract_trait::withdraw::WithdrawRepositoryTrait,
-    domain::request::withdraw::{CreateWithdrawRequest, UpdateWithdrawRequest},
+    domain::{
+        request::{
+            pagination::ListRequest,
+            withdraw::{
+                CreateWithdrawRequest, UpdateWithdrawPayoutRequest, UpdateWithdrawRequest,
+                WITHDRAW_LIST_FIELDS, WITHDRAW_STATUS_FAILED, WITHDRAW_STATUS_PENDING,
+            },
+        },
+        response::pagination::Pagination,
+    },
     entities::withdraws,
+    repository::pagination::{filter_amount_range, filter_date_range, paginate},
+    utils::fee::FeeQuote,
 };
+use tracing::instrument;
 
 pub struct WithdrawRepository {
     db_pool: DatabaseConnection,
@@ -17,18 +31,60 @@ impl WithdrawRepository {
     pub fn new(db_pool: DatabaseConnection) -> Self {
         Self { db_pool }
     }
+
+    fn sort_column(field: &str) -> withdraws::Column {
+        match field {
+            "withdraw_amount" => withdraws::Column::WithdrawAmount,
+            "withdraw_time" => withdraws::Column::WithdrawTime,
+            "created_at" => withdraws::Column::CreatedAt,
+            _ => withdraws::Column::WithdrawId,
+        }
+    }
 }
 
 #[async_trait]
 impl WithdrawRepositoryTrait for WithdrawRepository {
-    async fn find_all(&self) -> Result<Vec<withdraws::Model>, DbErr> {
-        withdraws::Entity::find().all(&self.db_pool).await
+    #[instrument(skip_all, fields(db.operation = "SELECT", db.sql.table = "withdraws"))]
+    async fn find_paginated(
+        &self,
+        input: &ListRequest,
+    ) -> Result<(Vec<withdraws::Model>, Pagination), DbErr> {
+        let mut query = withdraws::Entity::find();
+
+        if let Some(user_id) = input.user_id {
+            query = query.filter(withdraws::Column::UserId.eq(user_id));
+        }
+
+        if let Some(status) = &input.status {
+            query = query.filter(withdraws::Column::Status.eq(status.trim()));
+        }
+
+        let query = filter_date_range(query, withdraws::Column::WithdrawTime, input);
+        let query = filter_amount_range(query, withdraws::Column::WithdrawAmount, input);
+
+        paginate(
+            &self.db_pool,
+            query,
+            input,
+            &WITHDRAW_LIST_FIELDS,
+            Self::sort_column,
+            |withdraw| withdraw.withdraw_id,
+        )
+        .await
     }
 
+    #[instrument(
+        skip_all,
+        fields(db.operation = "SELECT", db.sql.table = "withdraws", withdraw_id = id)
+    )]
     async fn find_by_id(&self, id: i32) -> Result<Option<withdraws::Model>, DbErr> {
         withdraws::Entity::find_by_id(id).one(&self.db_pool).await
     }
 
+    #[instrument(
+        skip_all,
+        fields(db.operation = "SELECT", db.sql.table = "withdraws", user_id = id)
+    )]
     async fn find_by_users(&self, id: i32) -> Result<Option<Vec<withdraws::Model>>, DbErr> {
         let results = withdraws::Entity::find()
             .filter(withdraws::Column::UserId.eq(id))
@@ -37,6 +93,10 @@ impl WithdrawRepositoryTrait for WithdrawRepository {
         Ok(Some(results))
     }
 
+    #[instrument(
+        skip_all,
+        fields(db.operation = "SELECT", db.sql.table = "withdraws", user_id = id)
+    )]
     async fn find_by_user(&self, id: i32) -> Result<Option<withdraws::Model>, DbErr> {
         withdraws::Entity::find()
             .filter(withdraws::Column::UserId.eq(id))
@@ -44,36 +104,129 @@ impl WithdrawRepositoryTrait for WithdrawRepository {
             .await
     }
 
-    async fn create(&self, input: &CreateWithdrawRequest) -> Result<withdraws::Model, DbErr> {
+    #[instrument(
+        skip_all,
+        fields(
+            db.operation = "INSERT",
+            db.sql.table = "withdraws",
+            user_id = input.user_id,
+            beneficiary_id = input.beneficiary_id,
+        )
+    )]
+    async fn create(
+        &self,
+        input: &CreateWithdrawRequest,
+        fee: &FeeQuote,
+        status: &str,
+    ) -> Result<withdraws::Model, DbErr> {
         let withdraw_time_naive = input.withdraw_time.naive_utc();
 
         let new_withdraw = withdraws::ActiveModel {
             user_id: Set(input.user_id),
+            beneficiary_id: Set(Some(input.beneficiary_id)),
             withdraw_amount: Set(input.withdraw_amount),
             withdraw_time: Set(withdraw_time_naive),
+            status: Set(status.to_string()),
+            fee_amount: Set(fee.fee_amount),
+            fee_rule: Set(fee.fee_rule.clone()),
             ..Default::default()
         };
 
         new_withdraw.insert(&self.db_pool).await
     }
 
-    async fn update(&self, input: &UpdateWithdrawRequest) -> Result<withdraws::Model, DbErr> {
-        let mut withdraw_record: withdraws::ActiveModel =
-            withdraws::Entity::find_by_id(input.withdraw_id)
-                .one(&self.db_pool)
-                .await?
-                .ok_or(DbErr::RecordNotFound("Withdraw not found".to_owned()))?
-                .into();
+    #[instrument(
+        skip_all,
+        fields(
+            db.operation = "UPDATE",
+            db.sql.table = "withdraws",
+            user_id = input.user_id,
+            withdraw_id = input.withdraw_id,
+        )
+    )]
+    async fn update(
+        &self,
+        input: &UpdateWithdrawRequest,
+        fee: &FeeQuote,
+    ) -> Result<Option<withdraws::Model>, DbErr> {
+        // Once the payout has been claimed the amount sent to the provider is fixed
+        let updated = withdraws::Entity::update_many()
+            .col_expr(withdraws::Column::WithdrawAmount, Expr::value(input.withdraw_amount))
+            .col_expr(withdraws::Column::FeeAmount, Expr::value(fee.fee_amount))
+            .col_expr(withdraws::Column::FeeRule, Expr::value(fee.fee_rule.clone()))
+            .col_expr(
+                withdraws::Column::WithdrawTime,
+                Expr::value(input.withdraw_time.naive_utc()),
+            )
+            .col_expr(withdraws::Column::UpdatedAt, Expr::value(Utc::now().naive_utc()))
+            .filter(withdraws::Column::WithdrawId.eq(input.withdraw_id))
+            .filter(withdraws::Column::UserId.eq(input.user_id))
+            .filter(withdraws::Column::Status.eq(WITHDRAW_STATUS_PENDING))
+            .exec_with_returning(&self.db_pool)
+            .await?;
 
-        let withdraw_time_naive = input.withdraw_time.naive_utc();
+        Ok(updated.into_iter().next())
+    }
 
-        withdraw_record.withdraw_amount = Set(input.withdraw_amount);
+    #[instrument(skip_all, fields(db.operation = "SELECT", db.sql.table = "withdraws"))]
+    async fn find_by_status(&self, status: &str) -> Result<Vec<withdraws::Model>, DbErr> {
+        withdraws::Entity::find()
+            .filter(withdraws::Column::Status.eq(status))
+            .order_by_asc(withdraws::Column::WithdrawId)
+            .all(&self.db_pool)
+            .await
+    }
+
+    #[instrument(
+        skip_all,
+        fields(db.operation = "UPDATE", db.sql.table = "withdraws", withdraw_id = input.withdraw_id)
+    )]
+    async fn update_payout(&self, input: &UpdateWithdrawPayoutRequest) -> Result<bool, DbErr> {
+        let mut update = withdraws::Entity::update_many()
+            .col_expr(withdraws::Column::Status, Expr::value(input.status.clone()))
+            .col_expr(withdraws::Column::FailureReason, Expr::value(input.failure_reason.clone()))
+            .col_expr(withdraws::Column::UpdatedAt, Expr::value(Utc::now().naive_utc()));
+
+        if let Some(reference) = &input.payout_reference {
+            update = update.col_expr(
+                withdraws::Column::PayoutReference,
+                Expr::value(reference.clone()),
+            );
+        }
 
-        withdraw_record.withdraw_time = Set(withdraw_time_naive);
+        // Guarding on the current status keeps concurrent workers from applying a transition twice
+        let result = update
+            .filter(withdraws::Column::WithdrawId.eq(input.withdraw_id))
+            .filter(withdraws::Column::Status.eq(input.from_status.clone()))
+            .exec(&self.db_pool)
+            .await?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    #[instrument(
+        skip_all,
+        fields(db.operation = "SELECT", db.sql.table = "withdraws", user_id = user_id)
+    )]
+    async fn sum_since(&self, user_id: i32, since: NaiveDateTime) -> Result<i64, DbErr> {
+        // Failed payouts were refunded, so they don't count against the user's limits
+        let total: Option<Option<i64>> = withdraws::Entity::find()
+            .select_only()
+            .column_as(withdraws::Column::WithdrawAmount.sum(), "total")
+            .filter(withdraws::Column::UserId.eq(user_id))
+            .filter(withdraws::Column::WithdrawTime.gte(since))
+            .filter(withdraws::Column::Status.ne(WITHDRAW_STATUS_FAILED))
+            .into_tuple()
+            .one(&self.db_pool)
+            .await?;
 
-        withdraw_record.update(&self.db_pool).await
+        Ok(total.flatten().unwrap_or(0))
     }
 
+    #[instrument(
+        skip_all,
+        fields(db.operation = "DELETE", db.sql.table = "withdraws", withdraw_id = id)
+    )]
     async fn delete(&self, id: i32) -> Result<(), DbErr> {
         let result = withdraws::Entity::delete_many()
             .filter(withdraws::Column::UserId.eq(id))
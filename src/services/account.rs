@@ -0,0 +1,57 @@
+use async_trait::async_trait;
+use tracing::{error, instrument};
+
+use crate::{
+    abstract_trait::{account::AccountServiceTrait, user::DynUserRepository},
+    domain::response::{account::AccountLookupResponse, ApiResponse, ErrorResponse},
+    entities::users,
+    utils::{errors::AppError, random_vcc::is_valid_vcc},
+};
+
+pub struct AccountService {
+    user_repository: DynUserRepository,
+}
+
+impl AccountService {
+    pub fn new(user_repository: DynUserRepository) -> Self {
+        Self { user_repository }
+    }
+
+    async fn find_by_noc_transfer(&self, noc_transfer: &str) -> Result<users::Model, ErrorResponse> {
+        if !is_valid_vcc(noc_transfer) {
+            return Err(ErrorResponse::from(AppError::ValidationError(
+                "Account number is invalid".to_string(),
+            )));
+        }
+
+        self.user_repository
+            .find_by_noc_transfer(noc_transfer)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .ok_or_else(|| {
+                error!("Account {} not found", noc_transfer);
+                ErrorResponse::from(AppError::NotFound(format!(
+                    "Account {} not found",
+                    noc_transfer
+                )))
+            })
+    }
+}
+
+#[async_trait]
+impl AccountServiceTrait for AccountService {
+    #[instrument(skip_all)]
+    async fn lookup_account(
+        &self,
+        noc_transfer: &str,
+    ) -> Result<ApiResponse<AccountLookupResponse>, ErrorResponse> {
+        let user = self.find_by_noc_transfer(noc_transfer.trim()).await?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Account retrieved successfully".to_string(),
+            data: AccountLookupResponse::from(user),
+        })
+    }
+}
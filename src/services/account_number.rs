@@ -0,0 +1,140 @@
+use async_trait::async_trait;
+use sea_orm::{DbErr, SqlErr};
+use tracing::{error, info, warn, instrument};
+
+use crate::{
+    abstract_trait::{
+        account_number::{AccountNumberAllocatorTrait, DynAccountNumberRepository},
+        user::DynUserRepository,
+    },
+    domain::{request::user::CreateUserRequest, response::ErrorResponse},
+    entities::users,
+    utils::{
+        account_number::{permute, AccountNumberFormat, AccountNumberScheme},
+        errors::AppError,
+    },
+};
+
+pub struct AccountNumberAllocator {
+    account_number_repository: DynAccountNumberRepository,
+    user_repository: DynUserRepository,
+    format: AccountNumberFormat,
+    scheme: AccountNumberScheme,
+    key: u64,
+    max_attempts: u32,
+}
+
+impl AccountNumberAllocator {
+    pub fn new(
+        account_number_repository: DynAccountNumberRepository,
+        user_repository: DynUserRepository,
+        format: AccountNumberFormat,
+        scheme: AccountNumberScheme,
+        key: u64,
+        max_attempts: u32,
+    ) -> Self {
+        Self {
+            account_number_repository,
+            user_repository,
+            format,
+            scheme,
+            key,
+            max_attempts: max_attempts.max(1),
+        }
+    }
+
+    async fn next_body(&self) -> Result<u64, ErrorResponse> {
+        if self.scheme == AccountNumberScheme::Random {
+            return Ok(self.format.random_body());
+        }
+
+        let sequence = self
+            .account_number_repository
+            .next_sequence()
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        let capacity = self.format.capacity();
+
+        if sequence < 0 || sequence as u64 >= capacity {
+            error!("Account number sequence {} exceeds capacity {}", sequence, capacity);
+            return Err(ErrorResponse::from(AppError::InternalError(
+                "Account number space is exhausted".to_string(),
+            )));
+        }
+
+        Ok(match self.scheme {
+            AccountNumberScheme::EncryptedSequence => permute(sequence as u64, self.key, capacity),
+            _ => sequence as u64,
+        })
+    }
+
+    fn is_noc_transfer_collision(err: &DbErr) -> bool {
+        matches!(
+            err.sql_err(),
+            Some(SqlErr::UniqueConstraintViolation(message)) if message.contains("noc_transfer")
+        )
+    }
+}
+
+#[async_trait]
+impl AccountNumberAllocatorTrait for AccountNumberAllocator {
+    #[instrument(skip_all)]
+    async fn allocate(&self) -> Result<String, ErrorResponse> {
+        for attempt in 1..=self.max_attempts {
+            let candidate = self.format.compose(self.next_body().await?);
+
+            let exists = self
+                .account_number_repository
+                .exists(&candidate)
+                .await
+                .map_err(AppError::from)
+                .map_err(ErrorResponse::from)?;
+
+            if !exists {
+                return Ok(candidate);
+            }
+
+            warn!(
+                "Account number collision on attempt {} of {}",
+                attempt, self.max_attempts
+            );
+        }
+
+        error!(
+            "Failed to allocate a unique account number after {} attempts",
+            self.max_attempts
+        );
+
+        Err(ErrorResponse::from(AppError::InternalError(
+            "Unable to allocate a unique account number".to_string(),
+        )))
+    }
+
+    // Another request can still claim the same number between the check and the insert,
+    // so a unique violation on noc_transfer allocates a fresh number and retries
+    #[instrument(skip_all)]
+    async fn create_user(&self, input: &CreateUserRequest) -> Result<users::Model, ErrorResponse> {
+        let mut request = input.clone();
+
+        for attempt in 1..=self.max_attempts {
+            request.noc_transfer = Some(self.allocate().await?);
+
+            match self.user_repository.create_user(&request).await {
+                Ok(user) => {
+                    info!("Allocated account number for user {}", user.user_id);
+                    return Ok(user);
+                }
+                Err(e) if Self::is_noc_transfer_collision(&e) && attempt < self.max_attempts => {
+                    warn!("Account number taken during insert, retrying (attempt {})", attempt);
+                }
+                Err(e) => return Err(ErrorResponse::from(AppError::from(e))),
+            }
+        }
+
+        Err(ErrorResponse::from(AppError::InternalError(
+            "Unable to allocate a unique account number".to_string(),
+        )))
+    }
+}
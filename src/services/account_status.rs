@@ -0,0 +1,154 @@
+use async_trait::async_trait;
+use tracing::{error, info, instrument, warn};
+use validator::Validate;
+
+use crate::{
+    abstract_trait::{
+        account_status::{AccountStatusServiceTrait, DynAccountStatusRepository},
+        saldo::DynSaldoRepository,
+        user::DynUserRepository,
+    },
+    domain::{
+        request::account_status::{CreateAccountStatusChangeRequest, UpdateAccountStatusRequest},
+        response::{
+            account_status::AccountStatusChangeResponse, user::UserResponse, ApiResponse,
+            ErrorResponse,
+        },
+    },
+    utils::{account_status::AccountStatus, errors::AppError, rupiah::rupiah_format},
+};
+
+pub struct AccountStatusService {
+    account_status_repository: DynAccountStatusRepository,
+    user_repository: DynUserRepository,
+    saldo_repository: DynSaldoRepository,
+}
+
+impl AccountStatusService {
+    pub fn new(
+        account_status_repository: DynAccountStatusRepository,
+        user_repository: DynUserRepository,
+        saldo_repository: DynSaldoRepository,
+    ) -> Self {
+        Self {
+            account_status_repository,
+            user_repository,
+            saldo_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl AccountStatusServiceTrait for AccountStatusService {
+    #[instrument(skip_all)]
+    async fn update_status(
+        &self,
+        input: &UpdateAccountStatusRequest,
+    ) -> Result<ApiResponse<UserResponse>, ErrorResponse> {
+        if let Err(validation_err) = input.validate() {
+            error!("Validation failed for account status: {}", validation_err);
+            return Err(ErrorResponse::from(AppError::InvalidFields(validation_err)));
+        }
+
+        let user = self
+            .user_repository
+            .find_by_id(input.user_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .ok_or_else(|| {
+                ErrorResponse::from(AppError::UserNotFound(format!(
+                    "User with id {} not found",
+                    input.user_id
+                )))
+            })?;
+
+        let previous_status: AccountStatus = user.account_status.parse().map_err(|e| {
+            warn!("User {} has {}", user.user_id, e);
+            ErrorResponse::from(AppError::ValidationError(e))
+        })?;
+
+        previous_status
+            .can_transition_to(input.status)
+            .map_err(|e| ErrorResponse::from(AppError::ValidationError(e)))?;
+
+        // Closing keeps every row; the balance has to be paid out first so no funds are stranded
+        if input.status == AccountStatus::Closed {
+            let balance = self
+                .saldo_repository
+                .find_by_user_id(input.user_id)
+                .await
+                .map_err(AppError::from)
+                .map_err(ErrorResponse::from)?
+                .map_or(0, |saldo| saldo.total_balance);
+
+            if balance != 0 {
+                return Err(ErrorResponse::from(AppError::ValidationError(format!(
+                    "Account balance must be zero before closing; current balance is {}",
+                    rupiah_format(&balance.to_string())
+                ))));
+            }
+        }
+
+        let user = self
+            .account_status_repository
+            .change(&CreateAccountStatusChangeRequest {
+                user_id: input.user_id,
+                previous_status,
+                new_status: input.status,
+                reason: input.reason.clone(),
+                changed_by: input.changed_by,
+            })
+            .await
+            .map_err(|e| {
+                error!(
+                    "Failed to change status of account {}: {}",
+                    input.user_id, e
+                );
+                ErrorResponse::from(AppError::from(e))
+            })?
+            .ok_or_else(|| {
+                ErrorResponse::from(AppError::ValidationError(format!(
+                    "Status of account {} changed concurrently; please retry",
+                    input.user_id
+                )))
+            })?;
+
+        info!(
+            "Account {} changed from {} to {} by user {}: {}",
+            input.user_id,
+            previous_status,
+            input.status,
+            input.changed_by,
+            input.reason.trim()
+        );
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: format!("Account is now {}", input.status),
+            data: UserResponse::from(user),
+        })
+    }
+
+    #[instrument(skip_all, fields(user_id = user_id))]
+    async fn get_status_history(
+        &self,
+        user_id: i32,
+    ) -> Result<ApiResponse<Vec<AccountStatusChangeResponse>>, ErrorResponse> {
+        let changes = self
+            .account_status_repository
+            .find_by_user(user_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Status history retrieved successfully".to_string(),
+            data: changes
+                .into_iter()
+                .map(AccountStatusChangeResponse::from)
+                .collect(),
+        })
+    }
+}
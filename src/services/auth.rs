@@ -1,37 +1,44 @@
 use async_trait::async_trait;
-use tracing::{info, error};
+use tracing::{info, error, warn, instrument};
+use validator::Validate;
 
 use crate::{
-    abstract_trait::{auth::AuthServiceTrait, user::DynUserRepository},
+    abstract_trait::{account_number::DynAccountNumberAllocator, auth::AuthServiceTrait, screening::DynScreeningService, user::DynUserRepository},
     config::{hashing::Hashing, jwt_config::JwtConfig},
     domain::{
         request::{
             auth::{LoginRequest, RegisterRequest},
+            screening::SCREENING_CONTEXT_REGISTRATION,
             user::CreateUserRequest,
         },
         response::{user::UserResponse, ApiResponse, ErrorResponse},
     },
-    utils::{errors::AppError, random_vcc::random_vcc},
+    utils::{account_status::AccountStatus, errors::AppError},
 };
 
 pub struct AuthService {
     repository: DynUserRepository,
     hashing: Hashing,
     jwt_config: JwtConfig,
+    account_number_allocator: DynAccountNumberAllocator,
+    screening_service: DynScreeningService,
 }
 
 impl AuthService {
-    pub fn new(repository: DynUserRepository, hashing: Hashing, jwt_config: JwtConfig) -> Self {
+    pub fn new(repository: DynUserRepository, hashing: Hashing, jwt_config: JwtConfig, account_number_allocator: DynAccountNumberAllocator, screening_service: DynScreeningService) -> Self {
         Self {
             repository,
             hashing,
             jwt_config,
+            account_number_allocator,
+            screening_service,
         }
     }
 }
 
 #[async_trait]
 impl AuthServiceTrait for AuthService {
+    #[instrument(skip_all)]
     async fn register_user(
         &self,
         input: &RegisterRequest,
@@ -52,7 +59,7 @@ impl AuthServiceTrait for AuthService {
 
         if let Err(validation_err) = input.validate() {
             error!("Validation failed for user registration: {}", validation_err);
-            return Err(ErrorResponse::from(AppError::ValidationError(validation_err)));
+            return Err(ErrorResponse::from(AppError::InvalidFields(validation_err)));
         }
 
         let hashed_password = self
@@ -61,30 +68,30 @@ impl AuthServiceTrait for AuthService {
             .await
             .map_err(|e| ErrorResponse::from(AppError::HashingError(e)))?;
 
-        let noc_transfer = random_vcc().map(|value| Some(value)).unwrap_or(None);
-
-
-
-
         let request = CreateUserRequest {
             firstname: input.firstname.clone(),
             lastname: input.lastname.clone(),
             email: input.email.clone(),
             password: hashed_password,
             confirm_password: input.confirm_password.clone(),
-            noc_transfer: noc_transfer.to_owned(),
+            noc_transfer: None,
         };
 
         info!("Creating user with email: {}", input.email);
         let create_user = self
-            .repository
+            .account_number_allocator
             .create_user(&request)
-            .await
-            .map_err(AppError::from)
-            .map_err(ErrorResponse::from)?;
+            .await?;
 
         info!("User registered successfully with email: {}", input.email);
 
+        // A watchlist hit does not stop registration; it puts the user in the review queue
+        match self.screening_service.screen_user(&create_user, SCREENING_CONTEXT_REGISTRATION).await {
+            Ok(0) => {}
+            Ok(open) => warn!("User {} has {} watchlist match(es) awaiting review", create_user.user_id, open),
+            Err(e) => error!("Failed to screen user {}: {}", create_user.user_id, e),
+        }
+
         Ok(ApiResponse {
             status: "success".to_string(),
             message: "User registered successfully".to_string(),
@@ -92,6 +99,7 @@ impl AuthServiceTrait for AuthService {
         })
     }
 
+    #[instrument(skip_all)]
     async fn login_user(&self, input: &LoginRequest) -> Result<ApiResponse<String>, ErrorResponse> {
         info!("Attempting to login user with email: {}", input.email);
 
@@ -101,12 +109,13 @@ impl AuthServiceTrait for AuthService {
             .await
             .map_err(AppError::from)
             .map_err(ErrorResponse::from)?
-            .ok_or_else(|| ErrorResponse::from(AppError::NotFound("User not found".to_string())))?;
+            // An unknown email is reported like a wrong password so logins cannot probe for accounts
+            .ok_or_else(|| ErrorResponse::from(AppError::InvalidCredentials))?;
 
 
             if let Err(validation_err) = input.validate() {
                 error!("Validation failed for user login: {}", validation_err);
-                return Err(ErrorResponse::from(AppError::ValidationError(validation_err)));
+                return Err(ErrorResponse::from(AppError::InvalidFields(validation_err)));
             }
 
         if self
@@ -119,6 +128,11 @@ impl AuthServiceTrait for AuthService {
             return Err(ErrorResponse::from(AppError::InvalidCredentials));
         }
 
+        if user.account_status == AccountStatus::Closed.as_str() {
+            error!("Login attempt on closed account: {}", input.email);
+            return Err(ErrorResponse::from(AppError::AccountRestricted("Account is closed".to_string())));
+        }
+
         let token = self
             .jwt_config
             .generate_token(user.user_id as i64)
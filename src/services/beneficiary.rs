@@ -0,0 +1,136 @@
+use async_trait::async_trait;
+use tracing::{error, info, instrument};
+use validator::Validate;
+
+use crate::{
+    abstract_trait::{
+        beneficiary::{BeneficiaryServiceTrait, DynBeneficiaryRepository},
+        user::DynUserRepository,
+    },
+    domain::{
+        request::beneficiary::CreateBeneficiaryRequest,
+        response::{beneficiary::BeneficiaryResponse, ApiResponse, ErrorResponse},
+    },
+    utils::errors::AppError,
+};
+
+pub struct BeneficiaryService {
+    beneficiary_repository: DynBeneficiaryRepository,
+    user_repository: DynUserRepository,
+}
+
+impl BeneficiaryService {
+    pub fn new(
+        beneficiary_repository: DynBeneficiaryRepository,
+        user_repository: DynUserRepository,
+    ) -> Self {
+        Self {
+            beneficiary_repository,
+            user_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl BeneficiaryServiceTrait for BeneficiaryService {
+    #[instrument(skip_all, fields(user_id = user_id))]
+    async fn get_beneficiary_users(
+        &self,
+        user_id: i32,
+    ) -> Result<ApiResponse<Vec<BeneficiaryResponse>>, ErrorResponse> {
+        let beneficiaries = self
+            .beneficiary_repository
+            .find_by_user(user_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Beneficiaries retrieved successfully".to_string(),
+            data: beneficiaries
+                .into_iter()
+                .map(BeneficiaryResponse::from)
+                .collect(),
+        })
+    }
+
+    #[instrument(skip_all)]
+    async fn create_beneficiary(
+        &self,
+        input: &CreateBeneficiaryRequest,
+    ) -> Result<ApiResponse<BeneficiaryResponse>, ErrorResponse> {
+        if let Err(validation_err) = input.validate() {
+            error!("Validation failed for beneficiary create: {}", validation_err);
+            return Err(ErrorResponse::from(AppError::InvalidFields(validation_err)));
+        }
+
+        self.user_repository
+            .find_by_id(input.user_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .ok_or_else(|| {
+                error!("User with id {} not found", input.user_id);
+                ErrorResponse::from(AppError::UserNotFound(format!(
+                    "User with id {} not found",
+                    input.user_id
+                )))
+            })?;
+
+        let beneficiary = self
+            .beneficiary_repository
+            .create(input)
+            .await
+            .map_err(|e| {
+                error!("Failed to save beneficiary for user {}: {}", input.user_id, e);
+                ErrorResponse::from(AppError::from(e))
+            })?;
+
+        info!(
+            "Beneficiary {} saved for user {}",
+            beneficiary.beneficiary_id, input.user_id
+        );
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Beneficiary created successfully".to_string(),
+            data: BeneficiaryResponse::from(beneficiary),
+        })
+    }
+
+    #[instrument(skip_all, fields(id = id, user_id = user_id))]
+    async fn delete_beneficiary(
+        &self,
+        id: i32,
+        user_id: i32,
+    ) -> Result<ApiResponse<()>, ErrorResponse> {
+        let beneficiary = self
+            .beneficiary_repository
+            .find_by_id(id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .filter(|beneficiary| beneficiary.user_id == user_id)
+            .ok_or_else(|| {
+                ErrorResponse::from(AppError::NotFound(format!(
+                    "Beneficiary with id {} not found",
+                    id
+                )))
+            })?;
+
+        self.beneficiary_repository
+            .delete(beneficiary.beneficiary_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        info!("Beneficiary {} deleted by user {}", id, user_id);
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Beneficiary deleted successfully".to_string(),
+            data: (),
+        })
+    }
+}
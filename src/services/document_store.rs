@@ -0,0 +1,72 @@
+use std::path::{Component, Path, PathBuf};
+
+use async_trait::async_trait;
+use tracing::{info, instrument};
+
+use crate::abstract_trait::document_store::DocumentStoreTrait;
+
+// Keeps documents as plain files under a root directory, one file per key
+pub struct LocalDocumentStore {
+    root: PathBuf,
+}
+
+impl LocalDocumentStore {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    fn path_for(&self, key: &str) -> Result<PathBuf, String> {
+        let relative = Path::new(key);
+
+        // Keys are generated by the services, but never let one escape the root
+        if key.is_empty()
+            || !relative
+                .components()
+                .all(|component| matches!(component, Component::Normal(_)))
+        {
+            return Err(format!("Invalid document key '{}'", key));
+        }
+
+        Ok(self.root.join(relative))
+    }
+}
+
+#[async_trait]
+impl DocumentStoreTrait for LocalDocumentStore {
+    #[instrument(skip_all)]
+    async fn put(&self, key: &str, content: &[u8]) -> Result<(), String> {
+        let path = self.path_for(key)?;
+
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+        }
+
+        tokio::fs::write(&path, content)
+            .await
+            .map_err(|e| format!("Failed to write document {}: {}", key, e))?;
+
+        info!("Stored document {} ({} bytes)", key, content.len());
+
+        Ok(())
+    }
+
+    #[instrument(skip_all)]
+    async fn get(&self, key: &str) -> Result<Vec<u8>, String> {
+        let path = self.path_for(key)?;
+
+        tokio::fs::read(&path)
+            .await
+            .map_err(|e| format!("Failed to read document {}: {}", key, e))
+    }
+
+    #[instrument(skip_all)]
+    async fn delete(&self, key: &str) -> Result<(), String> {
+        let path = self.path_for(key)?;
+
+        tokio::fs::remove_file(&path)
+            .await
+            .map_err(|e| format!("Failed to delete document {}: {}", key, e))
+    }
+}
@@ -0,0 +1,202 @@
+use async_trait::async_trait;
+use tracing::{error, info, instrument, warn};
+use validator::Validate;
+
+use crate::{
+    abstract_trait::fee::{DynFeeRepository, FeeServiceTrait},
+    domain::{
+        request::fee::{FeePreviewRequest, FEE_ENTRY_STATUS_PENDING},
+        response::{fee::FeePreviewResponse, ApiResponse, ErrorResponse},
+    },
+    utils::{
+        errors::AppError,
+        fee::{FeeOperation, FeeQuote, FeeSchedule},
+    },
+};
+
+pub struct FeeService {
+    fee_schedule: FeeSchedule,
+    fee_repository: DynFeeRepository,
+    platform_revenue_user_id: Option<i32>,
+}
+
+impl FeeService {
+    pub fn new(
+        fee_schedule: FeeSchedule,
+        fee_repository: DynFeeRepository,
+        platform_revenue_user_id: Option<i32>,
+    ) -> Self {
+        Self {
+            fee_schedule,
+            fee_repository,
+            platform_revenue_user_id,
+        }
+    }
+
+    fn revenue_user_id(&self) -> Result<i32, ErrorResponse> {
+        // Config refuses to start with fee rules but no revenue account, so this is a wiring bug
+        self.platform_revenue_user_id.ok_or_else(|| {
+            error!("No platform revenue account configured");
+            ErrorResponse::from(AppError::InternalError(
+                "No platform revenue account configured".to_string(),
+            ))
+        })
+    }
+
+    // Books the fee, or leaves it queued for the retry job when booking fails
+    async fn adjust_revenue(&self, fee: &FeeQuote, delta: i32) -> Result<(), ErrorResponse> {
+        let revenue_user_id = self.revenue_user_id()?;
+
+        let Err(e) = self
+            .fee_repository
+            .book(revenue_user_id, delta, fee.fee_rule.clone())
+            .await
+        else {
+            return Ok(());
+        };
+
+        warn!(
+            "Failed to book fee of {} to platform revenue, queueing it for retry: {}",
+            delta, e
+        );
+
+        self.fee_repository
+            .queue(delta, fee.fee_rule.clone())
+            .await
+            .map(|_| ())
+            .map_err(|e| {
+                error!("Failed to queue fee of {} for retry: {}", delta, e);
+                ErrorResponse::from(AppError::from(e))
+            })
+    }
+}
+
+#[async_trait]
+impl FeeServiceTrait for FeeService {
+    fn quote(&self, operation: FeeOperation, channel: Option<&str>, amount: i32) -> FeeQuote {
+        self.fee_schedule.quote(operation, channel, amount)
+    }
+
+    #[instrument(skip_all)]
+    async fn collect(&self, fee: &FeeQuote) -> Result<(), ErrorResponse> {
+        if fee.fee_amount == 0 {
+            return Ok(());
+        }
+
+        self.adjust_revenue(fee, fee.fee_amount).await?;
+
+        info!(
+            "Collected fee {} under rule {}",
+            fee.fee_amount,
+            fee.fee_rule.as_deref().unwrap_or("-")
+        );
+
+        Ok(())
+    }
+
+    #[instrument(skip_all)]
+    async fn refund(&self, fee: &FeeQuote) -> Result<(), ErrorResponse> {
+        if fee.fee_amount == 0 {
+            return Ok(());
+        }
+
+        self.adjust_revenue(fee, -fee.fee_amount).await?;
+
+        info!(
+            "Refunded fee {} under rule {}",
+            fee.fee_amount,
+            fee.fee_rule.as_deref().unwrap_or("-")
+        );
+
+        Ok(())
+    }
+
+    #[instrument(skip_all)]
+    async fn adjust(&self, previous: &FeeQuote, updated: &FeeQuote) -> Result<(), ErrorResponse> {
+        let delta = updated.fee_amount - previous.fee_amount;
+
+        if delta == 0 {
+            return Ok(());
+        }
+
+        self.adjust_revenue(updated, delta).await?;
+
+        info!(
+            "Adjusted fee from {} to {} under rule {}",
+            previous.fee_amount,
+            updated.fee_amount,
+            updated.fee_rule.as_deref().unwrap_or("-")
+        );
+
+        Ok(())
+    }
+
+    #[instrument(skip_all)]
+    async fn retry_pending_fees(&self) -> Result<u64, ErrorResponse> {
+        let pending = self
+            .fee_repository
+            .find_by_status(FEE_ENTRY_STATUS_PENDING)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        if pending.is_empty() {
+            return Ok(0);
+        }
+
+        let revenue_user_id = self.revenue_user_id()?;
+        let mut booked = 0;
+
+        for entry in &pending {
+            match self
+                .fee_repository
+                .book_pending(entry.fee_entry_id, revenue_user_id)
+                .await
+            {
+                Ok(true) => booked += 1,
+                Ok(false) => {}
+                Err(e) => error!("Failed to book fee entry {}: {}", entry.fee_entry_id, e),
+            }
+        }
+
+        info!("Booked {} of {} pending fee entries", booked, pending.len());
+
+        Ok(booked)
+    }
+
+    #[instrument(skip_all)]
+    async fn preview_fee(
+        &self,
+        input: &FeePreviewRequest,
+    ) -> Result<ApiResponse<FeePreviewResponse>, ErrorResponse> {
+        if let Err(validation_err) = input.validate() {
+            error!("Validation failed for fee preview: {}", validation_err);
+            return Err(ErrorResponse::from(AppError::InvalidFields(validation_err)));
+        }
+
+        let quote = self.quote(input.operation, input.channel.as_deref(), input.amount);
+
+        let amount = input.amount as i64;
+        let fee_amount = quote.fee_amount as i64;
+
+        let (total_debit, net_amount) = if input.operation.fee_on_top() {
+            (amount + fee_amount, amount)
+        } else {
+            (amount, amount - fee_amount)
+        };
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Fee preview calculated successfully".to_string(),
+            data: FeePreviewResponse {
+                operation: input.operation,
+                channel: input.channel.clone(),
+                amount: input.amount,
+                fee_amount: quote.fee_amount,
+                fee_rule: quote.fee_rule,
+                total_debit,
+                net_amount,
+            },
+        })
+    }
+}
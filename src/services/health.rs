@@ -0,0 +1,135 @@
+use std::{
+    sync::atomic::{AtomicBool, Ordering},
+    time::Instant,
+};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_orm::DatabaseConnection;
+use tracing::{info, instrument, warn};
+
+use crate::{
+    abstract_trait::health::HealthServiceTrait,
+    config::database::{ConnectionManager, MigrationStatus},
+    domain::response::{
+        health::{HealthDetailsResponse, MigrationStatusResponse, PoolStatsResponse},
+        ApiResponse, ErrorResponse, MessageResponse,
+    },
+    migration::Migrator,
+    utils::errors::AppError,
+};
+
+pub struct HealthService {
+    pool: DatabaseConnection,
+    started_at: DateTime<Utc>,
+    // Monotonic, so the reported uptime does not jump with the wall clock
+    started: Instant,
+    shutting_down: AtomicBool,
+}
+
+impl HealthService {
+    pub fn new(pool: DatabaseConnection) -> Self {
+        Self {
+            pool,
+            started_at: Utc::now(),
+            started: Instant::now(),
+            shutting_down: AtomicBool::new(false),
+        }
+    }
+
+    fn is_shutting_down(&self) -> bool {
+        self.shutting_down.load(Ordering::SeqCst)
+    }
+
+    async fn database_reachable(&self) -> bool {
+        match ConnectionManager::ping(&self.pool).await {
+            Ok(()) => true,
+            Err(e) => {
+                warn!("Health check could not reach the database: {}", e);
+                false
+            }
+        }
+    }
+
+    async fn migration_status(&self) -> Option<MigrationStatus> {
+        match ConnectionManager::migration_status::<Migrator>(&self.pool).await {
+            Ok(status) => Some(status),
+            Err(e) => {
+                warn!("Health check could not read the migration status: {}", e);
+                None
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl HealthServiceTrait for HealthService {
+    fn begin_shutdown(&self) {
+        if !self.shutting_down.swap(true, Ordering::SeqCst) {
+            info!("Shutdown started, readiness checks now fail");
+        }
+    }
+
+    #[instrument(skip_all)]
+    async fn check_ready(&self) -> Result<MessageResponse, ErrorResponse> {
+        let unavailable =
+            |message: &str| ErrorResponse::from(AppError::ServiceUnavailable(message.to_string()));
+
+        if self.is_shutting_down() {
+            return Err(unavailable("Shutting down"));
+        }
+
+        if !self.database_reachable().await {
+            return Err(unavailable("Database is unreachable"));
+        }
+
+        let status = self
+            .migration_status()
+            .await
+            .ok_or_else(|| unavailable("Migration status is unavailable"))?;
+
+        if !status.pending.is_empty() {
+            return Err(unavailable(&format!(
+                "{} database migrations are pending",
+                status.pending.len()
+            )));
+        }
+
+        Ok(MessageResponse {
+            status: "success".to_string(),
+            message: "Ready".to_string(),
+        })
+    }
+
+    #[instrument(skip_all)]
+    async fn get_details(&self) -> Result<ApiResponse<HealthDetailsResponse>, ErrorResponse> {
+        let shutting_down = self.is_shutting_down();
+        let database_reachable = self.database_reachable().await;
+
+        let migrations = if database_reachable {
+            self.migration_status().await
+        } else {
+            None
+        };
+
+        let ready = !shutting_down
+            && migrations
+                .as_ref()
+                .is_some_and(|status| status.pending.is_empty());
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Health details retrieved successfully".to_string(),
+            data: HealthDetailsResponse {
+                version: env!("CARGO_PKG_VERSION").to_string(),
+                started_at: self.started_at,
+                uptime_secs: self.started.elapsed().as_secs(),
+                ready,
+                shutting_down,
+                database_reachable,
+                pool: ConnectionManager::pool_stats(&self.pool).map(PoolStatsResponse::from),
+                migrations: migrations.map(MigrationStatusResponse::from),
+            },
+        })
+    }
+}
@@ -0,0 +1,437 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use tracing::{error, info, instrument};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{
+    abstract_trait::{
+        invoice::{DynInvoiceRepository, InvoiceServiceTrait},
+        transfer::DynTransferService,
+        user::DynUserRepository,
+    },
+    domain::{
+        request::{
+            invoice::{
+                CreateInvoicePaymentRequest, CreateInvoiceRequest, PayInvoiceRequest,
+                INVOICE_PAYMENT_STATUS_COMPLETED, INVOICE_PAYMENT_STATUS_PENDING,
+                INVOICE_STATUS_EXPIRED, INVOICE_STATUS_PAID, INVOICE_STATUS_PARTIALLY_PAID,
+            },
+            transfer::{
+                CreateTransferRequest, TRANSFER_STATUS_COMPLETED, TRANSFER_STATUS_PENDING_REVIEW,
+                TRANSFER_STATUS_REJECTED,
+            },
+        },
+        response::{
+            invoice::{InvoicePaymentResponse, InvoiceResponse},
+            ApiResponse, ErrorResponse,
+        },
+    },
+    entities::invoices,
+    utils::errors::AppError,
+};
+
+pub struct InvoiceService {
+    invoice_repository: DynInvoiceRepository,
+    user_repository: DynUserRepository,
+    transfer_service: DynTransferService,
+}
+
+impl InvoiceService {
+    pub fn new(
+        invoice_repository: DynInvoiceRepository,
+        user_repository: DynUserRepository,
+        transfer_service: DynTransferService,
+    ) -> Self {
+        Self {
+            invoice_repository,
+            user_repository,
+            transfer_service,
+        }
+    }
+
+    async fn to_response(&self, invoice: invoices::Model) -> Result<InvoiceResponse, ErrorResponse> {
+        let items = self
+            .invoice_repository
+            .find_items(invoice.invoice_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        Ok(InvoiceResponse::new(invoice, items))
+    }
+}
+
+#[async_trait]
+impl InvoiceServiceTrait for InvoiceService {
+    #[instrument(skip_all, fields(id = id))]
+    async fn get_invoice(&self, id: i32) -> Result<ApiResponse<InvoiceResponse>, ErrorResponse> {
+        let invoice = self
+            .invoice_repository
+            .find_by_id(id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .ok_or_else(|| {
+                error!("Invoice with id {} not found", id);
+                ErrorResponse::from(AppError::NotFound(format!(
+                    "Invoice with id {} not found",
+                    id
+                )))
+            })?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Invoice retrieved successfully".to_string(),
+            data: self.to_response(invoice).await?,
+        })
+    }
+
+    #[instrument(skip_all, fields(merchant_id = merchant_id))]
+    async fn get_invoice_users(
+        &self,
+        merchant_id: i32,
+    ) -> Result<ApiResponse<Vec<InvoiceResponse>>, ErrorResponse> {
+        let invoices = self
+            .invoice_repository
+            .find_by_merchant(merchant_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        let mut invoice_response = Vec::with_capacity(invoices.len());
+
+        for invoice in invoices {
+            invoice_response.push(self.to_response(invoice).await?);
+        }
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Invoices retrieved successfully".to_string(),
+            data: invoice_response,
+        })
+    }
+
+    #[instrument(skip_all, fields(id = id))]
+    async fn get_invoice_payments(
+        &self,
+        id: i32,
+    ) -> Result<ApiResponse<Vec<InvoicePaymentResponse>>, ErrorResponse> {
+        let payments = self
+            .invoice_repository
+            .find_payments(id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Invoice payments retrieved successfully".to_string(),
+            data: payments
+                .into_iter()
+                .map(InvoicePaymentResponse::from)
+                .collect(),
+        })
+    }
+
+    #[instrument(skip_all)]
+    async fn get_payment_link(
+        &self,
+        token: &str,
+    ) -> Result<ApiResponse<InvoiceResponse>, ErrorResponse> {
+        let invoice = self
+            .invoice_repository
+            .find_by_token(token)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .ok_or_else(|| {
+                ErrorResponse::from(AppError::NotFound("Payment link not found".to_string()))
+            })?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Payment link retrieved successfully".to_string(),
+            data: self.to_response(invoice).await?,
+        })
+    }
+
+    #[instrument(skip_all)]
+    async fn create_invoice(
+        &self,
+        input: &CreateInvoiceRequest,
+    ) -> Result<ApiResponse<InvoiceResponse>, ErrorResponse> {
+        if let Err(validation_err) = input.validate() {
+            error!("Validation failed for invoice create: {}", validation_err);
+            return Err(ErrorResponse::from(AppError::InvalidFields(validation_err)));
+        }
+
+        if !input.items.is_empty() && input.total_amount().is_none() {
+            return Err(ErrorResponse::from(AppError::ValidationError(
+                "Invoice total is too large".to_string(),
+            )));
+        }
+
+        self.user_repository
+            .find_by_id(input.merchant_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .ok_or_else(|| {
+                error!("User with id {} not found", input.merchant_id);
+                ErrorResponse::from(AppError::UserNotFound(format!(
+                    "User with id {} not found",
+                    input.merchant_id
+                )))
+            })?;
+
+        let token = Uuid::new_v4().simple().to_string();
+
+        let invoice = self
+            .invoice_repository
+            .create(input, &token)
+            .await
+            .map_err(|e| {
+                error!("Failed to create invoice for merchant {}: {}", input.merchant_id, e);
+                ErrorResponse::from(AppError::from(e))
+            })?;
+
+        info!(
+            "Invoice {} created for merchant {}",
+            invoice.invoice_id, input.merchant_id
+        );
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Invoice created successfully".to_string(),
+            data: self.to_response(invoice).await?,
+        })
+    }
+
+    #[instrument(skip_all)]
+    async fn pay_invoice(
+        &self,
+        input: &PayInvoiceRequest,
+    ) -> Result<ApiResponse<InvoicePaymentResponse>, ErrorResponse> {
+        if let Err(validation_err) = input.validate() {
+            error!("Validation failed for invoice payment: {}", validation_err);
+            return Err(ErrorResponse::from(AppError::InvalidFields(validation_err)));
+        }
+
+        let invoice = self
+            .invoice_repository
+            .find_by_token(&input.token)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .ok_or_else(|| {
+                ErrorResponse::from(AppError::NotFound("Payment link not found".to_string()))
+            })?;
+
+        if invoice.status == INVOICE_STATUS_PAID {
+            return Err(ErrorResponse::from(AppError::ValidationError(
+                "Invoice is already paid".to_string(),
+            )));
+        }
+
+        if invoice.status == INVOICE_STATUS_EXPIRED
+            || invoice.due_date < Utc::now().naive_utc()
+        {
+            return Err(ErrorResponse::from(AppError::ValidationError(
+                "Invoice has expired".to_string(),
+            )));
+        }
+
+        if invoice.merchant_id == input.payer_id {
+            return Err(ErrorResponse::from(AppError::ValidationError(
+                "Merchant cannot pay their own invoice".to_string(),
+            )));
+        }
+
+        // Fixed amount invoices accept partial payments up to the amount due,
+        // an open amount link is settled by a single payment of any amount
+        let status = match invoice.total_amount {
+            Some(total_amount) => {
+                let amount_due = total_amount - invoice.paid_amount;
+
+                if input.amount > amount_due {
+                    return Err(ErrorResponse::from(AppError::ValidationError(format!(
+                        "Amount exceeds the amount due of {}",
+                        amount_due
+                    ))));
+                }
+
+                if input.amount == amount_due {
+                    INVOICE_STATUS_PAID
+                } else {
+                    INVOICE_STATUS_PARTIALLY_PAID
+                }
+            }
+            None => INVOICE_STATUS_PAID,
+        };
+
+        // The payment is counted before any money moves, so a concurrent payment priced against
+        // the same amount due is refused rather than overpaying the invoice
+        let reserved = self
+            .invoice_repository
+            .reserve_payment(&CreateInvoicePaymentRequest {
+                invoice_id: invoice.invoice_id,
+                payer_id: input.payer_id,
+                amount: input.amount,
+                paid_amount: invoice.paid_amount,
+                status: status.to_string(),
+            })
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .ok_or_else(|| {
+                ErrorResponse::from(AppError::ValidationError(
+                    "Invoice was updated by another payment, please retry".to_string(),
+                ))
+            })?;
+
+        let transfer = match self
+            .transfer_service
+            .create_transfer(&CreateTransferRequest {
+                transfer_from: input.payer_id,
+                transfer_to: invoice.merchant_id,
+                recipient: None,
+                transfer_amount: input.amount,
+            })
+            .await
+        {
+            Ok(transfer) => transfer,
+            Err(e) => {
+                if let Err(release_err) = self
+                    .invoice_repository
+                    .release_payment(reserved.payment_id)
+                    .await
+                {
+                    error!(
+                        "Failed to release payment {} of invoice {}: {}",
+                        reserved.payment_id, invoice.invoice_id, release_err
+                    );
+                }
+
+                return Err(e);
+            }
+        };
+
+        // A transfer held for review keeps the payment pending until the review settles it
+        let held = transfer.data.status == TRANSFER_STATUS_PENDING_REVIEW;
+
+        // The invoice already counts the payment, so a failure here only leaves it pending
+        let payment = self
+            .invoice_repository
+            .complete_payment(
+                reserved.payment_id,
+                transfer.data.transfer_id,
+                if held {
+                    INVOICE_PAYMENT_STATUS_PENDING
+                } else {
+                    INVOICE_PAYMENT_STATUS_COMPLETED
+                },
+            )
+            .await
+            .map_err(|e| {
+                error!(
+                    "Transfer {} for invoice {} succeeded but payment {} was not completed: {}",
+                    transfer.data.transfer_id, invoice.invoice_id, reserved.payment_id, e
+                );
+                ErrorResponse::from(AppError::from(e))
+            })?;
+
+        info!(
+            "Invoice {} received payment {} of {} from user {}",
+            invoice.invoice_id, payment.payment_id, input.amount, input.payer_id
+        );
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: if held {
+                "Invoice payment is held for review".to_string()
+            } else {
+                "Invoice paid successfully".to_string()
+            },
+            data: InvoicePaymentResponse::from(payment),
+        })
+    }
+
+    #[instrument(skip_all)]
+    async fn expire_invoices(&self) -> Result<u64, ErrorResponse> {
+        let expired = self
+            .invoice_repository
+            .expire_overdue(Utc::now().naive_utc())
+            .await
+            .map_err(|e| {
+                error!("Failed to expire overdue invoices: {}", e);
+                ErrorResponse::from(AppError::from(e))
+            })?;
+
+        if expired > 0 {
+            info!("Expired {} overdue invoices", expired);
+        }
+
+        Ok(expired)
+    }
+
+    #[instrument(skip_all)]
+    async fn settle_held_payments(&self) -> Result<u64, ErrorResponse> {
+        let pending = self
+            .invoice_repository
+            .find_payments_by_status(INVOICE_PAYMENT_STATUS_PENDING)
+            .await
+            .map_err(|e| {
+                error!("Failed to load pending invoice payments: {}", e);
+                ErrorResponse::from(AppError::from(e))
+            })?;
+
+        let mut settled = 0;
+
+        for payment in &pending {
+            let Some(transfer_id) = payment.transfer_id else {
+                continue;
+            };
+
+            let status = match self.transfer_service.get_transfer(transfer_id).await {
+                Ok(transfer) => transfer.data.map(|transfer| transfer.status),
+                Err(e) => {
+                    error!("Failed to load transfer {}: {}", transfer_id, e.message);
+                    continue;
+                }
+            };
+
+            // A rejected transfer was refunded to the payer, so the invoice owes it again
+            let result = match status.as_deref() {
+                Some(TRANSFER_STATUS_COMPLETED) => self
+                    .invoice_repository
+                    .complete_payment(
+                        payment.payment_id,
+                        transfer_id,
+                        INVOICE_PAYMENT_STATUS_COMPLETED,
+                    )
+                    .await
+                    .map(|_| ()),
+                Some(TRANSFER_STATUS_REJECTED) => self
+                    .invoice_repository
+                    .release_payment(payment.payment_id)
+                    .await,
+                _ => continue,
+            };
+
+            match result {
+                Ok(()) => settled += 1,
+                Err(e) => error!(
+                    "Failed to settle payment {} of invoice {}: {}",
+                    payment.payment_id, payment.invoice_id, e
+                ),
+            }
+        }
+
+        if settled > 0 {
+            info!("Settled {} held invoice payments", settled);
+        }
+
+        Ok(settled)
+    }
+}
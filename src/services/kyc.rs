@@ -0,0 +1,319 @@
+use async_trait::async_trait;
+use tracing::{error, info, instrument, warn};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{
+    abstract_trait::{
+        document_store::DynDocumentStore,
+        kyc::{DynKycRepository, KycServiceTrait},
+        user::DynUserRepository,
+    },
+    domain::{
+        request::kyc::{
+            CreateKycDocumentRequest, CreateKycReviewRequest, ReviewKycDocumentRequest,
+            KYC_DOCUMENT_STATUS_PENDING,
+        },
+        response::{
+            kyc::{KycDocumentFile, KycDocumentResponse, KycReviewResponse},
+            ApiResponse, ErrorResponse,
+        },
+    },
+    entities::users,
+    utils::{errors::AppError, kyc::KycTier},
+};
+
+pub struct KycService {
+    kyc_repository: DynKycRepository,
+    user_repository: DynUserRepository,
+    document_store: DynDocumentStore,
+}
+
+impl KycService {
+    pub fn new(
+        kyc_repository: DynKycRepository,
+        user_repository: DynUserRepository,
+        document_store: DynDocumentStore,
+    ) -> Self {
+        Self {
+            kyc_repository,
+            user_repository,
+            document_store,
+        }
+    }
+
+    async fn find_user(&self, user_id: i32) -> Result<users::Model, ErrorResponse> {
+        self.user_repository
+            .find_by_id(user_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .ok_or_else(|| {
+                error!("User with id {} not found", user_id);
+                ErrorResponse::from(AppError::UserNotFound(format!(
+                    "User with id {} not found",
+                    user_id
+                )))
+            })
+    }
+}
+
+fn current_tier(user: &users::Model) -> KycTier {
+    user.kyc_tier.parse().unwrap_or_else(|e| {
+        warn!("User {} has {}; treating as unverified", user.user_id, e);
+        KycTier::Unverified
+    })
+}
+
+#[async_trait]
+impl KycServiceTrait for KycService {
+    #[instrument(skip_all)]
+    async fn submit_document(
+        &self,
+        input: &CreateKycDocumentRequest,
+    ) -> Result<ApiResponse<KycDocumentResponse>, ErrorResponse> {
+        if let Err(validation_err) = input.validate() {
+            error!("Validation failed for KYC document: {}", validation_err);
+            return Err(ErrorResponse::from(AppError::InvalidFields(validation_err)));
+        }
+
+        let user = self.find_user(input.user_id).await?;
+        let tier = current_tier(&user);
+
+        if input.requested_tier <= tier {
+            return Err(ErrorResponse::from(AppError::ValidationError(format!(
+                "User is already verified at the {} tier",
+                tier
+            ))));
+        }
+
+        let storage_key = format!(
+            "users/{}/{}.{}",
+            input.user_id,
+            Uuid::new_v4().simple(),
+            input.file_extension()
+        );
+
+        self.document_store
+            .put(&storage_key, &input.content)
+            .await
+            .map_err(|e| {
+                error!(
+                    "Failed to store KYC document for user {}: {}",
+                    input.user_id, e
+                );
+                ErrorResponse::from(AppError::StorageError(e))
+            })?;
+
+        let document = match self.kyc_repository.create(input, &storage_key).await {
+            Ok(document) => document,
+            Err(e) => {
+                error!(
+                    "Failed to save KYC document for user {}: {}",
+                    input.user_id, e
+                );
+
+                // Don't leave an orphaned file behind when the record could not be written
+                if let Err(cleanup_err) = self.document_store.delete(&storage_key).await {
+                    warn!("{}", cleanup_err);
+                }
+
+                return Err(ErrorResponse::from(AppError::from(e)));
+            }
+        };
+
+        info!(
+            "KYC document {} ({}) submitted by user {} for the {} tier",
+            document.document_id, document.document_type, input.user_id, input.requested_tier
+        );
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Document submitted for review".to_string(),
+            data: KycDocumentResponse::from(document),
+        })
+    }
+
+    #[instrument(skip_all, fields(user_id = user_id))]
+    async fn get_document_users(
+        &self,
+        user_id: i32,
+    ) -> Result<ApiResponse<Vec<KycDocumentResponse>>, ErrorResponse> {
+        let documents = self
+            .kyc_repository
+            .find_by_user(user_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Documents retrieved successfully".to_string(),
+            data: documents
+                .into_iter()
+                .map(KycDocumentResponse::from)
+                .collect(),
+        })
+    }
+
+    #[instrument(skip_all)]
+    async fn get_pending_documents(
+        &self,
+    ) -> Result<ApiResponse<Vec<KycDocumentResponse>>, ErrorResponse> {
+        let documents = self
+            .kyc_repository
+            .find_by_status(KYC_DOCUMENT_STATUS_PENDING)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Review queue retrieved successfully".to_string(),
+            data: documents
+                .into_iter()
+                .map(KycDocumentResponse::from)
+                .collect(),
+        })
+    }
+
+    #[instrument(skip_all, fields(id = id))]
+    async fn get_document_file(&self, id: i32) -> Result<KycDocumentFile, ErrorResponse> {
+        let document = self
+            .kyc_repository
+            .find_by_id(id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .ok_or_else(|| {
+                ErrorResponse::from(AppError::NotFound(format!(
+                    "Document with id {} not found",
+                    id
+                )))
+            })?;
+
+        let content = self
+            .document_store
+            .get(&document.storage_key)
+            .await
+            .map_err(|e| {
+                error!("Failed to load KYC document {}: {}", id, e);
+                ErrorResponse::from(AppError::StorageError(e))
+            })?;
+
+        Ok(KycDocumentFile {
+            file_name: document.file_name,
+            content_type: document.content_type,
+            content,
+        })
+    }
+
+    #[instrument(skip_all)]
+    async fn review_document(
+        &self,
+        input: &ReviewKycDocumentRequest,
+    ) -> Result<ApiResponse<KycDocumentResponse>, ErrorResponse> {
+        if let Err(validation_err) = input.validate() {
+            error!("Validation failed for KYC review: {}", validation_err);
+            return Err(ErrorResponse::from(AppError::InvalidFields(validation_err)));
+        }
+
+        let document = self
+            .kyc_repository
+            .find_by_id(input.document_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .ok_or_else(|| {
+                ErrorResponse::from(AppError::NotFound(format!(
+                    "Document with id {} not found",
+                    input.document_id
+                )))
+            })?;
+
+        if document.user_id == input.reviewer_id {
+            return Err(ErrorResponse::from(AppError::ValidationError(
+                "Operators cannot review their own documents".to_string(),
+            )));
+        }
+
+        let user = self.find_user(document.user_id).await?;
+        let previous_tier = current_tier(&user);
+
+        // Approval never downgrades a user who was verified at a higher tier meanwhile
+        let new_tier = if input.is_approval() {
+            let requested_tier = document.requested_tier.parse().unwrap_or(previous_tier);
+            previous_tier.max(requested_tier)
+        } else {
+            previous_tier
+        };
+
+        let review = CreateKycReviewRequest {
+            document_id: document.document_id,
+            user_id: document.user_id,
+            reviewer_id: input.reviewer_id,
+            decision: input.decision.clone(),
+            reason: input
+                .reason
+                .as_deref()
+                .map(str::trim)
+                .filter(|reason| !reason.is_empty())
+                .map(str::to_string),
+            previous_tier,
+            new_tier,
+        };
+
+        let document = self
+            .kyc_repository
+            .review(&review)
+            .await
+            .map_err(|e| {
+                error!(
+                    "Failed to record review of document {}: {}",
+                    input.document_id, e
+                );
+                ErrorResponse::from(AppError::from(e))
+            })?
+            .ok_or_else(|| {
+                ErrorResponse::from(AppError::ValidationError(format!(
+                    "Document {} has already been reviewed",
+                    input.document_id
+                )))
+            })?;
+
+        info!(
+            "KYC document {} {} by operator {}; user {} tier {} -> {}",
+            document.document_id,
+            input.decision,
+            input.reviewer_id,
+            document.user_id,
+            previous_tier,
+            new_tier
+        );
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: format!("Document {}", input.decision),
+            data: KycDocumentResponse::from(document),
+        })
+    }
+
+    #[instrument(skip_all, fields(user_id = user_id))]
+    async fn get_review_users(
+        &self,
+        user_id: i32,
+    ) -> Result<ApiResponse<Vec<KycReviewResponse>>, ErrorResponse> {
+        let reviews = self
+            .kyc_repository
+            .find_reviews_by_user(user_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Review history retrieved successfully".to_string(),
+            data: reviews.into_iter().map(KycReviewResponse::from).collect(),
+        })
+    }
+}
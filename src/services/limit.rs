@@ -0,0 +1,282 @@
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use tracing::{info, instrument, warn};
+
+use crate::{
+    abstract_trait::{
+        limit::LimitServiceTrait, saldo::DynSaldoRepository, transfer::DynTransferRepository,
+        user::DynUserRepository, withdraw::DynWithdrawRepository,
+    },
+    domain::response::{limit::UserLimitResponse, ApiResponse, ErrorResponse},
+    entities::users,
+    utils::{
+        account_status::AccountStatus,
+        errors::AppError,
+        kyc::{KycTier, LimitOperation},
+        rupiah::rupiah_format,
+    },
+};
+
+pub struct LimitService {
+    user_repository: DynUserRepository,
+    saldo_repository: DynSaldoRepository,
+    transfer_repository: DynTransferRepository,
+    withdraw_repository: DynWithdrawRepository,
+}
+
+impl LimitService {
+    pub fn new(
+        user_repository: DynUserRepository,
+        saldo_repository: DynSaldoRepository,
+        transfer_repository: DynTransferRepository,
+        withdraw_repository: DynWithdrawRepository,
+    ) -> Self {
+        Self {
+            user_repository,
+            saldo_repository,
+            transfer_repository,
+            withdraw_repository,
+        }
+    }
+
+    async fn find_user(&self, user_id: i32) -> Result<users::Model, ErrorResponse> {
+        self.user_repository
+            .find_by_id(user_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .ok_or_else(|| {
+                ErrorResponse::from(AppError::UserNotFound(format!(
+                    "User with id {} not found",
+                    user_id
+                )))
+            })
+    }
+
+    async fn find_tier(&self, user_id: i32) -> Result<KycTier, ErrorResponse> {
+        let user = self.find_user(user_id).await?;
+
+        Ok(tier_of(&user))
+    }
+
+    async fn find_balance(&self, user_id: i32) -> Result<i64, ErrorResponse> {
+        let saldo = self
+            .saldo_repository
+            .find_by_user_id(user_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        Ok(saldo.map_or(0, |saldo| saldo.total_balance as i64))
+    }
+
+    // Usage over the rolling last 24 hours and last 30 days
+    async fn find_usage(
+        &self,
+        user_id: i32,
+        operation: LimitOperation,
+    ) -> Result<(i64, i64), ErrorResponse> {
+        let now = Utc::now().naive_utc();
+        let day_ago = now - Duration::hours(24);
+        let month_ago = now - Duration::days(30);
+
+        let (daily, monthly) = match operation {
+            LimitOperation::Transfer => (
+                self.transfer_repository
+                    .sum_sent_since(user_id, day_ago)
+                    .await,
+                self.transfer_repository
+                    .sum_sent_since(user_id, month_ago)
+                    .await,
+            ),
+            LimitOperation::Withdraw => (
+                self.withdraw_repository.sum_since(user_id, day_ago).await,
+                self.withdraw_repository.sum_since(user_id, month_ago).await,
+            ),
+        };
+
+        Ok((
+            daily.map_err(AppError::from).map_err(ErrorResponse::from)?,
+            monthly
+                .map_err(AppError::from)
+                .map_err(ErrorResponse::from)?,
+        ))
+    }
+
+    // Checks the per-transaction limit on each amount and the rolling limits on the requested total
+    async fn check_debit(
+        &self,
+        user_id: i32,
+        operation: LimitOperation,
+        amounts: &[i32],
+        requested: i64,
+    ) -> Result<(), ErrorResponse> {
+        let user = self.find_user(user_id).await?;
+        let status = status_of(&user);
+
+        if !status.can_debit() {
+            return Err(account_restricted(format!(
+                "Account {} is {} and cannot send funds",
+                user_id, status
+            )));
+        }
+
+        let tier = tier_of(&user);
+        let limits = *tier.limits().for_operation(operation);
+
+        if let Some(amount) = amounts
+            .iter()
+            .find(|amount| **amount as i64 > limits.per_transaction)
+        {
+            return Err(limit_exceeded(format!(
+                "A {} of {} exceeds the per-transaction limit of {} for {} accounts",
+                operation,
+                rupiah_format(&amount.to_string()),
+                rupiah_format(&limits.per_transaction.to_string()),
+                tier
+            )));
+        }
+
+        let (daily_used, monthly_used) = self.find_usage(user_id, operation).await?;
+
+        if daily_used + requested > limits.daily {
+            return Err(limit_exceeded(format!(
+                "Daily {} limit of {} for {} accounts would be exceeded: {} used in the last 24 hours, {} requested",
+                operation,
+                rupiah_format(&limits.daily.to_string()),
+                tier,
+                rupiah_format(&daily_used.to_string()),
+                rupiah_format(&requested.to_string())
+            )));
+        }
+
+        if monthly_used + requested > limits.monthly {
+            return Err(limit_exceeded(format!(
+                "Monthly {} limit of {} for {} accounts would be exceeded: {} used in the last 30 days, {} requested",
+                operation,
+                rupiah_format(&limits.monthly.to_string()),
+                tier,
+                rupiah_format(&monthly_used.to_string()),
+                rupiah_format(&requested.to_string())
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+fn tier_of(user: &users::Model) -> KycTier {
+    user.kyc_tier.parse().unwrap_or_else(|e| {
+        warn!(
+            "User {} has {}; applying unverified limits",
+            user.user_id, e
+        );
+        KycTier::Unverified
+    })
+}
+
+// An unreadable status is treated as frozen rather than letting money move
+fn status_of(user: &users::Model) -> AccountStatus {
+    user.account_status.parse().unwrap_or_else(|e| {
+        warn!(
+            "User {} has {}; treating the account as frozen",
+            user.user_id, e
+        );
+        AccountStatus::Frozen
+    })
+}
+
+fn account_restricted(message: String) -> ErrorResponse {
+    info!("Rejected request on restricted account: {}", message);
+    ErrorResponse::from(AppError::AccountRestricted(message))
+}
+
+fn limit_exceeded(message: String) -> ErrorResponse {
+    info!("Rejected over-limit request: {}", message);
+    ErrorResponse::from(AppError::LimitExceeded(message))
+}
+
+#[async_trait]
+impl LimitServiceTrait for LimitService {
+    #[instrument(skip_all, fields(user_id = user_id))]
+    async fn check_outgoing(
+        &self,
+        user_id: i32,
+        operation: LimitOperation,
+        amounts: &[i32],
+    ) -> Result<(), ErrorResponse> {
+        let requested = amounts.iter().map(|amount| *amount as i64).sum();
+
+        self.check_debit(user_id, operation, amounts, requested)
+            .await
+    }
+
+    #[instrument(skip_all, fields(user_id = user_id))]
+    async fn check_outgoing_change(
+        &self,
+        user_id: i32,
+        operation: LimitOperation,
+        previous: i32,
+        updated: i32,
+    ) -> Result<(), ErrorResponse> {
+        if updated <= previous {
+            return Ok(());
+        }
+
+        // The previous amount is already part of the usage, only the increase is new
+        self.check_debit(user_id, operation, &[updated], (updated - previous) as i64)
+            .await
+    }
+
+    #[instrument(skip_all, fields(user_id = user_id))]
+    async fn check_incoming(&self, user_id: i32, amount: i32) -> Result<(), ErrorResponse> {
+        let user = self.find_user(user_id).await?;
+        let status = status_of(&user);
+
+        if !status.can_credit() {
+            return Err(account_restricted(format!(
+                "Account {} is {} and cannot receive funds",
+                user_id, status
+            )));
+        }
+
+        let tier = tier_of(&user);
+        let max_balance = tier.limits().max_balance;
+        let balance = self.find_balance(user_id).await?;
+
+        if balance + amount as i64 > max_balance {
+            return Err(limit_exceeded(format!(
+                "Balance of user {} would exceed the maximum of {} for {} accounts",
+                user_id,
+                rupiah_format(&max_balance.to_string()),
+                tier
+            )));
+        }
+
+        Ok(())
+    }
+
+    #[instrument(skip_all, fields(user_id = user_id))]
+    async fn get_limits(
+        &self,
+        user_id: i32,
+    ) -> Result<ApiResponse<UserLimitResponse>, ErrorResponse> {
+        let tier = self.find_tier(user_id).await?;
+        let balance = self.find_balance(user_id).await?;
+        let transfer_used = self.find_usage(user_id, LimitOperation::Transfer).await?;
+        let withdraw_used = self.find_usage(user_id, LimitOperation::Withdraw).await?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Limits retrieved successfully".to_string(),
+            data: UserLimitResponse::new(
+                user_id,
+                tier,
+                tier.limits(),
+                balance,
+                transfer_used,
+                withdraw_used,
+            ),
+        })
+    }
+}
@@ -0,0 +1,155 @@
+use std::time::Duration;
+
+use prometheus::{
+    Encoder, HistogramOpts, HistogramVec, IntCounterVec, IntGauge, IntGaugeVec, Opts, Registry,
+    TextEncoder,
+};
+use sea_orm::DatabaseConnection;
+
+use crate::{
+    abstract_trait::metrics::MetricsTrait,
+    config::database::ConnectionManager,
+    utils::metrics::{TransactionKind, TransactionOutcome},
+};
+
+// Prometheus metrics kept in a registry of their own, rendered on every scrape of /metrics
+pub struct PrometheusMetrics {
+    registry: Registry,
+    pool: DatabaseConnection,
+    http_requests: IntCounterVec,
+    http_request_duration: HistogramVec,
+    db_pool_connections: IntGaugeVec,
+    db_pool_max_connections: IntGauge,
+    transactions: IntCounterVec,
+    transaction_rollbacks: IntCounterVec,
+    transaction_volume: IntCounterVec,
+}
+
+impl PrometheusMetrics {
+    pub fn new(pool: DatabaseConnection) -> Result<Self, prometheus::Error> {
+        let registry = Registry::new();
+
+        let http_requests = IntCounterVec::new(
+            Opts::new("http_requests_total", "HTTP requests served"),
+            &["method", "route", "status"],
+        )?;
+
+        let http_request_duration = HistogramVec::new(
+            HistogramOpts::new(
+                "http_request_duration_seconds",
+                "Time taken to serve HTTP requests",
+            ),
+            &["method", "route", "status"],
+        )?;
+
+        let db_pool_connections = IntGaugeVec::new(
+            Opts::new("db_pool_connections", "Database pool connections by state"),
+            &["state"],
+        )?;
+
+        let db_pool_max_connections = IntGauge::new(
+            "db_pool_max_connections",
+            "Connections the database pool may open",
+        )?;
+
+        let transactions = IntCounterVec::new(
+            Opts::new(
+                "payment_gateway_transactions_total",
+                "Topups, transfers and withdraws by outcome",
+            ),
+            &["operation", "outcome"],
+        )?;
+
+        let transaction_rollbacks = IntCounterVec::new(
+            Opts::new(
+                "payment_gateway_transaction_rollbacks_total",
+                "Transactions whose writes were undone after a later step failed",
+            ),
+            &["operation"],
+        )?;
+
+        let transaction_volume = IntCounterVec::new(
+            Opts::new(
+                "payment_gateway_transaction_volume_rupiah_total",
+                "Amount of created transactions in rupiah",
+            ),
+            &["operation", "channel"],
+        )?;
+
+        registry.register(Box::new(http_requests.clone()))?;
+        registry.register(Box::new(http_request_duration.clone()))?;
+        registry.register(Box::new(db_pool_connections.clone()))?;
+        registry.register(Box::new(db_pool_max_connections.clone()))?;
+        registry.register(Box::new(transactions.clone()))?;
+        registry.register(Box::new(transaction_rollbacks.clone()))?;
+        registry.register(Box::new(transaction_volume.clone()))?;
+
+        Ok(Self {
+            registry,
+            pool,
+            http_requests,
+            http_request_duration,
+            db_pool_connections,
+            db_pool_max_connections,
+            transactions,
+            transaction_rollbacks,
+            transaction_volume,
+        })
+    }
+
+    // Pool usage is sampled when scraped rather than tracked on every checkout
+    fn sample_pool(&self) {
+        if let Some(stats) = ConnectionManager::pool_stats(&self.pool) {
+            self.db_pool_connections
+                .with_label_values(&["idle"])
+                .set(stats.idle as i64);
+            self.db_pool_connections
+                .with_label_values(&["in_use"])
+                .set(stats.in_use() as i64);
+            self.db_pool_max_connections
+                .set(stats.max_connections as i64);
+        }
+    }
+}
+
+impl MetricsTrait for PrometheusMetrics {
+    fn observe_request(&self, method: &str, route: &str, status: u16, duration: Duration) {
+        let status = status.to_string();
+        let labels = [method, route, status.as_str()];
+
+        self.http_requests.with_label_values(&labels).inc();
+        self.http_request_duration
+            .with_label_values(&labels)
+            .observe(duration.as_secs_f64());
+    }
+
+    fn record_transaction(&self, kind: TransactionKind, outcome: TransactionOutcome) {
+        self.transactions
+            .with_label_values(&[kind.as_str(), outcome.as_str()])
+            .inc();
+    }
+
+    fn record_volume(&self, kind: TransactionKind, channel: &str, amount: i32) {
+        self.transaction_volume
+            .with_label_values(&[kind.as_str(), channel])
+            .inc_by(amount.max(0) as u64);
+    }
+
+    fn record_rollback(&self, kind: TransactionKind) {
+        self.transaction_rollbacks
+            .with_label_values(&[kind.as_str()])
+            .inc();
+    }
+
+    fn render(&self) -> Result<String, String> {
+        self.sample_pool();
+
+        let mut buffer = Vec::new();
+
+        TextEncoder::new()
+            .encode(&self.registry.gather(), &mut buffer)
+            .map_err(|e| format!("Failed to encode metrics: {}", e))?;
+
+        String::from_utf8(buffer).map_err(|e| format!("Failed to encode metrics: {}", e))
+    }
+}
@@ -3,4 +3,26 @@ pub mod auth;
 pub mod saldo;
 pub mod transfer;
 pub mod withdraw;
-pub mod topup;
\ No newline at end of file
+pub mod topup;
+pub mod virtual_account;
+pub mod qris;
+pub mod invoice;
+pub mod subscription;
+pub mod notification;
+pub mod scheduled_transfer;
+pub mod account;
+pub mod account_number;
+pub mod beneficiary;
+pub mod payout;
+pub mod payout_batch;
+pub mod fee;
+pub mod limit;
+pub mod document_store;
+pub mod kyc;
+pub mod risk;
+pub mod screening;
+pub mod account_status;
+pub mod rate_limit_store;
+pub mod rate_limit;
+pub mod metrics;
+pub mod health;
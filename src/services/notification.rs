@@ -0,0 +1,27 @@
+use async_trait::async_trait;
+use tracing::{info, instrument};
+
+use crate::abstract_trait::notification::NotificationServiceTrait;
+
+// Writes notifications to the log until a delivery channel (email, push) is wired in
+pub struct LogNotificationService;
+
+impl LogNotificationService {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for LogNotificationService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl NotificationServiceTrait for LogNotificationService {
+    #[instrument(skip_all, fields(user_id = user_id))]
+    async fn notify(&self, user_id: i32, subject: &str, message: &str) {
+        info!(user_id, subject, "Notification: {}", message);
+    }
+}
@@ -0,0 +1,70 @@
+use std::{collections::HashMap, sync::Mutex};
+
+use async_trait::async_trait;
+use tracing::{info, instrument};
+use uuid::Uuid;
+
+use crate::{
+    abstract_trait::payout::PayoutProviderTrait,
+    domain::{request::payout::PayoutRequest, response::payout::PayoutStatus},
+};
+
+// Local stand-in for a bank disbursement API. Payouts settle on the first status
+// check, except account numbers ending in "0000" which are rejected so the
+// failure and refund path can be exercised.
+pub struct SimulatedPayoutProvider {
+    // Accepted payouts by withdraw_id, standing in for the provider's idempotency key
+    submitted: Mutex<HashMap<i32, String>>,
+}
+
+impl SimulatedPayoutProvider {
+    pub fn new() -> Self {
+        Self {
+            submitted: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+impl Default for SimulatedPayoutProvider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl PayoutProviderTrait for SimulatedPayoutProvider {
+    #[instrument(skip_all)]
+    async fn submit(&self, input: &PayoutRequest) -> Result<String, String> {
+        if input.account_number.ends_with("0000") {
+            return Err(format!(
+                "Account {} at {} rejected by the receiving bank",
+                input.account_number, input.bank_code
+            ));
+        }
+
+        let mut submitted = self.submitted.lock().unwrap_or_else(|e| e.into_inner());
+
+        if let Some(reference) = submitted.get(&input.withdraw_id) {
+            info!(
+                "Simulated payout {} for withdraw {} was already accepted",
+                reference, input.withdraw_id
+            );
+            return Ok(reference.clone());
+        }
+
+        let reference = format!("SIM-{}", Uuid::new_v4().simple());
+        submitted.insert(input.withdraw_id, reference.clone());
+
+        info!(
+            "Simulated payout {} of {} for withdraw {} to {} {}",
+            reference, input.amount, input.withdraw_id, input.bank_code, input.account_number
+        );
+
+        Ok(reference)
+    }
+
+    #[instrument(skip_all)]
+    async fn status(&self, _reference: &str) -> Result<PayoutStatus, String> {
+        Ok(PayoutStatus::Paid)
+    }
+}
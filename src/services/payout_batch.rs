@@ -0,0 +1,725 @@
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use tracing::{error, info, instrument, warn};
+use validator::Validate;
+
+use crate::{
+    abstract_trait::{
+        beneficiary::DynBeneficiaryRepository,
+        limit::DynLimitService,
+        payout_batch::{DynPayoutBatchRepository, PayoutBatchServiceTrait},
+        saldo::DynSaldoRepository,
+        transfer::DynTransferService,
+        user::DynUserRepository,
+        withdraw::DynWithdrawService,
+    },
+    domain::{
+        request::{
+            beneficiary::CreateBeneficiaryRequest,
+            payout_batch::{
+                CreatePayoutBatchItemRequest, CreatePayoutBatchRequest, PayoutBatchItemRequest,
+                UpdatePayoutBatchItemRequest, PAYOUT_BATCH_STATUS_COMPLETED,
+                PAYOUT_BATCH_STATUS_FAILED, PAYOUT_BATCH_STATUS_PARTIALLY_FAILED,
+                PAYOUT_BATCH_STATUS_PENDING, PAYOUT_BATCH_STATUS_PROCESSING,
+                PAYOUT_ITEM_STATUS_COMPLETED, PAYOUT_ITEM_STATUS_FAILED,
+                PAYOUT_ITEM_STATUS_PENDING, PAYOUT_ITEM_STATUS_REJECTED,
+                PAYOUT_ITEM_STATUS_SUBMITTED,
+            },
+            transfer::{
+                CreateTransferRequest, TRANSFER_STATUS_COMPLETED, TRANSFER_STATUS_PENDING_REVIEW,
+                TRANSFER_STATUS_REJECTED,
+            },
+            withdraw::{CreateWithdrawRequest, WITHDRAW_STATUS_FAILED, WITHDRAW_STATUS_PAID},
+        },
+        response::{
+            payout_batch::{PayoutBatchItemResponse, PayoutBatchResponse},
+            ApiResponse, ErrorResponse,
+        },
+    },
+    entities::{payout_batch_items, payout_batches, users},
+    utils::{errors::AppError, kyc::LimitOperation, validation::validation_messages},
+};
+
+pub struct PayoutBatchService {
+    payout_batch_repository: DynPayoutBatchRepository,
+    user_repository: DynUserRepository,
+    saldo_repository: DynSaldoRepository,
+    beneficiary_repository: DynBeneficiaryRepository,
+    transfer_service: DynTransferService,
+    withdraw_service: DynWithdrawService,
+    limit_service: DynLimitService,
+}
+
+impl PayoutBatchService {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        payout_batch_repository: DynPayoutBatchRepository,
+        user_repository: DynUserRepository,
+        saldo_repository: DynSaldoRepository,
+        beneficiary_repository: DynBeneficiaryRepository,
+        transfer_service: DynTransferService,
+        withdraw_service: DynWithdrawService,
+        limit_service: DynLimitService,
+    ) -> Self {
+        Self {
+            payout_batch_repository,
+            user_repository,
+            saldo_repository,
+            beneficiary_repository,
+            transfer_service,
+            withdraw_service,
+            limit_service,
+        }
+    }
+
+    async fn find_recipient(
+        &self,
+        item: &PayoutBatchItemRequest,
+    ) -> Result<Option<users::Model>, ErrorResponse> {
+        let recipient = item.recipient.as_deref().unwrap_or_default().trim();
+
+        let user = if item.is_email_alias() {
+            self.user_repository.find_by_email(recipient).await
+        } else {
+            self.user_repository.find_by_noc_transfer(recipient).await
+        };
+
+        user.map_err(AppError::from).map_err(ErrorResponse::from)
+    }
+
+    // Validates one item and resolves its wallet recipient; problems reject the item, not the batch
+    async fn prepare_item(
+        &self,
+        user_id: i32,
+        line_number: i32,
+        item: &PayoutBatchItemRequest,
+    ) -> Result<CreatePayoutBatchItemRequest, ErrorResponse> {
+        let mut prepared = CreatePayoutBatchItemRequest {
+            line_number,
+            recipient: item.recipient.as_deref().map(|r| r.trim().to_string()),
+            recipient_user_id: None,
+            bank_code: item.bank_code.as_deref().map(|b| b.trim().to_lowercase()),
+            account_number: item.account_number.as_deref().map(|a| a.trim().to_string()),
+            account_name: item.account_name.as_deref().map(|a| a.trim().to_string()),
+            amount: item.amount,
+            reference: item.reference.clone(),
+            status: PAYOUT_ITEM_STATUS_PENDING.to_string(),
+            failure_reason: None,
+        };
+
+        let rejection = if let Err(validation_err) = item.validate() {
+            Some(validation_messages(&validation_err).join("; "))
+        } else if item.is_bank_payout() {
+            None
+        } else {
+            match self.find_recipient(item).await? {
+                Some(recipient) if recipient.user_id == user_id => {
+                    Some("Cannot pay out to your own account".to_string())
+                }
+                Some(recipient) => {
+                    prepared.recipient_user_id = Some(recipient.user_id);
+                    None
+                }
+                None => Some("Recipient not found".to_string()),
+            }
+        };
+
+        if let Some(reason) = rejection {
+            prepared.status = PAYOUT_ITEM_STATUS_REJECTED.to_string();
+            prepared.failure_reason = Some(reason);
+        }
+
+        Ok(prepared)
+    }
+
+    async fn pay_wallet_item(
+        &self,
+        batch: &payout_batches::Model,
+        item: &payout_batch_items::Model,
+    ) -> Result<UpdatePayoutBatchItemRequest, ErrorResponse> {
+        let recipient_user_id = item.recipient_user_id.ok_or_else(|| {
+            ErrorResponse::from(AppError::UserNotFound(
+                "Recipient no longer exists".to_string(),
+            ))
+        })?;
+
+        let transfer = self
+            .transfer_service
+            .create_transfer(&CreateTransferRequest {
+                transfer_from: batch.user_id,
+                transfer_to: recipient_user_id,
+                recipient: None,
+                transfer_amount: item.amount,
+            })
+            .await?
+            .data;
+
+        // A held transfer settles through its review, like a bank item through its payout
+        let status = if transfer.status == TRANSFER_STATUS_PENDING_REVIEW {
+            PAYOUT_ITEM_STATUS_SUBMITTED
+        } else {
+            PAYOUT_ITEM_STATUS_COMPLETED
+        };
+
+        Ok(UpdatePayoutBatchItemRequest {
+            item_id: item.item_id,
+            status: status.to_string(),
+            failure_reason: None,
+            transfer_id: Some(transfer.transfer_id),
+            withdraw_id: None,
+        })
+    }
+
+    async fn pay_bank_item(
+        &self,
+        batch: &payout_batches::Model,
+        item: &payout_batch_items::Model,
+    ) -> Result<UpdatePayoutBatchItemRequest, ErrorResponse> {
+        let bank_code = item.bank_code.clone().unwrap_or_default();
+        let account_number = item.account_number.clone().unwrap_or_default();
+
+        let existing = self
+            .beneficiary_repository
+            .find_by_user(batch.user_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .into_iter()
+            .find(|b| b.bank_code == bank_code && b.account_number == account_number);
+
+        let beneficiary = match existing {
+            Some(beneficiary) => beneficiary,
+            None => self
+                .beneficiary_repository
+                .create(&CreateBeneficiaryRequest {
+                    user_id: batch.user_id,
+                    bank_code,
+                    account_number,
+                    account_name: item.account_name.clone().unwrap_or_default(),
+                })
+                .await
+                .map_err(AppError::from)
+                .map_err(ErrorResponse::from)?,
+        };
+
+        let withdraw = self
+            .withdraw_service
+            .create_withdraw(&CreateWithdrawRequest {
+                user_id: batch.user_id,
+                beneficiary_id: beneficiary.beneficiary_id,
+                withdraw_amount: item.amount,
+                withdraw_time: Utc::now(),
+            })
+            .await?
+            .data;
+
+        Ok(UpdatePayoutBatchItemRequest {
+            item_id: item.item_id,
+            status: PAYOUT_ITEM_STATUS_SUBMITTED.to_string(),
+            failure_reason: None,
+            transfer_id: None,
+            withdraw_id: Some(withdraw.withdraw_id),
+        })
+    }
+
+    // Returns whether the item was paid, or None when another run already took it; an error
+    // means it was never started and is still reserved
+    async fn process_item(
+        &self,
+        batch: &payout_batches::Model,
+        item: &payout_batch_items::Model,
+    ) -> Result<Option<bool>, ErrorResponse> {
+        // The share goes back to the sender so the services debit it together with their fee
+        if self
+            .payout_batch_repository
+            .claim_item(item.item_id, batch.user_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .is_none()
+        {
+            return Ok(None);
+        }
+
+        let result = if item.bank_code.is_some() {
+            self.pay_bank_item(batch, item).await
+        } else {
+            self.pay_wallet_item(batch, item).await
+        };
+
+        let update = match result {
+            Ok(update) => update,
+            Err(e) => {
+                warn!(
+                    "Payout batch {} item {} failed: {}",
+                    batch.batch_id, item.line_number, e.message
+                );
+
+                UpdatePayoutBatchItemRequest {
+                    item_id: item.item_id,
+                    status: PAYOUT_ITEM_STATUS_FAILED.to_string(),
+                    failure_reason: Some(e.message),
+                    transfer_id: None,
+                    withdraw_id: None,
+                }
+            }
+        };
+
+        let paid = update.status != PAYOUT_ITEM_STATUS_FAILED;
+
+        // The money has already moved, so an unrecorded item stays in processing and is never retried
+        if let Err(db_err) = self.payout_batch_repository.update_item(&update).await {
+            error!(
+                "Failed to record payout batch {} item {} as {}: {}",
+                batch.batch_id, item.line_number, update.status, db_err
+            );
+        }
+
+        Ok(Some(paid))
+    }
+
+    // Releases the reservation of items that could not be started and fails them
+    async fn fail_unprocessed(
+        &self,
+        batch: &payout_batches::Model,
+        items: &[&payout_batch_items::Model],
+    ) -> Result<(), ErrorResponse> {
+        for item in items {
+            if self
+                .payout_batch_repository
+                .claim_item(item.item_id, batch.user_id)
+                .await
+                .map_err(AppError::from)
+                .map_err(ErrorResponse::from)?
+                .is_none()
+            {
+                continue;
+            }
+
+            self.payout_batch_repository
+                .update_item(&UpdatePayoutBatchItemRequest {
+                    item_id: item.item_id,
+                    status: PAYOUT_ITEM_STATUS_FAILED.to_string(),
+                    failure_reason: Some(
+                        "Payout could not be processed, the amount was returned".to_string(),
+                    ),
+                    transfer_id: None,
+                    withdraw_id: None,
+                })
+                .await
+                .map_err(AppError::from)
+                .map_err(ErrorResponse::from)?;
+        }
+
+        Ok(())
+    }
+
+    async fn process_batch(&self, batch: &payout_batches::Model) -> Result<(), ErrorResponse> {
+        let items = match self
+            .payout_batch_repository
+            .find_items(batch.batch_id)
+            .await
+        {
+            Ok(items) => items,
+            Err(db_err) => {
+                // Nothing has been paid yet, so the next run can pick the batch up again
+                self.payout_batch_repository
+                    .update_status(
+                        batch.batch_id,
+                        PAYOUT_BATCH_STATUS_PROCESSING,
+                        PAYOUT_BATCH_STATUS_PENDING,
+                    )
+                    .await
+                    .map_err(AppError::from)
+                    .map_err(ErrorResponse::from)?;
+
+                return Err(ErrorResponse::from(AppError::from(db_err)));
+            }
+        };
+
+        let mut paid = 0;
+        let mut failed = items
+            .iter()
+            .filter(|item| item.status == PAYOUT_ITEM_STATUS_REJECTED)
+            .count();
+        let mut unprocessed = Vec::new();
+
+        for item in items
+            .iter()
+            .filter(|item| item.status == PAYOUT_ITEM_STATUS_PENDING)
+        {
+            match self.process_item(batch, item).await {
+                Ok(Some(true)) => paid += 1,
+                Ok(Some(false)) => failed += 1,
+                Ok(None) => {}
+                Err(e) => {
+                    error!(
+                        "Payout batch {} item {} could not be started: {}",
+                        batch.batch_id, item.line_number, e.message
+                    );
+                    unprocessed.push(item);
+                }
+            }
+        }
+
+        if !unprocessed.is_empty() {
+            if let Err(e) = self.fail_unprocessed(batch, &unprocessed).await {
+                // The items are still pending with their reservation held, so retry on the next run
+                error!(
+                    "Failed to release payout batch {} reservation, retrying later: {}",
+                    batch.batch_id, e.message
+                );
+
+                self.payout_batch_repository
+                    .update_status(
+                        batch.batch_id,
+                        PAYOUT_BATCH_STATUS_PROCESSING,
+                        PAYOUT_BATCH_STATUS_PENDING,
+                    )
+                    .await
+                    .map_err(AppError::from)
+                    .map_err(ErrorResponse::from)?;
+
+                return Err(e);
+            }
+
+            failed += unprocessed.len();
+        }
+
+        let status = match (paid, failed) {
+            (_, 0) => PAYOUT_BATCH_STATUS_COMPLETED,
+            (0, _) => PAYOUT_BATCH_STATUS_FAILED,
+            _ => PAYOUT_BATCH_STATUS_PARTIALLY_FAILED,
+        };
+
+        self.payout_batch_repository
+            .update_status(batch.batch_id, PAYOUT_BATCH_STATUS_PROCESSING, status)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        info!(
+            "Payout batch {} {}: {} items paid, {} failed",
+            batch.batch_id, status, paid, failed
+        );
+
+        Ok(())
+    }
+
+    // Bank items follow their withdraw through the payout lifecycle, so the report reads it back
+    async fn build_response(
+        &self,
+        batch: payout_batches::Model,
+    ) -> Result<PayoutBatchResponse, ErrorResponse> {
+        let items = self
+            .payout_batch_repository
+            .find_items(batch.batch_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        let withdraws: HashMap<i32, _> = self
+            .payout_batch_repository
+            .find_withdraws(batch.batch_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .into_iter()
+            .map(|withdraw| (withdraw.withdraw_id, withdraw))
+            .collect();
+
+        let mut transfers = HashMap::new();
+
+        for item in &items {
+            if let (Some(transfer_id), PAYOUT_ITEM_STATUS_SUBMITTED) =
+                (item.transfer_id, item.status.as_str())
+            {
+                if let Some(transfer) = self.transfer_service.get_transfer(transfer_id).await?.data
+                {
+                    transfers.insert(transfer_id, transfer.status);
+                }
+            }
+        }
+
+        let items = items
+            .into_iter()
+            .map(|item| {
+                let withdraw = item.withdraw_id.and_then(|id| withdraws.get(&id));
+                let transfer_status = item.transfer_id.and_then(|id| transfers.get(&id));
+                let mut response = PayoutBatchItemResponse::from(item);
+
+                match transfer_status.map(String::as_str) {
+                    Some(TRANSFER_STATUS_COMPLETED) => {
+                        response.status = PAYOUT_ITEM_STATUS_COMPLETED.to_string();
+                    }
+                    Some(TRANSFER_STATUS_REJECTED) => {
+                        response.status = PAYOUT_ITEM_STATUS_FAILED.to_string();
+                        response.failure_reason =
+                            Some("Transfer was rejected in review".to_string());
+                    }
+                    _ => {}
+                }
+
+                if let Some(withdraw) = withdraw {
+                    match withdraw.status.as_str() {
+                        WITHDRAW_STATUS_PAID => {
+                            response.status = PAYOUT_ITEM_STATUS_COMPLETED.to_string();
+                        }
+                        WITHDRAW_STATUS_FAILED => {
+                            response.status = PAYOUT_ITEM_STATUS_FAILED.to_string();
+                            response.failure_reason = withdraw.failure_reason.clone();
+                        }
+                        _ => {}
+                    }
+                }
+
+                response
+            })
+            .collect();
+
+        Ok(PayoutBatchResponse::new(batch, items))
+    }
+}
+
+#[async_trait]
+impl PayoutBatchServiceTrait for PayoutBatchService {
+    #[instrument(skip_all, fields(id = id, user_id = user_id))]
+    async fn get_payout_batch(
+        &self,
+        id: i32,
+        user_id: i32,
+    ) -> Result<ApiResponse<PayoutBatchResponse>, ErrorResponse> {
+        let batch = self
+            .payout_batch_repository
+            .find_by_id(id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .filter(|batch| batch.user_id == user_id)
+            .ok_or_else(|| {
+                ErrorResponse::from(AppError::NotFound(format!(
+                    "Payout batch with id {} not found",
+                    id
+                )))
+            })?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Payout batch retrieved successfully".to_string(),
+            data: self.build_response(batch).await?,
+        })
+    }
+
+    #[instrument(skip_all, fields(user_id = user_id))]
+    async fn get_payout_batch_users(
+        &self,
+        user_id: i32,
+    ) -> Result<ApiResponse<Vec<PayoutBatchResponse>>, ErrorResponse> {
+        let batches = self
+            .payout_batch_repository
+            .find_by_user(user_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        let mut responses = Vec::with_capacity(batches.len());
+
+        for batch in batches {
+            responses.push(self.build_response(batch).await?);
+        }
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Payout batches retrieved successfully".to_string(),
+            data: responses,
+        })
+    }
+
+    #[instrument(skip_all)]
+    async fn create_payout_batch(
+        &self,
+        input: &CreatePayoutBatchRequest,
+    ) -> Result<ApiResponse<PayoutBatchResponse>, ErrorResponse> {
+        if let Err(validation_err) = input.validate() {
+            error!(
+                "Validation failed for payout batch create: {}",
+                validation_err
+            );
+            return Err(ErrorResponse::from(AppError::InvalidFields(validation_err)));
+        }
+
+        self.user_repository
+            .find_by_id(input.user_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .ok_or_else(|| {
+                ErrorResponse::from(AppError::UserNotFound(format!(
+                    "User with id {} not found",
+                    input.user_id
+                )))
+            })?;
+
+        let mut items = Vec::with_capacity(input.items.len());
+
+        for (index, item) in input.items.iter().enumerate() {
+            items.push(
+                self.prepare_item(input.user_id, index as i32 + 1, item)
+                    .await?,
+            );
+        }
+
+        let too_large = || {
+            ErrorResponse::from(AppError::ValidationError(
+                "Batch total is too large".to_string(),
+            ))
+        };
+
+        let total_amount = items
+            .iter()
+            .try_fold(0i32, |total, item| total.checked_add(item.amount))
+            .ok_or_else(too_large)?;
+
+        let reserved_amount = items
+            .iter()
+            .filter(|item| item.status == PAYOUT_ITEM_STATUS_PENDING)
+            .try_fold(0i32, |total, item| total.checked_add(item.amount))
+            .ok_or_else(too_large)?;
+
+        if reserved_amount == 0 {
+            let reasons = items
+                .iter()
+                .map(|item| {
+                    format!(
+                        "line {}: {}",
+                        item.line_number,
+                        item.failure_reason.as_deref().unwrap_or_default()
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join("; ");
+
+            return Err(ErrorResponse::from(AppError::ValidationError(format!(
+                "No valid items in batch ({})",
+                reasons
+            ))));
+        }
+
+        // The whole batch counts against the sender's limits as if each item were sent alone
+        let (bank_amounts, wallet_amounts): (Vec<_>, Vec<_>) = items
+            .iter()
+            .filter(|item| item.status == PAYOUT_ITEM_STATUS_PENDING)
+            .partition(|item| item.bank_code.is_some());
+
+        for (operation, batch_items) in [
+            (LimitOperation::Transfer, wallet_amounts),
+            (LimitOperation::Withdraw, bank_amounts),
+        ] {
+            if batch_items.is_empty() {
+                continue;
+            }
+
+            let amounts: Vec<i32> = batch_items.iter().map(|item| item.amount).collect();
+
+            self.limit_service
+                .check_outgoing(input.user_id, operation, &amounts)
+                .await?;
+        }
+
+        // Reserve the whole batch up front so items can't be starved by later spending
+        if !self
+            .saldo_repository
+            .debit(input.user_id, reserved_amount)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+        {
+            error!(
+                "Insufficient balance for user_id: {}. Attempted payout batch: {}",
+                input.user_id, reserved_amount
+            );
+            return Err(ErrorResponse::from(AppError::InsufficientFunds(
+                "Insufficient balance".to_string(),
+            )));
+        }
+
+        let batch = match self
+            .payout_batch_repository
+            .create(input.user_id, total_amount, reserved_amount, &items)
+            .await
+        {
+            Ok(batch) => batch,
+            Err(db_err) => {
+                error!("Failed to create payout batch: {}", db_err);
+
+                self.saldo_repository
+                    .credit(input.user_id, reserved_amount)
+                    .await
+                    .map_err(|rollback_err| {
+                        error!(
+                            "Failed to release payout batch reservation: {}",
+                            rollback_err
+                        );
+                    })
+                    .ok();
+
+                return Err(ErrorResponse::from(AppError::from(db_err)));
+            }
+        };
+
+        info!(
+            "Payout batch {} created for user {} with {} items, {} reserved",
+            batch.batch_id,
+            input.user_id,
+            items.len(),
+            reserved_amount
+        );
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Payout batch accepted for processing".to_string(),
+            data: self.build_response(batch).await?,
+        })
+    }
+
+    #[instrument(skip_all)]
+    async fn run_pending_batches(&self) -> Result<u64, ErrorResponse> {
+        let batches = self
+            .payout_batch_repository
+            .find_by_status(PAYOUT_BATCH_STATUS_PENDING)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        let mut processed = 0;
+
+        for batch in &batches {
+            let claimed = self
+                .payout_batch_repository
+                .update_status(
+                    batch.batch_id,
+                    PAYOUT_BATCH_STATUS_PENDING,
+                    PAYOUT_BATCH_STATUS_PROCESSING,
+                )
+                .await
+                .map_err(AppError::from)
+                .map_err(ErrorResponse::from)?;
+
+            if !claimed {
+                continue;
+            }
+
+            match self.process_batch(batch).await {
+                Ok(()) => processed += 1,
+                Err(e) => error!(
+                    "Payout batch {} stopped while processing: {}",
+                    batch.batch_id, e.message
+                ),
+            }
+        }
+
+        Ok(processed)
+    }
+}
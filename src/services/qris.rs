@@ -0,0 +1,140 @@
+use async_trait::async_trait;
+use tracing::{error, info, instrument};
+use validator::Validate;
+
+use crate::{
+    abstract_trait::{qris::QrisServiceTrait, user::DynUserRepository},
+    domain::{
+        request::qris::{CreateQrisRequest, ParseQrisRequest, RenderQrisRequest},
+        response::{
+            qris::{QrisImage, QrisResponse},
+            ApiResponse, ErrorResponse,
+        },
+    },
+    utils::{
+        errors::AppError,
+        qris::{generate_qris, parse_qris, render_png, render_svg, QrisPayload},
+    },
+};
+
+pub struct QrisService {
+    user_repository: DynUserRepository,
+    merchant_city: String,
+    merchant_category_code: String,
+}
+
+impl QrisService {
+    pub fn new(
+        user_repository: DynUserRepository,
+        merchant_city: String,
+        merchant_category_code: String,
+    ) -> Self {
+        Self {
+            user_repository,
+            merchant_city,
+            merchant_category_code,
+        }
+    }
+}
+
+#[async_trait]
+impl QrisServiceTrait for QrisService {
+    #[instrument(skip_all)]
+    async fn generate_qris(
+        &self,
+        input: &CreateQrisRequest,
+    ) -> Result<ApiResponse<QrisResponse>, ErrorResponse> {
+        if let Err(validation_err) = input.validate() {
+            error!("Validation failed for qris generate: {}", validation_err);
+            return Err(ErrorResponse::from(AppError::InvalidFields(validation_err)));
+        }
+
+        let merchant = self
+            .user_repository
+            .find_by_id(input.user_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .ok_or_else(|| {
+                error!("User with id {} not found", input.user_id);
+                ErrorResponse::from(AppError::UserNotFound(format!(
+                    "User with id {} not found",
+                    input.user_id
+                )))
+            })?;
+
+        let payload = QrisPayload {
+            merchant_name: format!("{} {}", merchant.firstname, merchant.lastname),
+            merchant_city: self.merchant_city.clone(),
+            merchant_id: merchant.user_id.to_string(),
+            merchant_pan: merchant.noc_transfer,
+            merchant_category_code: self.merchant_category_code.clone(),
+            postal_code: None,
+            amount: input.amount,
+            reference: input.reference.clone(),
+        };
+
+        let qris = generate_qris(&payload).map_err(|e| {
+            error!("Failed to generate qris for user {}: {}", input.user_id, e);
+            ErrorResponse::from(AppError::ValidationError(e))
+        })?;
+
+        info!("Generated qris for user {}", input.user_id);
+
+        // Parse the generated string back so the response reflects the encoded (truncated) values
+        let encoded = parse_qris(&qris)
+            .map_err(|e| ErrorResponse::from(AppError::ValidationError(e)))?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Qris generated successfully".to_string(),
+            data: QrisResponse::new(qris, encoded),
+        })
+    }
+
+    #[instrument(skip_all)]
+    async fn parse_qris(
+        &self,
+        input: &ParseQrisRequest,
+    ) -> Result<ApiResponse<QrisResponse>, ErrorResponse> {
+        let payload = parse_qris(&input.payload).map_err(|e| {
+            error!("Failed to parse qris payload: {}", e);
+            ErrorResponse::from(AppError::ValidationError(e))
+        })?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Qris parsed successfully".to_string(),
+            data: QrisResponse::new(input.payload.trim().to_string(), payload),
+        })
+    }
+
+    #[instrument(skip_all)]
+    async fn render_qris(&self, input: &RenderQrisRequest) -> Result<QrisImage, ErrorResponse> {
+        parse_qris(&input.payload)
+            .map_err(|e| ErrorResponse::from(AppError::ValidationError(e)))?;
+
+        let format = input.format.as_deref().unwrap_or("png").to_lowercase();
+
+        let image = match format.as_str() {
+            "png" => render_png(input.payload.trim()).map(|body| QrisImage {
+                content_type: "image/png".to_string(),
+                body,
+            }),
+            "svg" => render_svg(input.payload.trim()).map(|body| QrisImage {
+                content_type: "image/svg+xml".to_string(),
+                body: body.into_bytes(),
+            }),
+            _ => {
+                return Err(ErrorResponse::from(AppError::ValidationError(
+                    "Format must be either png or svg".to_string(),
+                )))
+            }
+        };
+
+        image.map_err(|e| {
+            error!("Failed to render qris: {}", e);
+            ErrorResponse::from(AppError::ValidationError(e))
+        })
+    }
+}
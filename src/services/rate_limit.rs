@@ -0,0 +1,73 @@
+use async_trait::async_trait;
+use tracing::{info, warn};
+
+use crate::{
+    abstract_trait::{rate_limit::RateLimitServiceTrait, rate_limit_store::DynRateLimitStore},
+    utils::rate_limit::{RateLimitDecision, RateLimitGroup, RateLimitIdentities, RateLimitRules},
+};
+
+pub struct RateLimitService {
+    rules: RateLimitRules,
+    store: DynRateLimitStore,
+}
+
+impl RateLimitService {
+    pub fn new(rules: RateLimitRules, store: DynRateLimitStore) -> Self {
+        Self { rules, store }
+    }
+}
+
+#[async_trait]
+impl RateLimitServiceTrait for RateLimitService {
+    fn is_limited(&self, group: RateLimitGroup) -> bool {
+        self.rules.rules_for(group).next().is_some()
+    }
+
+    // Every bucket the request has an identity for takes a token, so switching API keys or
+    // logging in as someone else never gets around the per-IP limit
+    async fn check(
+        &self,
+        group: RateLimitGroup,
+        identities: &RateLimitIdentities,
+    ) -> Option<RateLimitDecision> {
+        let mut strictest: Option<RateLimitDecision> = None;
+
+        for rule in self.rules.rules_for(group) {
+            let Some(identity) = identities.get(rule.identity) else {
+                continue;
+            };
+
+            let key = format!("{}:{}:{}", group, rule.identity.as_str(), identity);
+
+            // A store outage should not take the API down with it
+            let decision = match self.store.take(&key, rule).await {
+                Ok(decision) => decision,
+                Err(e) => {
+                    warn!(
+                        "Rate limit store failed for {} by {}: {}",
+                        group,
+                        rule.identity.as_str(),
+                        e
+                    );
+                    continue;
+                }
+            };
+
+            if !decision.allowed {
+                info!(
+                    "Rate limited {} by {} for {:?}",
+                    group,
+                    rule.identity.as_str(),
+                    decision.retry_after
+                );
+            }
+
+            strictest = Some(match strictest {
+                Some(current) => current.strictest(decision),
+                None => decision,
+            });
+        }
+
+        strictest
+    }
+}
@@ -0,0 +1,66 @@
+use std::{collections::HashMap, sync::Mutex, time::Instant};
+
+use async_trait::async_trait;
+
+use crate::{
+    abstract_trait::rate_limit_store::RateLimitStoreTrait,
+    utils::rate_limit::{RateLimitDecision, RateLimitRule, TokenBucket},
+};
+
+// Buckets are only swept once there are at least this many of them
+const SWEEP_THRESHOLD: usize = 10_000;
+
+struct Buckets {
+    entries: HashMap<String, (TokenBucket, RateLimitRule)>,
+    sweep_at: usize,
+}
+
+// Keeps buckets in this process only; every instance enforces its own limits
+pub struct InMemoryRateLimitStore {
+    started_at: Instant,
+    buckets: Mutex<Buckets>,
+}
+
+impl InMemoryRateLimitStore {
+    pub fn new() -> Self {
+        Self {
+            started_at: Instant::now(),
+            buckets: Mutex::new(Buckets {
+                entries: HashMap::new(),
+                sweep_at: SWEEP_THRESHOLD,
+            }),
+        }
+    }
+}
+
+impl Default for InMemoryRateLimitStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl RateLimitStoreTrait for InMemoryRateLimitStore {
+    async fn take(&self, key: &str, rule: &RateLimitRule) -> Result<RateLimitDecision, String> {
+        let now = self.started_at.elapsed().as_secs_f64();
+        let mut buckets = self
+            .buckets
+            .lock()
+            .map_err(|_| "Rate limit store lock poisoned".to_string())?;
+
+        // Full buckets carry no state, so dropping them keeps memory bounded by active callers
+        if buckets.entries.len() >= buckets.sweep_at {
+            buckets
+                .entries
+                .retain(|_, (bucket, rule)| !bucket.is_full(rule, now));
+            buckets.sweep_at = (buckets.entries.len() * 2).max(SWEEP_THRESHOLD);
+        }
+
+        let (bucket, _) = buckets
+            .entries
+            .entry(key.to_string())
+            .or_insert_with(|| (TokenBucket::full(rule, now), *rule));
+
+        Ok(bucket.take(rule, now))
+    }
+}
@@ -0,0 +1,294 @@
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use tracing::{error, info, instrument, warn};
+
+use crate::{
+    abstract_trait::{
+        risk::{DynRiskRepository, RiskEngineTrait},
+        transfer::DynTransferRepository,
+        user::DynUserRepository,
+    },
+    domain::{
+        request::risk::{
+            CreateRiskDecisionRequest, ResolveRiskReviewRequest, RiskAssessmentRequest,
+            RISK_REVIEW_STATUS_PENDING,
+        },
+        response::{
+            risk::{RiskAssessment, RiskDecisionResponse},
+            ApiResponse, ErrorResponse,
+        },
+    },
+    utils::{
+        errors::AppError,
+        risk::{RiskAction, RiskCheck, RiskOperation, RiskRule, RiskRules},
+    },
+};
+
+// Evaluates the configured rules against the user's recent history
+pub struct RuleRiskEngine {
+    risk_rules: RiskRules,
+    risk_repository: DynRiskRepository,
+    user_repository: DynUserRepository,
+    transfer_repository: DynTransferRepository,
+}
+
+impl RuleRiskEngine {
+    pub fn new(
+        risk_rules: RiskRules,
+        risk_repository: DynRiskRepository,
+        user_repository: DynUserRepository,
+        transfer_repository: DynTransferRepository,
+    ) -> Self {
+        Self {
+            risk_rules,
+            risk_repository,
+            user_repository,
+            transfer_repository,
+        }
+    }
+
+    async fn matches(
+        &self,
+        rule: &RiskRule,
+        input: &RiskAssessmentRequest,
+    ) -> Result<bool, ErrorResponse> {
+        let now = Utc::now().naive_utc();
+
+        let matched = match rule.check {
+            RiskCheck::Velocity {
+                max_count,
+                window_minutes,
+            } => {
+                let recent = self
+                    .risk_repository
+                    .count_since(
+                        input.user_id,
+                        input.operation,
+                        now - Duration::minutes(window_minutes),
+                    )
+                    .await
+                    .map_err(AppError::from)
+                    .map_err(ErrorResponse::from)?;
+
+                recent >= max_count
+            }
+            RiskCheck::NewRecipient { min_amount } => match input.counterparty_user_id {
+                Some(recipient) if input.amount >= min_amount => {
+                    self.transfer_repository
+                        .count_between(input.user_id, recipient, None)
+                        .await
+                        .map_err(AppError::from)
+                        .map_err(ErrorResponse::from)?
+                        == 0
+                }
+                _ => false,
+            },
+            RiskCheck::RoundTrip { window_minutes } => match input.counterparty_user_id {
+                Some(recipient) => {
+                    self.transfer_repository
+                        .count_between(
+                            recipient,
+                            input.user_id,
+                            Some(now - Duration::minutes(window_minutes)),
+                        )
+                        .await
+                        .map_err(AppError::from)
+                        .map_err(ErrorResponse::from)?
+                        > 0
+                }
+                None => false,
+            },
+            RiskCheck::NewAccount {
+                account_age_hours,
+                max_amount,
+            } => {
+                if input.amount <= max_amount {
+                    false
+                } else {
+                    let user = self
+                        .user_repository
+                        .find_by_id(input.user_id)
+                        .await
+                        .map_err(AppError::from)
+                        .map_err(ErrorResponse::from)?;
+
+                    user.and_then(|user| user.created_at)
+                        .is_some_and(|created_at| {
+                            created_at > now - Duration::hours(account_age_hours)
+                        })
+                }
+            }
+        };
+
+        Ok(matched)
+    }
+}
+
+#[async_trait]
+impl RiskEngineTrait for RuleRiskEngine {
+    #[instrument(skip_all)]
+    async fn assess(&self, input: &RiskAssessmentRequest) -> Result<RiskAssessment, ErrorResponse> {
+        let mut action = RiskAction::Allow;
+        let mut triggered_rules = Vec::new();
+
+        for rule in self.risk_rules.rules_for(input.operation) {
+            if self.matches(rule, input).await? {
+                triggered_rules.push(rule.name.clone());
+                action = action.max(rule.action);
+            }
+        }
+
+        let decision = self
+            .risk_repository
+            .create(&CreateRiskDecisionRequest {
+                user_id: input.user_id,
+                operation: input.operation,
+                amount: input.amount,
+                counterparty_user_id: input.counterparty_user_id,
+                action,
+                triggered_rules: triggered_rules.clone(),
+            })
+            .await
+            .map_err(|e| {
+                error!(
+                    "Failed to record risk decision for user {}: {}",
+                    input.user_id, e
+                );
+                ErrorResponse::from(AppError::from(e))
+            })?;
+
+        match action {
+            RiskAction::Allow => {}
+            RiskAction::Review => info!(
+                "Risk decision {}: {} of {} by user {} held for review ({})",
+                decision.decision_id,
+                input.operation,
+                input.amount,
+                input.user_id,
+                triggered_rules.join(", ")
+            ),
+            RiskAction::Block => {
+                warn!(
+                    "Risk decision {}: {} of {} by user {} blocked ({})",
+                    decision.decision_id,
+                    input.operation,
+                    input.amount,
+                    input.user_id,
+                    triggered_rules.join(", ")
+                );
+
+                return Err(ErrorResponse::from(AppError::RiskBlocked(format!(
+                    "The {} was declined by rule(s): {}",
+                    input.operation,
+                    triggered_rules.join(", ")
+                ))));
+            }
+        }
+
+        Ok(RiskAssessment {
+            decision_id: decision.decision_id,
+            action,
+            triggered_rules,
+        })
+    }
+
+    #[instrument(skip_all, fields(decision_id = decision_id, reference_id = reference_id))]
+    async fn attach(&self, decision_id: i32, reference_id: i32) {
+        if let Err(e) = self
+            .risk_repository
+            .set_reference(decision_id, reference_id)
+            .await
+        {
+            error!(
+                "Failed to link risk decision {} to transaction {}: {}",
+                decision_id, reference_id, e
+            );
+        }
+    }
+
+    #[instrument(skip_all)]
+    async fn resolve(
+        &self,
+        operation: RiskOperation,
+        input: &ResolveRiskReviewRequest,
+    ) -> Result<(), ErrorResponse> {
+        let decision = self
+            .risk_repository
+            .find_by_reference(operation, input.reference_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .filter(|decision| {
+                decision.review_status.as_deref() == Some(RISK_REVIEW_STATUS_PENDING)
+            })
+            .ok_or_else(|| {
+                ErrorResponse::from(AppError::NotFound(format!(
+                    "No pending review for {} {}",
+                    operation, input.reference_id
+                )))
+            })?;
+
+        if !self
+            .risk_repository
+            .resolve(decision.decision_id, input)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+        {
+            return Err(ErrorResponse::from(AppError::ValidationError(format!(
+                "Review of {} {} has already been resolved",
+                operation, input.reference_id
+            ))));
+        }
+
+        info!(
+            "Risk decision {} for {} {} {} by operator {}",
+            decision.decision_id, operation, input.reference_id, input.decision, input.reviewer_id
+        );
+
+        Ok(())
+    }
+
+    #[instrument(skip_all)]
+    async fn get_pending_reviews(
+        &self,
+    ) -> Result<ApiResponse<Vec<RiskDecisionResponse>>, ErrorResponse> {
+        let decisions = self
+            .risk_repository
+            .find_by_review_status(RISK_REVIEW_STATUS_PENDING)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Review queue retrieved successfully".to_string(),
+            data: decisions
+                .into_iter()
+                .map(RiskDecisionResponse::from)
+                .collect(),
+        })
+    }
+
+    #[instrument(skip_all, fields(user_id = user_id))]
+    async fn get_decision_users(
+        &self,
+        user_id: i32,
+    ) -> Result<ApiResponse<Vec<RiskDecisionResponse>>, ErrorResponse> {
+        let decisions = self
+            .risk_repository
+            .find_by_user(user_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Risk decisions retrieved successfully".to_string(),
+            data: decisions
+                .into_iter()
+                .map(RiskDecisionResponse::from)
+                .collect(),
+        })
+    }
+}
@@ -1,40 +1,60 @@
-use tracing::{error, info};
+use tracing::{error, info, instrument};
+use validator::Validate;
 
 use async_trait::async_trait;
 
 use crate::{
     abstract_trait::{
+        limit::DynLimitService,
         saldo::{DynSaldoRepository, SaldoServiceTrait},
         user::DynUserRepository,
     },
     domain::{
-        request::saldo::{CreateSaldoRequest, UpdateSaldoRequest},
-        response::{saldo::SaldoResponse, ApiResponse, ErrorResponse},
+        request::{
+            pagination::ListRequest,
+            saldo::{CreateSaldoRequest, UpdateSaldoRequest, SALDO_LIST_FIELDS},
+        },
+        response::{saldo::SaldoResponse, ApiResponse, ApiResponsePagination, ErrorResponse},
     },
    
-    utils::errors::AppError,
+    utils::{errors::AppError, kyc::LimitOperation},
 };
 
 pub struct SaldoService {
     user_repository: DynUserRepository,
     saldo_repository: DynSaldoRepository,
+    limit_service: DynLimitService,
 }
 
 impl SaldoService {
-    pub fn new(user_repository: DynUserRepository, saldo_repository: DynSaldoRepository) -> Self {
+    pub fn new(
+        user_repository: DynUserRepository,
+        saldo_repository: DynSaldoRepository,
+        limit_service: DynLimitService,
+    ) -> Self {
         Self {
             user_repository,
             saldo_repository,
+            limit_service,
         }
     }
 }
 
 #[async_trait]
 impl SaldoServiceTrait for SaldoService {
-    async fn get_saldos(&self) -> Result<ApiResponse<Vec<SaldoResponse>>, ErrorResponse> {
-        let saldo = self
+    #[instrument(skip_all)]
+    async fn get_saldos(
+        &self,
+        input: &ListRequest,
+    ) -> Result<ApiResponsePagination<Vec<SaldoResponse>>, ErrorResponse> {
+        if let Err(validation_err) = input.validate_fields(&SALDO_LIST_FIELDS) {
+            error!("Validation failed for saldo list: {}", validation_err);
+            return Err(ErrorResponse::from(AppError::InvalidFields(validation_err)));
+        }
+
+        let (saldo, pagination) = self
             .saldo_repository
-            .find_all()
+            .find_paginated(input)
             .await
             .map_err(AppError::from)
             .map_err(ErrorResponse::from)?;
@@ -44,13 +64,15 @@ impl SaldoServiceTrait for SaldoService {
             .map(|saldo| SaldoResponse::from(saldo))
             .collect();
 
-        Ok(ApiResponse {
+        Ok(ApiResponsePagination {
             status: "success".to_string(),
             message: "Saldos retrieved successfully".to_string(),
             data: saldo_response,
+            pagination,
         })
     }
 
+    #[instrument(skip_all, fields(id = id))]
     async fn get_saldo(
         &self,
         id: i32,
@@ -76,12 +98,13 @@ impl SaldoServiceTrait for SaldoService {
         }
     }
 
+    #[instrument(skip_all, fields(id = id))]
     async fn get_saldo_users(
         &self,
         id: i32,
     ) -> Result<ApiResponse<Option<Vec<SaldoResponse>>>, ErrorResponse> {
         let _user = self.user_repository.find_by_id(id).await.map_err(|_| {
-            ErrorResponse::from(AppError::NotFound(format!("User with id {} not found", id)))
+            ErrorResponse::from(AppError::UserNotFound(format!("User with id {} not found", id)))
         })?;
 
         let saldo = self
@@ -121,12 +144,13 @@ impl SaldoServiceTrait for SaldoService {
         Ok(response)
     }
 
+    #[instrument(skip_all, fields(id = id))]
     async fn get_saldo_user(
         &self,
         id: i32,
     ) -> Result<ApiResponse<Option<SaldoResponse>>, ErrorResponse> {
         let _user = self.user_repository.find_by_id(id).await.map_err(|_| {
-            ErrorResponse::from(AppError::NotFound(format!("User with id {} not found", id)))
+            ErrorResponse::from(AppError::UserNotFound(format!("User with id {} not found", id)))
         })?;
 
         let saldo: Option<SaldoResponse> = self
@@ -157,15 +181,14 @@ impl SaldoServiceTrait for SaldoService {
         Ok(response)
     }
 
+    #[instrument(skip_all)]
     async fn create_saldo(
         &self,
         input: &CreateSaldoRequest,
     ) -> Result<ApiResponse<SaldoResponse>, ErrorResponse> {
         if let Err(validation_err) = input.validate() {
             error!("Validation failed for saldo create: {}", validation_err);
-            return Err(ErrorResponse::from(AppError::ValidationError(
-                validation_err,
-            )));
+            return Err(ErrorResponse::from(AppError::InvalidFields(validation_err)));
         }
 
         let _user = self
@@ -173,7 +196,7 @@ impl SaldoServiceTrait for SaldoService {
             .find_by_id(input.user_id)
             .await
             .map_err(|_| {
-                ErrorResponse::from(AppError::NotFound(format!(
+                ErrorResponse::from(AppError::UserNotFound(format!(
                     "User with id {} not found",
                     input.user_id
                 )))
@@ -195,15 +218,14 @@ impl SaldoServiceTrait for SaldoService {
         })
     }
 
+    #[instrument(skip_all)]
     async fn update_saldo(
         &self,
         input: &UpdateSaldoRequest,
     ) -> Result<ApiResponse<Option<SaldoResponse>>, ErrorResponse> {
         if let Err(validation_err) = input.validate() {
             error!("Validation failed for saldo update: {}", validation_err);
-            return Err(ErrorResponse::from(AppError::ValidationError(
-                validation_err,
-            )));
+            return Err(ErrorResponse::from(AppError::InvalidFields(validation_err)));
         }
 
         let _user = self
@@ -211,7 +233,7 @@ impl SaldoServiceTrait for SaldoService {
             .find_by_id(input.user_id)
             .await
             .map_err(|_| {
-                ErrorResponse::from(AppError::NotFound(format!(
+                ErrorResponse::from(AppError::UserNotFound(format!(
                     "User with id {} not found",
                     input.user_id
                 )))
@@ -225,7 +247,14 @@ impl SaldoServiceTrait for SaldoService {
             .map_err(ErrorResponse::from)?;
 
         match existing_saldo {
-            Some(_) => {
+            Some(saldo) => {
+                // The update debits withdraw_amount, so it is held to the withdraw limits
+                if let Some(amount) = input.withdraw_amount.filter(|amount| *amount > 0) {
+                    self.limit_service
+                        .check_outgoing(saldo.user_id, LimitOperation::Withdraw, &[amount])
+                        .await?;
+                }
+
                 let updated_saldo = self
                     .saldo_repository
                     .update(input)
@@ -251,9 +280,10 @@ impl SaldoServiceTrait for SaldoService {
         }
     }
 
+    #[instrument(skip_all, fields(id = id))]
     async fn delete_saldo(&self, id: i32) -> Result<ApiResponse<()>, ErrorResponse> {
         let user = self.user_repository.find_by_id(id).await.map_err(|_| {
-            ErrorResponse::from(AppError::NotFound(format!("User with id {} not found", id)))
+            ErrorResponse::from(AppError::UserNotFound(format!("User with id {} not found", id)))
         })?;
 
         let existing_saldo = self
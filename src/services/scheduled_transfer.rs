@@ -0,0 +1,541 @@
+use async_trait::async_trait;
+use chrono::{NaiveDateTime, Utc};
+use tracing::{error, info, warn, instrument};
+use validator::Validate;
+
+use crate::{
+    abstract_trait::{
+        notification::DynNotificationService,
+        scheduled_transfer::{DynScheduledTransferRepository, ScheduledTransferServiceTrait},
+        transfer::DynTransferService,
+        user::DynUserRepository,
+    },
+    domain::{
+        request::{
+            scheduled_transfer::{
+                CreateScheduledTransferExecutionRequest, CreateScheduledTransferRequest,
+                UpdateScheduledTransferRequest, UpdateScheduledTransferRunRequest,
+                EXECUTION_STATUS_FAILED, EXECUTION_STATUS_PENDING, EXECUTION_STATUS_SUCCEEDED,
+                SCHEDULED_TRANSFER_STATUS_ACTIVE, SCHEDULED_TRANSFER_STATUS_COMPLETED,
+                SCHEDULED_TRANSFER_STATUS_FAILED, SCHEDULED_TRANSFER_STATUS_PROCESSING,
+            },
+            transfer::{
+                CreateTransferRequest, TRANSFER_STATUS_COMPLETED, TRANSFER_STATUS_PENDING_REVIEW,
+                TRANSFER_STATUS_REJECTED,
+            },
+        },
+        response::{
+            scheduled_transfer::{ScheduledTransferExecutionResponse, ScheduledTransferResponse},
+            ApiResponse, ErrorResponse,
+        },
+    },
+    entities::scheduled_transfers,
+    utils::{errors::AppError, recurrence::BillingInterval},
+};
+
+pub struct ScheduledTransferService {
+    scheduled_transfer_repository: DynScheduledTransferRepository,
+    user_repository: DynUserRepository,
+    transfer_service: DynTransferService,
+    notification_service: DynNotificationService,
+}
+
+impl ScheduledTransferService {
+    pub fn new(
+        scheduled_transfer_repository: DynScheduledTransferRepository,
+        user_repository: DynUserRepository,
+        transfer_service: DynTransferService,
+        notification_service: DynNotificationService,
+    ) -> Self {
+        Self {
+            scheduled_transfer_repository,
+            user_repository,
+            transfer_service,
+            notification_service,
+        }
+    }
+
+    async fn find_scheduled_transfer(
+        &self,
+        id: i32,
+    ) -> Result<scheduled_transfers::Model, ErrorResponse> {
+        self.scheduled_transfer_repository
+            .find_by_id(id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .ok_or_else(|| {
+                error!("Scheduled transfer with id {} not found", id);
+                ErrorResponse::from(AppError::NotFound(format!(
+                    "Scheduled transfer with id {} not found",
+                    id
+                )))
+            })
+    }
+
+    async fn ensure_user_exists(&self, user_id: i32) -> Result<(), ErrorResponse> {
+        self.user_repository
+            .find_by_id(user_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .ok_or_else(|| {
+                error!("User with id {} not found", user_id);
+                ErrorResponse::from(AppError::UserNotFound(format!(
+                    "User with id {} not found",
+                    user_id
+                )))
+            })?;
+
+        Ok(())
+    }
+
+    // Returns the next run after `now`, skipping occurrences missed while the executor was down
+    fn next_run_at(
+        scheduled_transfer: &scheduled_transfers::Model,
+        now: NaiveDateTime,
+    ) -> Option<NaiveDateTime> {
+        let recurrence: BillingInterval = scheduled_transfer.recurrence.as_deref()?.parse().ok()?;
+
+        // Counted from the anchor rather than the previous run, so a Jan 31 schedule that ran
+        // on Feb 28 still runs on Mar 31
+        let mut count = 1;
+
+        loop {
+            let next_run_at = recurrence.next_occurrence(scheduled_transfer.anchor_at, count)?;
+
+            if next_run_at > now {
+                return Some(next_run_at);
+            }
+
+            count += 1;
+        }
+    }
+
+    async fn execute(
+        &self,
+        scheduled_transfer: &scheduled_transfers::Model,
+    ) -> Result<bool, ErrorResponse> {
+        // Claim the occurrence before moving money; a recurring schedule moves on to its next
+        // occurrence whatever the outcome, a one-off one is settled once the transfer has run
+        let now = Utc::now().naive_utc();
+        let next_run_at = Self::next_run_at(scheduled_transfer, now);
+
+        let claim = UpdateScheduledTransferRunRequest {
+            scheduled_transfer_id: scheduled_transfer.scheduled_transfer_id,
+            status: if next_run_at.is_some() {
+                SCHEDULED_TRANSFER_STATUS_ACTIVE.to_string()
+            } else {
+                SCHEDULED_TRANSFER_STATUS_PROCESSING.to_string()
+            },
+            next_run_at: next_run_at.unwrap_or(scheduled_transfer.next_run_at),
+            last_run_at: now,
+        };
+
+        let claimed = self
+            .scheduled_transfer_repository
+            .claim_run(&claim, scheduled_transfer.next_run_at)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        if !claimed {
+            info!(
+                "Scheduled transfer {} was already run or changed, skipping",
+                scheduled_transfer.scheduled_transfer_id
+            );
+            return Ok(false);
+        }
+
+        let result = self
+            .transfer_service
+            .create_transfer(&CreateTransferRequest {
+                transfer_from: scheduled_transfer.transfer_from,
+                transfer_to: scheduled_transfer.transfer_to,
+                recipient: None,
+                transfer_amount: scheduled_transfer.transfer_amount,
+            })
+            .await;
+
+        // A held transfer is settled by `settle_held_executions` once its review is done
+        if let Ok(transfer) = &result {
+            if transfer.data.status == TRANSFER_STATUS_PENDING_REVIEW {
+                info!(
+                    "Scheduled transfer {} is held for review as transfer {}",
+                    scheduled_transfer.scheduled_transfer_id, transfer.data.transfer_id
+                );
+
+                self.scheduled_transfer_repository
+                    .record_execution(&CreateScheduledTransferExecutionRequest {
+                        scheduled_transfer_id: scheduled_transfer.scheduled_transfer_id,
+                        transfer_id: Some(transfer.data.transfer_id),
+                        status: EXECUTION_STATUS_PENDING.to_string(),
+                        failure_reason: None,
+                    })
+                    .await
+                    .map_err(AppError::from)
+                    .map_err(ErrorResponse::from)?;
+
+                return Ok(false);
+            }
+        }
+
+        let result = result.map(|transfer| transfer.data.transfer_id);
+
+        let execution = match &result {
+            Ok(transfer_id) => CreateScheduledTransferExecutionRequest {
+                scheduled_transfer_id: scheduled_transfer.scheduled_transfer_id,
+                transfer_id: Some(*transfer_id),
+                status: EXECUTION_STATUS_SUCCEEDED.to_string(),
+                failure_reason: None,
+            },
+            Err(e) => {
+                warn!(
+                    "Scheduled transfer {} failed: {}",
+                    scheduled_transfer.scheduled_transfer_id, e.message
+                );
+
+                CreateScheduledTransferExecutionRequest {
+                    scheduled_transfer_id: scheduled_transfer.scheduled_transfer_id,
+                    transfer_id: None,
+                    status: EXECUTION_STATUS_FAILED.to_string(),
+                    failure_reason: Some(e.message.clone()),
+                }
+            }
+        };
+
+        self.scheduled_transfer_repository
+            .record_execution(&execution)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        if matches!(&result, Err(e) if e.code == "INSUFFICIENT_FUNDS") {
+            self.notify_failed(scheduled_transfer, "due to insufficient funds")
+                .await;
+        }
+
+        if next_run_at.is_none() {
+            self.finish_one_off(scheduled_transfer, result.is_ok())
+                .await?;
+        }
+
+        Ok(result.is_ok())
+    }
+
+    async fn notify_failed(&self, scheduled_transfer: &scheduled_transfers::Model, reason: &str) {
+        self.notification_service
+            .notify(
+                scheduled_transfer.transfer_from,
+                "Scheduled transfer failed",
+                &format!(
+                    "Your scheduled transfer of {} to user {} could not be executed {}",
+                    scheduled_transfer.transfer_amount, scheduled_transfer.transfer_to, reason
+                ),
+            )
+            .await;
+    }
+
+    // A one-off schedule is settled by the outcome of its only run
+    async fn finish_one_off(
+        &self,
+        scheduled_transfer: &scheduled_transfers::Model,
+        succeeded: bool,
+    ) -> Result<(), ErrorResponse> {
+        let status = if succeeded {
+            SCHEDULED_TRANSFER_STATUS_COMPLETED
+        } else {
+            SCHEDULED_TRANSFER_STATUS_FAILED
+        };
+
+        if !self
+            .scheduled_transfer_repository
+            .finish_run(scheduled_transfer.scheduled_transfer_id, status)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+        {
+            info!(
+                "Scheduled transfer {} was cancelled while running",
+                scheduled_transfer.scheduled_transfer_id
+            );
+        }
+
+        Ok(())
+    }
+
+    // Settles executions whose transfer was held for review once an operator has decided on it
+    async fn settle_held_executions(&self) -> Result<u64, ErrorResponse> {
+        let pending = self
+            .scheduled_transfer_repository
+            .find_executions_by_status(EXECUTION_STATUS_PENDING)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        let mut settled = 0;
+
+        for execution in &pending {
+            let Some(transfer_id) = execution.transfer_id else {
+                continue;
+            };
+
+            let transfer = self.transfer_service.get_transfer(transfer_id).await?;
+
+            let (status, failure_reason) =
+                match transfer.data.as_ref().map(|transfer| transfer.status.as_str()) {
+                    Some(TRANSFER_STATUS_COMPLETED) => (EXECUTION_STATUS_SUCCEEDED, None),
+                    Some(TRANSFER_STATUS_REJECTED) => (
+                        EXECUTION_STATUS_FAILED,
+                        Some("Transfer was rejected in review".to_string()),
+                    ),
+                    _ => continue,
+                };
+
+            if !self
+                .scheduled_transfer_repository
+                .settle_execution(execution.execution_id, status, failure_reason)
+                .await
+                .map_err(AppError::from)
+                .map_err(ErrorResponse::from)?
+            {
+                continue;
+            }
+
+            let scheduled_transfer = self
+                .find_scheduled_transfer(execution.scheduled_transfer_id)
+                .await?;
+            let succeeded = status == EXECUTION_STATUS_SUCCEEDED;
+
+            if !succeeded {
+                self.notify_failed(&scheduled_transfer, "because it was rejected in review")
+                    .await;
+            }
+
+            if scheduled_transfer.recurrence.is_none() {
+                self.finish_one_off(&scheduled_transfer, succeeded).await?;
+            }
+
+            settled += 1;
+        }
+
+        if settled > 0 {
+            info!("Settled {} held scheduled transfer executions", settled);
+        }
+
+        Ok(settled)
+    }
+}
+
+#[async_trait]
+impl ScheduledTransferServiceTrait for ScheduledTransferService {
+    #[instrument(skip_all, fields(id = id))]
+    async fn get_scheduled_transfer(
+        &self,
+        id: i32,
+    ) -> Result<ApiResponse<ScheduledTransferResponse>, ErrorResponse> {
+        let scheduled_transfer = self.find_scheduled_transfer(id).await?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Scheduled transfer retrieved successfully".to_string(),
+            data: ScheduledTransferResponse::from(scheduled_transfer),
+        })
+    }
+
+    #[instrument(skip_all, fields(user_id = user_id))]
+    async fn get_scheduled_transfer_users(
+        &self,
+        user_id: i32,
+    ) -> Result<ApiResponse<Vec<ScheduledTransferResponse>>, ErrorResponse> {
+        let scheduled_transfers = self
+            .scheduled_transfer_repository
+            .find_by_user(user_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Scheduled transfers retrieved successfully".to_string(),
+            data: scheduled_transfers
+                .into_iter()
+                .map(ScheduledTransferResponse::from)
+                .collect(),
+        })
+    }
+
+    #[instrument(skip_all, fields(id = id))]
+    async fn get_scheduled_transfer_executions(
+        &self,
+        id: i32,
+    ) -> Result<ApiResponse<Vec<ScheduledTransferExecutionResponse>>, ErrorResponse> {
+        let executions = self
+            .scheduled_transfer_repository
+            .find_executions(id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Scheduled transfer executions retrieved successfully".to_string(),
+            data: executions
+                .into_iter()
+                .map(ScheduledTransferExecutionResponse::from)
+                .collect(),
+        })
+    }
+
+    #[instrument(skip_all)]
+    async fn create_scheduled_transfer(
+        &self,
+        input: &CreateScheduledTransferRequest,
+    ) -> Result<ApiResponse<ScheduledTransferResponse>, ErrorResponse> {
+        if let Err(validation_err) = input.validate() {
+            error!("Validation failed for scheduled transfer create: {}", validation_err);
+            return Err(ErrorResponse::from(AppError::InvalidFields(validation_err)));
+        }
+
+        self.ensure_user_exists(input.transfer_from).await?;
+        self.ensure_user_exists(input.transfer_to).await?;
+
+        let scheduled_transfer = self
+            .scheduled_transfer_repository
+            .create(input)
+            .await
+            .map_err(|e| {
+                error!(
+                    "Failed to schedule transfer for user {}: {}",
+                    input.transfer_from, e
+                );
+                ErrorResponse::from(AppError::from(e))
+            })?;
+
+        info!(
+            "Scheduled transfer {} created for user {}",
+            scheduled_transfer.scheduled_transfer_id, input.transfer_from
+        );
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Scheduled transfer created successfully".to_string(),
+            data: ScheduledTransferResponse::from(scheduled_transfer),
+        })
+    }
+
+    #[instrument(skip_all)]
+    async fn update_scheduled_transfer(
+        &self,
+        input: &UpdateScheduledTransferRequest,
+    ) -> Result<ApiResponse<ScheduledTransferResponse>, ErrorResponse> {
+        if let Err(validation_err) = input.validate() {
+            error!("Validation failed for scheduled transfer update: {}", validation_err);
+            return Err(ErrorResponse::from(AppError::InvalidFields(validation_err)));
+        }
+
+        let existing = self
+            .find_scheduled_transfer(input.scheduled_transfer_id)
+            .await?;
+
+        if existing.transfer_from != input.transfer_from {
+            return Err(ErrorResponse::from(AppError::NotFound(format!(
+                "Scheduled transfer with id {} not found",
+                input.scheduled_transfer_id
+            ))));
+        }
+
+        if existing.status != SCHEDULED_TRANSFER_STATUS_ACTIVE {
+            return Err(ErrorResponse::from(AppError::ValidationError(format!(
+                "Scheduled transfer in status '{}' cannot be updated",
+                existing.status
+            ))));
+        }
+
+        self.ensure_user_exists(input.transfer_to).await?;
+
+        let scheduled_transfer = self
+            .scheduled_transfer_repository
+            .update(input)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        info!(
+            "Scheduled transfer {} updated",
+            scheduled_transfer.scheduled_transfer_id
+        );
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Scheduled transfer updated successfully".to_string(),
+            data: ScheduledTransferResponse::from(scheduled_transfer),
+        })
+    }
+
+    #[instrument(skip_all, fields(id = id, user_id = user_id))]
+    async fn delete_scheduled_transfer(
+        &self,
+        id: i32,
+        user_id: i32,
+    ) -> Result<ApiResponse<()>, ErrorResponse> {
+        let existing = self.find_scheduled_transfer(id).await?;
+
+        if existing.transfer_from != user_id {
+            return Err(ErrorResponse::from(AppError::NotFound(format!(
+                "Scheduled transfer with id {} not found",
+                id
+            ))));
+        }
+
+        // Cancelled rather than removed so the execution history is kept
+        self.scheduled_transfer_repository
+            .cancel(id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        info!("Scheduled transfer {} cancelled by user {}", id, user_id);
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Scheduled transfer deleted successfully".to_string(),
+            data: (),
+        })
+    }
+
+    #[instrument(skip_all)]
+    async fn run_due_transfers(&self) -> Result<u64, ErrorResponse> {
+        if let Err(e) = self.settle_held_executions().await {
+            error!("Failed to settle held scheduled transfers: {}", e.message);
+        }
+
+        let due = self
+            .scheduled_transfer_repository
+            .find_due(Utc::now().naive_utc())
+            .await
+            .map_err(|e| {
+                error!("Failed to load due scheduled transfers: {}", e);
+                ErrorResponse::from(AppError::from(e))
+            })?;
+
+        let mut executed = 0;
+
+        for scheduled_transfer in &due {
+            match self.execute(scheduled_transfer).await {
+                Ok(true) => executed += 1,
+                Ok(false) => {}
+                Err(e) => error!(
+                    "Failed to process scheduled transfer {}: {}",
+                    scheduled_transfer.scheduled_transfer_id, e.message
+                ),
+            }
+        }
+
+        if !due.is_empty() {
+            info!("Executed {} of {} due scheduled transfers", executed, due.len());
+        }
+
+        Ok(executed)
+    }
+}
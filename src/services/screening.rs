@@ -0,0 +1,303 @@
+use std::sync::{Arc, RwLock};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use tracing::{error, info, instrument, warn};
+use validator::Validate;
+
+use crate::{
+    abstract_trait::{
+        screening::{DynScreeningRepository, ScreeningServiceTrait},
+        user::DynUserRepository,
+    },
+    domain::{
+        request::screening::{
+            CreateScreeningMatchRequest, ReviewScreeningMatchRequest, SCREENING_CONTEXT_TRANSFER,
+            SCREENING_MATCH_STATUS_PENDING,
+        },
+        response::{
+            screening::{ScreeningMatchResponse, WatchlistStatusResponse},
+            ApiResponse, ErrorResponse,
+        },
+    },
+    entities::users,
+    utils::{errors::AppError, screening::Watchlist},
+};
+
+struct LoadedWatchlist {
+    watchlist: Arc<Watchlist>,
+    loaded_at: DateTime<Utc>,
+}
+
+pub struct ScreeningService {
+    screening_repository: DynScreeningRepository,
+    user_repository: DynUserRepository,
+    list_file: Option<String>,
+    match_threshold: f64,
+    // Swapped as a whole on reload so screening never sees a half-loaded list
+    current: RwLock<LoadedWatchlist>,
+}
+
+impl ScreeningService {
+    pub fn new(
+        screening_repository: DynScreeningRepository,
+        user_repository: DynUserRepository,
+        watchlist: Watchlist,
+        list_file: Option<String>,
+        match_threshold: f64,
+    ) -> Self {
+        Self {
+            screening_repository,
+            user_repository,
+            list_file,
+            match_threshold,
+            current: RwLock::new(LoadedWatchlist {
+                watchlist: Arc::new(watchlist),
+                loaded_at: Utc::now(),
+            }),
+        }
+    }
+
+    fn status(&self) -> WatchlistStatusResponse {
+        let current = self.current.read().unwrap_or_else(|e| e.into_inner());
+
+        WatchlistStatusResponse {
+            source_file: self.list_file.clone(),
+            entries: current.watchlist.len(),
+            match_threshold: self.match_threshold,
+            loaded_at: current.loaded_at,
+        }
+    }
+
+    fn watchlist(&self) -> Arc<Watchlist> {
+        self.current
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .watchlist
+            .clone()
+    }
+}
+
+#[async_trait]
+impl ScreeningServiceTrait for ScreeningService {
+    #[instrument(skip_all)]
+    async fn screen_user(&self, user: &users::Model, context: &str) -> Result<u64, ErrorResponse> {
+        let full_name = format!("{} {}", user.firstname, user.lastname);
+        let watchlist = self.watchlist();
+
+        for found in watchlist.screen(&full_name, self.match_threshold) {
+            let entry_reference = found.entry.key();
+
+            let existing = self
+                .screening_repository
+                .find_by_user_entry(user.user_id, &entry_reference)
+                .await
+                .map_err(AppError::from)
+                .map_err(ErrorResponse::from)?;
+
+            // A match an operator already decided on is not raised again
+            if existing.is_some() {
+                continue;
+            }
+
+            let created = self
+                .screening_repository
+                .create(&CreateScreeningMatchRequest {
+                    user_id: user.user_id,
+                    context: context.to_string(),
+                    screened_name: full_name.clone(),
+                    entry_reference: entry_reference.clone(),
+                    entry_name: found.entry.name.clone(),
+                    entry_source: found.entry.source.clone(),
+                    score: found.score,
+                })
+                .await
+                .map_err(|e| {
+                    error!(
+                        "Failed to record watchlist match for user {}: {}",
+                        user.user_id, e
+                    );
+                    ErrorResponse::from(AppError::from(e))
+                })?;
+
+            warn!(
+                "Watchlist match {} during {}: user {} ({}) against {} ({}), score {:.3}",
+                created.match_id,
+                context,
+                user.user_id,
+                full_name,
+                created.entry_name,
+                entry_reference,
+                found.score
+            );
+        }
+
+        self.screening_repository
+            .count_open(user.user_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)
+    }
+
+    #[instrument(skip_all, fields(user_id = user_id))]
+    async fn check_recipient(&self, user_id: i32) -> Result<(), ErrorResponse> {
+        let user = self
+            .user_repository
+            .find_by_id(user_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .ok_or_else(|| {
+                ErrorResponse::from(AppError::UserNotFound(format!(
+                    "User with id {} not found",
+                    user_id
+                )))
+            })?;
+
+        if self.screen_user(&user, SCREENING_CONTEXT_TRANSFER).await? > 0 {
+            return Err(ErrorResponse::from(AppError::ScreeningHold(
+                "Recipient cannot receive funds until a compliance review is complete".to_string(),
+            )));
+        }
+
+        Ok(())
+    }
+
+    #[instrument(skip_all)]
+    async fn get_pending_matches(
+        &self,
+    ) -> Result<ApiResponse<Vec<ScreeningMatchResponse>>, ErrorResponse> {
+        let matches = self
+            .screening_repository
+            .find_by_status(SCREENING_MATCH_STATUS_PENDING)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Review queue retrieved successfully".to_string(),
+            data: matches
+                .into_iter()
+                .map(ScreeningMatchResponse::from)
+                .collect(),
+        })
+    }
+
+    #[instrument(skip_all, fields(user_id = user_id))]
+    async fn get_match_users(
+        &self,
+        user_id: i32,
+    ) -> Result<ApiResponse<Vec<ScreeningMatchResponse>>, ErrorResponse> {
+        let matches = self
+            .screening_repository
+            .find_by_user(user_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Watchlist matches retrieved successfully".to_string(),
+            data: matches
+                .into_iter()
+                .map(ScreeningMatchResponse::from)
+                .collect(),
+        })
+    }
+
+    #[instrument(skip_all)]
+    async fn review_match(
+        &self,
+        input: &ReviewScreeningMatchRequest,
+    ) -> Result<ApiResponse<ScreeningMatchResponse>, ErrorResponse> {
+        if let Err(validation_err) = input.validate() {
+            error!("Validation failed for watchlist review: {}", validation_err);
+            return Err(ErrorResponse::from(AppError::InvalidFields(validation_err)));
+        }
+
+        let found = self
+            .screening_repository
+            .find_by_id(input.match_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .ok_or_else(|| {
+                ErrorResponse::from(AppError::NotFound(format!(
+                    "Watchlist match with id {} not found",
+                    input.match_id
+                )))
+            })?;
+
+        if found.user_id == input.reviewer_id {
+            return Err(ErrorResponse::from(AppError::ValidationError(
+                "Operators cannot review their own watchlist matches".to_string(),
+            )));
+        }
+
+        let reviewed = self
+            .screening_repository
+            .review(input)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .ok_or_else(|| {
+                ErrorResponse::from(AppError::ValidationError(format!(
+                    "Watchlist match {} has already been reviewed",
+                    input.match_id
+                )))
+            })?;
+
+        info!(
+            "Watchlist match {} for user {} {} by operator {}",
+            reviewed.match_id, reviewed.user_id, input.decision, input.reviewer_id
+        );
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: format!("Watchlist match {}", input.decision),
+            data: ScreeningMatchResponse::from(reviewed),
+        })
+    }
+
+    #[instrument(skip_all)]
+    async fn get_watchlist(&self) -> Result<ApiResponse<WatchlistStatusResponse>, ErrorResponse> {
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Watchlist retrieved successfully".to_string(),
+            data: self.status(),
+        })
+    }
+
+    #[instrument(skip_all)]
+    async fn reload_watchlist(
+        &self,
+    ) -> Result<ApiResponse<WatchlistStatusResponse>, ErrorResponse> {
+        let path = self.list_file.as_deref().ok_or_else(|| {
+            ErrorResponse::from(AppError::ValidationError(
+                "SANCTIONS_LIST_FILE is not configured".to_string(),
+            ))
+        })?;
+
+        // The old list stays in use if the new file cannot be read or parsed
+        let watchlist = Watchlist::load(path).map_err(|e| {
+            error!("Failed to reload watchlist: {}", e);
+            ErrorResponse::from(AppError::ValidationError(e))
+        })?;
+
+        let entries = watchlist.len();
+
+        *self.current.write().unwrap_or_else(|e| e.into_inner()) = LoadedWatchlist {
+            watchlist: Arc::new(watchlist),
+            loaded_at: Utc::now(),
+        };
+
+        info!("Watchlist reloaded from {} with {} entries", path, entries);
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Watchlist reloaded successfully".to_string(),
+            data: self.status(),
+        })
+    }
+}
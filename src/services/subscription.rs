@@ -0,0 +1,724 @@
+use async_trait::async_trait;
+use chrono::{Duration, NaiveDateTime, Utc};
+use tracing::{error, info, warn, instrument};
+use validator::Validate;
+
+use crate::{
+    abstract_trait::{
+        subscription::{DynSubscriptionRepository, SubscriptionServiceTrait},
+        transfer::DynTransferService,
+        user::DynUserRepository,
+    },
+    domain::{
+        request::{
+            subscription::{
+                CreateSubscriptionChargeRequest, CreateSubscriptionPlanRequest,
+                CreateSubscriptionRequest, UpdateSubscriptionScheduleRequest,
+                CHARGE_STATUS_FAILED, CHARGE_STATUS_PENDING, CHARGE_STATUS_SUCCEEDED,
+                SUBSCRIPTION_STATUS_ACTIVE, SUBSCRIPTION_STATUS_CANCELLED,
+                SUBSCRIPTION_STATUS_PAST_DUE, SUBSCRIPTION_STATUS_PAUSED,
+                SUBSCRIPTION_STATUS_PROCESSING,
+            },
+            transfer::{
+                CreateTransferRequest, TRANSFER_STATUS_COMPLETED, TRANSFER_STATUS_PENDING_REVIEW,
+                TRANSFER_STATUS_REJECTED,
+            },
+        },
+        response::{
+            subscription::{
+                SubscriptionChargeResponse, SubscriptionPlanResponse, SubscriptionResponse,
+            },
+            ApiResponse, ErrorResponse,
+        },
+    },
+    entities::{subscription_plans, subscriptions},
+    utils::{errors::AppError, recurrence::BillingInterval},
+};
+
+pub struct SubscriptionService {
+    subscription_repository: DynSubscriptionRepository,
+    user_repository: DynUserRepository,
+    transfer_service: DynTransferService,
+    dunning_retry_hours: Vec<i64>,
+}
+
+impl SubscriptionService {
+    pub fn new(
+        subscription_repository: DynSubscriptionRepository,
+        user_repository: DynUserRepository,
+        transfer_service: DynTransferService,
+        dunning_retry_hours: Vec<i64>,
+    ) -> Self {
+        Self {
+            subscription_repository,
+            user_repository,
+            transfer_service,
+            dunning_retry_hours,
+        }
+    }
+
+    async fn find_plan(&self, id: i32) -> Result<subscription_plans::Model, ErrorResponse> {
+        self.subscription_repository
+            .find_plan_by_id(id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .ok_or_else(|| {
+                error!("Subscription plan with id {} not found", id);
+                ErrorResponse::from(AppError::NotFound(format!(
+                    "Subscription plan with id {} not found",
+                    id
+                )))
+            })
+    }
+
+    // Only the subscriber or the merchant owning the plan may change a subscription
+    async fn find_owned_subscription(
+        &self,
+        id: i32,
+        user_id: i32,
+    ) -> Result<subscriptions::Model, ErrorResponse> {
+        let subscription = self
+            .subscription_repository
+            .find_by_id(id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .ok_or_else(|| {
+                error!("Subscription with id {} not found", id);
+                ErrorResponse::from(AppError::NotFound(format!(
+                    "Subscription with id {} not found",
+                    id
+                )))
+            })?;
+
+        let plan = self.find_plan(subscription.plan_id).await?;
+
+        if subscription.subscriber_id != user_id && plan.merchant_id != user_id {
+            return Err(ErrorResponse::from(AppError::NotFound(format!(
+                "Subscription with id {} not found",
+                id
+            ))));
+        }
+
+        Ok(subscription)
+    }
+
+    // Due date of a billing period, always counted from the anchor so it never drifts
+    fn period_start(
+        plan: &subscription_plans::Model,
+        subscription: &subscriptions::Model,
+        period: i32,
+    ) -> Result<NaiveDateTime, ErrorResponse> {
+        let interval: BillingInterval = plan
+            .billing_interval
+            .parse()
+            .map_err(|e: String| ErrorResponse::from(AppError::ValidationError(e)))?;
+
+        u32::try_from(period)
+            .ok()
+            .and_then(|period| period.checked_mul(plan.interval_count as u32))
+            .and_then(|count| interval.next_occurrence(subscription.billing_anchor_at, count))
+            .ok_or_else(|| {
+                ErrorResponse::from(AppError::ValidationError(
+                    "Next charge date is out of range".to_string(),
+                ))
+            })
+    }
+
+    // The next period after a successful attempt; after a failed one the next dunning retry,
+    // or cancellation once the retries are exhausted. A failed period stays unbilled, so a late
+    // success still bills from the anchor.
+    fn next_schedule(
+        &self,
+        plan: &subscription_plans::Model,
+        subscription: &subscriptions::Model,
+        succeeded: bool,
+    ) -> Result<UpdateSubscriptionScheduleRequest, ErrorResponse> {
+        let attempt = subscription.retry_count + 1;
+
+        if succeeded {
+            let billed_periods = subscription.billed_periods + 1;
+
+            return Ok(UpdateSubscriptionScheduleRequest {
+                subscription_id: subscription.subscription_id,
+                status: SUBSCRIPTION_STATUS_ACTIVE.to_string(),
+                next_charge_at: Self::period_start(plan, subscription, billed_periods)?,
+                billed_periods,
+                retry_count: 0,
+            });
+        }
+
+        Ok(match self.dunning_retry_hours.get(subscription.retry_count as usize) {
+            Some(hours) => UpdateSubscriptionScheduleRequest {
+                subscription_id: subscription.subscription_id,
+                status: SUBSCRIPTION_STATUS_PAST_DUE.to_string(),
+                next_charge_at: Utc::now().naive_utc() + Duration::hours(*hours),
+                billed_periods: subscription.billed_periods,
+                retry_count: attempt,
+            },
+            None => {
+                info!(
+                    "Cancelling subscription {} after {} failed attempts",
+                    subscription.subscription_id, attempt
+                );
+
+                UpdateSubscriptionScheduleRequest {
+                    subscription_id: subscription.subscription_id,
+                    status: SUBSCRIPTION_STATUS_CANCELLED.to_string(),
+                    next_charge_at: subscription.next_charge_at,
+                    billed_periods: subscription.billed_periods,
+                    retry_count: attempt,
+                }
+            }
+        })
+    }
+
+    // Releases the billing job's claim on the subscription
+    async fn finish_charge(
+        &self,
+        schedule: &UpdateSubscriptionScheduleRequest,
+    ) -> Result<(), ErrorResponse> {
+        let updated = self
+            .subscription_repository
+            .update_schedule(schedule, SUBSCRIPTION_STATUS_PROCESSING)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        if updated.is_none() {
+            info!(
+                "Subscription {} was cancelled while being charged",
+                schedule.subscription_id
+            );
+        }
+
+        Ok(())
+    }
+
+    async fn charge_subscription(
+        &self,
+        subscription: &subscriptions::Model,
+    ) -> Result<bool, ErrorResponse> {
+        let plan = self.find_plan(subscription.plan_id).await?;
+        let attempt = subscription.retry_count + 1;
+
+        let claimed = self
+            .subscription_repository
+            .claim_charge(subscription)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        if !claimed {
+            info!(
+                "Subscription {} was already charged or changed, skipping",
+                subscription.subscription_id
+            );
+            return Ok(false);
+        }
+
+        let result = self
+            .transfer_service
+            .create_transfer(&CreateTransferRequest {
+                transfer_from: subscription.subscriber_id,
+                transfer_to: plan.merchant_id,
+                recipient: None,
+                transfer_amount: plan.amount,
+            })
+            .await;
+
+        let (charge, succeeded) = match result {
+            // A held transfer keeps the subscription claimed until its review settles the charge
+            Ok(transfer) if transfer.data.status == TRANSFER_STATUS_PENDING_REVIEW => {
+                info!(
+                    "Charge for subscription {} is held for review as transfer {}",
+                    subscription.subscription_id, transfer.data.transfer_id
+                );
+
+                self.subscription_repository
+                    .record_charge(&CreateSubscriptionChargeRequest {
+                        subscription_id: subscription.subscription_id,
+                        transfer_id: Some(transfer.data.transfer_id),
+                        amount: plan.amount,
+                        status: CHARGE_STATUS_PENDING.to_string(),
+                        attempt,
+                        failure_reason: None,
+                    })
+                    .await
+                    .map_err(AppError::from)
+                    .map_err(ErrorResponse::from)?;
+
+                return Ok(false);
+            }
+            Ok(transfer) => (
+                CreateSubscriptionChargeRequest {
+                    subscription_id: subscription.subscription_id,
+                    transfer_id: Some(transfer.data.transfer_id),
+                    amount: plan.amount,
+                    status: CHARGE_STATUS_SUCCEEDED.to_string(),
+                    attempt,
+                    failure_reason: None,
+                },
+                true,
+            ),
+            Err(e) => {
+                warn!(
+                    "Charge attempt {} for subscription {} failed: {}",
+                    attempt, subscription.subscription_id, e.message
+                );
+
+                (
+                    CreateSubscriptionChargeRequest {
+                        subscription_id: subscription.subscription_id,
+                        transfer_id: None,
+                        amount: plan.amount,
+                        status: CHARGE_STATUS_FAILED.to_string(),
+                        attempt,
+                        failure_reason: Some(e.message),
+                    },
+                    false,
+                )
+            }
+        };
+
+        // Release the claim first so a failure to record the charge cannot leave it stuck
+        let schedule = self.next_schedule(&plan, subscription, succeeded)?;
+        self.finish_charge(&schedule).await?;
+
+        self.subscription_repository
+            .record_charge(&charge)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        Ok(succeeded)
+    }
+
+    // Settles charges whose transfer was held for review once an operator has decided on it
+    async fn settle_held_charges(&self) -> Result<u64, ErrorResponse> {
+        let pending = self
+            .subscription_repository
+            .find_charges_by_status(CHARGE_STATUS_PENDING)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        let mut settled = 0;
+
+        for charge in &pending {
+            let Some(transfer_id) = charge.transfer_id else {
+                continue;
+            };
+
+            let transfer = self.transfer_service.get_transfer(transfer_id).await?;
+
+            let (status, failure_reason) =
+                match transfer.data.as_ref().map(|transfer| transfer.status.as_str()) {
+                    Some(TRANSFER_STATUS_COMPLETED) => (CHARGE_STATUS_SUCCEEDED, None),
+                    Some(TRANSFER_STATUS_REJECTED) => (
+                        CHARGE_STATUS_FAILED,
+                        Some("Transfer was rejected in review".to_string()),
+                    ),
+                    _ => continue,
+                };
+
+            let subscription = self
+                .subscription_repository
+                .find_by_id(charge.subscription_id)
+                .await
+                .map_err(AppError::from)
+                .map_err(ErrorResponse::from)?
+                .ok_or_else(|| {
+                    ErrorResponse::from(AppError::NotFound(format!(
+                        "Subscription with id {} not found",
+                        charge.subscription_id
+                    )))
+                })?;
+
+            let plan = self.find_plan(subscription.plan_id).await?;
+            let schedule =
+                self.next_schedule(&plan, &subscription, status == CHARGE_STATUS_SUCCEEDED)?;
+
+            if !self
+                .subscription_repository
+                .settle_charge(charge.charge_id, status, failure_reason)
+                .await
+                .map_err(AppError::from)
+                .map_err(ErrorResponse::from)?
+            {
+                continue;
+            }
+
+            self.finish_charge(&schedule).await?;
+            settled += 1;
+        }
+
+        if settled > 0 {
+            info!("Settled {} held subscription charges", settled);
+        }
+
+        Ok(settled)
+    }
+}
+
+#[async_trait]
+impl SubscriptionServiceTrait for SubscriptionService {
+    #[instrument(skip_all, fields(id = id))]
+    async fn get_plan(&self, id: i32) -> Result<ApiResponse<SubscriptionPlanResponse>, ErrorResponse> {
+        let plan = self.find_plan(id).await?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Subscription plan retrieved successfully".to_string(),
+            data: SubscriptionPlanResponse::from(plan),
+        })
+    }
+
+    #[instrument(skip_all, fields(merchant_id = merchant_id))]
+    async fn get_plan_users(
+        &self,
+        merchant_id: i32,
+    ) -> Result<ApiResponse<Vec<SubscriptionPlanResponse>>, ErrorResponse> {
+        let plans = self
+            .subscription_repository
+            .find_plans_by_merchant(merchant_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Subscription plans retrieved successfully".to_string(),
+            data: plans
+                .into_iter()
+                .map(SubscriptionPlanResponse::from)
+                .collect(),
+        })
+    }
+
+    #[instrument(skip_all)]
+    async fn create_plan(
+        &self,
+        input: &CreateSubscriptionPlanRequest,
+    ) -> Result<ApiResponse<SubscriptionPlanResponse>, ErrorResponse> {
+        if let Err(validation_err) = input.validate() {
+            error!("Validation failed for subscription plan create: {}", validation_err);
+            return Err(ErrorResponse::from(AppError::InvalidFields(validation_err)));
+        }
+
+        let plan = self
+            .subscription_repository
+            .create_plan(input)
+            .await
+            .map_err(|e| {
+                error!("Failed to create subscription plan for merchant {}: {}", input.merchant_id, e);
+                ErrorResponse::from(AppError::from(e))
+            })?;
+
+        info!(
+            "Subscription plan {} created for merchant {}",
+            plan.plan_id, input.merchant_id
+        );
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Subscription plan created successfully".to_string(),
+            data: SubscriptionPlanResponse::from(plan),
+        })
+    }
+
+    #[instrument(skip_all, fields(id = id))]
+    async fn get_subscription(
+        &self,
+        id: i32,
+    ) -> Result<ApiResponse<SubscriptionResponse>, ErrorResponse> {
+        let subscription = self
+            .subscription_repository
+            .find_by_id(id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .ok_or_else(|| {
+                error!("Subscription with id {} not found", id);
+                ErrorResponse::from(AppError::NotFound(format!(
+                    "Subscription with id {} not found",
+                    id
+                )))
+            })?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Subscription retrieved successfully".to_string(),
+            data: SubscriptionResponse::from(subscription),
+        })
+    }
+
+    #[instrument(skip_all, fields(subscriber_id = subscriber_id))]
+    async fn get_subscription_users(
+        &self,
+        subscriber_id: i32,
+    ) -> Result<ApiResponse<Vec<SubscriptionResponse>>, ErrorResponse> {
+        let subscriptions = self
+            .subscription_repository
+            .find_by_subscriber(subscriber_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Subscriptions retrieved successfully".to_string(),
+            data: subscriptions
+                .into_iter()
+                .map(SubscriptionResponse::from)
+                .collect(),
+        })
+    }
+
+    #[instrument(skip_all, fields(id = id))]
+    async fn get_subscription_charges(
+        &self,
+        id: i32,
+    ) -> Result<ApiResponse<Vec<SubscriptionChargeResponse>>, ErrorResponse> {
+        let charges = self
+            .subscription_repository
+            .find_charges(id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Subscription charges retrieved successfully".to_string(),
+            data: charges
+                .into_iter()
+                .map(SubscriptionChargeResponse::from)
+                .collect(),
+        })
+    }
+
+    #[instrument(skip_all)]
+    async fn create_subscription(
+        &self,
+        input: &CreateSubscriptionRequest,
+    ) -> Result<ApiResponse<SubscriptionResponse>, ErrorResponse> {
+        if let Err(validation_err) = input.validate() {
+            error!("Validation failed for subscription create: {}", validation_err);
+            return Err(ErrorResponse::from(AppError::InvalidFields(validation_err)));
+        }
+
+        let plan = self.find_plan(input.plan_id).await?;
+
+        if !plan.is_active {
+            return Err(ErrorResponse::from(AppError::ValidationError(
+                "Subscription plan is no longer active".to_string(),
+            )));
+        }
+
+        if plan.merchant_id == input.subscriber_id {
+            return Err(ErrorResponse::from(AppError::ValidationError(
+                "Merchant cannot subscribe to their own plan".to_string(),
+            )));
+        }
+
+        self.user_repository
+            .find_by_id(input.subscriber_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .ok_or_else(|| {
+                error!("User with id {} not found", input.subscriber_id);
+                ErrorResponse::from(AppError::UserNotFound(format!(
+                    "User with id {} not found",
+                    input.subscriber_id
+                )))
+            })?;
+
+        let now = Utc::now();
+        let next_charge_at = input.start_at.unwrap_or(now).max(now).naive_utc();
+
+        // Creating the subscription is the subscriber's mandate for recurring debits
+        let subscription = self
+            .subscription_repository
+            .create(input, next_charge_at)
+            .await
+            .map_err(|e| {
+                error!(
+                    "Failed to subscribe user {} to plan {}: {}",
+                    input.subscriber_id, input.plan_id, e
+                );
+                ErrorResponse::from(AppError::from(e))
+            })?;
+
+        info!(
+            "User {} subscribed to plan {} as subscription {}",
+            input.subscriber_id, input.plan_id, subscription.subscription_id
+        );
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Subscription created successfully".to_string(),
+            data: SubscriptionResponse::from(subscription),
+        })
+    }
+
+    #[instrument(skip_all, fields(id = id, user_id = user_id))]
+    async fn pause_subscription(
+        &self,
+        id: i32,
+        user_id: i32,
+    ) -> Result<ApiResponse<SubscriptionResponse>, ErrorResponse> {
+        let subscription = self.find_owned_subscription(id, user_id).await?;
+
+        if subscription.status != SUBSCRIPTION_STATUS_ACTIVE
+            && subscription.status != SUBSCRIPTION_STATUS_PAST_DUE
+        {
+            return Err(ErrorResponse::from(AppError::ValidationError(format!(
+                "Subscription in status '{}' cannot be paused",
+                subscription.status
+            ))));
+        }
+
+        let subscription = self
+            .subscription_repository
+            .update_status(id, SUBSCRIPTION_STATUS_PAUSED)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        info!("Subscription {} paused by user {}", id, user_id);
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Subscription paused successfully".to_string(),
+            data: SubscriptionResponse::from(subscription),
+        })
+    }
+
+    #[instrument(skip_all, fields(id = id, user_id = user_id))]
+    async fn resume_subscription(
+        &self,
+        id: i32,
+        user_id: i32,
+    ) -> Result<ApiResponse<SubscriptionResponse>, ErrorResponse> {
+        let subscription = self.find_owned_subscription(id, user_id).await?;
+
+        if subscription.status != SUBSCRIPTION_STATUS_PAUSED {
+            return Err(ErrorResponse::from(AppError::ValidationError(format!(
+                "Subscription in status '{}' cannot be resumed",
+                subscription.status
+            ))));
+        }
+
+        // Periods skipped while paused are not charged retroactively: billing resumes with the
+        // period that is running now, charged straight away if its due date has passed
+        let now = Utc::now().naive_utc();
+        let plan = self.find_plan(subscription.plan_id).await?;
+
+        let mut billed_periods = subscription.billed_periods;
+        while Self::period_start(&plan, &subscription, billed_periods + 1)? <= now {
+            billed_periods += 1;
+        }
+
+        let next_charge_at = if billed_periods == subscription.billed_periods {
+            subscription.next_charge_at.max(now)
+        } else {
+            now
+        };
+
+        let subscription = self
+            .subscription_repository
+            .update_schedule(
+                &UpdateSubscriptionScheduleRequest {
+                    subscription_id: id,
+                    status: SUBSCRIPTION_STATUS_ACTIVE.to_string(),
+                    next_charge_at,
+                    billed_periods,
+                    retry_count: 0,
+                },
+                SUBSCRIPTION_STATUS_PAUSED,
+            )
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .ok_or_else(|| {
+                ErrorResponse::from(AppError::ValidationError(
+                    "Subscription was updated by another request, please retry".to_string(),
+                ))
+            })?;
+
+        info!("Subscription {} resumed by user {}", id, user_id);
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Subscription resumed successfully".to_string(),
+            data: SubscriptionResponse::from(subscription),
+        })
+    }
+
+    #[instrument(skip_all, fields(id = id, user_id = user_id))]
+    async fn cancel_subscription(
+        &self,
+        id: i32,
+        user_id: i32,
+    ) -> Result<ApiResponse<SubscriptionResponse>, ErrorResponse> {
+        let subscription = self.find_owned_subscription(id, user_id).await?;
+
+        if subscription.status == SUBSCRIPTION_STATUS_CANCELLED {
+            return Err(ErrorResponse::from(AppError::ValidationError(
+                "Subscription is already cancelled".to_string(),
+            )));
+        }
+
+        let subscription = self
+            .subscription_repository
+            .update_status(id, SUBSCRIPTION_STATUS_CANCELLED)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        info!("Subscription {} cancelled by user {}", id, user_id);
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Subscription cancelled successfully".to_string(),
+            data: SubscriptionResponse::from(subscription),
+        })
+    }
+
+    #[instrument(skip_all)]
+    async fn run_due_charges(&self) -> Result<u64, ErrorResponse> {
+        if let Err(e) = self.settle_held_charges().await {
+            error!("Failed to settle held subscription charges: {}", e.message);
+        }
+
+        let due = self
+            .subscription_repository
+            .find_due(Utc::now().naive_utc())
+            .await
+            .map_err(|e| {
+                error!("Failed to load due subscriptions: {}", e);
+                ErrorResponse::from(AppError::from(e))
+            })?;
+
+        let mut charged = 0;
+
+        for subscription in &due {
+            match self.charge_subscription(subscription).await {
+                Ok(true) => charged += 1,
+                Ok(false) => {}
+                Err(e) => error!(
+                    "Failed to process subscription {}: {}",
+                    subscription.subscription_id, e.message
+                ),
+            }
+        }
+
+        if !due.is_empty() {
+            info!("Charged {} of {} due subscriptions", charged, due.len());
+        }
+
+        Ok(charged)
+    }
+}
@@ -1,19 +1,35 @@
 use crate::{
     abstract_trait::{
+        fee::DynFeeService,
+        limit::DynLimitService,
+        metrics::DynMetrics,
+        risk::DynRiskEngine,
         saldo::DynSaldoRepository,
         topup::{DynTopupRepository, TopupServiceTrait},
         user::DynUserRepository,
     },
     domain::{
         request::{
+            pagination::ListRequest,
+            risk::{ResolveRiskReviewRequest, RiskAssessmentRequest},
             saldo::{CreateSaldoRequest, UpdateSaldoBalance},
-            topup::{CreateTopupRequest, UpdateTopupAmount, UpdateTopupRequest},
+            topup::{
+                BankPaymentReference, CreateTopupRequest, UpdateTopupAmount, UpdateTopupRequest, TOPUP_LIST_FIELDS,
+                TOPUP_STATUS_COMPLETED, TOPUP_STATUS_PENDING_REVIEW, TOPUP_STATUS_REJECTED,
+            },
         },
-        response::{topup::TopupResponse, ApiResponse, ErrorResponse},
+        response::{topup::TopupResponse, ApiResponse, ApiResponsePagination, ErrorResponse},
+    },
+    entities::topups,
+    utils::{
+        errors::AppError,
+        fee::{FeeOperation, FeeQuote},
+        metrics::{TransactionKind, TransactionRecorder},
+        risk::{RiskAction, RiskOperation},
     },
-    utils::errors::AppError,
 };
-use tracing::{error, info};
+use tracing::{error, info, instrument};
+use validator::Validate;
 
 use async_trait::async_trait;
 
@@ -21,6 +37,10 @@ pub struct TopupService {
     topup_repository: DynTopupRepository,
     saldo_repository: DynSaldoRepository,
     user_repository: DynUserRepository,
+    fee_service: DynFeeService,
+    limit_service: DynLimitService,
+    risk_engine: DynRiskEngine,
+    metrics: DynMetrics,
 }
 
 impl TopupService {
@@ -28,38 +48,305 @@ impl TopupService {
         topup_repository: DynTopupRepository,
         saldo_repository: DynSaldoRepository,
         user_repository: DynUserRepository,
+        fee_service: DynFeeService,
+        limit_service: DynLimitService,
+        risk_engine: DynRiskEngine,
+        metrics: DynMetrics,
     ) -> Self {
         Self {
             topup_repository,
             saldo_repository,
             user_repository,
+            fee_service,
+            limit_service,
+            risk_engine,
+            metrics,
+        }
+    }
+
+    async fn create(
+        &self,
+        input: &CreateTopupRequest,
+        payment: Option<&BankPaymentReference>,
+    ) -> Result<ApiResponse<TopupResponse>, ErrorResponse> {
+        let recorder = TransactionRecorder::start(&self.metrics, TransactionKind::Topup);
+
+        if let Err(validation_err) = input.validate() {
+            error!("Validation failed for topup create: {}", validation_err);
+            return Err(ErrorResponse::from(AppError::InvalidFields(validation_err)));
+        }
+
+        let _user = self
+            .user_repository
+            .find_by_id(input.user_id)
+            .await
+            .map_err(|_| {
+                error!("User with id {} not found", input.user_id);
+                ErrorResponse::from(AppError::UserNotFound(format!(
+                    "User with id {} not found",
+                    input.user_id
+                )))
+            })?;
+
+        info!(
+            "User with id {} found, proceeding with topup creation",
+            input.user_id
+        );
+
+        let fee = self.fee_service.quote(
+            FeeOperation::Topup,
+            Some(&input.topup_method),
+            input.topup_amount,
+        );
+
+        if fee.fee_amount >= input.topup_amount {
+            return Err(ErrorResponse::from(AppError::ValidationError(
+                "Topup amount does not cover the fee".to_string(),
+            )));
         }
+
+        self.limit_service
+            .check_incoming(input.user_id, input.topup_amount - fee.fee_amount)
+            .await?;
+
+        let risk = self
+            .risk_engine
+            .assess(&RiskAssessmentRequest {
+                user_id: input.user_id,
+                operation: RiskOperation::Topup,
+                amount: input.topup_amount,
+                counterparty_user_id: None,
+            })
+            .await?;
+
+        let held = risk.action == RiskAction::Review;
+        let status = if held {
+            TOPUP_STATUS_PENDING_REVIEW
+        } else {
+            TOPUP_STATUS_COMPLETED
+        };
+
+        // The fee is taken out of the topup before it reaches the user's saldo
+        let net_amount = input.topup_amount - fee.fee_amount;
+
+        let topup = match payment {
+            // A bank payment is credited in the transaction that claims its reference, so a
+            // retried notification can never credit it twice
+            Some(payment) => {
+                let created = self
+                    .topup_repository
+                    .create_for_bank_payment(
+                        input,
+                        &fee,
+                        status,
+                        payment,
+                        (!held).then_some(net_amount),
+                    )
+                    .await
+                    .map_err(AppError::from)
+                    .map_err(ErrorResponse::from)?;
+
+                match created {
+                    Some(topup) => topup,
+                    None => return self.processed_bank_payment(payment).await,
+                }
+            }
+            None => self
+                .topup_repository
+                .create(input, &fee, status)
+                .await
+                .map_err(AppError::from)
+                .map_err(ErrorResponse::from)?,
+        };
+
+        self.risk_engine
+            .attach(risk.decision_id, topup.topup_id)
+            .await;
+
+        // A held topup is only credited once an operator releases it
+        if held {
+            recorder.created(&input.topup_method, topup.topup_amount);
+
+            return Ok(ApiResponse {
+                status: "success".to_string(),
+                message: "Topup is held for review".to_string(),
+                data: TopupResponse::from(topup),
+            });
+        }
+
+        info!(
+            "Topup created for user with id {}: topup amount {}",
+            input.user_id, topup.topup_amount
+        );
+
+        if payment.is_none() {
+            self.credit_saldo(&topup, net_amount).await?;
+        }
+
+        if let Err(e) = self.fee_service.collect(&fee).await {
+            error!("Failed to collect fee for topup {}: {}", topup.topup_id, e.message);
+        }
+
+        info!(
+            "Topup successfully created for user {}. Total balance updated.",
+            input.user_id
+        );
+
+        recorder.created(&input.topup_method, topup.topup_amount);
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Topup created successfully".to_string(),
+            data: TopupResponse::from(topup),
+        })
+    }
+
+    async fn credit_saldo(&self, topup: &topups::Model, net_amount: i32) -> Result<(), ErrorResponse> {
+        match self.saldo_repository.find_by_user_id(topup.user_id).await {
+            Ok(Some(current_saldo)) => {
+                let new_balance = current_saldo.total_balance + net_amount;
+                let request = UpdateSaldoBalance {
+                    user_id: topup.user_id,
+                    total_balance: new_balance,
+                };
+
+                if let Err(db_err) = self.saldo_repository.update_balance(&request).await {
+                    error!(
+                        "Failed to update saldo balance for user {}: {}",
+                        topup.user_id, db_err
+                    );
+
+                    self.metrics.record_rollback(TransactionKind::Topup);
+
+                    if let Err(rb_err) = self.topup_repository.delete(topup.topup_id).await {
+                        error!(
+                            "Failed to rollback topup creation for user {}: {}",
+                            topup.user_id, rb_err
+                        );
+                    }
+
+                    return Err(ErrorResponse::from(AppError::from(db_err)));
+                }
+
+                info!(
+                    "Saldo updated successfully for user {}. New balance: {}",
+                    topup.user_id, new_balance
+                );
+            }
+            Ok(None) => {
+                let create_saldo_request = CreateSaldoRequest {
+                    user_id: topup.user_id,
+                    total_balance: net_amount,
+                };
+
+                if let Err(db_err) = self.saldo_repository.create(&create_saldo_request).await {
+                    error!(
+                        "Failed to create initial saldo for user {}: {}",
+                        topup.user_id, db_err
+                    );
+
+                    self.metrics.record_rollback(TransactionKind::Topup);
+
+                    if let Err(rb_err) = self.topup_repository.delete(topup.topup_id).await {
+                        error!(
+                            "Failed to rollback topup creation for user {}: {}",
+                            topup.user_id, rb_err
+                        );
+                    }
+
+                    return Err(ErrorResponse::from(AppError::from(db_err)));
+                }
+
+                info!(
+                    "Initial saldo created for user {} with balance {}",
+                    topup.user_id, net_amount
+                );
+            }
+            Err(_) => {
+                error!("Failed to retrieve saldo for user {}", topup.user_id);
+
+                self.metrics.record_rollback(TransactionKind::Topup);
+
+                if let Err(rb_err) = self.topup_repository.delete(topup.topup_id).await {
+                    error!(
+                        "Failed to rollback topup creation for user {}: {}",
+                        topup.user_id, rb_err
+                    );
+                }
+
+                return Err(ErrorResponse::from(AppError::NotFound(format!(
+                    "Saldo with user_id {} not found",
+                    topup.user_id
+                ))));
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn processed_bank_payment(
+        &self,
+        payment: &BankPaymentReference,
+    ) -> Result<ApiResponse<TopupResponse>, ErrorResponse> {
+        let topup = self
+            .topup_repository
+            .find_by_bank_payment(payment)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .ok_or_else(|| {
+                ErrorResponse::from(AppError::InternalError(format!(
+                    "Payment reference {} is claimed without a topup",
+                    payment.payment_reference
+                )))
+            })?;
+
+        info!(
+            "Payment reference {} from {} already processed as topup {}",
+            payment.payment_reference, payment.bank_code, topup.topup_id
+        );
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Payment notification already processed".to_string(),
+            data: TopupResponse::from(topup),
+        })
     }
 }
 
 #[async_trait]
 impl TopupServiceTrait for TopupService {
-    async fn get_topups(&self) -> Result<ApiResponse<Vec<TopupResponse>>, ErrorResponse> {
+    #[instrument(skip_all)]
+    async fn get_topups(
+        &self,
+        input: &ListRequest,
+    ) -> Result<ApiResponsePagination<Vec<TopupResponse>>, ErrorResponse> {
+        if let Err(validation_err) = input.validate_fields(&TOPUP_LIST_FIELDS) {
+            error!("Validation failed for topup list: {}", validation_err);
+            return Err(ErrorResponse::from(AppError::InvalidFields(validation_err)));
+        }
+
         let topup = self
             .topup_repository
-            .find_all()
+            .find_paginated(input)
             .await
             .map_err(AppError::from)
             .map_err(ErrorResponse::from);
 
         match topup {
-            Ok(topup) => {
+            Ok((topup, pagination)) => {
                 let topup_response: Vec<TopupResponse> = topup
                     .into_iter()
-                    .map(|topup| TopupResponse::from(topup))
+                    .map(TopupResponse::from)
                     .collect();
 
                 info!("Successfully retrieved {} topups.", topup_response.len());
 
-                Ok(ApiResponse {
+                Ok(ApiResponsePagination {
                     status: "success".to_string(),
                     message: "Topup retrieved successfully".to_string(),
                     data: topup_response,
+                    pagination,
                 })
             }
             Err(err) => {
@@ -69,6 +356,7 @@ impl TopupServiceTrait for TopupService {
         }
     }
 
+    #[instrument(skip_all, fields(id = id))]
     async fn get_topup(
         &self,
         id: i32,
@@ -105,13 +393,14 @@ impl TopupServiceTrait for TopupService {
         }
     }
 
+    #[instrument(skip_all, fields(id = id))]
     async fn get_topup_users(
         &self,
         id: i32,
     ) -> Result<ApiResponse<Option<Vec<TopupResponse>>>, ErrorResponse> {
         let _user = self.user_repository.find_by_id(id).await.map_err(|_| {
             error!("User with id {} not found", id);
-            ErrorResponse::from(AppError::NotFound(format!("User with id {} not found", id)))
+            ErrorResponse::from(AppError::UserNotFound(format!("User with id {} not found", id)))
         })?;
 
         let topup = self
@@ -160,13 +449,14 @@ impl TopupServiceTrait for TopupService {
         }
     }
 
+    #[instrument(skip_all, fields(id = id))]
     async fn get_topup_user(
         &self,
         id: i32,
     ) -> Result<ApiResponse<Option<TopupResponse>>, ErrorResponse> {
         let _user = self.user_repository.find_by_id(id).await.map_err(|_| {
             error!("User with id {} not found", id);
-            ErrorResponse::from(AppError::NotFound(format!("User with id {} not found", id)))
+            ErrorResponse::from(AppError::UserNotFound(format!("User with id {} not found", id)))
         })?;
 
         let topup: Option<TopupResponse> = self
@@ -197,139 +487,149 @@ impl TopupServiceTrait for TopupService {
         }
     }
 
+    #[instrument(skip_all)]
     async fn create_topup(
         &self,
         input: &CreateTopupRequest,
+    ) -> Result<ApiResponse<TopupResponse>, ErrorResponse> {
+        self.create(input, None).await
+    }
+
+    #[instrument(skip_all)]
+    async fn create_bank_topup(
+        &self,
+        input: &CreateTopupRequest,
+        payment: &BankPaymentReference,
+    ) -> Result<ApiResponse<TopupResponse>, ErrorResponse> {
+        self.create(input, Some(payment)).await
+    }
+
+    #[instrument(skip_all)]
+    async fn review_topup(
+        &self,
+        input: &ResolveRiskReviewRequest,
     ) -> Result<ApiResponse<TopupResponse>, ErrorResponse> {
         if let Err(validation_err) = input.validate() {
-            error!("Validation failed for topup create: {}", validation_err);
-            return Err(ErrorResponse::from(AppError::ValidationError(
-                validation_err,
-            )));
+            error!("Validation failed for topup review: {}", validation_err);
+            return Err(ErrorResponse::from(AppError::InvalidFields(validation_err)));
         }
 
-        let _user = self
-            .user_repository
-            .find_by_id(input.user_id)
+        let topup = self
+            .topup_repository
+            .find_by_id(input.reference_id)
             .await
-            .map_err(|_| {
-                error!("User with id {} not found", input.user_id);
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .filter(|topup| topup.status == TOPUP_STATUS_PENDING_REVIEW)
+            .ok_or_else(|| {
                 ErrorResponse::from(AppError::NotFound(format!(
-                    "User with id {} not found",
-                    input.user_id
+                    "Held topup with id {} not found",
+                    input.reference_id
                 )))
             })?;
 
-        info!(
-            "User with id {} found, proceeding with topup creation",
-            input.user_id
-        );
+        let status = if input.is_release() {
+            TOPUP_STATUS_COMPLETED
+        } else {
+            TOPUP_STATUS_REJECTED
+        };
 
-        let topup = self
+        if !self
             .topup_repository
-            .create(&input)
+            .update_status(topup.topup_id, TOPUP_STATUS_PENDING_REVIEW, status)
             .await
             .map_err(AppError::from)
-            .map_err(ErrorResponse::from)?;
-
-        info!(
-            "Topup created for user with id {}: topup amount {}",
-            input.user_id, topup.topup_amount
-        );
-
-        match self.saldo_repository.find_by_user_id(input.user_id).await {
-            Ok(Some(current_saldo)) => {
-                let new_balance = current_saldo.total_balance + topup.topup_amount;
-                let request = UpdateSaldoBalance {
-                    user_id: input.user_id,
-                    total_balance: new_balance,
-                };
-
-                if let Err(db_err) = self.saldo_repository.update_balance(&request).await {
-                    error!(
-                        "Failed to update saldo balance for user {}: {}",
-                        input.user_id, db_err
-                    );
-
-                    if let Err(rb_err) = self.topup_repository.delete(topup.topup_id).await {
-                        error!(
-                            "Failed to rollback topup creation for user {}: {}",
-                            input.user_id, rb_err
-                        );
-                    }
-
-                    return Err(ErrorResponse::from(AppError::from(db_err)));
-                }
-
-                info!(
-                    "Saldo updated successfully for user {}. New balance: {}",
-                    input.user_id, new_balance
-                );
-            }
-            Ok(None) => {
-                let create_saldo_request = CreateSaldoRequest {
-                    user_id: input.user_id,
-                    total_balance: topup.topup_amount,
-                };
-
-                if let Err(db_err) = self.saldo_repository.create(&create_saldo_request).await {
-                    error!(
-                        "Failed to create initial saldo for user {}: {}",
-                        input.user_id, db_err
-                    );
-
-                    if let Err(rb_err) = self.topup_repository.delete(topup.topup_id).await {
-                        error!(
-                            "Failed to rollback topup creation for user {}: {}",
-                            input.user_id, rb_err
-                        );
-                    }
-
-                    return Err(ErrorResponse::from(AppError::from(db_err)));
-                }
+            .map_err(ErrorResponse::from)?
+        {
+            return Err(ErrorResponse::from(AppError::ValidationError(format!(
+                "Topup {} has already been reviewed",
+                topup.topup_id
+            ))));
+        }
 
-                info!(
-                    "Initial saldo created for user {} with balance {}",
-                    input.user_id, topup.topup_amount
+        if input.is_release() {
+            let net_amount = topup.topup_amount - topup.fee_amount;
+
+            let saldo = self
+                .saldo_repository
+                .find_by_user_id(topup.user_id)
+                .await
+                .map_err(AppError::from)
+                .map_err(ErrorResponse::from)?;
+
+            let credited = match saldo {
+                Some(saldo) => self
+                    .saldo_repository
+                    .update_balance(&UpdateSaldoBalance {
+                        user_id: topup.user_id,
+                        total_balance: saldo.total_balance + net_amount,
+                    })
+                    .await
+                    .map(|_| ()),
+                None => self
+                    .saldo_repository
+                    .create(&CreateSaldoRequest {
+                        user_id: topup.user_id,
+                        total_balance: net_amount,
+                    })
+                    .await
+                    .map(|_| ()),
+            };
+
+            credited
+                .map_err(AppError::from)
+                .map_err(ErrorResponse::from)?;
+
+            let fee = FeeQuote {
+                fee_amount: topup.fee_amount,
+                fee_rule: topup.fee_rule.clone(),
+            };
+
+            if let Err(e) = self.fee_service.collect(&fee).await {
+                error!(
+                    "Failed to collect fee for topup {}: {}",
+                    topup.topup_id, e.message
                 );
             }
-            Err(_) => {
-                error!("Failed to retrieve saldo for user {}", input.user_id);
-
-                if let Err(rb_err) = self.topup_repository.delete(topup.topup_id).await {
-                    error!(
-                        "Failed to rollback topup creation for user {}: {}",
-                        input.user_id, rb_err
-                    );
-                }
-
-                return Err(ErrorResponse::from(AppError::NotFound(format!(
-                    "Saldo with user_id {} not found",
-                    input.user_id
-                ))));
-            }
         }
 
+        self.risk_engine
+            .resolve(RiskOperation::Topup, input)
+            .await?;
+
         info!(
-            "Topup successfully created for user {}. Total balance updated.",
-            input.user_id
+            "Held topup {} {} by operator {}",
+            topup.topup_id, input.decision, input.reviewer_id
         );
+
+        let topup = self
+            .topup_repository
+            .find_by_id(topup.topup_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .ok_or_else(|| {
+                ErrorResponse::from(AppError::NotFound(format!(
+                    "Topup with id {} not found",
+                    input.reference_id
+                )))
+            })?;
+
         Ok(ApiResponse {
             status: "success".to_string(),
-            message: "Topup created successfully".to_string(),
+            message: format!("Topup {}", input.decision),
             data: TopupResponse::from(topup),
         })
     }
 
+    #[instrument(skip_all)]
     async fn update_topup(
         &self,
         input: &UpdateTopupRequest,
     ) -> Result<ApiResponse<Option<TopupResponse>>, ErrorResponse> {
         if let Err(validation_err) = input.validate() {
             error!("Validation failed for topup update: {}", validation_err);
-            return Err(ErrorResponse::from(AppError::ValidationError(
-                validation_err,
-            )));
+            return Err(ErrorResponse::from(AppError::InvalidFields(validation_err)));
         }
 
         info!(
@@ -343,7 +643,7 @@ impl TopupServiceTrait for TopupService {
             .await
             .map_err(|_| {
                 error!("User with id {} not found", input.user_id);
-                ErrorResponse::from(AppError::NotFound(format!(
+                ErrorResponse::from(AppError::UserNotFound(format!(
                     "User with id {} not found",
                     input.user_id
                 )))
@@ -374,6 +674,15 @@ impl TopupServiceTrait for TopupService {
             )))
         })?;
 
+        // A held topup is credited in full when released and a rejected one never landed, so
+        // only a completed topup can have its difference applied to the saldo
+        if existing_topup.status != TOPUP_STATUS_COMPLETED {
+            return Err(ErrorResponse::from(AppError::ValidationError(format!(
+                "Topup in status '{}' cannot be updated",
+                existing_topup.status
+            ))));
+        }
+
         let topup_difference = input.topup_amount - existing_topup.topup_amount;
 
         info!(
@@ -381,6 +690,12 @@ impl TopupServiceTrait for TopupService {
             input.topup_amount, existing_topup.topup_amount, topup_difference
         );
 
+        if topup_difference > 0 {
+            self.limit_service
+                .check_incoming(input.user_id, topup_difference)
+                .await?;
+        }
+
         let update_topup = UpdateTopupAmount {
             topup_id: input.topup_id,
             topup_amount: input.topup_amount,
@@ -472,9 +787,10 @@ impl TopupServiceTrait for TopupService {
         }
     }
 
+    #[instrument(skip_all, fields(id = id))]
     async fn delete_topup(&self, id: i32) -> Result<ApiResponse<()>, ErrorResponse> {
         let user = self.user_repository.find_by_id(id).await.map_err(|_| {
-            ErrorResponse::from(AppError::NotFound(format!("User with id {} not found", id)))
+            ErrorResponse::from(AppError::UserNotFound(format!("User with id {} not found", id)))
         })?;
 
         let existing_topup = self
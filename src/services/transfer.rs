@@ -1,64 +1,227 @@
+use std::cmp::Ordering;
+
 use async_trait::async_trait;
-use tracing::{error, info};
+use sea_orm::DbErr;
+use tracing::{error, info, instrument};
+use validator::Validate;
 
 use crate::{
     abstract_trait::{
+        fee::DynFeeService,
+        limit::DynLimitService,
+        metrics::DynMetrics,
+        risk::DynRiskEngine,
         saldo::DynSaldoRepository,
+        screening::DynScreeningService,
         transfer::{DynTransferRepository, TransferServiceTrait},
         user::DynUserRepository,
     },
     domain::{
         request::{
-            saldo::UpdateSaldoBalance,
-            transfer::{CreateTransferRequest, UpdateTransferRequest},
+            pagination::ListRequest,
+            risk::{ResolveRiskReviewRequest, RiskAssessmentRequest},
+            saldo::{CreateSaldoRequest, UpdateSaldoBalance},
+            transfer::{
+                CreateTransferRequest, UpdateTransferRequest, TRANSFER_LIST_FIELDS,
+                TRANSFER_STATUS_COMPLETED, TRANSFER_STATUS_PENDING_REVIEW,
+                TRANSFER_STATUS_REJECTED,
+            },
         },
-        response::{transfer::TransferResponse, ApiResponse, ErrorResponse},
+        response::{transfer::TransferResponse, ApiResponse, ApiResponsePagination, ErrorResponse},
+    },
+    utils::{
+        errors::AppError,
+        fee::{FeeOperation, FeeQuote},
+        kyc::LimitOperation,
+        metrics::{TransactionKind, TransactionRecorder, TRANSFER_CHANNEL},
+        risk::{RiskAction, RiskOperation},
     },
-    utils::errors::AppError,
 };
 
 pub struct TransferService {
     transfer_repository: DynTransferRepository,
     saldo_repository: DynSaldoRepository,
     user_repository: DynUserRepository,
+    fee_service: DynFeeService,
+    limit_service: DynLimitService,
+    risk_engine: DynRiskEngine,
+    screening_service: DynScreeningService,
+    metrics: DynMetrics,
 }
 
 impl TransferService {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         transfer_repository: DynTransferRepository,
         saldo_repository: DynSaldoRepository,
         user_repository: DynUserRepository,
+        fee_service: DynFeeService,
+        limit_service: DynLimitService,
+        risk_engine: DynRiskEngine,
+        screening_service: DynScreeningService,
+        metrics: DynMetrics,
     ) -> Self {
         Self {
             transfer_repository,
             saldo_repository,
             user_repository,
+            fee_service,
+            limit_service,
+            risk_engine,
+            screening_service,
+            metrics,
+        }
+    }
+
+    // Credits (or with a negative delta, debits) a user's saldo, creating it if needed
+    async fn adjust_saldo(&self, user_id: i32, delta: i32) -> Result<(), ErrorResponse> {
+        let saldo = self
+            .saldo_repository
+            .find_by_user_id(user_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        let result = match saldo {
+            Some(saldo) => self
+                .saldo_repository
+                .update_balance(&UpdateSaldoBalance {
+                    user_id,
+                    total_balance: saldo.total_balance + delta,
+                })
+                .await,
+            None => self
+                .saldo_repository
+                .create(&CreateSaldoRequest {
+                    user_id,
+                    total_balance: delta,
+                })
+                .await,
+        };
+
+        result
+            .map(|_| ())
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)
+    }
+
+    // Credits a positive delta or debits a negative one; the debit only applies while the
+    // balance covers it
+    async fn apply_delta(&self, user_id: i32, delta: i32, party: &str) -> Result<(), ErrorResponse> {
+        let applied = match delta.cmp(&0) {
+            Ordering::Greater => self.saldo_repository.credit(user_id, delta).await,
+            Ordering::Less => self.saldo_repository.debit(user_id, -delta).await,
+            Ordering::Equal => return Ok(()),
+        }
+        .map_err(|db_err| {
+            error!("Failed to update {}'s saldo: {}", party, db_err);
+            ErrorResponse::from(AppError::from(db_err))
+        })?;
+
+        if applied {
+            return Ok(());
+        }
+
+        if delta > 0 {
+            return Err(ErrorResponse::from(AppError::NotFound(format!(
+                "Saldo with User id {} not found",
+                user_id
+            ))));
+        }
+
+        Err(ErrorResponse::from(AppError::InsufficientFunds(format!(
+            "Insufficient balance for {}",
+            party
+        ))))
+    }
+
+    async fn undo_delta(&self, user_id: i32, delta: i32) {
+        if let Err(e) = self.apply_delta(user_id, -delta, "rollback").await {
+            error!(
+                "Failed to rollback saldo change of {} for user {}: {}",
+                delta, user_id, e.message
+            );
+        }
+    }
+
+    // Turns a transfer addressed by noc_transfer or email into one addressed by user id
+    async fn resolve_recipient(
+        &self,
+        input: &CreateTransferRequest,
+    ) -> Result<CreateTransferRequest, ErrorResponse> {
+        let Some(recipient) = input.recipient.as_deref().map(str::trim) else {
+            return Ok(input.clone());
+        };
+
+        let user = if input.is_email_alias() {
+            self.user_repository.find_by_email(recipient).await
+        } else {
+            self.user_repository.find_by_noc_transfer(recipient).await
+        };
+
+        let user = user
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .ok_or_else(|| {
+                error!("Recipient {} not found", recipient);
+                ErrorResponse::from(AppError::NotFound(format!(
+                    "Recipient {} not found",
+                    recipient
+                )))
+            })?;
+
+        if user.user_id == input.transfer_from {
+            return Err(ErrorResponse::from(AppError::ValidationError(
+                "Cannot transfer to your own account".to_string(),
+            )));
         }
+
+        info!(
+            "Resolved recipient {} to user {} for transfer from user {}",
+            recipient, user.user_id, input.transfer_from
+        );
+
+        Ok(CreateTransferRequest {
+            transfer_to: user.user_id,
+            recipient: None,
+            ..input.clone()
+        })
     }
 }
 
 #[async_trait]
 impl TransferServiceTrait for TransferService {
-    async fn get_transfers(&self) -> Result<ApiResponse<Vec<TransferResponse>>, ErrorResponse> {
-        let transfer = self
+    #[instrument(skip_all)]
+    async fn get_transfers(
+        &self,
+        input: &ListRequest,
+    ) -> Result<ApiResponsePagination<Vec<TransferResponse>>, ErrorResponse> {
+        if let Err(validation_err) = input.validate_fields(&TRANSFER_LIST_FIELDS) {
+            error!("Validation failed for transfer list: {}", validation_err);
+            return Err(ErrorResponse::from(AppError::InvalidFields(validation_err)));
+        }
+
+        let (transfer, pagination) = self
             .transfer_repository
-            .find_all()
+            .find_paginated(input)
             .await
             .map_err(AppError::from)
             .map_err(ErrorResponse::from)?;
 
         let transfer_response: Vec<TransferResponse> = transfer
             .into_iter()
-            .map(|transfer| TransferResponse::from(transfer))
+            .map(TransferResponse::from)
             .collect();
 
-        Ok(ApiResponse {
+        Ok(ApiResponsePagination {
             status: "success".to_string(),
             message: "Transfer retrieved successfully".to_string(),
             data: transfer_response,
+            pagination,
         })
     }
 
+    #[instrument(skip_all, fields(id = id))]
     async fn get_transfer(
         &self,
         id: i32,
@@ -84,12 +247,13 @@ impl TransferServiceTrait for TransferService {
         }
     }
 
+    #[instrument(skip_all, fields(id = id))]
     async fn get_transfer_users(
         &self,
         id: i32,
     ) -> Result<ApiResponse<Option<Vec<TransferResponse>>>, ErrorResponse> {
         let _user = self.user_repository.find_by_id(id).await.map_err(|_| {
-            ErrorResponse::from(AppError::NotFound(format!("User with id {} not found", id)))
+            ErrorResponse::from(AppError::UserNotFound(format!("User with id {} not found", id)))
         })?;
 
         let transfer = self
@@ -111,12 +275,13 @@ impl TransferServiceTrait for TransferService {
         Ok(response)
     }
 
+    #[instrument(skip_all, fields(id = id))]
     async fn get_transfer_user(
         &self,
         id: i32,
     ) -> Result<ApiResponse<Option<TransferResponse>>, ErrorResponse> {
         let _user = self.user_repository.find_by_id(id).await.map_err(|_| {
-            ErrorResponse::from(AppError::NotFound(format!("User with id {} not found", id)))
+            ErrorResponse::from(AppError::UserNotFound(format!("User with id {} not found", id)))
         })?;
 
         let transfer: Option<TransferResponse> = self
@@ -136,23 +301,26 @@ impl TransferServiceTrait for TransferService {
         Ok(response)
     }
 
+    #[instrument(skip_all)]
     async fn create_transfer(
         &self,
         input: &CreateTransferRequest,
     ) -> Result<ApiResponse<TransferResponse>, ErrorResponse> {
+        let recorder = TransactionRecorder::start(&self.metrics, TransactionKind::Transfer);
+
         if let Err(validation_err) = input.validate() {
             error!("Validation failed for transfer create: {}", validation_err);
-            return Err(ErrorResponse::from(AppError::ValidationError(
-                validation_err,
-            )));
+            return Err(ErrorResponse::from(AppError::InvalidFields(validation_err)));
         }
 
+        let input = &self.resolve_recipient(input).await?;
+
         // Check if sender and receiver exist
         self.user_repository
             .find_by_id(input.transfer_from)
             .await
             .map_err(|_| {
-                ErrorResponse::from(AppError::NotFound(format!(
+                ErrorResponse::from(AppError::UserNotFound(format!(
                     "User with id {} not found",
                     input.transfer_from
                 )))
@@ -162,78 +330,170 @@ impl TransferServiceTrait for TransferService {
             .find_by_id(input.transfer_to)
             .await
             .map_err(|_| {
-                ErrorResponse::from(AppError::NotFound(format!(
+                ErrorResponse::from(AppError::UserNotFound(format!(
                     "User with id {} not found",
                     input.transfer_to
                 )))
             })?;
 
-        // Create the transfer
-        let transfer = self
-            .transfer_repository
-            .create(&input)
-            .await
-            .map_err(AppError::from)
-            .map_err(ErrorResponse::from)?;
+        self.limit_service
+            .check_outgoing(
+                input.transfer_from,
+                LimitOperation::Transfer,
+                &[input.transfer_amount],
+            )
+            .await?;
 
-        // Sender's saldo adjustment
-        let sender_saldo = self
-            .saldo_repository
+        self.limit_service
+            .check_incoming(input.transfer_to, input.transfer_amount)
+            .await?;
+
+        self.screening_service
+            .check_recipient(input.transfer_to)
+            .await?;
+
+        // Both saldos must exist before anything is written
+        self.saldo_repository
             .find_by_user_id(input.transfer_from)
             .await
-            .map_err(|_| {
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .ok_or_else(|| {
                 ErrorResponse::from(AppError::NotFound(format!(
                     "Saldo with User id {} not found",
                     input.transfer_from
                 )))
             })?;
 
-        let sender_balance = sender_saldo.unwrap().total_balance - input.transfer_amount;
+        self.saldo_repository
+            .find_by_user_id(input.transfer_to)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .ok_or_else(|| {
+                ErrorResponse::from(AppError::NotFound(format!(
+                    "Saldo with User id {} not found",
+                    input.transfer_to
+                )))
+            })?;
 
-        let request_sender_balance = UpdateSaldoBalance {
-            user_id: input.transfer_from,
-            total_balance: sender_balance,
-        };
+        // The sender pays the fee on top of the amount the receiver gets
+        let fee = self
+            .fee_service
+            .quote(FeeOperation::Transfer, None, input.transfer_amount);
 
-        if let Err(db_err) = self
+        let total_debit = input
+            .transfer_amount
+            .checked_add(fee.fee_amount)
+            .ok_or_else(|| {
+                ErrorResponse::from(AppError::ValidationError(
+                    "Transfer amount is too large".to_string(),
+                ))
+            })?;
+
+        let risk = self
+            .risk_engine
+            .assess(&RiskAssessmentRequest {
+                user_id: input.transfer_from,
+                operation: RiskOperation::Transfer,
+                amount: input.transfer_amount,
+                counterparty_user_id: Some(input.transfer_to),
+            })
+            .await?;
+
+        let held = risk.action == RiskAction::Review;
+
+        // Create the transfer
+        let transfer = self
+            .transfer_repository
+            .create(
+                input,
+                &fee,
+                if held {
+                    TRANSFER_STATUS_PENDING_REVIEW
+                } else {
+                    TRANSFER_STATUS_COMPLETED
+                },
+            )
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        // Sender's saldo adjustment; the debit only applies while the balance covers it
+        match self
             .saldo_repository
-            .update_balance(&request_sender_balance)
+            .debit(input.transfer_from, total_debit)
             .await
         {
-            error!("Failed to update saldo balance for sender: {}", db_err);
-            self.transfer_repository
-                .delete(transfer.transfer_id)
-                .await
-                .map_err(AppError::from)
-                .map_err(ErrorResponse::from)?;
+            Ok(true) => {}
+            Ok(false) => {
+                error!(
+                    "Insufficient balance for user_id: {}. Attempted transfer: {}",
+                    input.transfer_from, total_debit
+                );
 
-            return Err(ErrorResponse::from(AppError::from(db_err)));
+                self.transfer_repository
+                    .delete(transfer.transfer_id)
+                    .await
+                    .map_err(AppError::from)
+                    .map_err(ErrorResponse::from)?;
+
+                return Err(ErrorResponse::from(AppError::InsufficientFunds(
+                    "Insufficient balance".to_string(),
+                )));
+            }
+            Err(db_err) => {
+                error!("Failed to update saldo balance for sender: {}", db_err);
+                self.metrics.record_rollback(TransactionKind::Transfer);
+
+                self.transfer_repository
+                    .delete(transfer.transfer_id)
+                    .await
+                    .map_err(AppError::from)
+                    .map_err(ErrorResponse::from)?;
+
+                return Err(ErrorResponse::from(AppError::from(db_err)));
+            }
         }
 
-        let receiver_saldo = self
-            .saldo_repository
-            .find_by_user_id(input.transfer_to)
-            .await
-            .map_err(|_| {
-                ErrorResponse::from(AppError::NotFound(format!(
-                    "Saldo with User id {} not found",
-                    input.transfer_to
-                )))
-            })?;
+        self.risk_engine
+            .attach(risk.decision_id, transfer.transfer_id)
+            .await;
 
-        let receiver_balance = receiver_saldo.unwrap().total_balance + input.transfer_amount;
+        // A held transfer keeps the sender's funds reserved until an operator releases it
+        if held {
+            recorder.created(TRANSFER_CHANNEL, transfer.transfer_amount);
 
-        let request_receiver_balance = UpdateSaldoBalance {
-            user_id: input.transfer_to,
-            total_balance: receiver_balance,
-        };
+            return Ok(ApiResponse {
+                status: "success".to_string(),
+                message: "Transfer is held for review".to_string(),
+                data: TransferResponse::from(transfer),
+            });
+        }
 
-        if let Err(db_err) = self
+        let receiver_result = match self
             .saldo_repository
-            .update_balance(&request_receiver_balance)
+            .credit(input.transfer_to, input.transfer_amount)
             .await
         {
+            Ok(true) => Ok(()),
+            Ok(false) => Err(DbErr::RecordNotFound("Saldo not found".to_owned())),
+            Err(db_err) => Err(db_err),
+        };
+
+        if let Err(db_err) = receiver_result {
             error!("Failed to update saldo balance for receiver: {}", db_err);
+            self.metrics.record_rollback(TransactionKind::Transfer);
+
+            // Rollback sender's saldo update
+            self.saldo_repository
+                .credit(input.transfer_from, total_debit)
+                .await
+                .map_err(|rollback_err| {
+                    error!("Failed to rollback sender's saldo update: {}", rollback_err);
+                })
+                .ok();
+
             self.transfer_repository
                 .delete(transfer.transfer_id) // Corrected rollback
                 .await
@@ -243,6 +503,12 @@ impl TransferServiceTrait for TransferService {
             return Err(ErrorResponse::from(AppError::from(db_err)));
         }
 
+        if let Err(e) = self.fee_service.collect(&fee).await {
+            error!("Failed to collect fee for transfer {}: {}", transfer.transfer_id, e.message);
+        }
+
+        recorder.created(TRANSFER_CHANNEL, transfer.transfer_amount);
+
         Ok(ApiResponse {
             status: "success".to_string(),
             message: "Transfer created successfully".to_string(),
@@ -250,6 +516,7 @@ impl TransferServiceTrait for TransferService {
         })
     }
 
+    #[instrument(skip_all)]
     async fn update_transfer(
         &self,
         input: &UpdateTransferRequest,
@@ -257,9 +524,7 @@ impl TransferServiceTrait for TransferService {
         // Validate input
         if let Err(validation_err) = input.validate() {
             error!("Validation failed for transfer update: {}", validation_err);
-            return Err(ErrorResponse::from(AppError::ValidationError(
-                validation_err,
-            )));
+            return Err(ErrorResponse::from(AppError::InvalidFields(validation_err)));
         }
 
         // Retrieve the existing transfer
@@ -280,116 +545,193 @@ impl TransferServiceTrait for TransferService {
                 )))
             })?;
 
+        // A held transfer has not credited the receiver yet and a rejected one has been
+        // refunded, so only a completed transfer can be adjusted
+        if transfer.status != TRANSFER_STATUS_COMPLETED {
+            return Err(ErrorResponse::from(AppError::ValidationError(format!(
+                "Transfer in status '{}' cannot be updated",
+                transfer.status
+            ))));
+        }
+
         // Calculate the difference in transfer amount
         let amount_difference = input.transfer_amount as i64 - transfer.transfer_amount as i64;
 
-        // Update sender's saldo
-        let sender_saldo = self
-            .saldo_repository
-            .find_by_user_id(transfer.transfer_from)
-            .await
-            .map_err(|_| {
-                ErrorResponse::from(AppError::NotFound(format!(
-                    "Saldo with User id {} not found",
-                    transfer.transfer_from
-                )))
-            })?
-            .ok_or_else(|| {
-                ErrorResponse::from(AppError::NotFound(format!(
-                    "Saldo with User id {} not found",
-                    transfer.transfer_from
-                )))
-            })?;
-
-        let new_sender_balance = sender_saldo.total_balance - amount_difference as i32;
-
-        if new_sender_balance < 0 {
-            return Err(ErrorResponse::from(AppError::ValidationError(
-                "Insufficient balance for sender".to_string(),
-            )));
+        // Only an increase moves more money, so only the increase is checked against the limits
+        self.limit_service
+            .check_outgoing_change(
+                transfer.transfer_from,
+                LimitOperation::Transfer,
+                transfer.transfer_amount,
+                input.transfer_amount,
+            )
+            .await?;
+
+        if amount_difference > 0 {
+            self.limit_service
+                .check_incoming(transfer.transfer_to, amount_difference as i32)
+                .await?;
         }
 
-        let update_sender_balance = UpdateSaldoBalance {
-            user_id: transfer.transfer_from,
-            total_balance: new_sender_balance,
+        // The fee is re-quoted for the new amount and the sender pays or gets back the change
+        let previous_fee = FeeQuote {
+            fee_amount: transfer.fee_amount,
+            fee_rule: transfer.fee_rule.clone(),
         };
+        let fee = self
+            .fee_service
+            .quote(FeeOperation::Transfer, None, input.transfer_amount);
+
+        let sender_delta = (transfer.transfer_amount as i64 + transfer.fee_amount as i64)
+            - (input.transfer_amount as i64 + fee.fee_amount as i64);
+        let sender_delta = i32::try_from(sender_delta).map_err(|_| {
+            ErrorResponse::from(AppError::ValidationError(
+                "Transfer amount is too large".to_string(),
+            ))
+        })?;
 
-        if let Err(db_err) = self
-            .saldo_repository
-            .update_balance(&update_sender_balance)
+        // The guarded debit means concurrent updates and transfers cannot overdraw either side
+        self.apply_delta(transfer.transfer_from, sender_delta, "sender")
+            .await?;
+
+        if let Err(e) = self
+            .apply_delta(transfer.transfer_to, amount_difference as i32, "receiver")
             .await
         {
-            error!("Failed to update sender's saldo: {}", db_err);
-            return Err(ErrorResponse::from(AppError::from(db_err)));
+            // Rollback sender's saldo update
+            self.undo_delta(transfer.transfer_from, sender_delta).await;
+
+            return Err(e);
         }
 
-        // Update receiver's saldo
-        let receiver_saldo = self
-            .saldo_repository
-            .find_by_user_id(transfer.transfer_to)
+        // Update the transfer record
+        let updated_transfer = match self.transfer_repository.update(input, &fee).await {
+            Ok(updated_transfer) => updated_transfer,
+            Err(db_err) => {
+                error!("Failed to update transfer {}: {}", input.transfer_id, db_err);
+
+                self.undo_delta(transfer.transfer_to, amount_difference as i32)
+                    .await;
+                self.undo_delta(transfer.transfer_from, sender_delta).await;
+
+                return Err(ErrorResponse::from(AppError::from(db_err)));
+            }
+        };
+
+        if let Err(e) = self.fee_service.adjust(&previous_fee, &fee).await {
+            error!(
+                "Failed to adjust fee for transfer {}: {}",
+                transfer.transfer_id, e.message
+            );
+        }
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Transfer updated successfully".to_string(),
+            data: TransferResponse::from(updated_transfer),
+        })
+    }
+
+    #[instrument(skip_all)]
+    async fn review_transfer(
+        &self,
+        input: &ResolveRiskReviewRequest,
+    ) -> Result<ApiResponse<TransferResponse>, ErrorResponse> {
+        if let Err(validation_err) = input.validate() {
+            error!("Validation failed for transfer review: {}", validation_err);
+            return Err(ErrorResponse::from(AppError::InvalidFields(validation_err)));
+        }
+
+        let transfer = self
+            .transfer_repository
+            .find_by_id(input.reference_id)
             .await
-            .map_err(|_| {
-                ErrorResponse::from(AppError::NotFound(format!(
-                    "Saldo with User id {} not found",
-                    transfer.transfer_to
-                )))
-            })?
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .filter(|transfer| transfer.status == TRANSFER_STATUS_PENDING_REVIEW)
             .ok_or_else(|| {
                 ErrorResponse::from(AppError::NotFound(format!(
-                    "Saldo with User id {} not found",
-                    transfer.transfer_to
+                    "Held transfer with id {} not found",
+                    input.reference_id
                 )))
             })?;
 
-        let new_receiver_balance = receiver_saldo.total_balance + amount_difference as i32;
-
-        let update_receiver_balance = UpdateSaldoBalance {
-            user_id: transfer.transfer_to,
-            total_balance: new_receiver_balance,
+        let status = if input.is_release() {
+            TRANSFER_STATUS_COMPLETED
+        } else {
+            TRANSFER_STATUS_REJECTED
         };
 
-        if let Err(db_err) = self
-            .saldo_repository
-            .update_balance(&update_receiver_balance)
+        if !self
+            .transfer_repository
+            .update_status(transfer.transfer_id, TRANSFER_STATUS_PENDING_REVIEW, status)
             .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
         {
-            error!("Failed to update receiver's saldo: {}", db_err);
+            return Err(ErrorResponse::from(AppError::ValidationError(format!(
+                "Transfer {} has already been reviewed",
+                transfer.transfer_id
+            ))));
+        }
 
-            // Rollback sender's saldo update
-            let rollback_sender_balance = UpdateSaldoBalance {
-                user_id: transfer.transfer_from,
-                total_balance: sender_saldo.total_balance,
-            };
+        let fee = FeeQuote {
+            fee_amount: transfer.fee_amount,
+            fee_rule: transfer.fee_rule.clone(),
+        };
 
-            self.saldo_repository
-                .update_balance(&rollback_sender_balance)
-                .await
-                .map_err(|rollback_err| {
-                    error!("Failed to rollback sender's saldo update: {}", rollback_err);
-                })
-                .ok();
+        if input.is_release() {
+            self.adjust_saldo(transfer.transfer_to, transfer.transfer_amount)
+                .await?;
 
-            return Err(ErrorResponse::from(AppError::from(db_err)));
+            if let Err(e) = self.fee_service.collect(&fee).await {
+                error!(
+                    "Failed to collect fee for transfer {}: {}",
+                    transfer.transfer_id, e.message
+                );
+            }
+        } else {
+            // The sender gets back everything that was reserved, fee included
+            self.adjust_saldo(
+                transfer.transfer_from,
+                transfer.transfer_amount + transfer.fee_amount,
+            )
+            .await?;
         }
 
-        // Update the transfer record
-        let updated_transfer = self
+        self.risk_engine
+            .resolve(RiskOperation::Transfer, input)
+            .await?;
+
+        info!(
+            "Held transfer {} {} by operator {}",
+            transfer.transfer_id, input.decision, input.reviewer_id
+        );
+
+        let transfer = self
             .transfer_repository
-            .update(input)
+            .find_by_id(transfer.transfer_id)
             .await
             .map_err(AppError::from)
-            .map_err(ErrorResponse::from)?;
+            .map_err(ErrorResponse::from)?
+            .ok_or_else(|| {
+                ErrorResponse::from(AppError::NotFound(format!(
+                    "Transfer with id {} not found",
+                    input.reference_id
+                )))
+            })?;
 
         Ok(ApiResponse {
             status: "success".to_string(),
-            message: "Transfer updated successfully".to_string(),
-            data: TransferResponse::from(updated_transfer),
+            message: format!("Transfer {}", input.decision),
+            data: TransferResponse::from(transfer),
         })
     }
 
+    #[instrument(skip_all, fields(id = id))]
     async fn delete_transfer(&self, id: i32) -> Result<ApiResponse<()>, ErrorResponse> {
         let user = self.user_repository.find_by_id(id).await.map_err(|_| {
-            ErrorResponse::from(AppError::NotFound(format!("User with id {} not found", id)))
+            ErrorResponse::from(AppError::UserNotFound(format!("User with id {} not found", id)))
         })?;
 
         let existing_transfer = self
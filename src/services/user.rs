@@ -1,17 +1,19 @@
-use tracing::{error, info};
+use tracing::{error, info, instrument};
+use validator::Validate;
 
-use crate::{abstract_trait::user::{DynUserRepository, UserServiceTrait}, config::hashing::Hashing, domain::{request::{auth::RegisterRequest, user::{CreateUserRequest, UpdateUserRequest}}, response::{user::UserResponse, ApiResponse, ErrorResponse}}, utils::{errors::AppError, random_vcc::random_vcc}};
+use crate::{abstract_trait::{account_number::DynAccountNumberAllocator, user::{DynUserRepository, UserServiceTrait}}, config::hashing::Hashing, domain::{request::{auth::RegisterRequest, pagination::ListRequest, user::{CreateUserRequest, UpdateUserRequest, USER_LIST_FIELDS}}, response::{user::UserResponse, ApiResponse, ApiResponsePagination, ErrorResponse}}, utils::errors::AppError};
 
 use async_trait::async_trait;
 
 pub struct UserService {
     repository: DynUserRepository,
-    hashing: Hashing
+    hashing: Hashing,
+    account_number_allocator: DynAccountNumberAllocator,
 }
 
 impl UserService {
-    pub fn new(repository: DynUserRepository, hashing: Hashing) -> Self {
-        Self { repository, hashing }
+    pub fn new(repository: DynUserRepository, hashing: Hashing, account_number_allocator: DynAccountNumberAllocator) -> Self {
+        Self { repository, hashing, account_number_allocator }
     }
 }
 
@@ -19,19 +21,27 @@ impl UserService {
 
 #[async_trait]
 impl UserServiceTrait for UserService{
-    async fn get_users(&self) -> Result<ApiResponse<Vec<UserResponse>>, ErrorResponse>{
-        let users = self.repository.find_all().await.map_err(AppError::from).map_err(ErrorResponse::from)?;
+    #[instrument(skip_all)]
+    async fn get_users(&self, input: &ListRequest) -> Result<ApiResponsePagination<Vec<UserResponse>>, ErrorResponse>{
+        if let Err(validation_err) = input.validate_fields(&USER_LIST_FIELDS) {
+            error!("Validation failed for user list: {}", validation_err);
+            return Err(ErrorResponse::from(AppError::InvalidFields(validation_err)));
+        }
+
+        let (users, pagination) = self.repository.find_paginated(input).await.map_err(AppError::from).map_err(ErrorResponse::from)?;
 
-        let users_response: Vec<UserResponse> = users.into_iter().map(|users| UserResponse::from(users)).collect();
+        let users_response: Vec<UserResponse> = users.into_iter().map(UserResponse::from).collect();
     
        
-        Ok(ApiResponse {
+        Ok(ApiResponsePagination {
             status: "success".to_string(),
             message: "Users retrieved successfully".to_string(),
             data: users_response,
+            pagination,
         })
     }
 
+    #[instrument(skip_all, fields(id = id))]
     async fn find_by_id(
         &self,
         id: i32,
@@ -45,10 +55,11 @@ impl UserServiceTrait for UserService{
                 data: Some(UserResponse::from(user)),
             })
         } else {
-            Err(ErrorResponse::from(AppError::NotFound(format!("User with id {} not found", id))))
+            Err(ErrorResponse::from(AppError::UserNotFound(format!("User with id {} not found", id))))
         }
     }
 
+    #[instrument(skip_all)]
     async fn create_user(
         &self,
         input: &RegisterRequest,
@@ -69,7 +80,7 @@ impl UserServiceTrait for UserService{
 
         if let Err(validation_err) = input.validate() {
             error!("Validation failed for user create: {}", validation_err);
-            return Err(ErrorResponse::from(AppError::ValidationError(validation_err)));
+            return Err(ErrorResponse::from(AppError::InvalidFields(validation_err)));
         }
 
         let hashed_password = self
@@ -78,24 +89,20 @@ impl UserServiceTrait for UserService{
             .await
             .map_err(|e| ErrorResponse::from(AppError::HashingError(e)))?;
 
-        let noc_transfer = random_vcc().map(|value| Some(value)).unwrap_or(None);
-
         let request = CreateUserRequest {
             firstname: input.firstname.clone(),
             lastname: input.lastname.clone(),
             email: input.email.clone(),
             password: hashed_password,
             confirm_password: input.confirm_password.clone(),
-            noc_transfer: noc_transfer.to_owned(),
+            noc_transfer: None,
         };
 
         info!("Creating user with email: {}", input.email);
         let create_user = self
-            .repository
+            .account_number_allocator
             .create_user(&request)
-            .await
-            .map_err(AppError::from)
-            .map_err(ErrorResponse::from)?;
+            .await?;
 
         info!("User Create successfully with email: {}", input.email);
 
@@ -106,6 +113,7 @@ impl UserServiceTrait for UserService{
         })
     }
 
+    #[instrument(skip_all)]
     async fn update_user(
         &self,
         input: &UpdateUserRequest,
@@ -114,7 +122,7 @@ impl UserServiceTrait for UserService{
 
         if let Err(validation_err) = input.validate() {
             error!("Validation failed for user update: {}", validation_err);
-            return Err(ErrorResponse::from(AppError::ValidationError(validation_err)));
+            return Err(ErrorResponse::from(AppError::InvalidFields(validation_err)));
         }
         
         Ok(Some(ApiResponse {
@@ -123,14 +131,4 @@ impl UserServiceTrait for UserService{
             data: UserResponse::from(user),
         }))
     }
-
-    async fn delete_user(&self, id: i32) -> Result<ApiResponse<()>, ErrorResponse> {
-        self.repository.delete_user(id).await.map_err(AppError::from).map_err(ErrorResponse::from)?;
-        
-        Ok(ApiResponse {
-            status: "success".to_string(),
-            message: "User deleted successfully".to_string(),
-            data: (),
-        })
-    }
 }
\ No newline at end of file
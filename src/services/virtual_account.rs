@@ -0,0 +1,264 @@
+use async_trait::async_trait;
+use tracing::{error, info, instrument, warn};
+use validator::Validate;
+
+use crate::{
+    abstract_trait::{
+        topup::{DynTopupRepository, DynTopupService},
+        user::DynUserRepository,
+        virtual_account::{DynVirtualAccountRepository, VirtualAccountServiceTrait},
+    },
+    domain::{
+        request::{
+            topup::{BankPaymentReference, CreateTopupRequest},
+            virtual_account::{CreateVirtualAccountRequest, VirtualAccountNotificationRequest},
+        },
+        response::{
+            topup::TopupResponse, virtual_account::VirtualAccountResponse, ApiResponse,
+            ErrorResponse,
+        },
+    },
+    utils::{
+        errors::AppError,
+        virtual_account::{generate_va_number, verify_notification_signature},
+    },
+};
+
+pub struct VirtualAccountService {
+    virtual_account_repository: DynVirtualAccountRepository,
+    user_repository: DynUserRepository,
+    topup_repository: DynTopupRepository,
+    topup_service: DynTopupService,
+    bank_prefixes: Vec<(String, String)>,
+    notification_secrets: Vec<(String, String)>,
+}
+
+impl VirtualAccountService {
+    pub fn new(
+        virtual_account_repository: DynVirtualAccountRepository,
+        user_repository: DynUserRepository,
+        topup_repository: DynTopupRepository,
+        topup_service: DynTopupService,
+        bank_prefixes: Vec<(String, String)>,
+        notification_secrets: Vec<(String, String)>,
+    ) -> Self {
+        Self {
+            virtual_account_repository,
+            user_repository,
+            topup_repository,
+            topup_service,
+            bank_prefixes,
+            notification_secrets,
+        }
+    }
+}
+
+#[async_trait]
+impl VirtualAccountServiceTrait for VirtualAccountService {
+    #[instrument(skip_all, fields(user_id = user_id))]
+    async fn get_virtual_accounts(
+        &self,
+        user_id: i32,
+    ) -> Result<ApiResponse<Vec<VirtualAccountResponse>>, ErrorResponse> {
+        self.user_repository
+            .find_by_id(user_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .ok_or_else(|| {
+                error!("User with id {} not found", user_id);
+                ErrorResponse::from(AppError::UserNotFound(format!(
+                    "User with id {} not found",
+                    user_id
+                )))
+            })?;
+
+        let virtual_accounts = self
+            .virtual_account_repository
+            .find_by_user(user_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Virtual accounts retrieved successfully".to_string(),
+            data: virtual_accounts
+                .into_iter()
+                .map(VirtualAccountResponse::from)
+                .collect(),
+        })
+    }
+
+    #[instrument(skip_all, fields(user_id = user_id))]
+    async fn issue_virtual_accounts(
+        &self,
+        user_id: i32,
+    ) -> Result<ApiResponse<Vec<VirtualAccountResponse>>, ErrorResponse> {
+        self.user_repository
+            .find_by_id(user_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .ok_or_else(|| {
+                error!("User with id {} not found", user_id);
+                ErrorResponse::from(AppError::UserNotFound(format!(
+                    "User with id {} not found",
+                    user_id
+                )))
+            })?;
+
+        let mut virtual_accounts = self
+            .virtual_account_repository
+            .find_by_user(user_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        // Issue a virtual account for every configured bank the user does not have yet
+        for (bank_code, prefix) in &self.bank_prefixes {
+            if virtual_accounts.iter().any(|va| &va.bank_code == bank_code) {
+                continue;
+            }
+
+            let request = CreateVirtualAccountRequest {
+                user_id,
+                bank_code: bank_code.clone(),
+                va_number: generate_va_number(prefix, user_id),
+            };
+
+            let virtual_account = self
+                .virtual_account_repository
+                .create(&request)
+                .await
+                .map_err(|e| {
+                    error!(
+                        "Failed to issue {} virtual account for user {}: {}",
+                        bank_code, user_id, e
+                    );
+                    ErrorResponse::from(AppError::from(e))
+                })?;
+
+            info!(
+                "Issued {} virtual account {} for user {}",
+                bank_code, virtual_account.va_number, user_id
+            );
+
+            virtual_accounts.push(virtual_account);
+        }
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Virtual accounts issued successfully".to_string(),
+            data: virtual_accounts
+                .into_iter()
+                .map(VirtualAccountResponse::from)
+                .collect(),
+        })
+    }
+
+    fn verify_notification(
+        &self,
+        bank_code: &str,
+        signature: &str,
+        body: &[u8],
+    ) -> Result<(), ErrorResponse> {
+        let bank_code = bank_code.to_lowercase();
+
+        let verified = self
+            .notification_secrets
+            .iter()
+            .find(|(bank, _)| *bank == bank_code)
+            .is_some_and(|(_, secret)| verify_notification_signature(secret, body, signature));
+
+        if !verified {
+            warn!("Rejected unsigned virtual account notification from bank '{}'", bank_code);
+            return Err(ErrorResponse::from(AppError::Unauthorized(
+                "Invalid notification signature".to_string(),
+            )));
+        }
+
+        Ok(())
+    }
+
+    #[instrument(skip_all)]
+    async fn handle_payment_notification(
+        &self,
+        bank_code: &str,
+        input: &VirtualAccountNotificationRequest,
+    ) -> Result<ApiResponse<TopupResponse>, ErrorResponse> {
+        if let Err(validation_err) = input.validate() {
+            error!("Validation failed for virtual account notification: {}", validation_err);
+            return Err(ErrorResponse::from(AppError::InvalidFields(validation_err)));
+        }
+
+        let virtual_account = self
+            .virtual_account_repository
+            .find_by_va_number(&input.va_number)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .ok_or_else(|| {
+                error!("Virtual account {} not found", input.va_number);
+                ErrorResponse::from(AppError::NotFound(format!(
+                    "Virtual account {} not found",
+                    input.va_number
+                )))
+            })?;
+
+        // A bank may only credit the virtual accounts it issued
+        if !virtual_account.bank_code.eq_ignore_ascii_case(bank_code) {
+            error!(
+                "Bank '{}' notified a payment to {} virtual account {}",
+                bank_code, virtual_account.bank_code, virtual_account.va_number
+            );
+            return Err(ErrorResponse::from(AppError::Forbidden(format!(
+                "Virtual account {} was not issued by {}",
+                input.va_number, bank_code
+            ))));
+        }
+
+        let payment = BankPaymentReference {
+            bank_code: virtual_account.bank_code.clone(),
+            payment_reference: input.payment_reference.clone(),
+        };
+
+        // Banks retry notifications; a repeat is answered without assessing it again, while the
+        // unique bank reference keeps concurrent deliveries from crediting twice
+        let existing_topup = self
+            .topup_repository
+            .find_by_bank_payment(&payment)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
+
+        if let Some(topup) = existing_topup {
+            info!(
+                "Payment reference {} already processed as topup {}",
+                input.payment_reference, topup.topup_id
+            );
+
+            return Ok(ApiResponse {
+                status: "success".to_string(),
+                message: "Payment notification already processed".to_string(),
+                data: TopupResponse::from(topup),
+            });
+        }
+
+        info!(
+            "Crediting {} from {} virtual account {} to user {}",
+            input.amount, virtual_account.bank_code, virtual_account.va_number, virtual_account.user_id
+        );
+
+        let request = CreateTopupRequest {
+            user_id: virtual_account.user_id,
+            topup_no: input.payment_reference.clone(),
+            topup_amount: input.amount,
+            topup_method: virtual_account.bank_code.clone(),
+        };
+
+        self.topup_service
+            .create_bank_topup(&request, &payment)
+            .await
+    }
+}
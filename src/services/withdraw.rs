@@ -1,55 +1,321 @@
 use crate::{
     abstract_trait::{
+        beneficiary::DynBeneficiaryRepository,
+        fee::DynFeeService,
+        limit::DynLimitService,
+        metrics::DynMetrics,
+        payout::DynPayoutProvider,
+        risk::DynRiskEngine,
         saldo::DynSaldoRepository,
         user::DynUserRepository,
         withdraw::{DynWithdrawRepository, WithdrawServiceTrait},
     },
     domain::{
         request::{
-            saldo::UpdateSaldoWithdraw,
-            withdraw::{CreateWithdrawRequest, UpdateWithdrawRequest},
+            pagination::ListRequest,
+            payout::PayoutRequest,
+            risk::{ResolveRiskReviewRequest, RiskAssessmentRequest},
+            withdraw::{
+                CreateWithdrawRequest, UpdateWithdrawPayoutRequest, UpdateWithdrawRequest,
+                WITHDRAW_LIST_FIELDS, WITHDRAW_STATUS_FAILED, WITHDRAW_STATUS_PAID,
+                WITHDRAW_STATUS_PENDING, WITHDRAW_STATUS_PENDING_REVIEW,
+                WITHDRAW_STATUS_PROCESSING,
+            },
         },
-        response::{withdraw::WithdrawResponse, ApiResponse, ErrorResponse},
+        response::{
+            payout::PayoutStatus, withdraw::WithdrawResponse, ApiResponse, ApiResponsePagination,
+            ErrorResponse,
+        },
+    },
+    entities::withdraws,
+    utils::{
+        errors::AppError,
+        fee::{FeeOperation, FeeQuote},
+        kyc::LimitOperation,
+        metrics::{TransactionKind, TransactionRecorder},
+        risk::{RiskAction, RiskOperation},
     },
-    utils::errors::AppError,
 };
 use async_trait::async_trait;
-use chrono::Utc;
-use tracing::{error, info};
+use chrono::{Duration, Utc};
+use tracing::{error, info, warn, instrument};
+use validator::Validate;
+
+// How long a claimed payout may go without a recorded reference before it is resubmitted
+const PAYOUT_RECONCILE_AFTER_MINUTES: i64 = 10;
 
 pub struct WithdrawService {
     withdraw_repository: DynWithdrawRepository,
     saldo_repository: DynSaldoRepository,
     user_repository: DynUserRepository,
+    beneficiary_repository: DynBeneficiaryRepository,
+    payout_provider: DynPayoutProvider,
+    fee_service: DynFeeService,
+    limit_service: DynLimitService,
+    risk_engine: DynRiskEngine,
+    metrics: DynMetrics,
 }
 
 impl WithdrawService {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         withdraw_repository: DynWithdrawRepository,
         saldo_repository: DynSaldoRepository,
         user_repository: DynUserRepository,
+        beneficiary_repository: DynBeneficiaryRepository,
+        payout_provider: DynPayoutProvider,
+        fee_service: DynFeeService,
+        limit_service: DynLimitService,
+        risk_engine: DynRiskEngine,
+        metrics: DynMetrics,
     ) -> Self {
         Self {
             withdraw_repository,
             saldo_repository,
             user_repository,
+            beneficiary_repository,
+            payout_provider,
+            fee_service,
+            limit_service,
+            risk_engine,
+            metrics,
+        }
+    }
+
+    async fn transition(
+        &self,
+        withdraw: &withdraws::Model,
+        from_status: &str,
+        status: &str,
+        payout_reference: Option<String>,
+        failure_reason: Option<String>,
+    ) -> Result<bool, ErrorResponse> {
+        self.withdraw_repository
+            .update_payout(&UpdateWithdrawPayoutRequest {
+                withdraw_id: withdraw.withdraw_id,
+                from_status: from_status.to_string(),
+                status: status.to_string(),
+                payout_reference,
+                failure_reason,
+            })
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)
+    }
+
+    // Marks the payout failed and returns the amount to the user's saldo; false when another
+    // request moved the withdraw out of from_status first
+    async fn fail_payout(
+        &self,
+        withdraw: &withdraws::Model,
+        from_status: &str,
+        reason: String,
+    ) -> Result<bool, ErrorResponse> {
+        warn!("Payout for withdraw {} failed: {}", withdraw.withdraw_id, reason);
+
+        if !self
+            .transition(withdraw, from_status, WITHDRAW_STATUS_FAILED, None, Some(reason))
+            .await?
+        {
+            return Ok(false);
+        }
+
+        // The fee is returned along with the amount since the payout never happened
+        let refund_amount = withdraw.withdraw_amount + withdraw.fee_amount;
+
+        if !self
+            .saldo_repository
+            .credit(withdraw.user_id, refund_amount)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+        {
+            error!(
+                "Saldo not found for user {} while refunding withdraw {}",
+                withdraw.user_id, withdraw.withdraw_id
+            );
+            return Err(ErrorResponse::from(AppError::NotFound(
+                "Saldo not found".to_string(),
+            )));
+        }
+
+        let fee = FeeQuote {
+            fee_amount: withdraw.fee_amount,
+            fee_rule: withdraw.fee_rule.clone(),
+        };
+
+        if let Err(e) = self.fee_service.refund(&fee).await {
+            error!(
+                "Failed to reverse fee for withdraw {}: {}",
+                withdraw.withdraw_id, e.message
+            );
+        }
+
+        // The payout never happened, so the debit taken when the withdraw was created is undone
+        self.metrics.record_rollback(TransactionKind::Withdraw);
+
+        info!(
+            "Refunded {} to user {} for failed withdraw {}",
+            refund_amount, withdraw.user_id, withdraw.withdraw_id
+        );
+
+        Ok(true)
+    }
+
+    async fn payout_request(
+        &self,
+        withdraw: &withdraws::Model,
+    ) -> Result<Option<PayoutRequest>, ErrorResponse> {
+        let beneficiary = match withdraw.beneficiary_id {
+            Some(beneficiary_id) => self
+                .beneficiary_repository
+                .find_by_id(beneficiary_id)
+                .await
+                .map_err(AppError::from)
+                .map_err(ErrorResponse::from)?,
+            None => None,
+        };
+
+        Ok(beneficiary.map(|beneficiary| PayoutRequest {
+            withdraw_id: withdraw.withdraw_id,
+            amount: withdraw.withdraw_amount,
+            bank_code: beneficiary.bank_code,
+            account_number: beneficiary.account_number,
+            account_name: beneficiary.account_name,
+        }))
+    }
+
+    // Sends a claimed withdraw to the provider and records the reference it returns
+    async fn send_payout(
+        &self,
+        withdraw: &withdraws::Model,
+        request: &PayoutRequest,
+    ) -> Result<(), ErrorResponse> {
+        match self.payout_provider.submit(request).await {
+            Ok(reference) => {
+                // If this is lost the withdraw is resubmitted by poll_payout, which the provider
+                // answers with the same reference
+                self.transition(
+                    withdraw,
+                    WITHDRAW_STATUS_PROCESSING,
+                    WITHDRAW_STATUS_PROCESSING,
+                    Some(reference),
+                    None,
+                )
+                .await?;
+
+                Ok(())
+            }
+            Err(reason) => self
+                .fail_payout(withdraw, WITHDRAW_STATUS_PROCESSING, reason)
+                .await
+                .map(|_| ()),
+        }
+    }
+
+    async fn submit_payout(&self, withdraw: &withdraws::Model) -> Result<(), ErrorResponse> {
+        let Some(request) = self.payout_request(withdraw).await? else {
+            return self
+                .fail_payout(
+                    withdraw,
+                    WITHDRAW_STATUS_PENDING,
+                    "Beneficiary no longer exists".to_string(),
+                )
+                .await
+                .map(|_| ());
+        };
+
+        // Claim the withdraw before calling the provider so it is never submitted twice
+        if !self
+            .transition(withdraw, WITHDRAW_STATUS_PENDING, WITHDRAW_STATUS_PROCESSING, None, None)
+            .await?
+        {
+            return Ok(());
+        }
+
+        self.send_payout(withdraw, &request).await
+    }
+
+    // A processing withdraw without a reference was claimed but its submission was never
+    // recorded; once it is clearly not in flight any more it is submitted again
+    async fn reconcile_payout(&self, withdraw: &withdraws::Model) -> Result<(), ErrorResponse> {
+        let claimed_at = withdraw.updated_at.unwrap_or(withdraw.withdraw_time);
+
+        if Utc::now().naive_utc() - claimed_at < Duration::minutes(PAYOUT_RECONCILE_AFTER_MINUTES) {
+            return Ok(());
+        }
+
+        let Some(request) = self.payout_request(withdraw).await? else {
+            error!(
+                "Withdraw {} is processing without a payout reference and its beneficiary is gone",
+                withdraw.withdraw_id
+            );
+            return Ok(());
+        };
+
+        warn!(
+            "Withdraw {} is processing without a payout reference, resubmitting",
+            withdraw.withdraw_id
+        );
+
+        self.send_payout(withdraw, &request).await
+    }
+
+    async fn poll_payout(&self, withdraw: &withdraws::Model) -> Result<(), ErrorResponse> {
+        let Some(reference) = &withdraw.payout_reference else {
+            return self.reconcile_payout(withdraw).await;
+        };
+
+        match self.payout_provider.status(reference).await {
+            Ok(PayoutStatus::Paid) => {
+                self.transition(
+                    withdraw,
+                    WITHDRAW_STATUS_PROCESSING,
+                    WITHDRAW_STATUS_PAID,
+                    None,
+                    None,
+                )
+                .await?;
+
+                info!("Payout {} for withdraw {} paid", reference, withdraw.withdraw_id);
+
+                Ok(())
+            }
+            Ok(PayoutStatus::Failed(reason)) => self
+                .fail_payout(withdraw, WITHDRAW_STATUS_PROCESSING, reason)
+                .await
+                .map(|_| ()),
+            Ok(PayoutStatus::Processing) => Ok(()),
+            Err(e) => {
+                warn!("Failed to fetch payout status for {}: {}", reference, e);
+                Ok(())
+            }
         }
     }
 }
 
 #[async_trait]
 impl WithdrawServiceTrait for WithdrawService {
-    async fn get_withdraws(&self) -> Result<ApiResponse<Vec<WithdrawResponse>>, ErrorResponse> {
-        let withdraw = self
+    #[instrument(skip_all)]
+    async fn get_withdraws(
+        &self,
+        input: &ListRequest,
+    ) -> Result<ApiResponsePagination<Vec<WithdrawResponse>>, ErrorResponse> {
+        if let Err(validation_err) = input.validate_fields(&WITHDRAW_LIST_FIELDS) {
+            error!("Validation failed for withdraw list: {}", validation_err);
+            return Err(ErrorResponse::from(AppError::InvalidFields(validation_err)));
+        }
+
+        let (withdraw, pagination) = self
             .withdraw_repository
-            .find_all()
+            .find_paginated(input)
             .await
             .map_err(AppError::from)
             .map_err(ErrorResponse::from)?;
 
         let withdraw_response: Vec<WithdrawResponse> = withdraw
             .into_iter()
-            .map(|withdraw| WithdrawResponse::from(withdraw))
+            .map(WithdrawResponse::from)
             .collect();
 
         info!(
@@ -57,13 +323,15 @@ impl WithdrawServiceTrait for WithdrawService {
             withdraw_response.len()
         );
 
-        Ok(ApiResponse {
+        Ok(ApiResponsePagination {
             status: "success".to_string(),
             message: "Withdraw retrieved successfully".to_string(),
             data: withdraw_response,
+            pagination,
         })
     }
 
+    #[instrument(skip_all, fields(id = id))]
     async fn get_withdraw(
         &self,
         id: i32,
@@ -91,12 +359,13 @@ impl WithdrawServiceTrait for WithdrawService {
         }
     }
 
+    #[instrument(skip_all, fields(id = id))]
     async fn get_withdraw_users(
         &self,
         id: i32,
     ) -> Result<ApiResponse<Option<Vec<WithdrawResponse>>>, ErrorResponse> {
         let _user = self.user_repository.find_by_id(id).await.map_err(|_| {
-            ErrorResponse::from(AppError::NotFound(format!("User with id {} not found", id)))
+            ErrorResponse::from(AppError::UserNotFound(format!("User with id {} not found", id)))
         })?;
 
         let withdraw = self
@@ -135,12 +404,13 @@ impl WithdrawServiceTrait for WithdrawService {
         Ok(response)
     }
 
+    #[instrument(skip_all, fields(id = id))]
     async fn get_withdraw_user(
         &self,
         id: i32,
     ) -> Result<ApiResponse<Option<WithdrawResponse>>, ErrorResponse> {
         let _user = self.user_repository.find_by_id(id).await.map_err(|_| {
-            ErrorResponse::from(AppError::NotFound(format!("User with id {} not found", id)))
+            ErrorResponse::from(AppError::UserNotFound(format!("User with id {} not found", id)))
         })?;
 
         let withdraw: Option<WithdrawResponse> = self
@@ -171,20 +441,47 @@ impl WithdrawServiceTrait for WithdrawService {
         }
     }
 
+    #[instrument(skip_all)]
     async fn create_withdraw(
         &self,
         input: &CreateWithdrawRequest,
     ) -> Result<ApiResponse<WithdrawResponse>, ErrorResponse> {
         info!("Creating withdraw for user_id: {}", input.user_id);
 
+        let recorder = TransactionRecorder::start(&self.metrics, TransactionKind::Withdraw);
+
         if let Err(validation_err) = input.validate() {
             error!("Validation failed for withdraw create: {}", validation_err);
-            return Err(ErrorResponse::from(AppError::ValidationError(
-                validation_err,
-            )));
+            return Err(ErrorResponse::from(AppError::InvalidFields(validation_err)));
         }
         info!("Validation passed for withdraw creation");
 
+        let beneficiary = self
+            .beneficiary_repository
+            .find_by_id(input.beneficiary_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .filter(|beneficiary| beneficiary.user_id == input.user_id)
+            .ok_or_else(|| {
+                error!(
+                    "Beneficiary {} not found for user_id: {}",
+                    input.beneficiary_id, input.user_id
+                );
+                ErrorResponse::from(AppError::NotFound(format!(
+                    "Beneficiary with id {} not found",
+                    input.beneficiary_id
+                )))
+            })?;
+
+        self.limit_service
+            .check_outgoing(
+                input.user_id,
+                LimitOperation::Withdraw,
+                &[input.withdraw_amount],
+            )
+            .await?;
+
         let saldo = self
             .saldo_repository
             .find_by_user_id(input.user_id)
@@ -207,173 +504,439 @@ impl WithdrawServiceTrait for WithdrawService {
             input.user_id, saldo_ref.total_balance
         );
 
-        if saldo_ref.total_balance < input.withdraw_amount {
+        let fee = self.fee_service.quote(
+            FeeOperation::Withdraw,
+            Some(&beneficiary.bank_code),
+            input.withdraw_amount,
+        );
+
+        let total_debit = input
+            .withdraw_amount
+            .checked_add(fee.fee_amount)
+            .ok_or_else(|| {
+                ErrorResponse::from(AppError::ValidationError(
+                    "Withdraw amount is too large".to_string(),
+                ))
+            })?;
+
+        let risk = self
+            .risk_engine
+            .assess(&RiskAssessmentRequest {
+                user_id: input.user_id,
+                operation: RiskOperation::Withdraw,
+                amount: input.withdraw_amount,
+                counterparty_user_id: None,
+            })
+            .await?;
+
+        // A held withdraw is debited now but only queued for payout once released
+        let held = risk.action == RiskAction::Review;
+
+        // The fee is taken along with the amount; the debit only applies while the balance covers it
+        if !self
+            .saldo_repository
+            .debit(input.user_id, total_debit)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+        {
             error!(
                 "Insufficient balance for user_id: {}. Attempted withdrawal: {}",
-                input.user_id, input.withdraw_amount
+                input.user_id, total_debit
             );
-            return Err(ErrorResponse::from(AppError::ValidationError(
+            return Err(ErrorResponse::from(AppError::InsufficientFunds(
                 "Insufficient balance".to_string(),
             )));
         }
-        info!("User has sufficient balance for withdrawal");
 
-        let new_total_balance = saldo_ref.total_balance - input.withdraw_amount;
+        info!(
+            "Debited {} from saldo of user_id: {}",
+            total_debit, input.user_id
+        );
 
-        let _update_saldo_balance = self
-            .saldo_repository
-            .update_saldo_withdraw(&UpdateSaldoWithdraw {
-                user_id: input.user_id,
-                withdraw_amount: Some(input.withdraw_amount),
-                withdraw_time: Some(Utc::now().naive_utc()),
-                total_balance: new_total_balance,
-            })
-            .await
-            .map_err(AppError::from)
-            .map_err(ErrorResponse::from)?;
+        // The fee is booked before the withdraw exists, so a failure leaves nothing to unwind
+        // except the debit
+        if let Err(e) = self.fee_service.collect(&fee).await {
+            error!(
+                "Failed to collect fee for withdraw of user_id {}: {}",
+                input.user_id, e.message
+            );
+            self.metrics.record_rollback(TransactionKind::Withdraw);
+
+            self.saldo_repository
+                .credit(input.user_id, total_debit)
+                .await
+                .map_err(|rollback_err| {
+                    error!("Failed to rollback saldo debit: {}", rollback_err);
+                })
+                .ok();
+
+            return Err(e);
+        }
+
+        let status = if held {
+            WITHDRAW_STATUS_PENDING_REVIEW
+        } else {
+            WITHDRAW_STATUS_PENDING
+        };
+
+        let withdraw_create_result = match self.withdraw_repository.create(input, &fee, status).await
+        {
+            Ok(withdraw) => withdraw,
+            Err(db_err) => {
+                error!("Failed to create withdraw for user_id {}: {}", input.user_id, db_err);
+                self.metrics.record_rollback(TransactionKind::Withdraw);
+
+                self.saldo_repository
+                    .credit(input.user_id, total_debit)
+                    .await
+                    .map_err(|rollback_err| {
+                        error!("Failed to rollback saldo debit: {}", rollback_err);
+                    })
+                    .ok();
+
+                if let Err(e) = self.fee_service.refund(&fee).await {
+                    error!("Failed to reverse fee for unrecorded withdraw: {}", e.message);
+                }
+
+                return Err(ErrorResponse::from(AppError::from(db_err)));
+            }
+        };
+
+        self.risk_engine
+            .attach(risk.decision_id, withdraw_create_result.withdraw_id)
+            .await;
 
         info!(
-            "Saldo balance updated for user_id: {}. New balance: {}",
-            input.user_id, new_total_balance
+            "Withdraw created successfully for user_id: {}",
+            input.user_id
         );
 
-        let withdraw_create_result = self
+        recorder.created(&beneficiary.bank_code, withdraw_create_result.withdraw_amount);
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: if held {
+                "Withdraw is held for review".to_string()
+            } else {
+                "Withdraw created successfully".to_string()
+            },
+            data: withdraw_create_result.into(),
+        })
+    }
+
+    #[instrument(skip_all)]
+    async fn review_withdraw(
+        &self,
+        input: &ResolveRiskReviewRequest,
+    ) -> Result<ApiResponse<WithdrawResponse>, ErrorResponse> {
+        if let Err(validation_err) = input.validate() {
+            error!("Validation failed for withdraw review: {}", validation_err);
+            return Err(ErrorResponse::from(AppError::InvalidFields(validation_err)));
+        }
+
+        let withdraw = self
             .withdraw_repository
-            .create(&input)
+            .find_by_id(input.reference_id)
             .await
             .map_err(AppError::from)
-            .map_err(ErrorResponse::from)?;
+            .map_err(ErrorResponse::from)?
+            .filter(|withdraw| withdraw.status == WITHDRAW_STATUS_PENDING_REVIEW)
+            .ok_or_else(|| {
+                ErrorResponse::from(AppError::NotFound(format!(
+                    "Held withdraw with id {} not found",
+                    input.reference_id
+                )))
+            })?;
+
+        if input.is_release() {
+            if !self
+                .transition(
+                    &withdraw,
+                    WITHDRAW_STATUS_PENDING_REVIEW,
+                    WITHDRAW_STATUS_PENDING,
+                    None,
+                    None,
+                )
+                .await?
+            {
+                return Err(ErrorResponse::from(AppError::ValidationError(format!(
+                    "Withdraw {} has already been reviewed",
+                    withdraw.withdraw_id
+                ))));
+            }
+        } else {
+            let reason = input
+                .reason
+                .clone()
+                .unwrap_or_else(|| "Rejected by risk review".to_string());
+
+            if !self
+                .fail_payout(&withdraw, WITHDRAW_STATUS_PENDING_REVIEW, reason)
+                .await?
+            {
+                return Err(ErrorResponse::from(AppError::ValidationError(format!(
+                    "Withdraw {} has already been reviewed",
+                    withdraw.withdraw_id
+                ))));
+            }
+        }
+
+        self.risk_engine
+            .resolve(RiskOperation::Withdraw, input)
+            .await?;
 
         info!(
-            "Withdraw created successfully for user_id: {}",
-            input.user_id
+            "Held withdraw {} {} by operator {}",
+            withdraw.withdraw_id, input.decision, input.reviewer_id
         );
 
+        let withdraw = self
+            .withdraw_repository
+            .find_by_id(withdraw.withdraw_id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .ok_or_else(|| {
+                ErrorResponse::from(AppError::NotFound(format!(
+                    "Withdraw with id {} not found",
+                    input.reference_id
+                )))
+            })?;
+
         Ok(ApiResponse {
             status: "success".to_string(),
-            message: "Withdraw created successfully".to_string(),
-            data: withdraw_create_result.into(),
+            message: format!("Withdraw {}", input.decision),
+            data: withdraw.into(),
         })
     }
 
+    #[instrument(skip_all)]
     async fn update_withdraw(
         &self,
         input: &UpdateWithdrawRequest,
     ) -> Result<ApiResponse<Option<WithdrawResponse>>, ErrorResponse> {
         if let Err(validation_err) = input.validate() {
             error!("Validation failed for withdraw update: {}", validation_err);
-            return Err(ErrorResponse::from(AppError::ValidationError(
-                validation_err,
-            )));
+            return Err(ErrorResponse::from(AppError::InvalidFields(validation_err)));
         }
 
-        let _withdraw = self
+        let withdraw = self
             .withdraw_repository
             .find_by_id(input.withdraw_id)
             .await
-            .map_err(|_| {
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .filter(|withdraw| withdraw.user_id == input.user_id)
+            .ok_or_else(|| {
                 ErrorResponse::from(AppError::NotFound(format!(
                     "Withdraw with id {} not found",
                     input.withdraw_id
                 )))
             })?;
 
-        let saldo = self
-            .saldo_repository
-            .find_by_user_id(input.user_id)
-            .await
-            .map_err(|_| {
-                ErrorResponse::from(AppError::NotFound(format!(
-                    "Saldo with user_id {} not found",
-                    input.user_id
-                )))
-            })?;
+        let not_pending = |status: &str| {
+            ErrorResponse::from(AppError::ValidationError(format!(
+                "Withdraw in status '{}' cannot be updated",
+                status
+            )))
+        };
 
-        let saldo_ref = saldo.as_ref().ok_or_else(|| {
-            ErrorResponse::from(AppError::NotFound("Saldo not found".to_string()))
-        })?;
+        // A claimed payout has already gone to the provider with its amount
+        if withdraw.status != WITHDRAW_STATUS_PENDING {
+            return Err(not_pending(&withdraw.status));
+        }
 
-        let new_total_balance = saldo_ref.total_balance - input.withdraw_amount;
+        self.limit_service
+            .check_outgoing_change(
+                withdraw.user_id,
+                LimitOperation::Withdraw,
+                withdraw.withdraw_amount,
+                input.withdraw_amount,
+            )
+            .await?;
+
+        let bank_code = match withdraw.beneficiary_id {
+            Some(beneficiary_id) => self
+                .beneficiary_repository
+                .find_by_id(beneficiary_id)
+                .await
+                .map_err(AppError::from)
+                .map_err(ErrorResponse::from)?
+                .map(|beneficiary| beneficiary.bank_code),
+            None => None,
+        };
 
-        let updated_withdraw = self
-            .withdraw_repository
-            .update(&input)
-            .await
-            .map_err(AppError::from)
-            .map_err(ErrorResponse::from);
+        // The fee follows the new amount, so a refund later returns what was actually taken
+        let previous_fee = FeeQuote {
+            fee_amount: withdraw.fee_amount,
+            fee_rule: withdraw.fee_rule.clone(),
+        };
+        let fee = self.fee_service.quote(
+            FeeOperation::Withdraw,
+            bank_code.as_deref(),
+            input.withdraw_amount,
+        );
 
-        if let Err(err) = updated_withdraw {
-            let _rollback_saldo = self
+        let difference = (input.withdraw_amount as i64 + fee.fee_amount as i64)
+            - (withdraw.withdraw_amount as i64 + withdraw.fee_amount as i64);
+        let difference = i32::try_from(difference).map_err(|_| {
+            ErrorResponse::from(AppError::ValidationError(
+                "Withdraw amount is too large".to_string(),
+            ))
+        })?;
+
+        // An increase is debited before the amount changes so it can never be paid out unfunded
+        if difference > 0
+            && !self
                 .saldo_repository
-                .update_saldo_withdraw(&UpdateSaldoWithdraw {
-                    user_id: input.user_id,
-                    withdraw_amount: None,
-                    withdraw_time: None,
-                    total_balance: saldo_ref.total_balance,
-                })
+                .debit(withdraw.user_id, difference)
                 .await
                 .map_err(AppError::from)
-                .map_err(ErrorResponse::from)?;
+                .map_err(ErrorResponse::from)?
+        {
+            return Err(ErrorResponse::from(AppError::InsufficientFunds(
+                "Insufficient balance".to_string(),
+            )));
+        }
 
-            error!("Rollback: Saldo reverted due to withdraw update failure");
+        let updated_withdraw = match self.withdraw_repository.update(input, &fee).await {
+            Ok(Some(updated_withdraw)) => updated_withdraw,
+            result => {
+                // The payout was claimed in the meantime or the update failed
+                if difference > 0 {
+                    self.saldo_repository
+                        .credit(withdraw.user_id, difference)
+                        .await
+                        .map_err(|rollback_err| {
+                            error!(
+                                "Failed to return {} for withdraw {}: {}",
+                                difference, withdraw.withdraw_id, rollback_err
+                            );
+                        })
+                        .ok();
+                }
+
+                return Err(match result {
+                    Err(db_err) => ErrorResponse::from(AppError::from(db_err)),
+                    _ => not_pending(WITHDRAW_STATUS_PROCESSING),
+                });
+            }
+        };
 
-            return Err(err);
+        if difference < 0
+            && !self
+                .saldo_repository
+                .credit(withdraw.user_id, -difference)
+                .await
+                .map_err(AppError::from)
+                .map_err(ErrorResponse::from)?
+        {
+            return Err(ErrorResponse::from(AppError::NotFound(
+                "Saldo not found".to_string(),
+            )));
         }
 
-        let _update_saldo = self
-            .saldo_repository
-            .update_saldo_withdraw(&UpdateSaldoWithdraw {
-                user_id: input.user_id,
-                withdraw_amount: Some(input.withdraw_amount),
-                withdraw_time: Some(Utc::now().naive_utc()),
-                total_balance: new_total_balance,
-            })
-            .await
-            .map_err(AppError::from)
-            .map_err(ErrorResponse::from)?;
+        if let Err(e) = self.fee_service.adjust(&previous_fee, &fee).await {
+            error!(
+                "Failed to adjust fee for withdraw {}: {}",
+                withdraw.withdraw_id, e.message
+            );
+        }
+
+        info!(
+            "Withdraw {} updated from {} to {}",
+            withdraw.withdraw_id, withdraw.withdraw_amount, updated_withdraw.withdraw_amount
+        );
 
         Ok(ApiResponse {
             status: "success".to_string(),
             message: "Withdraw updated successfully".to_string(),
-            data: Some(updated_withdraw.unwrap().into()),
+            data: Some(updated_withdraw.into()),
         })
     }
 
+    // Withdraws are kept for the ledger; a pending one is cancelled and refunded instead
+    #[instrument(skip_all, fields(id = id))]
     async fn delete_withdraw(&self, id: i32) -> Result<ApiResponse<()>, ErrorResponse> {
-        let user = self.user_repository.find_by_id(id).await.map_err(|_| {
-            ErrorResponse::from(AppError::NotFound(format!("User with id {} not found", id)))
-        })?;
+        let withdraw = self
+            .withdraw_repository
+            .find_by_id(id)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?
+            .ok_or_else(|| {
+                error!("Withdraw with id {} not found", id);
+                ErrorResponse::from(AppError::NotFound(format!(
+                    "Withdraw with id {} not found",
+                    id
+                )))
+            })?;
 
-        let existing = self
+        let cannot_cancel = |status: &str| {
+            ErrorResponse::from(AppError::ValidationError(format!(
+                "Withdraw in status '{}' cannot be cancelled",
+                status
+            )))
+        };
+
+        // A held withdraw is settled by its review instead
+        if withdraw.status != WITHDRAW_STATUS_PENDING {
+            return Err(cannot_cancel(&withdraw.status));
+        }
+
+        if !self
+            .fail_payout(&withdraw, WITHDRAW_STATUS_PENDING, "Cancelled by the user".to_string())
+            .await?
+        {
+            return Err(cannot_cancel(WITHDRAW_STATUS_PROCESSING));
+        }
+
+        info!("Withdraw {} cancelled", id);
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Withdraw cancelled and refunded".to_string(),
+            data: (),
+        })
+    }
+
+    #[instrument(skip_all)]
+    async fn process_payouts(&self) -> Result<u64, ErrorResponse> {
+        let processing = self
             .withdraw_repository
-            .find_by_user(user.unwrap().user_id)
+            .find_by_status(WITHDRAW_STATUS_PROCESSING)
             .await
             .map_err(AppError::from)
             .map_err(ErrorResponse::from)?;
 
-        match existing {
-            Some(_) => {
-                self.withdraw_repository
-                    .delete(existing.unwrap().withdraw_id)
-                    .await
-                    .map_err(AppError::from)
-                    .map_err(ErrorResponse::from)?;
+        for withdraw in &processing {
+            if let Err(e) = self.poll_payout(withdraw).await {
+                error!("Failed to poll payout for withdraw {}: {}", withdraw.withdraw_id, e.message);
+            }
+        }
 
-                info!("Withdraw deleted successfully for id: {}", id);
+        let pending = self
+            .withdraw_repository
+            .find_by_status(WITHDRAW_STATUS_PENDING)
+            .await
+            .map_err(AppError::from)
+            .map_err(ErrorResponse::from)?;
 
-                Ok(ApiResponse {
-                    status: "success".to_string(),
-                    message: "Withdraw deleted successfully".to_string(),
-                    data: (),
-                })
-            }
-            None => {
-                error!("Withdraw with id {} not found", id);
-                Err(ErrorResponse::from(AppError::NotFound(format!(
-                    "Withdraw with id {} not found",
-                    id
-                ))))
+        for withdraw in &pending {
+            if let Err(e) = self.submit_payout(withdraw).await {
+                error!("Failed to submit payout for withdraw {}: {}", withdraw.withdraw_id, e.message);
             }
         }
+
+        if !pending.is_empty() || !processing.is_empty() {
+            info!(
+                "Processed {} pending and {} in-flight payouts",
+                pending.len(),
+                processing.len()
+            );
+        }
+
+        Ok((pending.len() + processing.len()) as u64)
     }
 }
@@ -1,6 +1,6 @@
 use sea_orm::DatabaseConnection;
 
-use crate::{config::{hashing::Hashing, jwt_config::JwtConfig}, utils::di::DependenciesInject};
+use crate::{config::{config::Config, hashing::Hashing, jwt_config::JwtConfig}, utils::{api_version::DeprecationPolicy, di::DependenciesInject}};
 
 
 
@@ -8,15 +8,24 @@ use crate::{config::{hashing::Hashing, jwt_config::JwtConfig}, utils::di::Depend
 pub struct AppState{
     pub di_container: DependenciesInject,
     pub jwt_config: JwtConfig,
+    pub operator_user_ids: Vec<i32>,
+    pub api_v1_deprecation: DeprecationPolicy,
+    pub rate_limit_trust_proxy: bool,
 }
 
 impl AppState{
-    pub fn new(pool: DatabaseConnection, jwt_secret: &str) -> Self{
-        let jwt_config = JwtConfig::new(jwt_secret);
+    pub fn new(pool: DatabaseConnection, config: &Config) -> Self{
+        let jwt_config = JwtConfig::new(&config.jwt_secret);
         let hashing = Hashing::new();
 
-        let di_container = DependenciesInject::new(pool, hashing, jwt_config.clone());
+        let di_container = DependenciesInject::new(pool, hashing, jwt_config.clone(), config);
 
-        Self { di_container, jwt_config }
+        Self {
+            di_container,
+            jwt_config,
+            operator_user_ids: config.operator_user_ids.clone(),
+            api_v1_deprecation: config.api_v1_deprecation,
+            rate_limit_trust_proxy: config.rate_limit_trust_proxy,
+        }
     }
 }
\ No newline at end of file
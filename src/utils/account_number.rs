@@ -0,0 +1,170 @@
+use std::str::FromStr;
+
+use rand::Rng;
+
+use super::random_vcc::calculate_check_digit;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccountNumberScheme {
+    Random,
+    Sequential,
+    EncryptedSequence,
+}
+
+impl FromStr for AccountNumberScheme {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_lowercase().as_str() {
+            "random" => Ok(AccountNumberScheme::Random),
+            "sequential" => Ok(AccountNumberScheme::Sequential),
+            "encrypted" | "encrypted_sequence" => Ok(AccountNumberScheme::EncryptedSequence),
+            _ => Err(format!("Unsupported account number scheme '{}'", value)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccountNumberFormat {
+    pub prefix: String,
+    pub length: usize,
+}
+
+impl AccountNumberFormat {
+    pub fn new(prefix: &str, length: usize) -> Result<Self, String> {
+        if !prefix.chars().all(|c| c.is_ascii_digit()) {
+            return Err("Account number prefix must only contain digits".to_string());
+        }
+
+        // Leave room for at least 6 body digits and the check digit, the body must fit in a u64
+        let body_digits = length
+            .checked_sub(prefix.len() + 1)
+            .ok_or_else(|| "Account number length is shorter than its prefix".to_string())?;
+
+        if !(6..=18).contains(&body_digits) {
+            return Err("Account number must have between 6 and 18 digits after the prefix".to_string());
+        }
+
+        Ok(Self {
+            prefix: prefix.to_string(),
+            length,
+        })
+    }
+
+    pub fn body_digits(&self) -> usize {
+        self.length - self.prefix.len() - 1
+    }
+
+    // Number of distinct bodies, i.e. 10^body_digits
+    pub fn capacity(&self) -> u64 {
+        10u64.pow(self.body_digits() as u32)
+    }
+
+    pub fn compose(&self, body: u64) -> String {
+        let partial_number = format!("{}{:0width$}", self.prefix, body, width = self.body_digits());
+        let check_digit = calculate_check_digit(&partial_number);
+
+        format!("{}{}", partial_number, check_digit)
+    }
+
+    pub fn random_body(&self) -> u64 {
+        rand::thread_rng().gen_range(0..self.capacity())
+    }
+}
+
+fn splitmix64(value: u64) -> u64 {
+    let mut z = value.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+pub fn derive_key(secret: &str) -> u64 {
+    secret
+        .bytes()
+        .fold(0u64, |key, byte| splitmix64(key ^ byte as u64))
+}
+
+// Keyed permutation of [0, domain): a balanced Feistel network over the smallest
+// even bit width covering the domain, cycle-walking until the result falls inside it.
+// Consecutive sequence values therefore map to unrelated, still unique, account numbers.
+pub fn permute(value: u64, key: u64, domain: u64) -> u64 {
+    let bits = 64 - domain.saturating_sub(1).leading_zeros();
+    let half_bits = bits.div_ceil(2).max(1);
+    let mask = (1u64 << half_bits) - 1;
+
+    let mut current = value;
+
+    loop {
+        let mut left = current >> half_bits;
+        let mut right = current & mask;
+
+        for round in 0..4u64 {
+            let mixed = splitmix64(right ^ key.rotate_left(round as u32 * 16) ^ round) & mask;
+            (left, right) = (right, left ^ mixed);
+        }
+
+        current = (left << half_bits) | right;
+
+        if current < domain {
+            return current;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::random_vcc::is_valid_vcc;
+
+    fn assert_bijective(key: u64, domain: u64) {
+        let mut seen = vec![false; domain as usize];
+
+        for value in 0..domain {
+            let permuted = permute(value, key, domain);
+
+            assert!(permuted < domain, "{} left the domain {}", permuted, domain);
+            assert!(!seen[permuted as usize], "{} was produced twice", permuted);
+            seen[permuted as usize] = true;
+        }
+    }
+
+    #[test]
+    fn permute_is_a_bijection_on_the_smallest_account_space() {
+        assert_bijective(derive_key("secret"), 1_000_000);
+    }
+
+    #[test]
+    fn permute_is_a_bijection_on_domains_that_are_not_powers_of_two() {
+        for domain in [1, 2, 3, 10, 1000, 4097] {
+            assert_bijective(derive_key("secret"), domain);
+        }
+    }
+
+    #[test]
+    fn permute_depends_on_the_key() {
+        let domain = 1_000_000;
+        let differing = (0..100)
+            .filter(|value| {
+                permute(*value, derive_key("one"), domain)
+                    != permute(*value, derive_key("two"), domain)
+            })
+            .count();
+
+        assert!(differing > 90);
+        assert_eq!(
+            permute(42, derive_key("one"), domain),
+            permute(42, derive_key("one"), domain)
+        );
+    }
+
+    #[test]
+    fn composed_numbers_carry_a_valid_check_digit() {
+        let format = AccountNumberFormat::new("4", 16).unwrap();
+        let number = format.compose(permute(7, derive_key("secret"), format.capacity()));
+
+        assert_eq!(number.len(), 16);
+        assert!(number.starts_with('4'));
+        assert!(is_valid_vcc(&number));
+    }
+}
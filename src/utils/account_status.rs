@@ -0,0 +1,67 @@
+use std::{fmt, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AccountStatus {
+    Active,
+    // Nothing moves in or out, e.g. while a compromise is investigated
+    Frozen,
+    // Funds can still arrive but nothing can leave
+    DebitBlocked,
+    // Final; the user and their history are kept but the account can no longer be used
+    Closed,
+}
+
+impl AccountStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AccountStatus::Active => "active",
+            AccountStatus::Frozen => "frozen",
+            AccountStatus::DebitBlocked => "debit_blocked",
+            AccountStatus::Closed => "closed",
+        }
+    }
+
+    pub fn can_debit(&self) -> bool {
+        *self == AccountStatus::Active
+    }
+
+    pub fn can_credit(&self) -> bool {
+        matches!(self, AccountStatus::Active | AccountStatus::DebitBlocked)
+    }
+
+    pub fn can_transition_to(&self, status: AccountStatus) -> Result<(), String> {
+        if *self == status {
+            return Err(format!("Account is already {}", self));
+        }
+
+        if *self == AccountStatus::Closed {
+            return Err("A closed account cannot be reopened".to_string());
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for AccountStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for AccountStatus {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_lowercase().as_str() {
+            "active" => Ok(AccountStatus::Active),
+            "frozen" => Ok(AccountStatus::Frozen),
+            "debit_blocked" => Ok(AccountStatus::DebitBlocked),
+            "closed" => Ok(AccountStatus::Closed),
+            _ => Err(format!("Unsupported account status '{}'", value)),
+        }
+    }
+}
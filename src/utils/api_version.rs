@@ -0,0 +1,46 @@
+use chrono::{DateTime, Utc};
+
+pub const API_V1_PREFIX: &str = "/api/v1";
+pub const API_V2_PREFIX: &str = "/api/v2";
+
+// Paths mounted before versioning existed; they keep serving v1
+pub const API_LEGACY_PREFIX: &str = "/api";
+
+/// What v1 responses announce about the version going away.
+///
+/// `Deprecation` is always sent, as a date when one is configured; `Sunset` only once a
+/// shutdown date has been set.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DeprecationPolicy {
+    pub deprecated_at: Option<DateTime<Utc>>,
+    pub sunset_at: Option<DateTime<Utc>>,
+}
+
+impl DeprecationPolicy {
+    // RFC 9745 dates are "@" followed by a unix timestamp
+    pub fn deprecation_header(&self) -> String {
+        match self.deprecated_at {
+            Some(at) => format!("@{}", at.timestamp()),
+            None => "true".to_string(),
+        }
+    }
+
+    // RFC 8594 uses an HTTP-date
+    pub fn sunset_header(&self) -> Option<String> {
+        self.sunset_at
+            .map(|at| at.format("%a, %d %b %Y %H:%M:%S GMT").to_string())
+    }
+}
+
+// The same route under the current version, e.g. /api/v1/topups/3 -> /api/v2/topups/3
+pub fn successor_path(path: &str) -> Option<String> {
+    let rest = path
+        .strip_prefix(API_V1_PREFIX)
+        .or_else(|| path.strip_prefix(API_LEGACY_PREFIX))?;
+
+    if !rest.is_empty() && !rest.starts_with('/') {
+        return None;
+    }
+
+    Some(format!("{}{}", API_V2_PREFIX, rest))
+}
@@ -2,7 +2,7 @@ use std::sync::Arc;
 
 use sea_orm::DatabaseConnection;
 
-use crate::{abstract_trait::{auth::DynAuthService, saldo::{DynSaldoRepository, DynSaldoService}, topup::{DynTopupRepository, DynTopupService}, transfer::{DynTransferRepository, DynTransferService}, user::{DynUserRepository, DynUserService}, withdraw::DynWithdrawService}, config::{hashing::Hashing, jwt_config::JwtConfig}, repository::{saldo::SaldoRepository, topup::TopupRepository, transfer::TransferRepository, user::UserRepository, withdraw::WithdrawRepository}, services::{auth::AuthService, saldo::SaldoService, topup::TopupService, transfer::TransferService, user::UserService, withdraw::WithdrawService}};
+use crate::{abstract_trait::{account::DynAccountService, account_status::{DynAccountStatusRepository, DynAccountStatusService}, account_number::{DynAccountNumberAllocator, DynAccountNumberRepository}, auth::DynAuthService, document_store::DynDocumentStore, fee::{DynFeeRepository, DynFeeService}, health::DynHealthService, beneficiary::{DynBeneficiaryRepository, DynBeneficiaryService}, invoice::{DynInvoiceRepository, DynInvoiceService}, kyc::{DynKycRepository, DynKycService}, limit::DynLimitService, metrics::DynMetrics, notification::DynNotificationService, payout::DynPayoutProvider, payout_batch::{DynPayoutBatchRepository, DynPayoutBatchService}, qris::DynQrisService, rate_limit::DynRateLimitService, rate_limit_store::DynRateLimitStore, risk::{DynRiskEngine, DynRiskRepository}, screening::{DynScreeningRepository, DynScreeningService}, saldo::{DynSaldoRepository, DynSaldoService}, scheduled_transfer::{DynScheduledTransferRepository, DynScheduledTransferService}, subscription::{DynSubscriptionRepository, DynSubscriptionService}, topup::{DynTopupRepository, DynTopupService}, transfer::{DynTransferRepository, DynTransferService}, user::{DynUserRepository, DynUserService}, virtual_account::{DynVirtualAccountRepository, DynVirtualAccountService}, withdraw::{DynWithdrawRepository, DynWithdrawService}}, config::{config::Config, hashing::Hashing, jwt_config::JwtConfig}, repository::{account_number::AccountNumberRepository, account_status::AccountStatusRepository, beneficiary::BeneficiaryRepository, fee::FeeRepository, invoice::InvoiceRepository, kyc::KycRepository, payout_batch::PayoutBatchRepository, risk::RiskRepository, saldo::SaldoRepository, screening::ScreeningRepository, scheduled_transfer::ScheduledTransferRepository, subscription::SubscriptionRepository, topup::TopupRepository, transfer::TransferRepository, user::UserRepository, virtual_account::VirtualAccountRepository, withdraw::WithdrawRepository}, services::{account::AccountService, account_status::AccountStatusService, account_number::AccountNumberAllocator, auth::AuthService, beneficiary::BeneficiaryService, document_store::LocalDocumentStore, fee::FeeService, health::HealthService, invoice::InvoiceService, kyc::KycService, limit::LimitService, metrics::PrometheusMetrics, notification::LogNotificationService, payout::SimulatedPayoutProvider, payout_batch::PayoutBatchService, qris::QrisService, rate_limit::RateLimitService, rate_limit_store::InMemoryRateLimitStore, risk::RuleRiskEngine, saldo::SaldoService, screening::ScreeningService, scheduled_transfer::ScheduledTransferService, subscription::SubscriptionService, topup::TopupService, transfer::TransferService, user::UserService, virtual_account::VirtualAccountService, withdraw::WithdrawService}};
 
 
 
@@ -14,15 +14,42 @@ pub struct DependenciesInject{
     pub topup_service: DynTopupService,
     pub transfer_service: DynTransferService,
     pub withdraw_service: DynWithdrawService,
+    pub beneficiary_service: DynBeneficiaryService,
+    pub payout_batch_service: DynPayoutBatchService,
+    pub virtual_account_service: DynVirtualAccountService,
+    pub qris_service: DynQrisService,
+    pub invoice_service: DynInvoiceService,
+    pub subscription_service: DynSubscriptionService,
+    pub scheduled_transfer_service: DynScheduledTransferService,
+    pub account_service: DynAccountService,
+    pub fee_service: DynFeeService,
+    pub limit_service: DynLimitService,
+    pub kyc_service: DynKycService,
+    pub risk_engine: DynRiskEngine,
+    pub screening_service: DynScreeningService,
+    pub account_status_service: DynAccountStatusService,
+    pub rate_limit_service: DynRateLimitService,
+    pub metrics: DynMetrics,
+    pub health_service: DynHealthService,
 }
 
 impl DependenciesInject{
-    pub fn new(pool: DatabaseConnection, hashing: Hashing, jwt_config: JwtConfig) -> Self{
+    pub fn new(pool: DatabaseConnection, hashing: Hashing, jwt_config: JwtConfig, config: &Config) -> Self{
+        let metrics = Arc::new(PrometheusMetrics::new(pool.clone()).expect("Failed to register metrics")) as DynMetrics;
+
         let user_repository = Arc::new(UserRepository::new(pool.clone())) as DynUserRepository;
 
-        let user_service = Arc::new(UserService::new(user_repository.clone(), hashing.clone())) as DynUserService;
+        let account_number_repository = Arc::new(AccountNumberRepository::new(pool.clone())) as DynAccountNumberRepository;
+
+        let account_number_allocator = Arc::new(AccountNumberAllocator::new(account_number_repository, user_repository.clone(), config.account_number_format.clone(), config.account_number_scheme, config.account_number_key, config.account_number_max_attempts)) as DynAccountNumberAllocator;
+
+        let user_service = Arc::new(UserService::new(user_repository.clone(), hashing.clone(), account_number_allocator.clone())) as DynUserService;
+
+        let screening_repository = Arc::new(ScreeningRepository::new(pool.clone())) as DynScreeningRepository;
+
+        let screening_service = Arc::new(ScreeningService::new(screening_repository, user_repository.clone(), config.sanctions_watchlist.clone(), config.sanctions_list_file.clone(), config.sanctions_match_threshold)) as DynScreeningService;
 
-        let auth_service = Arc::new(AuthService::new(user_repository.clone(), hashing, jwt_config));
+        let auth_service = Arc::new(AuthService::new(user_repository.clone(), hashing, jwt_config, account_number_allocator.clone(), screening_service.clone()));
 
 
         let saldo_repository = Arc::new(SaldoRepository::new(pool.clone())) as DynSaldoRepository;
@@ -31,22 +58,80 @@ impl DependenciesInject{
 
         let transfer_repository = Arc::new(TransferRepository::new(pool.clone())) as DynTransferRepository;
 
-        let withdraw_repository = Arc::new(WithdrawRepository::new(pool.clone()));
+        let withdraw_repository = Arc::new(WithdrawRepository::new(pool.clone())) as DynWithdrawRepository;
+
+        let beneficiary_repository = Arc::new(BeneficiaryRepository::new(pool.clone())) as DynBeneficiaryRepository;
+
+        let payout_batch_repository = Arc::new(PayoutBatchRepository::new(pool.clone())) as DynPayoutBatchRepository;
+
+        let kyc_repository = Arc::new(KycRepository::new(pool.clone())) as DynKycRepository;
+
+        let virtual_account_repository = Arc::new(VirtualAccountRepository::new(pool.clone())) as DynVirtualAccountRepository;
+
+        let invoice_repository = Arc::new(InvoiceRepository::new(pool.clone())) as DynInvoiceRepository;
+
+        let subscription_repository = Arc::new(SubscriptionRepository::new(pool.clone())) as DynSubscriptionRepository;
+
+        let scheduled_transfer_repository = Arc::new(ScheduledTransferRepository::new(pool.clone())) as DynScheduledTransferRepository;
+
+        let notification_service = Arc::new(LogNotificationService::new()) as DynNotificationService;
+
+        let payout_provider = Arc::new(SimulatedPayoutProvider::new()) as DynPayoutProvider;
+
+        let fee_repository = Arc::new(FeeRepository::new(pool.clone())) as DynFeeRepository;
+
+        let fee_service = Arc::new(FeeService::new(config.fee_schedule.clone(), fee_repository, config.platform_revenue_user_id)) as DynFeeService;
+
+        let limit_service = Arc::new(LimitService::new(user_repository.clone(), saldo_repository.clone(), transfer_repository.clone(), withdraw_repository.clone())) as DynLimitService;
+
+        let account_status_repository = Arc::new(AccountStatusRepository::new(pool.clone())) as DynAccountStatusRepository;
+
+        let account_status_service = Arc::new(AccountStatusService::new(account_status_repository, user_repository.clone(), saldo_repository.clone())) as DynAccountStatusService;
+
+        let risk_repository = Arc::new(RiskRepository::new(pool.clone())) as DynRiskRepository;
+
+        let risk_engine = Arc::new(RuleRiskEngine::new(config.risk_rules.clone(), risk_repository, user_repository.clone(), transfer_repository.clone())) as DynRiskEngine;
+
+
+        let saldo_service = Arc::new(SaldoService::new(user_repository.clone(), saldo_repository.clone(), limit_service.clone())) as DynSaldoService;
+
+        let topup_service = Arc::new(TopupService::new(topup_repository.clone(), saldo_repository.clone(), user_repository.clone(), fee_service.clone(), limit_service.clone(), risk_engine.clone(), metrics.clone())) as DynTopupService;
+
+        let transfer_service = Arc::new(TransferService::new(transfer_repository.clone(), saldo_repository.clone(), user_repository.clone(), fee_service.clone(), limit_service.clone(), risk_engine.clone(), screening_service.clone(), metrics.clone())) as DynTransferService;
+
+        let withdraw_service = Arc::new(WithdrawService::new(withdraw_repository.clone(), saldo_repository.clone(), user_repository.clone(), beneficiary_repository.clone(), payout_provider.clone(), fee_service.clone(), limit_service.clone(), risk_engine.clone(), metrics.clone())) as DynWithdrawService;
+
+        let document_store = Arc::new(LocalDocumentStore::new(config.kyc_storage_dir.clone())) as DynDocumentStore;
+
+        let kyc_service = Arc::new(KycService::new(kyc_repository.clone(), user_repository.clone(), document_store.clone())) as DynKycService;
+
+        let beneficiary_service = Arc::new(BeneficiaryService::new(beneficiary_repository.clone(), user_repository.clone())) as DynBeneficiaryService;
+
+        let payout_batch_service = Arc::new(PayoutBatchService::new(payout_batch_repository.clone(), user_repository.clone(), saldo_repository.clone(), beneficiary_repository.clone(), transfer_service.clone(), withdraw_service.clone(), limit_service.clone())) as DynPayoutBatchService;
+
+        let virtual_account_service = Arc::new(VirtualAccountService::new(virtual_account_repository.clone(), user_repository.clone(), topup_repository.clone(), topup_service.clone(), config.va_bank_prefixes.clone(), config.va_notification_secrets.clone())) as DynVirtualAccountService;
+
+        let qris_service = Arc::new(QrisService::new(user_repository.clone(), config.qris_merchant_city.clone(), config.qris_merchant_category_code.clone())) as DynQrisService;
+
+        let invoice_service = Arc::new(InvoiceService::new(invoice_repository.clone(), user_repository.clone(), transfer_service.clone())) as DynInvoiceService;
+
+        let subscription_service = Arc::new(SubscriptionService::new(subscription_repository.clone(), user_repository.clone(), transfer_service.clone(), config.dunning_retry_hours.clone())) as DynSubscriptionService;
 
+        let scheduled_transfer_service = Arc::new(ScheduledTransferService::new(scheduled_transfer_repository.clone(), user_repository.clone(), transfer_service.clone(), notification_service.clone())) as DynScheduledTransferService;
 
-        let saldo_service = Arc::new(SaldoService::new(user_repository.clone(), saldo_repository.clone())) as DynSaldoService;
+        let account_service = Arc::new(AccountService::new(user_repository.clone())) as DynAccountService;
 
-        let topup_service = Arc::new(TopupService::new(topup_repository.clone(), saldo_repository.clone(), user_repository.clone())) as DynTopupService;
+        let rate_limit_store = Arc::new(InMemoryRateLimitStore::new()) as DynRateLimitStore;
 
-        let transfer_service = Arc::new(TransferService::new(transfer_repository.clone(), saldo_repository.clone(), user_repository.clone())) as DynTransferService;
+        let rate_limit_service = Arc::new(RateLimitService::new(config.rate_limit_rules.clone(), rate_limit_store)) as DynRateLimitService;
 
-        let withdraw_service = Arc::new(WithdrawService::new(withdraw_repository.clone(), saldo_repository.clone(), user_repository.clone())) as DynWithdrawService;
+        let health_service = Arc::new(HealthService::new(pool.clone())) as DynHealthService;
 
         
 
 
 
-        Self { auth_service, user_service, saldo_service, topup_service, transfer_service, withdraw_service }
+        Self { auth_service, user_service, saldo_service, topup_service, transfer_service, withdraw_service, beneficiary_service, payout_batch_service, virtual_account_service, qris_service, invoice_service, subscription_service, scheduled_transfer_service, account_service, fee_service, limit_service, kyc_service, risk_engine, screening_service, account_status_service, rate_limit_service, metrics, health_service }
     }
 
 }
\ No newline at end of file
@@ -1,8 +1,10 @@
+use actix_web::http::StatusCode;
 use bcrypt::BcryptError;
 use sea_orm::DbErr;
 use thiserror::Error;
 use jsonwebtoken::errors::Error as JwtError;
 use serde::Serialize;
+use validator::ValidationErrors;
 
 #[derive(Debug, Error)]
 pub enum AppError {
@@ -16,10 +18,16 @@ pub enum AppError {
     #[error("Not Found: {0}")]
     NotFound(String),
 
+    #[error("User not found: {0}")]
+    UserNotFound(String),
+
 
     #[error("Error Validation: {0}")]
     ValidationError(String),
 
+    #[error("Invalid fields: {0}")]
+    InvalidFields(#[from] ValidationErrors),
+
     #[error("No Password Not Same: {0}")]
     PasswordError(String),
 
@@ -40,6 +48,99 @@ pub enum AppError {
 
     #[error("Email already exists")]
     EmailAlreadyExists,
+
+    #[error("Limit exceeded: {0}")]
+    LimitExceeded(String),
+
+    #[error("Storage error: {0}")]
+    StorageError(String),
+
+    #[error("Blocked by risk rules: {0}")]
+    RiskBlocked(String),
+
+    #[error("Compliance hold: {0}")]
+    ScreeningHold(String),
+
+    #[error("Account restricted: {0}")]
+    AccountRestricted(String),
+
+    #[error("Insufficient funds: {0}")]
+    InsufficientFunds(String),
+
+    #[error("Unauthorized: {0}")]
+    Unauthorized(String),
+
+    #[error("Forbidden: {0}")]
+    Forbidden(String),
+
+    #[error("Too many requests: {0}")]
+    RateLimited(String),
+
+    #[error("Internal error: {0}")]
+    InternalError(String),
+
+    #[error("Service unavailable: {0}")]
+    ServiceUnavailable(String),
+}
+
+impl AppError {
+    // Stable identifiers clients can branch on; the message text may change, these may not
+    pub fn code(&self) -> &'static str {
+        match self {
+            AppError::DbError(_) => "DATABASE_ERROR",
+            AppError::HashingError(_) => "HASHING_ERROR",
+            AppError::NotFound(_) => "NOT_FOUND",
+            AppError::UserNotFound(_) => "USER_NOT_FOUND",
+            AppError::ValidationError(_) | AppError::InvalidFields(_) => "VALIDATION_ERROR",
+            AppError::PasswordError(_) => "PASSWORD_MISMATCH",
+            AppError::TokenExpiredError => "TOKEN_EXPIRED",
+            AppError::TokenValidationError => "TOKEN_INVALID",
+            AppError::TokenGenerationError(_) => "TOKEN_GENERATION_FAILED",
+            AppError::BcryptError(_) => "HASHING_ERROR",
+            AppError::InvalidCredentials => "INVALID_CREDENTIALS",
+            AppError::EmailAlreadyExists => "EMAIL_ALREADY_EXISTS",
+            AppError::LimitExceeded(_) => "LIMIT_EXCEEDED",
+            AppError::StorageError(_) => "STORAGE_ERROR",
+            AppError::RiskBlocked(_) => "RISK_BLOCKED",
+            AppError::ScreeningHold(_) => "COMPLIANCE_HOLD",
+            AppError::AccountRestricted(_) => "ACCOUNT_RESTRICTED",
+            AppError::InsufficientFunds(_) => "INSUFFICIENT_FUNDS",
+            AppError::Unauthorized(_) => "UNAUTHORIZED",
+            AppError::Forbidden(_) => "FORBIDDEN",
+            AppError::RateLimited(_) => "RATE_LIMITED",
+            AppError::InternalError(_) => "INTERNAL_ERROR",
+            AppError::ServiceUnavailable(_) => "SERVICE_UNAVAILABLE",
+        }
+    }
+
+    pub fn status_code(&self) -> StatusCode {
+        match self {
+            AppError::DbError(_)
+            | AppError::HashingError(_)
+            | AppError::TokenGenerationError(_)
+            | AppError::BcryptError(_)
+            | AppError::StorageError(_)
+            | AppError::InternalError(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            AppError::NotFound(_) | AppError::UserNotFound(_) => StatusCode::NOT_FOUND,
+            AppError::ValidationError(_)
+            | AppError::InvalidFields(_)
+            | AppError::PasswordError(_) => StatusCode::BAD_REQUEST,
+            AppError::TokenExpiredError
+            | AppError::TokenValidationError
+            | AppError::InvalidCredentials
+            | AppError::Unauthorized(_) => StatusCode::UNAUTHORIZED,
+            AppError::EmailAlreadyExists => StatusCode::CONFLICT,
+            AppError::LimitExceeded(_) | AppError::InsufficientFunds(_) => {
+                StatusCode::UNPROCESSABLE_ENTITY
+            }
+            AppError::RiskBlocked(_)
+            | AppError::ScreeningHold(_)
+            | AppError::AccountRestricted(_)
+            | AppError::Forbidden(_) => StatusCode::FORBIDDEN,
+            AppError::RateLimited(_) => StatusCode::TOO_MANY_REQUESTS,
+            AppError::ServiceUnavailable(_) => StatusCode::SERVICE_UNAVAILABLE,
+        }
+    }
 }
 
 impl Serialize for AppError {
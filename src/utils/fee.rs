@@ -0,0 +1,352 @@
+use std::{fmt, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum FeeOperation {
+    Topup,
+    Transfer,
+    Withdraw,
+}
+
+impl FeeOperation {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FeeOperation::Topup => "topup",
+            FeeOperation::Transfer => "transfer",
+            FeeOperation::Withdraw => "withdraw",
+        }
+    }
+
+    // Topup fees come out of the credited amount; the others are charged on top
+    pub fn fee_on_top(&self) -> bool {
+        !matches!(self, FeeOperation::Topup)
+    }
+}
+
+impl fmt::Display for FeeOperation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for FeeOperation {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_lowercase().as_str() {
+            "topup" => Ok(FeeOperation::Topup),
+            "transfer" => Ok(FeeOperation::Transfer),
+            "withdraw" => Ok(FeeOperation::Withdraw),
+            _ => Err(format!("Unsupported fee operation '{}'", value)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeeTier {
+    // Upper bound (inclusive) of the amounts this tier covers; the last tier may leave it open
+    #[serde(default)]
+    pub up_to: Option<i32>,
+    #[serde(default)]
+    pub flat: i32,
+    #[serde(default)]
+    pub basis_points: i32,
+}
+
+/// One fee rule from the FEE_RULES_FILE, for example:
+///
+/// ```json
+/// [
+///   { "name": "transfer-default", "operation": "transfer", "flat": 1000 },
+///   { "name": "topup-card", "operation": "topup", "channel": "visa", "basis_points": 290, "min": 2000 },
+///   { "name": "withdraw-tiered", "operation": "withdraw", "tiers": [
+///       { "up_to": 1000000, "flat": 2500 },
+///       { "flat": 5000, "basis_points": 10 }
+///   ], "max": 25000 }
+/// ]
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeeRule {
+    pub name: String,
+    pub operation: FeeOperation,
+    // Applies to every channel of the operation when omitted
+    #[serde(default)]
+    pub channel: Option<String>,
+    #[serde(default)]
+    pub flat: i32,
+    #[serde(default)]
+    pub basis_points: i32,
+    #[serde(default)]
+    pub tiers: Vec<FeeTier>,
+    #[serde(default)]
+    pub min: Option<i32>,
+    #[serde(default)]
+    pub max: Option<i32>,
+}
+
+fn flat_plus_percentage(amount: i32, flat: i32, basis_points: i32) -> i64 {
+    // Percentages round half up to the nearest rupiah
+    flat as i64 + (amount as i64 * basis_points as i64 + 5000) / 10000
+}
+
+impl FeeRule {
+    fn validate(&self) -> Result<(), String> {
+        if self.name.trim().is_empty() {
+            return Err("Fee rule name is required".to_string());
+        }
+
+        let invalid = |reason: &str| Err(format!("Fee rule '{}': {}", self.name, reason));
+
+        if self.flat < 0 || self.basis_points < 0 {
+            return invalid("flat and basis_points must not be negative");
+        }
+
+        if self.basis_points > 10000 {
+            return invalid("basis_points must not exceed 10000");
+        }
+
+        for (index, tier) in self.tiers.iter().enumerate() {
+            if tier.flat < 0 || tier.basis_points < 0 || tier.basis_points > 10000 {
+                return invalid("tier fees must be between 0 and 10000 basis points");
+            }
+
+            let is_last = index + 1 == self.tiers.len();
+
+            match tier.up_to {
+                None if !is_last => return invalid("only the last tier may omit up_to"),
+                Some(up_to) if index > 0 && self.tiers[index - 1].up_to >= Some(up_to) => {
+                    return invalid("tiers must be ordered by increasing up_to")
+                }
+                _ => {}
+            }
+        }
+
+        if let (Some(min), Some(max)) = (self.min, self.max) {
+            if min > max {
+                return invalid("min must not exceed max");
+            }
+        }
+
+        if self.min.is_some_and(|min| min < 0) || self.max.is_some_and(|max| max < 0) {
+            return invalid("min and max must not be negative");
+        }
+
+        Ok(())
+    }
+
+    pub fn compute(&self, amount: i32) -> i32 {
+        let fee = if self.tiers.is_empty() {
+            flat_plus_percentage(amount, self.flat, self.basis_points)
+        } else {
+            self.tiers
+                .iter()
+                .find(|tier| tier.up_to.is_none_or(|up_to| amount <= up_to))
+                .or(self.tiers.last())
+                .map(|tier| flat_plus_percentage(amount, tier.flat, tier.basis_points))
+                .unwrap_or_default()
+        };
+
+        let fee = self.min.map_or(fee, |min| fee.max(min as i64));
+        let fee = self.max.map_or(fee, |max| fee.min(max as i64));
+
+        fee.clamp(0, i32::MAX as i64) as i32
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeeQuote {
+    pub fee_amount: i32,
+    pub fee_rule: Option<String>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct FeeSchedule {
+    rules: Vec<FeeRule>,
+}
+
+impl FeeSchedule {
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let rules: Vec<FeeRule> =
+            serde_json::from_str(json).map_err(|e| format!("Invalid fee rules: {}", e))?;
+
+        for rule in &rules {
+            rule.validate()?;
+        }
+
+        Ok(Self { rules })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    // A rule for the exact channel wins over the operation's catch-all rule
+    pub fn find_rule(&self, operation: FeeOperation, channel: Option<&str>) -> Option<&FeeRule> {
+        let rules = self.rules.iter().filter(|rule| rule.operation == operation);
+
+        channel
+            .and_then(|channel| {
+                rules.clone().find(|rule| {
+                    rule.channel
+                        .as_deref()
+                        .is_some_and(|c| c.eq_ignore_ascii_case(channel))
+                })
+            })
+            .or_else(|| rules.clone().find(|rule| rule.channel.is_none()))
+    }
+
+    pub fn quote(&self, operation: FeeOperation, channel: Option<&str>, amount: i32) -> FeeQuote {
+        match self.find_rule(operation, channel) {
+            Some(rule) => FeeQuote {
+                fee_amount: rule.compute(amount),
+                fee_rule: Some(rule.name.clone()),
+            },
+            None => FeeQuote::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RULES: &str = r#"[
+        { "name": "transfer-default", "operation": "transfer", "flat": 1000 },
+        { "name": "topup-card", "operation": "topup", "channel": "visa", "basis_points": 290, "min": 2000 },
+        { "name": "topup-default", "operation": "topup", "basis_points": 50 },
+        { "name": "withdraw-tiered", "operation": "withdraw", "tiers": [
+            { "up_to": 1000000, "flat": 2500 },
+            { "up_to": 5000000, "flat": 4000 },
+            { "flat": 5000, "basis_points": 10 }
+        ], "max": 25000 }
+    ]"#;
+
+    fn schedule() -> FeeSchedule {
+        FeeSchedule::from_json(RULES).unwrap()
+    }
+
+    fn rule(json: &str) -> FeeRule {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn flat_plus_percentage_rounds_half_up() {
+        let rule =
+            rule(r#"{ "name": "r", "operation": "topup", "flat": 100, "basis_points": 25 }"#);
+
+        // 0.25% of 10,200 is 25.5
+        assert_eq!(rule.compute(10_200), 126);
+        // 0.25% of 10,100 is 25.25
+        assert_eq!(rule.compute(10_100), 125);
+        assert_eq!(rule.compute(0), 100);
+    }
+
+    #[test]
+    fn tiers_include_their_upper_bound() {
+        let rule = schedule()
+            .find_rule(FeeOperation::Withdraw, None)
+            .cloned()
+            .unwrap();
+
+        assert_eq!(rule.compute(1), 2500);
+        assert_eq!(rule.compute(1_000_000), 2500);
+        assert_eq!(rule.compute(1_000_001), 4000);
+        assert_eq!(rule.compute(5_000_000), 4000);
+        assert_eq!(rule.compute(5_000_001), 10_000);
+    }
+
+    #[test]
+    fn amounts_past_closed_tiers_use_the_last_tier() {
+        let rule = rule(
+            r#"{ "name": "r", "operation": "withdraw", "tiers": [
+                { "up_to": 100, "flat": 1 },
+                { "up_to": 200, "flat": 2 }
+            ] }"#,
+        );
+
+        assert_eq!(rule.compute(200), 2);
+        assert_eq!(rule.compute(201), 2);
+    }
+
+    #[test]
+    fn min_and_max_clamp_the_fee() {
+        let schedule = schedule();
+
+        // 2.9% of 10,000 is 290, below the minimum
+        assert_eq!(
+            schedule
+                .quote(FeeOperation::Topup, Some("visa"), 10_000)
+                .fee_amount,
+            2000
+        );
+        assert_eq!(
+            schedule
+                .quote(FeeOperation::Topup, Some("visa"), 1_000_000)
+                .fee_amount,
+            29_000
+        );
+        // 5,000 + 0.1% of 100,000,000 is 105,000, above the maximum
+        assert_eq!(
+            schedule
+                .quote(FeeOperation::Withdraw, None, 100_000_000)
+                .fee_amount,
+            25_000
+        );
+    }
+
+    #[test]
+    fn large_amounts_do_not_overflow() {
+        let rule =
+            rule(r#"{ "name": "r", "operation": "transfer", "flat": 1, "basis_points": 10000 }"#);
+
+        assert_eq!(rule.compute(i32::MAX), i32::MAX);
+    }
+
+    #[test]
+    fn channel_rules_win_over_the_catch_all() {
+        let schedule = schedule();
+
+        let card = schedule.quote(FeeOperation::Topup, Some("VISA"), 100_000);
+        assert_eq!(card.fee_rule.as_deref(), Some("topup-card"));
+
+        let other = schedule.quote(FeeOperation::Topup, Some("bca"), 100_000);
+        assert_eq!(other.fee_rule.as_deref(), Some("topup-default"));
+        assert_eq!(other.fee_amount, 500);
+
+        let default = schedule.quote(FeeOperation::Topup, None, 100_000);
+        assert_eq!(default.fee_rule.as_deref(), Some("topup-default"));
+    }
+
+    #[test]
+    fn operations_without_a_rule_are_free() {
+        let schedule = FeeSchedule::from_json(
+            r#"[{ "name": "transfer-default", "operation": "transfer", "flat": 1000 }]"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            schedule.quote(FeeOperation::Withdraw, None, 100_000),
+            FeeQuote::default()
+        );
+    }
+
+    #[test]
+    fn invalid_rules_are_rejected() {
+        let invalid = [
+            r#"[{ "name": " ", "operation": "transfer" }]"#,
+            r#"[{ "name": "r", "operation": "transfer", "flat": -1 }]"#,
+            r#"[{ "name": "r", "operation": "transfer", "basis_points": 10001 }]"#,
+            r#"[{ "name": "r", "operation": "transfer", "min": 10, "max": 5 }]"#,
+            r#"[{ "name": "r", "operation": "transfer", "tiers": [{ "flat": 1 }, { "up_to": 10, "flat": 2 }] }]"#,
+            r#"[{ "name": "r", "operation": "transfer", "tiers": [{ "up_to": 10 }, { "up_to": 10 }] }]"#,
+            r#"[{ "name": "r", "operation": "cashback" }]"#,
+        ];
+
+        for json in invalid {
+            assert!(FeeSchedule::from_json(json).is_err(), "{}", json);
+        }
+    }
+}
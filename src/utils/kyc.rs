@@ -0,0 +1,130 @@
+use std::{fmt, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum KycTier {
+    Unverified,
+    Basic,
+    Full,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LimitOperation {
+    Transfer,
+    Withdraw,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct OperationLimits {
+    pub per_transaction: i64,
+    pub daily: i64,
+    pub monthly: i64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct TierLimits {
+    pub max_balance: i64,
+    pub transfer: OperationLimits,
+    pub withdraw: OperationLimits,
+}
+
+impl TierLimits {
+    pub fn for_operation(&self, operation: LimitOperation) -> &OperationLimits {
+        match operation {
+            LimitOperation::Transfer => &self.transfer,
+            LimitOperation::Withdraw => &self.withdraw,
+        }
+    }
+}
+
+impl KycTier {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            KycTier::Unverified => "unverified",
+            KycTier::Basic => "basic",
+            KycTier::Full => "full",
+        }
+    }
+
+    pub fn limits(&self) -> TierLimits {
+        match self {
+            KycTier::Unverified => TierLimits {
+                max_balance: 2_000_000,
+                transfer: OperationLimits {
+                    per_transaction: 1_000_000,
+                    daily: 2_000_000,
+                    monthly: 10_000_000,
+                },
+                withdraw: OperationLimits {
+                    per_transaction: 1_000_000,
+                    daily: 1_000_000,
+                    monthly: 5_000_000,
+                },
+            },
+            KycTier::Basic => TierLimits {
+                max_balance: 20_000_000,
+                transfer: OperationLimits {
+                    per_transaction: 10_000_000,
+                    daily: 20_000_000,
+                    monthly: 100_000_000,
+                },
+                withdraw: OperationLimits {
+                    per_transaction: 10_000_000,
+                    daily: 10_000_000,
+                    monthly: 50_000_000,
+                },
+            },
+            KycTier::Full => TierLimits {
+                max_balance: 100_000_000,
+                transfer: OperationLimits {
+                    per_transaction: 50_000_000,
+                    daily: 100_000_000,
+                    monthly: 500_000_000,
+                },
+                withdraw: OperationLimits {
+                    per_transaction: 50_000_000,
+                    daily: 50_000_000,
+                    monthly: 250_000_000,
+                },
+            },
+        }
+    }
+}
+
+impl fmt::Display for KycTier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for KycTier {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_lowercase().as_str() {
+            "unverified" => Ok(KycTier::Unverified),
+            "basic" => Ok(KycTier::Basic),
+            "full" => Ok(KycTier::Full),
+            _ => Err(format!("Unsupported KYC tier '{}'", value)),
+        }
+    }
+}
+
+impl LimitOperation {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LimitOperation::Transfer => "transfer",
+            LimitOperation::Withdraw => "withdraw",
+        }
+    }
+}
+
+impl fmt::Display for LimitOperation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
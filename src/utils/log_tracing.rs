@@ -1,19 +1,104 @@
+use std::str::FromStr;
+
+use opentelemetry::{global, trace::TracerProvider};
+use opentelemetry_otlp::{SpanExporter, WithExportConfig};
+use opentelemetry_sdk::{propagation::TraceContextPropagator, trace::SdkTracerProvider, Resource};
+use tracing::{Level, Subscriber};
 use tracing_subscriber::prelude::*;
-use tracing_subscriber::{fmt, EnvFilter};
+use tracing_subscriber::{filter::Targets, fmt, registry::LookupSpan, EnvFilter, Layer};
 
-pub fn tracing(){
-    let fmt_layer = fmt::layer()
-        .without_time()
-        .with_line_number(true)
-        .with_level(true)
-        .with_target(true);
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LogFormat {
+    // Human readable lines for local development
+    #[default]
+    Text,
+    // One JSON object per line, with the fields of the enclosing request span
+    Json,
+}
+
+impl FromStr for LogFormat {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_lowercase().as_str() {
+            "text" => Ok(LogFormat::Text),
+            "json" => Ok(LogFormat::Json),
+            _ => Err(format!("Unsupported log format '{}'", value)),
+        }
+    }
+}
+
+/// Where spans are exported to when distributed tracing is enabled.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OtlpConfig {
+    // Full URL of the collector's OTLP/HTTP traces endpoint
+    pub endpoint: String,
+    pub service_name: String,
+}
+
+pub fn tracer_provider(config: &OtlpConfig) -> Result<SdkTracerProvider, String> {
+    let exporter = SpanExporter::builder()
+        .with_http()
+        .with_endpoint(&config.endpoint)
+        .build()
+        .map_err(|e| format!("Failed to build the OTLP exporter: {}", e))?;
+
+    let resource = Resource::builder()
+        .with_service_name(config.service_name.clone())
+        .build();
+
+    Ok(SdkTracerProvider::builder()
+        .with_batch_exporter(exporter)
+        .with_resource(resource)
+        .build())
+}
 
+/// Turns the spans of this crate into OpenTelemetry spans exported by `provider`.
+///
+/// Spans of dependencies are left out, the exporter's own HTTP client among them.
+pub fn otel_layer<S>(provider: &SdkTracerProvider) -> impl Layer<S>
+where
+    S: Subscriber + for<'span> LookupSpan<'span>,
+{
+    tracing_opentelemetry::layer()
+        .with_tracer(provider.tracer(env!("CARGO_PKG_NAME")))
+        .with_filter(Targets::new().with_target(env!("CARGO_CRATE_NAME"), Level::TRACE))
+}
+
+// Returns the tracer provider when OTLP export is enabled, to be shut down on exit so the
+// last batch of spans is flushed
+pub fn tracing(format: LogFormat, otlp: Option<&OtlpConfig>) -> Option<SdkTracerProvider> {
     let filter_layer = EnvFilter::try_from_default_env()
         .or_else(|_| EnvFilter::try_new("trace"))
         .unwrap();
 
-    tracing_subscriber::registry()
+    let provider = otlp.map(|config| tracer_provider(config).unwrap_or_else(|e| panic!("{}", e)));
+
+    if provider.is_some() {
+        // Incoming traceparent headers continue the caller's trace
+        global::set_text_map_propagator(TraceContextPropagator::new());
+    }
+
+    let fmt_layer = fmt::layer()
+        .with_line_number(true)
+        .with_level(true)
+        .with_target(true);
+
+    let registry = tracing_subscriber::registry()
         .with(filter_layer)
-        .with(fmt_layer)
-        .init();
-}
\ No newline at end of file
+        .with(provider.as_ref().map(otel_layer));
+
+    match format {
+        LogFormat::Text => registry.with(fmt_layer).init(),
+        LogFormat::Json => registry
+            .with(
+                fmt_layer
+                    .json()
+                    .with_current_span(true)
+                    .with_span_list(false),
+            )
+            .init(),
+    }
+
+    provider
+}
@@ -0,0 +1,40 @@
+// Keeps the first two characters of every word, e.g. "John Doe" -> "Jo** Do*"
+pub fn mask_name(name: &str) -> String {
+    name.split_whitespace()
+        .map(|word| {
+            word.chars()
+                .enumerate()
+                .map(|(index, c)| if index < 2 { c } else { '*' })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::mask_name;
+
+    #[test]
+    fn keeps_the_first_two_characters_of_each_word() {
+        assert_eq!(mask_name("John Doe"), "Jo** Do*");
+        assert_eq!(mask_name("Siti Nurhaliza"), "Si** Nu*******");
+    }
+
+    #[test]
+    fn short_words_are_not_masked() {
+        assert_eq!(mask_name("Al"), "Al");
+        assert_eq!(mask_name("A B"), "A B");
+    }
+
+    #[test]
+    fn collapses_surrounding_and_repeated_whitespace() {
+        assert_eq!(mask_name("  John \t  Doe \n"), "Jo** Do*");
+        assert_eq!(mask_name("   "), "");
+    }
+
+    #[test]
+    fn masks_by_character_not_byte() {
+        assert_eq!(mask_name("Zoë Ünal"), "Zo* Ün**");
+    }
+}
@@ -0,0 +1,81 @@
+use std::fmt;
+
+use crate::abstract_trait::metrics::DynMetrics;
+
+// Channel of transfers, which move money between saldos without an outside payment method
+pub const TRANSFER_CHANNEL: &str = "internal";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransactionKind {
+    Topup,
+    Transfer,
+    Withdraw,
+}
+
+impl TransactionKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TransactionKind::Topup => "topup",
+            TransactionKind::Transfer => "transfer",
+            TransactionKind::Withdraw => "withdraw",
+        }
+    }
+}
+
+impl fmt::Display for TransactionKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransactionOutcome {
+    Created,
+    Failed,
+}
+
+impl TransactionOutcome {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TransactionOutcome::Created => "created",
+            TransactionOutcome::Failed => "failed",
+        }
+    }
+}
+
+/// Counts one attempt to create a transaction.
+///
+/// Started when a service begins creating a topup, transfer or withdraw. The attempt counts
+/// as failed unless `created` is called, so every early return is covered without touching it.
+pub struct TransactionRecorder {
+    metrics: DynMetrics,
+    kind: TransactionKind,
+    created: bool,
+}
+
+impl TransactionRecorder {
+    pub fn start(metrics: &DynMetrics, kind: TransactionKind) -> Self {
+        Self {
+            metrics: metrics.clone(),
+            kind,
+            created: false,
+        }
+    }
+
+    pub fn created(mut self, channel: &str, amount: i32) {
+        self.created = true;
+        self.metrics.record_volume(self.kind, channel, amount);
+    }
+}
+
+impl Drop for TransactionRecorder {
+    fn drop(&mut self) {
+        let outcome = if self.created {
+            TransactionOutcome::Created
+        } else {
+            TransactionOutcome::Failed
+        };
+
+        self.metrics.record_transaction(self.kind, outcome);
+    }
+}
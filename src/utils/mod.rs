@@ -4,3 +4,18 @@ pub mod rupiah;
 pub mod errors;
 pub mod di;
 pub mod log_tracing;
+pub mod virtual_account;
+pub mod qris;
+pub mod scheduler;
+pub mod recurrence;
+pub mod mask;
+pub mod account_number;
+pub mod fee;
+pub mod kyc;
+pub mod risk;
+pub mod screening;
+pub mod account_status;
+pub mod validation;
+pub mod api_version;
+pub mod rate_limit;
+pub mod metrics;
@@ -0,0 +1,362 @@
+use image::{ImageFormat, Luma};
+use qrcode::{render::svg, QrCode};
+use std::io::Cursor;
+use unicode_normalization::UnicodeNormalization;
+
+const PAYLOAD_FORMAT_INDICATOR: &str = "01";
+const POINT_OF_INITIATION_STATIC: &str = "11";
+const POINT_OF_INITIATION_DYNAMIC: &str = "12";
+const QRIS_GLOBAL_UNIQUE_ID: &str = "ID.CO.QRIS.WWW";
+const CURRENCY_IDR: &str = "360";
+const COUNTRY_CODE: &str = "ID";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct QrisPayload {
+    pub merchant_name: String,
+    pub merchant_city: String,
+    pub merchant_id: String,
+    pub merchant_pan: String,
+    pub merchant_category_code: String,
+    pub postal_code: Option<String>,
+    pub amount: Option<i32>,
+    pub reference: Option<String>,
+}
+
+impl QrisPayload {
+    // A payload with a fixed amount is a dynamic (single payment intent) QR
+    pub fn is_dynamic(&self) -> bool {
+        self.amount.is_some()
+    }
+}
+
+// CRC-16/CCITT-FALSE as required by EMVCo tag 63
+pub fn crc16_ccitt(data: &[u8]) -> u16 {
+    let mut crc: u16 = 0xFFFF;
+
+    for byte in data {
+        crc ^= (*byte as u16) << 8;
+
+        for _ in 0..8 {
+            if crc & 0x8000 != 0 {
+                crc = (crc << 1) ^ 0x1021;
+            } else {
+                crc <<= 1;
+            }
+        }
+    }
+
+    crc
+}
+
+// Lengths count bytes, so a multi-byte value can never overrun its declared length
+pub fn encode_tlv(tag: &str, value: &str) -> Result<String, String> {
+    let length = value.len();
+
+    if length > 99 {
+        return Err(format!("Value for tag {} exceeds 99 bytes", tag));
+    }
+
+    Ok(format!("{}{:02}{}", tag, length, value))
+}
+
+pub fn parse_tlv(data: &str) -> Result<Vec<(String, String)>, String> {
+    let bytes = data.as_bytes();
+    let mut fields = Vec::new();
+    let mut position = 0;
+
+    while position < bytes.len() {
+        if position + 4 > bytes.len() {
+            return Err("Truncated TLV header".to_string());
+        }
+
+        let header = std::str::from_utf8(&bytes[position..position + 4])
+            .map_err(|_| "Invalid TLV header".to_string())?;
+        let (tag, length) = header.split_at(2);
+        let length: usize = length
+            .parse()
+            .map_err(|_| format!("Invalid length for tag {}", tag))?;
+
+        let start = position + 4;
+        let end = start + length;
+
+        if end > bytes.len() {
+            return Err(format!("Value for tag {} is truncated", tag));
+        }
+
+        let value = std::str::from_utf8(&bytes[start..end])
+            .map_err(|_| format!("Value for tag {} is not valid text", tag))?;
+
+        fields.push((tag.to_string(), value.to_string()));
+        position = end;
+    }
+
+    Ok(fields)
+}
+
+pub fn generate_qris(payload: &QrisPayload) -> Result<String, String> {
+    let merchant_account = [
+        encode_tlv("00", QRIS_GLOBAL_UNIQUE_ID)?,
+        encode_tlv("01", &payload.merchant_pan)?,
+        encode_tlv("02", &payload.merchant_id)?,
+        encode_tlv("03", "UMI")?,
+    ]
+    .concat();
+
+    let point_of_initiation = if payload.is_dynamic() {
+        POINT_OF_INITIATION_DYNAMIC
+    } else {
+        POINT_OF_INITIATION_STATIC
+    };
+
+    let mut qris = [
+        encode_tlv("00", PAYLOAD_FORMAT_INDICATOR)?,
+        encode_tlv("01", point_of_initiation)?,
+        encode_tlv("26", &merchant_account)?,
+        encode_tlv("52", &payload.merchant_category_code)?,
+        encode_tlv("53", CURRENCY_IDR)?,
+    ]
+    .concat();
+
+    if let Some(amount) = payload.amount {
+        qris.push_str(&encode_tlv("54", &amount.to_string())?);
+    }
+
+    qris.push_str(&encode_tlv("58", COUNTRY_CODE)?);
+    qris.push_str(&encode_tlv(
+        "59",
+        &ascii_field("Merchant name", &payload.merchant_name, 25)?,
+    )?);
+    qris.push_str(&encode_tlv(
+        "60",
+        &ascii_field("Merchant city", &payload.merchant_city, 15)?,
+    )?);
+
+    if let Some(postal_code) = &payload.postal_code {
+        qris.push_str(&encode_tlv("61", postal_code)?);
+    }
+
+    if let Some(reference) = &payload.reference {
+        qris.push_str(&encode_tlv("62", &encode_tlv("05", reference)?)?);
+    }
+
+    // The checksum covers everything up to and including the tag and length of field 63
+    qris.push_str("6304");
+    let crc = crc16_ccitt(qris.as_bytes());
+
+    Ok(format!("{}{:04X}", qris, crc))
+}
+
+pub fn parse_qris(qris: &str) -> Result<QrisPayload, String> {
+    let qris = qris.trim();
+
+    if qris.len() < 8 || !qris.is_ascii() {
+        return Err("Invalid QRIS payload".to_string());
+    }
+
+    let (body, checksum) = qris.split_at(qris.len() - 4);
+
+    if !body.ends_with("6304") {
+        return Err("QRIS payload must end with a CRC field".to_string());
+    }
+
+    let expected = format!("{:04X}", crc16_ccitt(body.as_bytes()));
+    if !checksum.eq_ignore_ascii_case(&expected) {
+        return Err("QRIS checksum mismatch".to_string());
+    }
+
+    let fields = parse_tlv(&body[..body.len() - 4])?;
+    let field = |tag: &str| {
+        fields
+            .iter()
+            .find(|(t, _)| t == tag)
+            .map(|(_, value)| value.clone())
+    };
+
+    if field("00").as_deref() != Some(PAYLOAD_FORMAT_INDICATOR) {
+        return Err("Unsupported payload format indicator".to_string());
+    }
+
+    let merchant_account = field("26")
+        .ok_or_else(|| "Merchant account information is missing".to_string())
+        .and_then(|value| parse_tlv(&value))?;
+    let account_field = |tag: &str| {
+        merchant_account
+            .iter()
+            .find(|(t, _)| t == tag)
+            .map(|(_, value)| value.clone())
+    };
+
+    let amount = match field("54") {
+        Some(value) => Some(
+            value
+                .split('.')
+                .next()
+                .unwrap_or_default()
+                .parse::<i32>()
+                .map_err(|_| "Invalid transaction amount".to_string())?,
+        ),
+        None => None,
+    };
+
+    let reference = match field("62") {
+        Some(value) => parse_tlv(&value)?
+            .into_iter()
+            .find(|(tag, _)| tag == "05")
+            .map(|(_, value)| value),
+        None => None,
+    };
+
+    Ok(QrisPayload {
+        merchant_name: field("59").ok_or_else(|| "Merchant name is missing".to_string())?,
+        merchant_city: field("60").ok_or_else(|| "Merchant city is missing".to_string())?,
+        merchant_id: account_field("02").unwrap_or_default(),
+        merchant_pan: account_field("01").unwrap_or_default(),
+        merchant_category_code: field("52").unwrap_or_default(),
+        postal_code: field("61"),
+        amount,
+        reference,
+    })
+}
+
+pub fn render_svg(qris: &str) -> Result<String, String> {
+    let code = QrCode::new(qris.as_bytes()).map_err(|e| e.to_string())?;
+
+    Ok(code.render::<svg::Color>().min_dimensions(256, 256).build())
+}
+
+pub fn render_png(qris: &str) -> Result<Vec<u8>, String> {
+    let code = QrCode::new(qris.as_bytes()).map_err(|e| e.to_string())?;
+    let image = code.render::<Luma<u8>>().min_dimensions(256, 256).build();
+
+    let mut buffer = Cursor::new(Vec::new());
+    image
+        .write_to(&mut buffer, ImageFormat::Png)
+        .map_err(|e| e.to_string())?;
+
+    Ok(buffer.into_inner())
+}
+
+// Merchant name and city are printable ASCII in QRIS: accents are folded onto their base letter,
+// anything else is dropped and the result is cut to the field's maximum length
+fn ascii_field(label: &str, value: &str, max_len: usize) -> Result<String, String> {
+    let folded: String = value
+        .nfd()
+        .map(|c| if c.is_whitespace() { ' ' } else { c })
+        .filter(|c| c.is_ascii() && !c.is_ascii_control())
+        .collect();
+
+    let field: String = folded
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .chars()
+        .take(max_len)
+        .collect();
+
+    let field = field.trim_end().to_string();
+
+    if field.is_empty() {
+        return Err(format!("{} has no characters that can be encoded", label));
+    }
+
+    Ok(field)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn payload() -> QrisPayload {
+        QrisPayload {
+            merchant_name: "Toko Budi".to_string(),
+            merchant_city: "Jakarta".to_string(),
+            merchant_id: "42".to_string(),
+            merchant_pan: "4000000000000042".to_string(),
+            merchant_category_code: "5499".to_string(),
+            postal_code: Some("10110".to_string()),
+            amount: Some(150000),
+            reference: Some("INV-7".to_string()),
+        }
+    }
+
+    #[test]
+    fn crc_matches_the_ccitt_false_check_value() {
+        assert_eq!(crc16_ccitt(b"123456789"), 0x29B1);
+    }
+
+    #[test]
+    fn crc_matches_the_emvco_sample_payload() {
+        let sample = "00020101021229300012D156000000000510A93FO3230Q31280012D15600000001030812345678520441115802CN5914BEST TRANSPORT6007BEIJING64200002ZH0104最佳运输0202北京540523.7253031565502016233030412340603***0708A60086670902ME91320016A0112233449988770708123456786304";
+
+        assert_eq!(crc16_ccitt(sample.as_bytes()), 0xA13A);
+    }
+
+    #[test]
+    fn tlv_round_trips() {
+        let data = [
+            encode_tlv("00", "01").unwrap(),
+            encode_tlv("59", "Toko Budi").unwrap(),
+            encode_tlv("62", "").unwrap(),
+        ]
+        .concat();
+
+        assert_eq!(data, "0002015909Toko Budi6200");
+        assert_eq!(
+            parse_tlv(&data).unwrap(),
+            [
+                ("00".to_string(), "01".to_string()),
+                ("59".to_string(), "Toko Budi".to_string()),
+                ("62".to_string(), String::new()),
+            ]
+        );
+    }
+
+    #[test]
+    fn tlv_lengths_count_bytes() {
+        assert_eq!(encode_tlv("59", "Café").unwrap(), "5905Café");
+        assert_eq!(
+            parse_tlv("5905Café").unwrap(),
+            [("59".to_string(), "Café".to_string())]
+        );
+        assert!(encode_tlv("59", &"é".repeat(50)).is_err());
+        assert!(parse_tlv("5904Café").is_err());
+        assert!(parse_tlv("5910Café").is_err());
+    }
+
+    #[test]
+    fn generated_payload_parses_back() {
+        let qris = generate_qris(&payload()).unwrap();
+
+        assert!(qris.starts_with("000201010212"));
+        assert_eq!(parse_qris(&qris).unwrap(), payload());
+    }
+
+    #[test]
+    fn merchant_fields_are_folded_to_ascii_and_truncated() {
+        let mut payload = payload();
+        payload.merchant_name = "Kedai Kopi Señor Ãndré 咖啡 dan Roti".to_string();
+        payload.merchant_city = "Yogyakarta\tIstimewa".to_string();
+
+        let parsed = parse_qris(&generate_qris(&payload).unwrap()).unwrap();
+
+        assert_eq!(parsed.merchant_name, "Kedai Kopi Senor Andre da");
+        assert_eq!(parsed.merchant_city, "Yogyakarta Isti");
+    }
+
+    #[test]
+    fn merchant_name_without_encodable_characters_is_rejected() {
+        let mut payload = payload();
+        payload.merchant_name = "咖啡".to_string();
+
+        assert!(generate_qris(&payload).is_err());
+    }
+
+    #[test]
+    fn tampered_payload_fails_the_checksum() {
+        let qris = generate_qris(&payload())
+            .unwrap()
+            .replace("150000", "950000");
+
+        assert_eq!(parse_qris(&qris), Err("QRIS checksum mismatch".to_string()));
+    }
+}
@@ -1,24 +1,4 @@
-use rand::Rng;
-
-pub fn random_vcc() -> Result<String, &'static str> {
-    let mut rng = rand::thread_rng();
-
-    // Generate a random 15-digit number as a string
-    let mut random_number = String::new();
-    for _ in 0..15 {
-        random_number.push_str(&rng.gen_range(0..10).to_string());
-    }
-
-    // Prepend "4" to signify a Visa-like card
-    let partial_card_number = format!("4{}", random_number);
-    let check_digit = calculate_check_digit(&partial_card_number);
-
-    // Final credit card number with check digit
-    let credit_card_number = format!("{}{}", partial_card_number, check_digit);
-    Ok(credit_card_number)
-}
-
-fn calculate_check_digit(number: &str) -> u32 {
+pub fn calculate_check_digit(number: &str) -> u32 {
     let mut sum = 0;
     let mut alternate = false;
 
@@ -37,4 +17,14 @@ fn calculate_check_digit(number: &str) -> u32 {
     }
 
     (10 - (sum % 10)) % 10
-}
\ No newline at end of file
+}
+
+pub fn is_valid_vcc(number: &str) -> bool {
+    if number.len() < 2 || !number.chars().all(|c| c.is_ascii_digit()) {
+        return false;
+    }
+
+    let (partial_number, check_digit) = number.split_at(number.len() - 1);
+
+    check_digit.parse::<u32>().ok() == Some(calculate_check_digit(partial_number))
+}
@@ -0,0 +1,361 @@
+use std::{fmt, time::Duration};
+
+use serde::{Deserialize, Serialize};
+
+// Routes share a bucket per group, whichever API version they are served under
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RateLimitGroup {
+    // Every route under the API scopes
+    Api,
+    // Login and registration
+    Auth,
+    // Endpoints that move money between accounts
+    Transfer,
+}
+
+impl RateLimitGroup {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RateLimitGroup::Api => "api",
+            RateLimitGroup::Auth => "auth",
+            RateLimitGroup::Transfer => "transfer",
+        }
+    }
+}
+
+impl fmt::Display for RateLimitGroup {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RateLimitIdentity {
+    Ip,
+    // The user_id of a valid JWT
+    User,
+    // The merchant key sent in X-Api-Key
+    ApiKey,
+}
+
+impl RateLimitIdentity {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RateLimitIdentity::Ip => "ip",
+            RateLimitIdentity::User => "user",
+            RateLimitIdentity::ApiKey => "api_key",
+        }
+    }
+}
+
+// Who a request comes from; identities the request does not carry are None
+#[derive(Debug, Clone, Default)]
+pub struct RateLimitIdentities {
+    pub ip: Option<String>,
+    pub user_id: Option<i32>,
+    pub api_key: Option<String>,
+}
+
+impl RateLimitIdentities {
+    pub fn get(&self, identity: RateLimitIdentity) -> Option<String> {
+        match identity {
+            RateLimitIdentity::Ip => self.ip.clone(),
+            RateLimitIdentity::User => self.user_id.map(|id| id.to_string()),
+            RateLimitIdentity::ApiKey => self.api_key.clone(),
+        }
+    }
+}
+
+/// One bucket definition from the RATE_LIMIT_RULES_FILE, for example:
+///
+/// ```json
+/// [
+///   { "group": "auth", "identity": "ip", "capacity": 10, "period_secs": 60 },
+///   { "group": "transfer", "identity": "user", "capacity": 20, "period_secs": 60 },
+///   { "group": "api", "identity": "api_key", "capacity": 600, "period_secs": 60 }
+/// ]
+/// ```
+///
+/// A bucket holds `capacity` requests and refills evenly over `period_secs`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RateLimitRule {
+    pub group: RateLimitGroup,
+    pub identity: RateLimitIdentity,
+    pub capacity: u32,
+    pub period_secs: u64,
+}
+
+impl RateLimitRule {
+    fn validate(&self) -> Result<(), String> {
+        if self.capacity == 0 || self.period_secs == 0 {
+            return Err(format!(
+                "Rate limit rule for {} by {}: capacity and period_secs must be positive",
+                self.group,
+                self.identity.as_str()
+            ));
+        }
+
+        Ok(())
+    }
+
+    // Tokens added back per second
+    fn refill_rate(&self) -> f64 {
+        self.capacity as f64 / self.period_secs as f64
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RateLimitRules {
+    rules: Vec<RateLimitRule>,
+}
+
+// Used when no rules file is configured
+impl Default for RateLimitRules {
+    fn default() -> Self {
+        let rule = |group, identity, capacity, period_secs| RateLimitRule {
+            group,
+            identity,
+            capacity,
+            period_secs,
+        };
+
+        Self {
+            rules: vec![
+                rule(RateLimitGroup::Auth, RateLimitIdentity::Ip, 10, 60),
+                rule(RateLimitGroup::Transfer, RateLimitIdentity::User, 20, 60),
+                rule(RateLimitGroup::Transfer, RateLimitIdentity::Ip, 60, 60),
+                rule(RateLimitGroup::Transfer, RateLimitIdentity::ApiKey, 120, 60),
+            ],
+        }
+    }
+}
+
+impl RateLimitRules {
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let rules: Vec<RateLimitRule> =
+            serde_json::from_str(json).map_err(|e| format!("Invalid rate limit rules: {}", e))?;
+
+        for (index, rule) in rules.iter().enumerate() {
+            rule.validate()?;
+
+            if rules[..index]
+                .iter()
+                .any(|other| other.group == rule.group && other.identity == rule.identity)
+            {
+                return Err(format!(
+                    "Duplicate rate limit rule for {} by {}",
+                    rule.group,
+                    rule.identity.as_str()
+                ));
+            }
+        }
+
+        Ok(Self { rules })
+    }
+
+    pub fn rules_for(&self, group: RateLimitGroup) -> impl Iterator<Item = &RateLimitRule> {
+        self.rules.iter().filter(move |rule| rule.group == group)
+    }
+}
+
+/// The outcome of taking a token from one bucket.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimitDecision {
+    pub allowed: bool,
+    pub limit: u32,
+    pub remaining: u32,
+    // Until the bucket is full again
+    pub reset_after: Duration,
+    // Until the next token, zero when the request was allowed
+    pub retry_after: Duration,
+}
+
+impl RateLimitDecision {
+    // The decision to report when several buckets applied: any refusal, otherwise the tightest
+    pub fn strictest(self, other: RateLimitDecision) -> RateLimitDecision {
+        match (self.allowed, other.allowed) {
+            (true, false) => other,
+            (false, true) => self,
+            (false, false) if other.retry_after > self.retry_after => other,
+            (true, true) if other.remaining < self.remaining => other,
+            _ => self,
+        }
+    }
+}
+
+/// A token bucket measured in seconds since an arbitrary clock origin, so stores can keep it
+/// next to whatever clock they use.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TokenBucket {
+    tokens: f64,
+    updated_at: f64,
+}
+
+impl TokenBucket {
+    pub fn full(rule: &RateLimitRule, now: f64) -> Self {
+        Self {
+            tokens: rule.capacity as f64,
+            updated_at: now,
+        }
+    }
+
+    fn refill(&mut self, rule: &RateLimitRule, now: f64) {
+        let elapsed = (now - self.updated_at).max(0.0);
+
+        self.tokens = (self.tokens + elapsed * rule.refill_rate()).min(rule.capacity as f64);
+        self.updated_at = now.max(self.updated_at);
+    }
+
+    pub fn take(&mut self, rule: &RateLimitRule, now: f64) -> RateLimitDecision {
+        self.refill(rule, now);
+
+        let allowed = self.tokens >= 1.0;
+
+        if allowed {
+            self.tokens -= 1.0;
+        }
+
+        let seconds_until =
+            |tokens: f64| Duration::from_secs_f64(tokens.max(0.0) / rule.refill_rate());
+
+        RateLimitDecision {
+            allowed,
+            limit: rule.capacity,
+            remaining: self.tokens.floor() as u32,
+            reset_after: seconds_until(rule.capacity as f64 - self.tokens),
+            retry_after: if allowed {
+                Duration::ZERO
+            } else {
+                seconds_until(1.0 - self.tokens)
+            },
+        }
+    }
+
+    // A bucket that has refilled completely is the same as one that was never created
+    pub fn is_full(&self, rule: &RateLimitRule, now: f64) -> bool {
+        let mut bucket = *self;
+        bucket.refill(rule, now);
+        bucket.tokens >= rule.capacity as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Three requests, refilled at one token per second
+    const RULE: RateLimitRule = RateLimitRule {
+        group: RateLimitGroup::Transfer,
+        identity: RateLimitIdentity::User,
+        capacity: 3,
+        period_secs: 3,
+    };
+
+    fn decision(allowed: bool, remaining: u32, retry_after: f64) -> RateLimitDecision {
+        RateLimitDecision {
+            allowed,
+            limit: 10,
+            remaining,
+            reset_after: Duration::ZERO,
+            retry_after: Duration::from_secs_f64(retry_after),
+        }
+    }
+
+    #[test]
+    fn bucket_allows_its_capacity_then_refuses() {
+        let mut bucket = TokenBucket::full(&RULE, 0.0);
+
+        let decisions: Vec<_> = (0..3).map(|_| bucket.take(&RULE, 0.0)).collect();
+        assert!(decisions
+            .iter()
+            .all(|d| d.allowed && d.retry_after.is_zero()));
+        assert_eq!(
+            decisions.iter().map(|d| d.remaining).collect::<Vec<_>>(),
+            [2, 1, 0]
+        );
+
+        let refused = bucket.take(&RULE, 0.0);
+        assert!(!refused.allowed);
+        assert_eq!(refused.limit, 3);
+        assert_eq!(refused.retry_after, Duration::from_secs(1));
+        assert_eq!(refused.reset_after, Duration::from_secs(3));
+    }
+
+    #[test]
+    fn bucket_refills_evenly_and_caps_at_capacity() {
+        let mut bucket = TokenBucket::full(&RULE, 0.0);
+        for _ in 0..3 {
+            bucket.take(&RULE, 0.0);
+        }
+
+        let refused = bucket.take(&RULE, 0.5);
+        assert!(!refused.allowed);
+        assert_eq!(refused.retry_after, Duration::from_millis(500));
+
+        assert!(bucket.take(&RULE, 1.0).allowed);
+        assert!(!bucket.take(&RULE, 1.0).allowed);
+
+        // A long idle period refills to capacity, not beyond
+        assert!(bucket.is_full(&RULE, 100.0));
+        let after_idle = bucket.take(&RULE, 100.0);
+        assert!(after_idle.allowed);
+        assert_eq!(after_idle.remaining, 2);
+    }
+
+    #[test]
+    fn clock_going_backwards_does_not_add_tokens() {
+        let mut bucket = TokenBucket::full(&RULE, 10.0);
+        for _ in 0..3 {
+            bucket.take(&RULE, 10.0);
+        }
+
+        assert!(!bucket.take(&RULE, 5.0).allowed);
+        assert!(!bucket.take(&RULE, 10.5).allowed);
+        assert!(bucket.take(&RULE, 11.0).allowed);
+    }
+
+    #[test]
+    fn strictest_prefers_refusals_then_the_tightest_bucket() {
+        let open = decision(true, 5, 0.0);
+        let tight = decision(true, 1, 0.0);
+        let refused_soon = decision(false, 0, 1.0);
+        let refused_later = decision(false, 0, 30.0);
+
+        assert_eq!(open.strictest(tight), tight);
+        assert_eq!(tight.strictest(open), tight);
+        assert_eq!(open.strictest(refused_soon), refused_soon);
+        assert_eq!(refused_soon.strictest(open), refused_soon);
+        assert_eq!(refused_soon.strictest(refused_later), refused_later);
+        assert_eq!(refused_later.strictest(refused_soon), refused_later);
+    }
+
+    #[test]
+    fn rules_are_validated_and_filtered_by_group() {
+        let rules = RateLimitRules::from_json(
+            r#"[
+                { "group": "auth", "identity": "ip", "capacity": 10, "period_secs": 60 },
+                { "group": "transfer", "identity": "user", "capacity": 20, "period_secs": 60 },
+                { "group": "transfer", "identity": "api_key", "capacity": 120, "period_secs": 60 }
+            ]"#,
+        )
+        .unwrap();
+
+        assert_eq!(rules.rules_for(RateLimitGroup::Transfer).count(), 2);
+        assert_eq!(rules.rules_for(RateLimitGroup::Api).count(), 0);
+
+        let invalid = [
+            r#"[{ "group": "auth", "identity": "ip", "capacity": 0, "period_secs": 60 }]"#,
+            r#"[{ "group": "auth", "identity": "ip", "capacity": 10, "period_secs": 0 }]"#,
+            r#"[
+                { "group": "auth", "identity": "ip", "capacity": 10, "period_secs": 60 },
+                { "group": "auth", "identity": "ip", "capacity": 5, "period_secs": 60 }
+            ]"#,
+        ];
+
+        for json in invalid {
+            assert!(RateLimitRules::from_json(json).is_err(), "{}", json);
+        }
+    }
+}
@@ -0,0 +1,121 @@
+use std::{fmt, str::FromStr};
+
+use chrono::{Days, Months, NaiveDateTime};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum BillingInterval {
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+impl BillingInterval {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BillingInterval::Daily => "daily",
+            BillingInterval::Weekly => "weekly",
+            BillingInterval::Monthly => "monthly",
+            BillingInterval::Yearly => "yearly",
+        }
+    }
+
+    // The `count`th occurrence after `anchor`. Month based intervals clamp to the last day of
+    // shorter months (Jan 31 -> Feb 28), so callers keep the anchor fixed and raise `count`
+    // instead of feeding a result back in, which would leave every later date on the 28th
+    pub fn next_occurrence(&self, anchor: NaiveDateTime, count: u32) -> Option<NaiveDateTime> {
+        match self {
+            BillingInterval::Daily => anchor.checked_add_days(Days::new(count as u64)),
+            BillingInterval::Weekly => anchor.checked_add_days(Days::new(count as u64 * 7)),
+            BillingInterval::Monthly => anchor.checked_add_months(Months::new(count)),
+            BillingInterval::Yearly => {
+                anchor.checked_add_months(Months::new(count.checked_mul(12)?))
+            }
+        }
+    }
+}
+
+impl fmt::Display for BillingInterval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for BillingInterval {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_lowercase().as_str() {
+            "daily" => Ok(BillingInterval::Daily),
+            "weekly" => Ok(BillingInterval::Weekly),
+            "monthly" => Ok(BillingInterval::Monthly),
+            "yearly" => Ok(BillingInterval::Yearly),
+            _ => Err(format!("Unsupported billing interval '{}'", value)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::*;
+
+    fn at(year: i32, month: u32, day: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(year, month, day)
+            .unwrap()
+            .and_hms_opt(9, 30, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn monthly_from_month_end_keeps_the_billing_day() {
+        let anchor = at(2023, 1, 31);
+        let monthly = BillingInterval::Monthly;
+
+        assert_eq!(monthly.next_occurrence(anchor, 1), Some(at(2023, 2, 28)));
+        assert_eq!(monthly.next_occurrence(anchor, 2), Some(at(2023, 3, 31)));
+        assert_eq!(monthly.next_occurrence(anchor, 3), Some(at(2023, 4, 30)));
+        assert_eq!(monthly.next_occurrence(anchor, 13), Some(at(2024, 2, 29)));
+    }
+
+    #[test]
+    fn chaining_occurrences_drifts_to_the_shortest_month() {
+        let monthly = BillingInterval::Monthly;
+        let february = monthly.next_occurrence(at(2023, 1, 31), 1).unwrap();
+
+        assert_eq!(monthly.next_occurrence(february, 1), Some(at(2023, 3, 28)));
+    }
+
+    #[test]
+    fn yearly_from_leap_day_returns_to_it_in_leap_years() {
+        let anchor = at(2024, 2, 29);
+        let yearly = BillingInterval::Yearly;
+
+        assert_eq!(yearly.next_occurrence(anchor, 1), Some(at(2025, 2, 28)));
+        assert_eq!(yearly.next_occurrence(anchor, 4), Some(at(2028, 2, 29)));
+    }
+
+    #[test]
+    fn daily_and_weekly_cross_month_and_year_ends() {
+        assert_eq!(
+            BillingInterval::Daily.next_occurrence(at(2023, 12, 31), 1),
+            Some(at(2024, 1, 1))
+        );
+        assert_eq!(
+            BillingInterval::Weekly.next_occurrence(at(2024, 2, 26), 1),
+            Some(at(2024, 3, 4))
+        );
+    }
+
+    #[test]
+    fn out_of_range_occurrence_is_none() {
+        assert_eq!(
+            BillingInterval::Yearly.next_occurrence(at(2024, 1, 1), u32::MAX),
+            None
+        );
+    }
+}
@@ -0,0 +1,289 @@
+use std::{collections::HashSet, fmt, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+// Ordered by severity so the strictest outcome of several matching rules wins
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum RiskAction {
+    Allow,
+    Review,
+    Block,
+}
+
+impl RiskAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RiskAction::Allow => "allow",
+            RiskAction::Review => "review",
+            RiskAction::Block => "block",
+        }
+    }
+}
+
+impl fmt::Display for RiskAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum RiskOperation {
+    Topup,
+    Transfer,
+    Withdraw,
+}
+
+impl RiskOperation {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RiskOperation::Topup => "topup",
+            RiskOperation::Transfer => "transfer",
+            RiskOperation::Withdraw => "withdraw",
+        }
+    }
+}
+
+impl fmt::Display for RiskOperation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for RiskOperation {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_lowercase().as_str() {
+            "topup" => Ok(RiskOperation::Topup),
+            "transfer" => Ok(RiskOperation::Transfer),
+            "withdraw" => Ok(RiskOperation::Withdraw),
+            _ => Err(format!("Unsupported risk operation '{}'", value)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RiskCheck {
+    // More than max_count operations of the same kind within the window
+    Velocity {
+        max_count: u64,
+        window_minutes: i64,
+    },
+    // A transfer of at least min_amount to someone the sender has never paid
+    NewRecipient {
+        min_amount: i32,
+    },
+    // A transfer back to a user who paid the sender within the window
+    RoundTrip {
+        window_minutes: i64,
+    },
+    // Accounts younger than account_age_hours moving more than max_amount
+    NewAccount {
+        account_age_hours: i64,
+        max_amount: i32,
+    },
+}
+
+/// One risk rule from the RISK_RULES_FILE, for example:
+///
+/// ```json
+/// [
+///   { "name": "transfer-burst", "type": "velocity", "operations": ["transfer"], "max_count": 5, "window_minutes": 10, "action": "review" },
+///   { "name": "large-new-payee", "type": "new_recipient", "min_amount": 5000000, "action": "review" },
+///   { "name": "round-trip", "type": "round_trip", "window_minutes": 60, "action": "block" },
+///   { "name": "fresh-account", "type": "new_account", "operations": ["withdraw"], "account_age_hours": 24, "max_amount": 500000, "action": "block" }
+/// ]
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RiskRule {
+    pub name: String,
+    // Applies to every operation when omitted
+    #[serde(default)]
+    pub operations: Vec<RiskOperation>,
+    pub action: RiskAction,
+    #[serde(flatten)]
+    pub check: RiskCheck,
+}
+
+impl RiskRule {
+    fn validate(&self) -> Result<(), String> {
+        if self.name.trim().is_empty() {
+            return Err("Risk rule name is required".to_string());
+        }
+
+        let invalid = |reason: &str| Err(format!("Risk rule '{}': {}", self.name, reason));
+
+        if self.action == RiskAction::Allow {
+            return invalid("action must be review or block");
+        }
+
+        let transfer_only = matches!(
+            self.check,
+            RiskCheck::NewRecipient { .. } | RiskCheck::RoundTrip { .. }
+        );
+
+        if transfer_only
+            && self
+                .operations
+                .iter()
+                .any(|operation| *operation != RiskOperation::Transfer)
+        {
+            return invalid("only applies to transfers");
+        }
+
+        match self.check {
+            RiskCheck::Velocity {
+                max_count,
+                window_minutes,
+            } if max_count == 0 || window_minutes <= 0 => {
+                invalid("max_count and window_minutes must be positive")
+            }
+            RiskCheck::NewRecipient { min_amount } if min_amount < 0 => {
+                invalid("min_amount must not be negative")
+            }
+            RiskCheck::RoundTrip { window_minutes } if window_minutes <= 0 => {
+                invalid("window_minutes must be positive")
+            }
+            RiskCheck::NewAccount {
+                account_age_hours,
+                max_amount,
+            } if account_age_hours <= 0 || max_amount < 0 => {
+                invalid("account_age_hours must be positive and max_amount not negative")
+            }
+            _ => Ok(()),
+        }
+    }
+
+    pub fn applies_to(&self, operation: RiskOperation) -> bool {
+        let supported = match self.check {
+            RiskCheck::NewRecipient { .. } | RiskCheck::RoundTrip { .. } => {
+                operation == RiskOperation::Transfer
+            }
+            _ => true,
+        };
+
+        supported && (self.operations.is_empty() || self.operations.contains(&operation))
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct RiskRules {
+    rules: Vec<RiskRule>,
+}
+
+impl RiskRules {
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let rules: Vec<RiskRule> =
+            serde_json::from_str(json).map_err(|e| format!("Invalid risk rules: {}", e))?;
+
+        let mut names = HashSet::new();
+
+        for rule in &rules {
+            rule.validate()?;
+
+            if !names.insert(rule.name.as_str()) {
+                return Err(format!("Duplicate risk rule name '{}'", rule.name));
+            }
+        }
+
+        Ok(Self { rules })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    pub fn rules_for(&self, operation: RiskOperation) -> impl Iterator<Item = &RiskRule> {
+        self.rules
+            .iter()
+            .filter(move |rule| rule.applies_to(operation))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RULES: &str = r#"[
+        { "name": "transfer-burst", "type": "velocity", "operations": ["transfer"], "max_count": 5, "window_minutes": 10, "action": "review" },
+        { "name": "large-new-payee", "type": "new_recipient", "min_amount": 5000000, "action": "review" },
+        { "name": "round-trip", "type": "round_trip", "window_minutes": 60, "action": "block" },
+        { "name": "fresh-account", "type": "new_account", "account_age_hours": 24, "max_amount": 500000, "action": "block" }
+    ]"#;
+
+    fn names(rules: &RiskRules, operation: RiskOperation) -> Vec<&str> {
+        rules
+            .rules_for(operation)
+            .map(|rule| rule.name.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn rules_are_selected_by_operation() {
+        let rules = RiskRules::from_json(RULES).unwrap();
+
+        assert_eq!(
+            names(&rules, RiskOperation::Transfer),
+            [
+                "transfer-burst",
+                "large-new-payee",
+                "round-trip",
+                "fresh-account"
+            ]
+        );
+        assert_eq!(names(&rules, RiskOperation::Topup), ["fresh-account"]);
+        assert_eq!(names(&rules, RiskOperation::Withdraw), ["fresh-account"]);
+    }
+
+    #[test]
+    fn transfer_only_checks_never_apply_to_other_operations() {
+        let rule = RiskRule {
+            name: "payee".to_string(),
+            operations: Vec::new(),
+            action: RiskAction::Review,
+            check: RiskCheck::NewRecipient { min_amount: 0 },
+        };
+
+        assert!(rule.applies_to(RiskOperation::Transfer));
+        assert!(!rule.applies_to(RiskOperation::Topup));
+        assert!(!rule.applies_to(RiskOperation::Withdraw));
+    }
+
+    #[test]
+    fn strictest_action_wins() {
+        let actions = [RiskAction::Review, RiskAction::Block, RiskAction::Allow];
+
+        assert_eq!(actions.iter().max(), Some(&RiskAction::Block));
+        assert!(RiskAction::Allow < RiskAction::Review);
+    }
+
+    #[test]
+    fn invalid_rules_are_rejected() {
+        let invalid = [
+            r#"[{ "name": "", "type": "round_trip", "window_minutes": 60, "action": "block" }]"#,
+            r#"[{ "name": "a", "type": "round_trip", "window_minutes": 60, "action": "allow" }]"#,
+            r#"[{ "name": "a", "type": "round_trip", "operations": ["topup"], "window_minutes": 60, "action": "block" }]"#,
+            r#"[{ "name": "a", "type": "velocity", "max_count": 0, "window_minutes": 10, "action": "review" }]"#,
+            r#"[{ "name": "a", "type": "new_account", "account_age_hours": 0, "max_amount": 1, "action": "block" }]"#,
+            r#"[{ "name": "a", "type": "unknown", "action": "block" }]"#,
+            r#"[
+                { "name": "a", "type": "round_trip", "window_minutes": 60, "action": "block" },
+                { "name": "a", "type": "round_trip", "window_minutes": 30, "action": "review" }
+            ]"#,
+        ];
+
+        for json in invalid {
+            assert!(RiskRules::from_json(json).is_err(), "accepted {}", json);
+        }
+    }
+
+    #[test]
+    fn operation_names_parse_case_insensitively() {
+        assert_eq!("Transfer".parse(), Ok(RiskOperation::Transfer));
+        assert!("payout".parse::<RiskOperation>().is_err());
+    }
+}
@@ -0,0 +1,23 @@
+use std::{future::Future, time::Duration};
+
+use tokio::time::{interval, MissedTickBehavior};
+use tracing::info;
+
+// Runs `job` every `period` on the tokio runtime until the process exits
+pub fn spawn_job<F, Fut>(name: &'static str, period: Duration, job: F)
+where
+    F: Fn() -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = ()> + Send + 'static,
+{
+    tokio::spawn(async move {
+        let mut ticker = interval(period);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Skip);
+
+        info!("Scheduled job '{}' every {:?}", name, period);
+
+        loop {
+            ticker.tick().await;
+            job().await;
+        }
+    });
+}
@@ -0,0 +1,263 @@
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+/// One watchlist entry. A CSV list needs a header row naming these fields, for example:
+///
+/// ```text
+/// reference,name,source
+/// SDN-10001,John Doe,OFAC SDN
+/// ```
+///
+/// and a JSON list is an array of the same objects. Aliases are listed as extra rows
+/// sharing the reference of the primary name.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WatchlistEntry {
+    #[serde(default)]
+    pub reference: Option<String>,
+    pub name: String,
+    #[serde(default)]
+    pub source: Option<String>,
+}
+
+impl WatchlistEntry {
+    // Identifies the listed party, so an operator's decision on a match sticks
+    pub fn key(&self) -> String {
+        self.reference
+            .as_deref()
+            .map(str::trim)
+            .filter(|reference| !reference.is_empty())
+            .map(str::to_string)
+            .unwrap_or_else(|| normalize_name(&self.name))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct IndexedEntry {
+    entry: WatchlistEntry,
+    normalized: String,
+    sorted: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct WatchlistMatch<'a> {
+    pub entry: &'a WatchlistEntry,
+    pub score: f64,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Watchlist {
+    entries: Vec<IndexedEntry>,
+}
+
+impl Watchlist {
+    pub fn new(entries: Vec<WatchlistEntry>) -> Result<Self, String> {
+        let entries = entries
+            .into_iter()
+            .enumerate()
+            .map(|(index, entry)| {
+                let normalized = normalize_name(&entry.name);
+
+                if normalized.is_empty() {
+                    return Err(format!("Watchlist entry {} has no name", index + 1));
+                }
+
+                Ok(IndexedEntry {
+                    sorted: sort_tokens(&normalized),
+                    normalized,
+                    entry,
+                })
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+
+        Ok(Self { entries })
+    }
+
+    pub fn from_csv(data: &[u8]) -> Result<Self, String> {
+        let mut reader = csv::ReaderBuilder::new()
+            .trim(csv::Trim::All)
+            .from_reader(data);
+
+        let entries = reader
+            .deserialize::<WatchlistEntry>()
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| format!("Invalid watchlist CSV: {}", e))?;
+
+        Self::new(entries)
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let entries: Vec<WatchlistEntry> =
+            serde_json::from_str(json).map_err(|e| format!("Invalid watchlist JSON: {}", e))?;
+
+        Self::new(entries)
+    }
+
+    // The format follows the file extension, .csv or .json
+    pub fn load(path: &str) -> Result<Self, String> {
+        let content =
+            std::fs::read(path).map_err(|e| format!("Failed to read watchlist {}: {}", path, e))?;
+
+        let extension = Path::new(path)
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .unwrap_or_default()
+            .to_lowercase();
+
+        match extension.as_str() {
+            "csv" => Self::from_csv(&content),
+            "json" => Self::from_json(&String::from_utf8_lossy(&content)),
+            _ => Err(format!("Watchlist {} must be a .csv or .json file", path)),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    // Returns every entry whose name scores at least the threshold, best match first
+    pub fn screen(&self, name: &str, threshold: f64) -> Vec<WatchlistMatch<'_>> {
+        let normalized = normalize_name(name);
+
+        if normalized.is_empty() {
+            return Vec::new();
+        }
+
+        let sorted = sort_tokens(&normalized);
+
+        let mut matches: Vec<WatchlistMatch<'_>> = self
+            .entries
+            .iter()
+            .filter_map(|indexed| {
+                // Comparing sorted tokens as well catches "Doe John" against "John Doe"
+                let score = strsim::jaro_winkler(&normalized, &indexed.normalized)
+                    .max(strsim::jaro_winkler(&sorted, &indexed.sorted));
+
+                (score >= threshold).then_some(WatchlistMatch {
+                    entry: &indexed.entry,
+                    score,
+                })
+            })
+            .collect();
+
+        matches.sort_by(|a, b| b.score.total_cmp(&a.score));
+        matches
+    }
+}
+
+// Lowercases and strips punctuation so "O'Brien,  Jane" and "obrien jane" compare equal
+pub fn normalize_name(name: &str) -> String {
+    name.split_whitespace()
+        .map(|token| {
+            token
+                .chars()
+                .filter(|c| c.is_alphanumeric())
+                .flat_map(char::to_lowercase)
+                .collect::<String>()
+        })
+        .filter(|token| !token.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn sort_tokens(normalized: &str) -> String {
+    let mut tokens: Vec<&str> = normalized.split(' ').collect();
+    tokens.sort_unstable();
+    tokens.join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const THRESHOLD: f64 = 0.9;
+
+    fn watchlist() -> Watchlist {
+        Watchlist::from_csv(
+            b"reference,name,source\n\
+              SDN-10001,John Doe,OFAC SDN\n\
+              SDN-10001,Johnny Doe,OFAC SDN\n\
+              SDN-10002,Jane O'Brien,OFAC SDN\n\
+              ,Ivan Petrov,\n",
+        )
+        .unwrap()
+    }
+
+    fn references(matches: &[WatchlistMatch<'_>]) -> Vec<String> {
+        matches.iter().map(|m| m.entry.key()).collect()
+    }
+
+    #[test]
+    fn normalize_name_strips_punctuation_case_and_spacing() {
+        assert_eq!(normalize_name("O'Brien,  Jane"), "obrien jane");
+        assert_eq!(normalize_name("  JOHN\tDOE "), "john doe");
+        assert_eq!(normalize_name("- . -"), "");
+    }
+
+    #[test]
+    fn exact_and_reordered_names_match() {
+        let watchlist = watchlist();
+
+        let exact = watchlist.screen("John Doe", THRESHOLD);
+        assert_eq!(exact[0].entry.name, "John Doe");
+        assert_eq!(exact[0].score, 1.0);
+
+        let reordered = watchlist.screen("Doe, John", THRESHOLD);
+        assert_eq!(reordered[0].entry.name, "John Doe");
+        assert_eq!(reordered[0].score, 1.0);
+
+        let punctuated = watchlist.screen("jane obrien", THRESHOLD);
+        assert_eq!(references(&punctuated), ["SDN-10002"]);
+    }
+
+    #[test]
+    fn small_misspellings_match() {
+        let watchlist = watchlist();
+        let matches = watchlist.screen("Jon Doe", THRESHOLD);
+
+        assert!(references(&matches).contains(&"SDN-10001".to_string()));
+        assert!(matches.iter().all(|m| m.score >= THRESHOLD));
+    }
+
+    #[test]
+    fn matches_are_ordered_best_first() {
+        let watchlist = watchlist();
+        let matches = watchlist.screen("Johnny Doe", 0.8);
+
+        assert_eq!(matches[0].entry.name, "Johnny Doe");
+        assert!(matches
+            .windows(2)
+            .all(|pair| pair[0].score >= pair[1].score));
+    }
+
+    #[test]
+    fn unrelated_and_empty_names_do_not_match() {
+        let watchlist = watchlist();
+
+        assert!(watchlist.screen("Siti Rahmawati", THRESHOLD).is_empty());
+        assert!(watchlist.screen(" , ", 0.0).is_empty());
+    }
+
+    #[test]
+    fn entries_without_a_reference_are_keyed_by_name() {
+        let watchlist = watchlist();
+        let matches = watchlist.screen("Ivan Petrov", THRESHOLD);
+
+        assert_eq!(references(&matches), ["ivan petrov"]);
+    }
+
+    #[test]
+    fn entries_without_a_name_are_rejected() {
+        assert!(Watchlist::from_json(r#"[{ "reference": "X-1", "name": "--" }]"#).is_err());
+        assert_eq!(
+            Watchlist::from_json(r#"[{ "name": "John Doe" }]"#)
+                .unwrap()
+                .len(),
+            1
+        );
+    }
+}
@@ -0,0 +1,184 @@
+use std::borrow::Cow;
+
+use chrono::{DateTime, Utc};
+use serde_json::{json, Map, Value};
+use validator::{ValidationError, ValidationErrors, ValidationErrorsKind};
+
+use crate::utils::{
+    payment_method_validator::payment_method_validator, random_vcc::is_valid_vcc,
+    virtual_account::is_valid_va_number,
+};
+
+// Every movement of funds executed as a transfer, topup or withdraw shares this floor
+pub const MINIMUM_AMOUNT: i32 = 50_000;
+
+pub const MAX_REASON_LENGTH: u64 = 500;
+
+pub fn invalid(code: &'static str, message: impl Into<Cow<'static, str>>) -> ValidationError {
+    ValidationError::new(code).with_message(message.into())
+}
+
+pub fn validate_minimum_amount(amount: i32) -> Result<(), ValidationError> {
+    if amount < MINIMUM_AMOUNT {
+        return Err(invalid("minimum_amount", "Amount must be at least 50,000"));
+    }
+
+    Ok(())
+}
+
+pub fn validate_payment_method(method: &str) -> Result<(), ValidationError> {
+    // The rule list reports whether the method is missing, not whether it is allowed
+    if payment_method_validator(method) {
+        return Err(invalid(
+            "unsupported_payment_method",
+            "Payment method is not supported",
+        ));
+    }
+
+    Ok(())
+}
+
+pub fn validate_not_blank(value: &str) -> Result<(), ValidationError> {
+    if value.trim().is_empty() {
+        return Err(invalid("required", "This field is required"));
+    }
+
+    Ok(())
+}
+
+// A noc_transfer account number or an email alias
+pub fn validate_recipient(recipient: &str) -> Result<(), ValidationError> {
+    let recipient = recipient.trim();
+
+    if recipient.is_empty() {
+        return Err(invalid("required", "Recipient is required"));
+    }
+
+    if !recipient.contains('@') && !is_valid_vcc(recipient) {
+        return Err(invalid(
+            "invalid_account_number",
+            "Recipient account number is invalid",
+        ));
+    }
+
+    Ok(())
+}
+
+pub fn validate_bank_account_number(account_number: &str) -> Result<(), ValidationError> {
+    let account_number = account_number.trim();
+
+    if !(6..=20).contains(&account_number.len())
+        || !account_number.chars().all(|c| c.is_ascii_digit())
+    {
+        return Err(invalid(
+            "invalid_account_number",
+            "Account number must be 6 to 20 digits",
+        ));
+    }
+
+    Ok(())
+}
+
+pub fn validate_va_number(va_number: &str) -> Result<(), ValidationError> {
+    if !is_valid_va_number(va_number) {
+        return Err(invalid(
+            "invalid_va_number",
+            "Invalid virtual account number",
+        ));
+    }
+
+    Ok(())
+}
+
+pub fn validate_in_future(at: &DateTime<Utc>) -> Result<(), ValidationError> {
+    if *at <= Utc::now() {
+        return Err(invalid("not_in_future", "Time must be in the future"));
+    }
+
+    Ok(())
+}
+
+pub fn validate_not_in_future(at: &DateTime<Utc>) -> Result<(), ValidationError> {
+    if *at > Utc::now() {
+        return Err(invalid("in_future", "Time cannot be in the future"));
+    }
+
+    Ok(())
+}
+
+/// Flattens validation errors into `field path -> [{code, message}]`.
+///
+/// Nested structs are joined with dots and list entries are indexed, so an invalid invoice
+/// item shows up as `items[2].unit_price`. Errors that span several fields are reported
+/// under `__all__` of the struct they belong to.
+pub fn validation_details(errors: &ValidationErrors) -> Value {
+    let mut fields = Map::new();
+
+    for (path, error) in flatten(errors) {
+        let entry = json!({
+            "code": error.code,
+            "message": message_of(error),
+        });
+
+        match fields.get_mut(&path) {
+            Some(Value::Array(entries)) => entries.push(entry),
+            _ => {
+                fields.insert(path, Value::Array(vec![entry]));
+            }
+        }
+    }
+
+    json!({ "fields": fields })
+}
+
+// One "path: message" line per error, for places that store a reason as text
+pub fn validation_messages(errors: &ValidationErrors) -> Vec<String> {
+    flatten(errors)
+        .into_iter()
+        .map(|(path, error)| match path.as_str() {
+            "__all__" => message_of(error),
+            _ => format!("{}: {}", path, message_of(error)),
+        })
+        .collect()
+}
+
+fn message_of(error: &ValidationError) -> String {
+    error
+        .message
+        .as_deref()
+        .unwrap_or(error.code.as_ref())
+        .to_string()
+}
+
+fn flatten(errors: &ValidationErrors) -> Vec<(String, &ValidationError)> {
+    let mut flattened = Vec::new();
+    collect(errors, "", &mut flattened);
+    flattened.sort_by(|a, b| a.0.cmp(&b.0));
+    flattened
+}
+
+fn collect<'a>(
+    errors: &'a ValidationErrors,
+    prefix: &str,
+    out: &mut Vec<(String, &'a ValidationError)>,
+) {
+    for (field, kind) in errors.errors() {
+        let path = if prefix.is_empty() {
+            field.to_string()
+        } else {
+            format!("{}.{}", prefix, field)
+        };
+
+        match kind {
+            ValidationErrorsKind::Field(field_errors) => {
+                out.extend(field_errors.iter().map(|error| (path.clone(), error)));
+            }
+            ValidationErrorsKind::Struct(nested) => collect(nested, &path, out),
+            ValidationErrorsKind::List(items) => {
+                for (index, nested) in items {
+                    collect(nested, &format!("{}[{}]", path, index), out);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+use super::random_vcc::{calculate_check_digit, is_valid_vcc};
+
+pub fn generate_va_number(prefix: &str, user_id: i32) -> String {
+    // Bank prefix followed by the user id padded to 10 digits
+    let partial_number = format!("{}{:010}", prefix, user_id);
+    let check_digit = calculate_check_digit(&partial_number);
+
+    format!("{}{}", partial_number, check_digit)
+}
+
+pub fn is_valid_va_number(va_number: &str) -> bool {
+    is_valid_vcc(va_number)
+}
+
+// Banks sign the raw notification body with HMAC-SHA256 and send the digest hex encoded
+pub fn verify_notification_signature(secret: &str, body: &[u8], signature: &str) -> bool {
+    let Ok(signature) = hex::decode(signature.trim()) else {
+        return false;
+    };
+
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any length");
+    mac.update(body);
+
+    // Constant time, so the digest cannot be guessed byte by byte
+    mac.verify_slice(&signature).is_ok()
+}